@@ -0,0 +1,157 @@
+use openapiv3::OpenAPI;
+use serde::Deserialize;
+
+use crate::conformance::{
+    ConformanceReport, HttpRequest, HttpResponse, check_request, check_response,
+};
+
+/// The conformance of a single recorded request/response pair
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HarEntryReport {
+    pub method: String,
+    pub url: String,
+    pub request: ConformanceReport,
+    pub response: ConformanceReport,
+}
+
+/// Check every entry of a HAR (HTTP Archive) recording
+///
+/// URLs are matched by path only; scheme and host are ignored and server base
+/// paths are stripped like in [`crate::conformance::match_path`]. Base64 encoded
+/// response bodies are not inspected.
+pub fn check_har(spec: &OpenAPI, har: &str) -> Result<Vec<HarEntryReport>, serde_json::Error> {
+    let har: Har = serde_json::from_str(har)?;
+
+    Ok(har
+        .log
+        .entries
+        .into_iter()
+        .map(|entry| {
+            let request = entry.request.to_http();
+            let response = entry.response.to_http();
+            HarEntryReport {
+                request: check_request(spec, &request),
+                response: check_response(spec, &request.method, &request.path, &response),
+                method: entry.request.method,
+                url: entry.request.url,
+            }
+        })
+        .collect())
+}
+
+#[derive(Deserialize)]
+struct Har {
+    log: HarLog,
+}
+
+#[derive(Deserialize)]
+struct HarLog {
+    entries: Vec<HarEntry>,
+}
+
+#[derive(Deserialize)]
+struct HarEntry {
+    request: HarRequest,
+    response: HarResponse,
+}
+
+#[derive(Deserialize)]
+struct HarHeader {
+    name: String,
+    value: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HarRequest {
+    method: String,
+    url: String,
+    #[serde(default)]
+    headers: Vec<HarHeader>,
+    post_data: Option<HarPostData>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HarPostData {
+    mime_type: Option<String>,
+    text: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct HarResponse {
+    status: u16,
+    #[serde(default)]
+    headers: Vec<HarHeader>,
+    content: Option<HarContent>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HarContent {
+    mime_type: Option<String>,
+    text: Option<String>,
+    encoding: Option<String>,
+}
+
+impl HarRequest {
+    fn to_http(&self) -> HttpRequest {
+        let mut request = HttpRequest::new(&self.method, request_target(&self.url));
+        request.headers = headers(&self.headers);
+
+        if let Some(post_data) = &self.post_data {
+            with_content_type(&mut request.headers, post_data.mime_type.as_deref());
+            request.body = post_data.text.clone();
+        }
+        request
+    }
+}
+
+impl HarResponse {
+    fn to_http(&self) -> HttpResponse {
+        let mut response = HttpResponse::new(self.status);
+        response.headers = headers(&self.headers);
+
+        if let Some(content) = &self.content {
+            with_content_type(&mut response.headers, content.mime_type.as_deref());
+            if content.encoding.as_deref() != Some("base64") {
+                response.body = content.text.clone();
+            }
+        }
+        response
+    }
+}
+
+fn headers(headers: &[HarHeader]) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|header| (header.name.clone(), header.value.clone()))
+        .collect()
+}
+
+/// Fall back to the recorded MIME type when no `Content-Type` header was captured
+fn with_content_type(headers: &mut Vec<(String, String)>, mime_type: Option<&str>) {
+    let has_content_type = headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case("content-type"));
+    if let Some(mime_type) = mime_type.filter(|mime_type| !mime_type.is_empty())
+        && !has_content_type
+    {
+        headers.push(("Content-Type".to_string(), mime_type.to_string()));
+    }
+}
+
+/// Strip scheme, authority and fragment from a URL
+fn request_target(url: &str) -> &str {
+    let url = url.split('#').next().unwrap_or_default();
+    match url.find("://") {
+        Some(scheme_end) => {
+            let after_scheme = &url[scheme_end + 3..];
+            after_scheme
+                .find(['/', '?'])
+                .map(|i| &after_scheme[i..])
+                .unwrap_or("/")
+        }
+        None => url,
+    }
+}
@@ -0,0 +1,167 @@
+use indexmap::IndexMap;
+use openapiv3::{OpenAPI, Operation, PathItem, ReferenceOr};
+use regex::Regex;
+
+/// A path item whose template matched a concrete request path
+#[derive(Debug, Clone)]
+pub struct PathMatch<'a> {
+    /// The path template as declared in `openapi.paths`, e.g. `/pets/{petId}`
+    pub template: &'a str,
+    pub path_item: &'a PathItem,
+    /// Raw (still percent-encoded) values of the templated segments
+    pub path_params: IndexMap<String, String>,
+}
+
+/// An operation matched by method and path
+#[derive(Debug, Clone)]
+pub struct OperationMatch<'a> {
+    pub template: &'a str,
+    /// Lowercase HTTP method, e.g. `get`
+    pub method: &'a str,
+    pub path_item: &'a PathItem,
+    pub operation: &'a Operation,
+    /// Raw (still percent-encoded) values of the templated segments
+    pub path_params: IndexMap<String, String>,
+}
+
+/// Find the path item whose template matches `path`
+///
+/// Literal templates win over templated ones, and among templated paths the one
+/// with the fewest parameters wins, so `/pets/mine` is preferred over
+/// `/pets/{petId}`. Base paths of the declared servers are stripped when the
+/// path does not match as is.
+pub fn match_path<'a>(spec: &'a OpenAPI, path: &str) -> Option<PathMatch<'a>> {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+
+    if let Some(found) = match_path_exact(spec, path) {
+        return Some(found);
+    }
+
+    server_base_paths(spec).iter().find_map(|base| {
+        let rest = path.strip_prefix(base.as_str())?;
+        if !rest.is_empty() && !rest.starts_with('/') {
+            return None;
+        }
+        match_path_exact(spec, if rest.is_empty() { "/" } else { rest })
+    })
+}
+
+/// Find the operation for an HTTP method and request path
+pub fn find_operation<'a>(
+    spec: &'a OpenAPI,
+    method: &str,
+    path: &str,
+) -> Option<OperationMatch<'a>> {
+    let found = match_path(spec, path)?;
+    let method = method.to_ascii_lowercase();
+    let (method, operation) = found
+        .path_item
+        .iter()
+        .find(|(candidate, _)| *candidate == method)?;
+
+    Some(OperationMatch {
+        template: found.template,
+        method,
+        path_item: found.path_item,
+        operation,
+        path_params: found.path_params,
+    })
+}
+
+fn match_path_exact<'a>(spec: &'a OpenAPI, path: &str) -> Option<PathMatch<'a>> {
+    let path = normalize(path);
+    let mut best: Option<(usize, PathMatch<'a>)> = None;
+
+    for (template, path_item) in &spec.paths.paths {
+        let ReferenceOr::Item(path_item) = path_item else {
+            continue;
+        };
+        let Some(path_params) = match_template(normalize(template), path) else {
+            continue;
+        };
+
+        let score = path_params.len();
+        if best
+            .as_ref()
+            .is_none_or(|(best_score, _)| score < *best_score)
+        {
+            best = Some((
+                score,
+                PathMatch {
+                    template,
+                    path_item,
+                    path_params,
+                },
+            ));
+        }
+    }
+
+    best.map(|(_, found)| found)
+}
+
+/// Match a single template, returning the captured parameters
+fn match_template(template: &str, path: &str) -> Option<IndexMap<String, String>> {
+    if !template.contains('{') {
+        return (template == path).then(IndexMap::new);
+    }
+
+    let mut pattern = String::from("^");
+    let mut names = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let end = start + rest[start..].find('}')?;
+        pattern.push_str(&regex::escape(&rest[..start]));
+        pattern.push_str("([^/]+)");
+        names.push(rest[start + 1..end].to_string());
+        rest = &rest[end + 1..];
+    }
+    pattern.push_str(&regex::escape(rest));
+    pattern.push('$');
+
+    let captures = Regex::new(&pattern).ok()?.captures(path)?;
+    Some(
+        names
+            .into_iter()
+            .zip(captures.iter().skip(1))
+            .map(|(name, value)| {
+                (
+                    name,
+                    value.map(|m| m.as_str()).unwrap_or_default().to_string(),
+                )
+            })
+            .collect(),
+    )
+}
+
+fn normalize(path: &str) -> &str {
+    if path.len() > 1 {
+        path.trim_end_matches('/')
+    } else {
+        path
+    }
+}
+
+/// The path component of every server URL, with variables set to their defaults
+fn server_base_paths(spec: &OpenAPI) -> Vec<String> {
+    spec.servers
+        .iter()
+        .filter_map(|server| {
+            let mut url = server.url.clone();
+            for (name, variable) in server.variables.iter().flatten() {
+                url = url.replace(&format!("{{{}}}", name), &variable.default);
+            }
+            let path = match url.find("://") {
+                Some(scheme_end) => {
+                    let after_scheme = &url[scheme_end + 3..];
+                    after_scheme
+                        .find('/')
+                        .map(|i| &after_scheme[i..])
+                        .unwrap_or("")
+                }
+                None => url.as_str(),
+            };
+            let path = path.trim_end_matches('/');
+            (!path.is_empty()).then(|| path.to_string())
+        })
+        .collect()
+}
@@ -0,0 +1,863 @@
+//! Conformance checking of HTTP traffic against an OpenAPI specification
+//!
+//! This module matches concrete requests and responses to the operations they
+//! belong to and reports every way in which they deviate from the specification:
+//! parameters, headers, content types, bodies and status codes. Recorded traffic
+//! in HAR format can be checked in one go with [`check_har`].
+
+mod har;
+mod matcher;
+mod params;
+
+use std::fmt;
+
+use indexmap::IndexMap;
+use openapiv3::{
+    MediaType, OpenAPI, Parameter, ParameterSchemaOrContent, ReferenceOr, Response, Responses,
+    Schema, StatusCode,
+};
+use serde_json::Value;
+
+use crate::resolve_ref::{
+    HeaderResolver, OpenApiResolver, ParameterResolver, RequestBodyResolver, ResponseResolver,
+    resolve_schema_fully,
+};
+use crate::validate::{ValidationError, ValidationOptions, validate_value_with};
+use params::{RequestParts, extract_parameter, raw_parameter};
+
+pub(crate) use params::find_header;
+
+// Re-export the public API
+pub use har::{HarEntryReport, check_har};
+pub use matcher::{OperationMatch, PathMatch, find_operation, match_path};
+
+/// A concrete HTTP request
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: String,
+    /// Request path without the query string, e.g. `/pets/42`
+    pub path: String,
+    /// Raw query string without the leading `?`
+    pub query: Option<String>,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+impl HttpRequest {
+    /// Create a request from a method and a request target such as `/pets?limit=10`
+    pub fn new(method: impl Into<String>, target: &str) -> Self {
+        let (path, query) = match target.split_once('?') {
+            Some((path, query)) => (path, Some(query.to_string())),
+            None => (target, None),
+        };
+        HttpRequest {
+            method: method.into(),
+            path: path.to_string(),
+            query,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn with_body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+}
+
+/// A concrete HTTP response
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+impl HttpResponse {
+    pub fn new(status: u16) -> Self {
+        HttpResponse {
+            status,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn with_body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+}
+
+/// The part of a request or response an issue was found in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Location {
+    Operation,
+    Path,
+    Query,
+    Header,
+    Cookie,
+    Body,
+    Status,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Location::Operation => "operation",
+            Location::Path => "path",
+            Location::Query => "query",
+            Location::Header => "header",
+            Location::Cookie => "cookie",
+            Location::Body => "body",
+            Location::Status => "status",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A single deviation from the specification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub location: Location,
+    /// Name of the parameter or header, if the issue concerns one
+    pub name: Option<String>,
+    pub message: String,
+    /// JSON pointer into the offending value, for schema validation failures
+    pub instance_path: Option<String>,
+    /// Location of the failing keyword, for schema validation failures
+    pub schema_path: Option<String>,
+}
+
+impl Issue {
+    fn new(location: Location, name: Option<&str>, message: impl Into<String>) -> Self {
+        Issue {
+            location,
+            name: name.map(String::from),
+            message: message.into(),
+            instance_path: None,
+            schema_path: None,
+        }
+    }
+
+    fn from_validation(location: Location, name: Option<&str>, error: ValidationError) -> Self {
+        Issue {
+            location,
+            name: name.map(String::from),
+            message: error.message,
+            instance_path: Some(error.instance_path),
+            schema_path: Some(error.schema_path),
+        }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.location)?;
+        if let Some(name) = &self.name {
+            write!(f, " '{}'", name)?;
+        }
+        if let Some(instance_path) = self.instance_path.as_deref().filter(|p| !p.is_empty()) {
+            write!(f, " at {}", instance_path)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// The outcome of checking a request or a response
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConformanceReport {
+    /// Path template and lowercase method of the matched operation
+    pub operation: Option<(String, String)>,
+    pub issues: Vec<Issue>,
+}
+
+impl ConformanceReport {
+    pub fn is_conformant(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Check a request against the operation it matches
+///
+/// ### Examples
+///
+/// ```rust
+/// # use rustval::conformance::{HttpRequest, check_request};
+/// # let spec: openapiv3::OpenAPI = serde_yaml::from_str(r#"
+/// # openapi: 3.0.3
+/// # info: { title: Pets, version: "1.0" }
+/// # paths:
+/// #   /pets/{petId}:
+/// #     get:
+/// #       parameters:
+/// #         - { name: petId, in: path, required: true, schema: { type: integer } }
+/// #       responses: { "200": { description: ok } }
+/// # "#).unwrap();
+/// let report = check_request(&spec, &HttpRequest::new("GET", "/pets/abc"));
+/// assert_eq!(report.issues.len(), 1);
+/// assert!(check_request(&spec, &HttpRequest::new("GET", "/pets/42")).is_conformant());
+/// ```
+pub fn check_request(spec: &OpenAPI, request: &HttpRequest) -> ConformanceReport {
+    let Some(found) = find_operation(spec, &request.method, &request.path) else {
+        return unmatched(&request.method, &request.path);
+    };
+
+    let mut report = ConformanceReport {
+        operation: Some((found.template.to_string(), found.method.to_string())),
+        issues: Vec::new(),
+    };
+    let parts = RequestParts::new(
+        &found.path_params,
+        request.query.as_deref(),
+        &request.headers,
+    );
+
+    for parameter in operation_parameters(spec, &found) {
+        check_parameter(spec, &parameter, &parts, &mut report.issues);
+    }
+
+    let body = request.body.as_deref().filter(|body| !body.is_empty());
+    let request_body = found
+        .operation
+        .request_body
+        .as_ref()
+        .and_then(|body| RequestBodyResolver::new().resolve_reference_or(body, spec));
+
+    match (request_body, body) {
+        (Some(request_body), Some(body)) => check_body(
+            spec,
+            &request_body.content,
+            find_header(&request.headers, "content-type"),
+            body,
+            &ValidationOptions::request(),
+            &mut report.issues,
+        ),
+        (Some(request_body), None) if request_body.required => {
+            report
+                .issues
+                .push(Issue::new(Location::Body, None, "request body is required"))
+        }
+        (None, Some(_)) => report.issues.push(Issue::new(
+            Location::Body,
+            None,
+            "operation does not accept a request body",
+        )),
+        _ => {}
+    }
+
+    report
+}
+
+/// Check a response to a request for `method` and `path`
+///
+/// The response is selected by exact status code first, then by status range
+/// such as `4XX`, and finally falls back to `default`.
+pub fn check_response(
+    spec: &OpenAPI,
+    method: &str,
+    path: &str,
+    response: &HttpResponse,
+) -> ConformanceReport {
+    let Some(found) = find_operation(spec, method, path) else {
+        return unmatched(method, path);
+    };
+
+    let mut report = ConformanceReport {
+        operation: Some((found.template.to_string(), found.method.to_string())),
+        issues: Vec::new(),
+    };
+
+    let Some(declared) = select_response(&found.operation.responses, response.status)
+        .and_then(|declared| ResponseResolver::new().resolve_reference_or(declared, spec))
+    else {
+        report.issues.push(Issue::new(
+            Location::Status,
+            None,
+            format!("status {} is not documented", response.status),
+        ));
+        return report;
+    };
+
+    check_response_headers(spec, &declared, &response.headers, &mut report.issues);
+
+    let body = response.body.as_deref().filter(|body| !body.is_empty());
+    match body {
+        Some(_) if declared.content.is_empty() => report.issues.push(Issue::new(
+            Location::Body,
+            None,
+            format!("status {} does not declare a body", response.status),
+        )),
+        Some(body) => check_body(
+            spec,
+            &declared.content,
+            find_header(&response.headers, "content-type"),
+            body,
+            &ValidationOptions::response(),
+            &mut report.issues,
+        ),
+        None => {}
+    }
+
+    report
+}
+
+fn unmatched(method: &str, path: &str) -> ConformanceReport {
+    ConformanceReport {
+        operation: None,
+        issues: vec![Issue::new(
+            Location::Operation,
+            None,
+            format!(
+                "no operation matches {} {}",
+                method.to_ascii_uppercase(),
+                path
+            ),
+        )],
+    }
+}
+
+/// Path item parameters merged with operation parameters, the latter taking precedence
+fn operation_parameters(spec: &OpenAPI, found: &OperationMatch<'_>) -> Vec<Parameter> {
+    let resolver = ParameterResolver::new();
+    let mut merged: IndexMap<(String, Location), Parameter> = IndexMap::new();
+
+    for parameter in found
+        .path_item
+        .parameters
+        .iter()
+        .chain(&found.operation.parameters)
+    {
+        if let Some(parameter) = resolver.resolve_reference_or(parameter, spec) {
+            let key = (
+                parameter.parameter_data_ref().name.clone(),
+                location_of(&parameter),
+            );
+            merged.insert(key, parameter);
+        }
+    }
+
+    merged.into_values().collect()
+}
+
+fn location_of(parameter: &Parameter) -> Location {
+    match parameter {
+        Parameter::Path { .. } => Location::Path,
+        Parameter::Query { .. } => Location::Query,
+        Parameter::Header { .. } => Location::Header,
+        Parameter::Cookie { .. } => Location::Cookie,
+    }
+}
+
+fn check_parameter(
+    spec: &OpenAPI,
+    parameter: &Parameter,
+    parts: &RequestParts<'_>,
+    issues: &mut Vec<Issue>,
+) {
+    let data = parameter.parameter_data_ref();
+    let location = location_of(parameter);
+    let name = Some(data.name.as_str());
+    let options = ValidationOptions::request();
+
+    let (value, schema) = match &data.format {
+        ParameterSchemaOrContent::Schema(schema) => {
+            let resolved = resolve_schema_fully(schema, spec);
+            let value = extract_parameter(parameter, resolved.as_ref(), parts, spec);
+            (value, Some(schema.clone()))
+        }
+        ParameterSchemaOrContent::Content(content) => {
+            let media_type = content.values().next();
+            let value = raw_parameter(parameter, parts)
+                .map(|raw| serde_json::from_str(&raw).unwrap_or(Value::String(raw)));
+            (
+                value,
+                media_type.and_then(|media_type| media_type.schema.clone()),
+            )
+        }
+    };
+
+    let Some(value) = value else {
+        if data.required {
+            issues.push(Issue::new(location, name, "required parameter is missing"));
+        }
+        return;
+    };
+
+    if let Some(schema) = schema {
+        for error in validate_value_with(&value, &schema, spec, &options) {
+            issues.push(Issue::from_validation(location, name, error));
+        }
+    }
+}
+
+fn check_response_headers(
+    spec: &OpenAPI,
+    declared: &Response,
+    headers: &[(String, String)],
+    issues: &mut Vec<Issue>,
+) {
+    let resolver = HeaderResolver::new();
+    let options = ValidationOptions::response();
+
+    for (name, header) in &declared.headers {
+        // A `Content-Type` header definition is ignored per the specification
+        if name.eq_ignore_ascii_case("content-type") {
+            continue;
+        }
+        let Some(header) = resolver.resolve_reference_or(header, spec) else {
+            continue;
+        };
+
+        let Some(raw) = find_header(headers, name) else {
+            if header.required {
+                issues.push(Issue::new(
+                    Location::Header,
+                    Some(name),
+                    "required header is missing",
+                ));
+            }
+            continue;
+        };
+
+        if let ParameterSchemaOrContent::Schema(schema) = &header.format {
+            let resolved = resolve_schema_fully(schema, spec);
+            let value = params::coerce(raw, resolved.as_ref(), spec);
+            for error in validate_value_with(&value, schema, spec, &options) {
+                issues.push(Issue::from_validation(Location::Header, Some(name), error));
+            }
+        }
+    }
+}
+
+/// Check a body against the declared content, negotiating the media type
+fn check_body(
+    spec: &OpenAPI,
+    content: &IndexMap<String, MediaType>,
+    content_type: Option<&str>,
+    body: &str,
+    options: &ValidationOptions,
+    issues: &mut Vec<Issue>,
+) {
+    let Some(content_type) = content_type else {
+        issues.push(Issue::new(
+            Location::Header,
+            Some("Content-Type"),
+            "body is present but Content-Type is missing",
+        ));
+        return;
+    };
+
+    let Some(media_type) = select_media_type(content, content_type) else {
+        let declared: Vec<&str> = content.keys().map(String::as_str).collect();
+        issues.push(Issue::new(
+            Location::Header,
+            Some("Content-Type"),
+            format!(
+                "content type {} is not one of {}",
+                content_type,
+                declared.join(", ")
+            ),
+        ));
+        return;
+    };
+
+    let Some(schema) = &media_type.schema else {
+        return;
+    };
+
+    let essence = essence(content_type);
+    let value = if is_json(&essence) {
+        match serde_json::from_str(body) {
+            Ok(value) => value,
+            Err(error) => {
+                issues.push(Issue::new(
+                    Location::Body,
+                    None,
+                    format!("body is not valid JSON: {}", error),
+                ));
+                return;
+            }
+        }
+    } else if essence == "application/x-www-form-urlencoded" {
+        let Some(resolved) = resolve_schema_fully(schema, spec) else {
+            return;
+        };
+        params::form_object(params::parse_query(body), &resolved, spec)
+    } else if essence.starts_with("text/") && is_string_schema(schema, spec) {
+        Value::String(body.to_string())
+    } else {
+        // Other media types, such as binary uploads, are not inspected
+        return;
+    };
+
+    for error in validate_value_with(&value, schema, spec, options) {
+        issues.push(Issue::from_validation(Location::Body, None, error));
+    }
+}
+
+/// Pick the media type matching `content_type`: exact, then `type/*`, then `*/*`
+fn select_media_type<'c>(
+    content: &'c IndexMap<String, MediaType>,
+    content_type: &str,
+) -> Option<&'c MediaType> {
+    let essence = essence(content_type);
+    let wildcard = essence
+        .split_once('/')
+        .map(|(main, _)| format!("{}/*", main))
+        .unwrap_or_default();
+
+    [essence.as_str(), wildcard.as_str(), "*/*"]
+        .iter()
+        .find_map(|candidate| content.iter().find(|(name, _)| essence_eq(name, candidate)))
+        .map(|(_, media_type)| media_type)
+}
+
+/// The media type without parameters, lowercased
+pub(crate) fn essence(content_type: &str) -> String {
+    content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+fn essence_eq(declared: &str, candidate: &str) -> bool {
+    essence(declared) == candidate
+}
+
+pub(crate) fn is_json(essence: &str) -> bool {
+    essence == "application/json" || essence.ends_with("+json")
+}
+
+fn is_string_schema(schema: &ReferenceOr<Schema>, spec: &OpenAPI) -> bool {
+    matches!(
+        resolve_schema_fully(schema, spec).map(|schema| schema.schema_kind),
+        Some(openapiv3::SchemaKind::Type(openapiv3::Type::String(_)))
+    )
+}
+
+/// Select the documented response for a status code
+pub(crate) fn select_response(
+    responses: &Responses,
+    status: u16,
+) -> Option<&ReferenceOr<Response>> {
+    responses
+        .responses
+        .get(&StatusCode::Code(status))
+        .or_else(|| responses.responses.get(&StatusCode::Range(status / 100)))
+        .or(responses.default.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r##"
+openapi: 3.0.3
+info: { title: Pets, version: "1.0" }
+servers:
+  - url: https://api.example.com/v1
+paths:
+  /pets:
+    get:
+      parameters:
+        - name: limit
+          in: query
+          schema: { type: integer, maximum: 100 }
+        - name: tags
+          in: query
+          style: pipeDelimited
+          explode: false
+          schema: { type: array, items: { type: string } }
+        - name: filter
+          in: query
+          style: deepObject
+          schema:
+            type: object
+            properties:
+              age: { type: integer }
+        - name: X-Request-Id
+          in: header
+          required: true
+          schema: { type: string, format: uuid }
+      responses:
+        "200":
+          description: ok
+          headers:
+            X-Total:
+              required: true
+              schema: { type: integer }
+          content:
+            application/json:
+              schema:
+                type: array
+                items: { $ref: "#/components/schemas/Pet" }
+        "4XX":
+          description: client error
+          content:
+            application/problem+json:
+              schema: { $ref: "#/components/schemas/Problem" }
+        default:
+          description: unexpected
+    post:
+      requestBody:
+        required: true
+        content:
+          application/json:
+            schema: { $ref: "#/components/schemas/Pet" }
+          application/x-www-form-urlencoded:
+            schema: { $ref: "#/components/schemas/Pet" }
+      responses:
+        "201": { description: created }
+  /pets/mine:
+    get:
+      responses:
+        "200": { description: ok }
+  /pets/{petId}:
+    parameters:
+      - name: petId
+        in: path
+        required: true
+        schema: { type: integer }
+    get:
+      responses:
+        "200": { description: ok }
+  /pets/{petId}/tags{fields}:
+    get:
+      parameters:
+        - $ref: "#/components/parameters/PetId"
+        - name: fields
+          in: path
+          required: true
+          style: matrix
+          explode: true
+          schema: { type: array, items: { type: string } }
+      responses:
+        "200": { description: ok }
+components:
+  parameters:
+    PetId:
+      name: petId
+      in: path
+      required: true
+      schema: { type: integer }
+  schemas:
+    Pet:
+      type: object
+      required: [name]
+      properties:
+        id: { type: integer, readOnly: true }
+        name: { type: string }
+        age: { type: integer, minimum: 0 }
+    Problem:
+      type: object
+      required: [title]
+      properties:
+        title: { type: string }
+"##;
+
+    fn spec() -> OpenAPI {
+        serde_yaml::from_str(SPEC).unwrap()
+    }
+
+    fn found(issues: &[Issue]) -> Vec<(Location, Option<&str>, Option<&str>)> {
+        issues
+            .iter()
+            .map(|i| (i.location, i.name.as_deref(), i.instance_path.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn test_match_path() {
+        let spec = spec();
+        assert_eq!(
+            match_path(&spec, "/pets/mine").unwrap().template,
+            "/pets/mine"
+        );
+        assert_eq!(
+            match_path(&spec, "/pets/12/").unwrap().template,
+            "/pets/{petId}"
+        );
+        assert_eq!(
+            match_path(&spec, "/v1/pets?limit=1").unwrap().template,
+            "/pets"
+        );
+
+        let found = find_operation(&spec, "GET", "/pets/7/tags;fields=a;fields=b").unwrap();
+        assert_eq!(found.template, "/pets/{petId}/tags{fields}");
+        assert_eq!(found.path_params["petId"], "7");
+        assert!(find_operation(&spec, "DELETE", "/pets").is_none());
+    }
+
+    #[test]
+    fn test_check_request_parameters() {
+        let spec = spec();
+
+        let request = HttpRequest::new("GET", "/pets?limit=10&tags=a%7Cb&filter%5Bage%5D=3")
+            .with_header("x-request-id", "0b7c1a52-57a4-4a53-a2d4-3f1d7c1e0c8e");
+        assert_eq!(check_request(&spec, &request).issues, vec![]);
+
+        let request = HttpRequest::new("GET", "/pets?limit=500&filter[age]=old");
+        let report = check_request(&spec, &request);
+        assert_eq!(
+            report.operation,
+            Some(("/pets".to_string(), "get".to_string()))
+        );
+        assert_eq!(
+            found(&report.issues),
+            vec![
+                (Location::Query, Some("limit"), Some("")),
+                (Location::Query, Some("filter"), Some("/age")),
+                (Location::Header, Some("X-Request-Id"), None),
+            ]
+        );
+
+        let report = check_request(&spec, &HttpRequest::new("GET", "/pets/x/tags;fields=a"));
+        assert_eq!(
+            found(&report.issues),
+            vec![(Location::Path, Some("petId"), Some(""))]
+        );
+
+        let report = check_request(&spec, &HttpRequest::new("PUT", "/pets"));
+        assert_eq!(
+            found(&report.issues),
+            vec![(Location::Operation, None, None)]
+        );
+    }
+
+    #[test]
+    fn test_check_request_body() {
+        let spec = spec();
+
+        let request = HttpRequest::new("POST", "/pets")
+            .with_header("Content-Type", "application/json; charset=utf-8")
+            .with_body(r#"{ "name": "Rex", "age": 3 }"#);
+        assert!(check_request(&spec, &request).is_conformant());
+
+        let request = HttpRequest::new("POST", "/pets")
+            .with_header("Content-Type", "application/x-www-form-urlencoded")
+            .with_body("name=Rex&age=-1");
+        assert_eq!(
+            found(&check_request(&spec, &request).issues),
+            vec![(Location::Body, None, Some("/age"))]
+        );
+
+        let request = HttpRequest::new("POST", "/pets")
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"{ "id": 1, "name": "Rex" }"#);
+        assert_eq!(
+            found(&check_request(&spec, &request).issues),
+            vec![(Location::Body, None, Some("/id"))]
+        );
+
+        let request = HttpRequest::new("POST", "/pets")
+            .with_header("Content-Type", "text/xml")
+            .with_body("<pet/>");
+        assert_eq!(
+            found(&check_request(&spec, &request).issues),
+            vec![(Location::Header, Some("Content-Type"), None)]
+        );
+
+        let report = check_request(&spec, &HttpRequest::new("POST", "/pets"));
+        assert_eq!(report.issues[0].message, "request body is required");
+    }
+
+    #[test]
+    fn test_check_response() {
+        let spec = spec();
+
+        let response = HttpResponse::new(200)
+            .with_header("Content-Type", "application/json")
+            .with_header("X-Total", "1")
+            .with_body(r#"[{ "id": 1, "name": "Rex" }]"#);
+        assert!(check_response(&spec, "GET", "/pets", &response).is_conformant());
+
+        let response = HttpResponse::new(200)
+            .with_header("Content-Type", "application/json")
+            .with_body(r#"[{ "id": "1" }]"#);
+        assert_eq!(
+            found(&check_response(&spec, "GET", "/pets", &response).issues),
+            vec![
+                (Location::Header, Some("X-Total"), None),
+                (Location::Body, None, Some("/0")),
+                (Location::Body, None, Some("/0/id")),
+            ]
+        );
+
+        // 404 falls back to the 4XX range
+        let response = HttpResponse::new(404)
+            .with_header("Content-Type", "application/problem+json")
+            .with_body("{}");
+        assert_eq!(
+            found(&check_response(&spec, "GET", "/pets", &response).issues),
+            vec![(Location::Body, None, Some(""))]
+        );
+
+        // 500 falls back to default, which has no body
+        let response = HttpResponse::new(500);
+        assert!(check_response(&spec, "GET", "/pets", &response).is_conformant());
+
+        let response = HttpResponse::new(500).with_body("oops");
+        assert_eq!(
+            found(&check_response(&spec, "GET", "/pets", &response).issues),
+            vec![(Location::Body, None, None)]
+        );
+
+        let report = check_response(&spec, "GET", "/pets/mine", &HttpResponse::new(404));
+        assert_eq!(found(&report.issues), vec![(Location::Status, None, None)]);
+    }
+
+    #[test]
+    fn test_check_har() {
+        let spec = spec();
+        let har = r#"{
+          "log": {
+            "entries": [
+              {
+                "request": {
+                  "method": "GET",
+                  "url": "https://api.example.com/v1/pets?limit=5",
+                  "headers": [{ "name": "X-Request-Id", "value": "0b7c1a52-57a4-4a53-a2d4-3f1d7c1e0c8e" }]
+                },
+                "response": {
+                  "status": 200,
+                  "headers": [{ "name": "X-Total", "value": "1" }],
+                  "content": { "mimeType": "application/json", "text": "[{\"name\":\"Rex\"}]" }
+                }
+              },
+              {
+                "request": {
+                  "method": "POST",
+                  "url": "https://api.example.com/v1/pets",
+                  "headers": [],
+                  "postData": { "mimeType": "application/json", "text": "{}" }
+                },
+                "response": { "status": 418, "headers": [], "content": { "mimeType": "text/plain", "text": "" } }
+              }
+            ]
+          }
+        }"#;
+
+        let reports = check_har(&spec, har).unwrap();
+        assert_eq!(reports.len(), 2);
+        assert!(reports[0].request.is_conformant());
+        assert!(reports[0].response.is_conformant());
+        assert_eq!(
+            found(&reports[1].request.issues),
+            vec![(Location::Body, None, Some(""))]
+        );
+        assert_eq!(
+            found(&reports[1].response.issues),
+            vec![(Location::Status, None, None)]
+        );
+    }
+}
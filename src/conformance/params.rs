@@ -0,0 +1,493 @@
+use indexmap::IndexMap;
+use openapiv3::{
+    CookieStyle, HeaderStyle, OpenAPI, Parameter, PathStyle, QueryStyle, ReferenceOr, Schema,
+    SchemaKind, Type,
+};
+use serde_json::{Map, Value};
+
+use crate::resolve_ref::resolve_schema_ref;
+
+/// The raw parameter sources of a request
+pub(crate) struct RequestParts<'r> {
+    pub path_params: &'r IndexMap<String, String>,
+    pub query: Vec<(String, String)>,
+    pub headers: &'r [(String, String)],
+    pub cookies: Vec<(String, String)>,
+}
+
+impl<'r> RequestParts<'r> {
+    pub fn new(
+        path_params: &'r IndexMap<String, String>,
+        query: Option<&str>,
+        headers: &'r [(String, String)],
+    ) -> Self {
+        let cookies = headers
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case("cookie"))
+            .flat_map(|(_, value)| parse_cookies(value))
+            .collect();
+        RequestParts {
+            path_params,
+            query: query.map(parse_query).unwrap_or_default(),
+            headers,
+            cookies,
+        }
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(self.headers, name)
+    }
+}
+
+/// Case-insensitive header lookup
+pub(crate) fn find_header<'h>(headers: &'h [(String, String)], name: &str) -> Option<&'h str> {
+    headers
+        .iter()
+        .find(|(candidate, _)| candidate.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// The shape of a parameter value, which decides how it is serialized
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Shape {
+    Primitive,
+    Array,
+    Object,
+}
+
+/// Deserialize a parameter from the request according to its `style` and `explode`
+///
+/// Returns `None` when the parameter is absent. Values are coerced to the types
+/// declared by the schema, so that `?limit=10` becomes the JSON number `10`;
+/// values that cannot be coerced are kept as strings and reported by validation.
+pub(crate) fn extract_parameter(
+    parameter: &Parameter,
+    schema: Option<&Schema>,
+    parts: &RequestParts<'_>,
+    spec: &OpenAPI,
+) -> Option<Value> {
+    let data = parameter.parameter_data_ref();
+    let name = data.name.as_str();
+    let shape = schema.map_or(Shape::Primitive, |schema| shape_of(schema, spec));
+
+    match parameter {
+        Parameter::Path { style, .. } => {
+            let raw = parts.path_params.get(name)?;
+            let explode = data.explode.unwrap_or(false);
+            let raw = match style {
+                PathStyle::Simple => raw.as_str(),
+                PathStyle::Label => raw.strip_prefix('.')?,
+                PathStyle::Matrix => {
+                    return Some(from_matrix(name, raw, explode, shape, schema, spec));
+                }
+            };
+            let separator = match (style, explode) {
+                (PathStyle::Label, true) => '.',
+                _ => ',',
+            };
+            Some(from_delimited(raw, separator, explode, shape, schema, spec))
+        }
+        Parameter::Header { style, .. } => {
+            let HeaderStyle::Simple = style;
+            let raw = parts.header(name)?;
+            let explode = data.explode.unwrap_or(false);
+            Some(from_delimited(raw, ',', explode, shape, schema, spec))
+        }
+        Parameter::Query { style, .. } => {
+            let explode = data.explode.unwrap_or(matches!(style, QueryStyle::Form));
+            from_pairs(name, &parts.query, style, explode, shape, schema, spec)
+        }
+        Parameter::Cookie { style, .. } => {
+            let CookieStyle::Form = style;
+            let explode = data.explode.unwrap_or(true);
+            from_pairs(
+                name,
+                &parts.cookies,
+                &QueryStyle::Form,
+                explode,
+                shape,
+                schema,
+                spec,
+            )
+        }
+    }
+}
+
+/// Read the raw, undecoded string of a parameter that uses `content`
+pub(crate) fn raw_parameter(parameter: &Parameter, parts: &RequestParts<'_>) -> Option<String> {
+    let name = parameter.parameter_data_ref().name.as_str();
+    match parameter {
+        Parameter::Path { .. } => parts
+            .path_params
+            .get(name)
+            .map(|raw| percent_decode(raw, false)),
+        Parameter::Header { .. } => parts.header(name).map(String::from),
+        Parameter::Query { .. } => lookup(&parts.query, name).map(String::from),
+        Parameter::Cookie { .. } => lookup(&parts.cookies, name).map(String::from),
+    }
+}
+
+/// `simple` and `label` serialization: values joined by a separator
+fn from_delimited(
+    raw: &str,
+    separator: char,
+    explode: bool,
+    shape: Shape,
+    schema: Option<&Schema>,
+    spec: &OpenAPI,
+) -> Value {
+    match shape {
+        Shape::Primitive => coerce(&percent_decode(raw, false), schema, spec),
+        Shape::Array => array_of(
+            raw.split(separator).map(|v| percent_decode(v, false)),
+            schema,
+            spec,
+        ),
+        Shape::Object => {
+            let pieces: Vec<String> = raw
+                .split(separator)
+                .map(|v| percent_decode(v, false))
+                .collect();
+            let pairs = if explode {
+                pairs_from_assignments(&pieces)
+            } else {
+                pairs_from_flat_list(&pieces)
+            };
+            object_of(pairs, schema, spec)
+        }
+    }
+}
+
+/// `matrix` serialization: `;name=value`, `;name=a,b` or `;name=a;name=b`
+fn from_matrix(
+    name: &str,
+    raw: &str,
+    explode: bool,
+    shape: Shape,
+    schema: Option<&Schema>,
+    spec: &OpenAPI,
+) -> Value {
+    let assignments: Vec<String> = raw
+        .split(';')
+        .filter(|piece| !piece.is_empty())
+        .map(|piece| percent_decode(piece, false))
+        .collect();
+    let pairs = pairs_from_assignments(&assignments);
+    let own_values = || {
+        pairs
+            .iter()
+            .filter(|(key, _)| key == name)
+            .map(|(_, value)| value.clone())
+    };
+
+    match (shape, explode) {
+        (Shape::Primitive, _) => coerce(&own_values().next().unwrap_or_default(), schema, spec),
+        (Shape::Array, true) => array_of(own_values(), schema, spec),
+        (Shape::Array, false) => array_of(
+            own_values()
+                .next()
+                .unwrap_or_default()
+                .split(',')
+                .map(String::from),
+            schema,
+            spec,
+        ),
+        (Shape::Object, true) => object_of(pairs.clone(), schema, spec),
+        (Shape::Object, false) => {
+            let value = own_values().next().unwrap_or_default();
+            let pieces: Vec<String> = value.split(',').map(String::from).collect();
+            object_of(pairs_from_flat_list(&pieces), schema, spec)
+        }
+    }
+}
+
+/// `form`, `spaceDelimited`, `pipeDelimited` and `deepObject` serialization
+fn from_pairs(
+    name: &str,
+    pairs: &[(String, String)],
+    style: &QueryStyle,
+    explode: bool,
+    shape: Shape,
+    schema: Option<&Schema>,
+    spec: &OpenAPI,
+) -> Option<Value> {
+    if let QueryStyle::DeepObject = style {
+        let prefix = format!("{}[", name);
+        let members: Vec<(String, String)> = pairs
+            .iter()
+            .filter_map(|(key, value)| {
+                let member = key.strip_prefix(&prefix)?.strip_suffix(']')?;
+                Some((member.to_string(), value.clone()))
+            })
+            .collect();
+        return (!members.is_empty()).then(|| object_of(members, schema, spec));
+    }
+
+    match (shape, explode) {
+        (Shape::Primitive, _) => lookup(pairs, name).map(|raw| coerce(raw, schema, spec)),
+        (Shape::Array, true) => {
+            let values: Vec<String> = pairs
+                .iter()
+                .filter(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+                .collect();
+            (!values.is_empty()).then(|| array_of(values.into_iter(), schema, spec))
+        }
+        (Shape::Array, false) => {
+            let separator = match style {
+                QueryStyle::SpaceDelimited => ' ',
+                QueryStyle::PipeDelimited => '|',
+                _ => ',',
+            };
+            lookup(pairs, name)
+                .map(|raw| array_of(raw.split(separator).map(String::from), schema, spec))
+        }
+        (Shape::Object, true) => {
+            // Exploded objects spread their properties over the query string
+            let members: Vec<(String, String)> = object_property_names(schema)
+                .into_iter()
+                .filter_map(|property| {
+                    lookup(pairs, &property).map(|value| (property, value.to_string()))
+                })
+                .collect();
+            (!members.is_empty()).then(|| object_of(members, schema, spec))
+        }
+        (Shape::Object, false) => lookup(pairs, name).map(|raw| {
+            let pieces: Vec<String> = raw.split(',').map(String::from).collect();
+            object_of(pairs_from_flat_list(&pieces), schema, spec)
+        }),
+    }
+}
+
+fn lookup<'p>(pairs: &'p [(String, String)], name: &str) -> Option<&'p str> {
+    pairs
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// `a,1,b,2` -> `[(a, 1), (b, 2)]`
+fn pairs_from_flat_list(pieces: &[String]) -> Vec<(String, String)> {
+    pieces
+        .chunks(2)
+        .map(|chunk| (chunk[0].clone(), chunk.get(1).cloned().unwrap_or_default()))
+        .collect()
+}
+
+/// `a=1,b=2` -> `[(a, 1), (b, 2)]`
+fn pairs_from_assignments(pieces: &[String]) -> Vec<(String, String)> {
+    pieces
+        .iter()
+        .map(|piece| match piece.split_once('=') {
+            Some((key, value)) => (key.to_string(), value.to_string()),
+            None => (piece.clone(), String::new()),
+        })
+        .collect()
+}
+
+fn array_of(
+    values: impl Iterator<Item = String>,
+    schema: Option<&Schema>,
+    spec: &OpenAPI,
+) -> Value {
+    let items = schema.and_then(|schema| item_schema(schema, spec));
+    Value::Array(
+        values
+            .map(|value| coerce(&value, items.as_ref(), spec))
+            .collect(),
+    )
+}
+
+fn object_of(pairs: Vec<(String, String)>, schema: Option<&Schema>, spec: &OpenAPI) -> Value {
+    let mut map = Map::new();
+    for (key, value) in pairs {
+        let property = schema.and_then(|schema| property_schema(schema, &key, spec));
+        map.insert(key, coerce(&value, property.as_ref(), spec));
+    }
+    Value::Object(map)
+}
+
+/// Convert a raw string to the JSON type declared by the schema
+pub(crate) fn coerce(raw: &str, schema: Option<&Schema>, spec: &OpenAPI) -> Value {
+    let Some(schema) = schema else {
+        return Value::String(raw.to_string());
+    };
+    if schema.schema_data.nullable && raw == "null" {
+        return Value::Null;
+    }
+
+    let coerced = match type_name(schema, spec) {
+        Some("integer") => raw.parse::<i64>().ok().map(Value::from),
+        Some("number") => raw
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number),
+        Some("boolean") => match raw {
+            "true" => Some(Value::Bool(true)),
+            "false" => Some(Value::Bool(false)),
+            _ => None,
+        },
+        _ => None,
+    };
+    coerced.unwrap_or_else(|| Value::String(raw.to_string()))
+}
+
+fn shape_of(schema: &Schema, spec: &OpenAPI) -> Shape {
+    match type_name(schema, spec) {
+        Some("array") => Shape::Array,
+        Some("object") => Shape::Object,
+        _ => Shape::Primitive,
+    }
+}
+
+/// The declared type of a schema, looking through single-schema compositions
+fn type_name(schema: &Schema, spec: &OpenAPI) -> Option<&'static str> {
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::String(_)) => Some("string"),
+        SchemaKind::Type(Type::Number(_)) => Some("number"),
+        SchemaKind::Type(Type::Integer(_)) => Some("integer"),
+        SchemaKind::Type(Type::Boolean(_)) => Some("boolean"),
+        SchemaKind::Type(Type::Object(_)) => Some("object"),
+        SchemaKind::Type(Type::Array(_)) => Some("array"),
+        SchemaKind::Any(any) => any.typ.as_deref().map(static_type_name).or_else(|| {
+            if !any.properties.is_empty() {
+                Some("object")
+            } else if any.items.is_some() {
+                Some("array")
+            } else {
+                None
+            }
+        }),
+        SchemaKind::AllOf { all_of: list }
+        | SchemaKind::OneOf { one_of: list }
+        | SchemaKind::AnyOf { any_of: list } => list.iter().find_map(|item| match item {
+            ReferenceOr::Item(item) => type_name(item, spec),
+            ReferenceOr::Reference { reference } => {
+                resolve_schema_ref(reference, spec).and_then(|resolved| type_name(&resolved, spec))
+            }
+        }),
+        SchemaKind::Not { .. } => None,
+    }
+}
+
+/// Map a type name from a schema without a known `type` to a static string
+fn static_type_name(name: &str) -> &'static str {
+    match name {
+        "string" => "string",
+        "number" => "number",
+        "integer" => "integer",
+        "boolean" => "boolean",
+        "object" => "object",
+        "array" => "array",
+        _ => "",
+    }
+}
+
+fn resolve_boxed(schema: &ReferenceOr<Box<Schema>>, spec: &OpenAPI) -> Option<Schema> {
+    match schema {
+        ReferenceOr::Reference { reference } => resolve_schema_ref(reference, spec),
+        ReferenceOr::Item(schema) => Some(schema.as_ref().clone()),
+    }
+}
+
+fn item_schema(schema: &Schema, spec: &OpenAPI) -> Option<Schema> {
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::Array(array)) => resolve_boxed(array.items.as_ref()?, spec),
+        SchemaKind::Any(any) => resolve_boxed(any.items.as_ref()?, spec),
+        _ => None,
+    }
+}
+
+fn property_schema(schema: &Schema, name: &str, spec: &OpenAPI) -> Option<Schema> {
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(object)) => resolve_boxed(object.properties.get(name)?, spec),
+        SchemaKind::Any(any) => resolve_boxed(any.properties.get(name)?, spec),
+        _ => None,
+    }
+}
+
+fn object_property_names(schema: Option<&Schema>) -> Vec<String> {
+    match schema.map(|schema| &schema.schema_kind) {
+        Some(SchemaKind::Type(Type::Object(object))) => object.properties.keys().cloned().collect(),
+        Some(SchemaKind::Any(any)) => any.properties.keys().cloned().collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Split a query string into decoded name/value pairs
+pub(crate) fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (percent_decode(name, true), percent_decode(value, true))
+        })
+        .collect()
+}
+
+/// Split a `Cookie` header into name/value pairs
+pub(crate) fn parse_cookies(header: &str) -> Vec<(String, String)> {
+    header
+        .split(';')
+        .filter_map(|cookie| {
+            let (name, value) = cookie.trim().split_once('=')?;
+            Some((name.to_string(), percent_decode(value, false)))
+        })
+        .collect()
+}
+
+/// Decode `%XX` escapes, and `+` as a space in query strings
+pub(crate) fn percent_decode(input: &str, plus_as_space: bool) -> String {
+    let bytes = input.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("");
+                match u8::from_str_radix(hex, 16) {
+                    Ok(byte) => {
+                        decoded.push(byte);
+                        i += 3;
+                        continue;
+                    }
+                    Err(_) => decoded.push(b'%'),
+                }
+            }
+            b'+' if plus_as_space => decoded.push(b' '),
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Build an object from `application/x-www-form-urlencoded` pairs
+///
+/// Repeated keys are collected into an array when the property is an array.
+pub(crate) fn form_object(pairs: Vec<(String, String)>, schema: &Schema, spec: &OpenAPI) -> Value {
+    let mut map = Map::new();
+    for (key, value) in pairs {
+        let property = property_schema(schema, &key, spec);
+        let is_array = property
+            .as_ref()
+            .is_some_and(|property| shape_of(property, spec) == Shape::Array);
+
+        if is_array {
+            let items = property
+                .as_ref()
+                .and_then(|property| item_schema(property, spec));
+            let entry = map.entry(key).or_insert_with(|| Value::Array(Vec::new()));
+            if let Value::Array(values) = entry {
+                values.push(coerce(&value, items.as_ref(), spec));
+            }
+        } else {
+            let coerced = coerce(&value, property.as_ref(), spec);
+            map.insert(key, coerced);
+        }
+    }
+    Value::Object(map)
+}
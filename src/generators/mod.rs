@@ -0,0 +1,161 @@
+//! Code generation from the normalized API model
+//!
+//! Every generator consumes an [`ApiModel`](crate::ir::ApiModel) and produces a
+//! set of files. Writing them is left to the caller; [`GeneratedFile::write`]
+//! keeps hand-edited scaffolds that already exist.
+
+pub mod python;
+pub mod rust;
+pub mod typescript;
+mod writer;
+
+use std::io;
+use std::path::Path;
+
+use crate::ir::ApiModel;
+
+// Re-export the public API
+pub use writer::CodeWriter;
+
+/// A generated source file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedFile {
+    /// Path relative to the output directory, using `/` as separator
+    pub path: String,
+    pub contents: String,
+    /// Whether regeneration replaces the file; scaffolds are written once and
+    /// then belong to the user
+    pub overwrite: bool,
+}
+
+impl GeneratedFile {
+    pub fn new(path: impl Into<String>, contents: impl Into<String>) -> Self {
+        GeneratedFile {
+            path: path.into(),
+            contents: contents.into(),
+            overwrite: true,
+        }
+    }
+
+    /// A starting point for hand-written code, e.g. a handler stub, that is
+    /// never overwritten
+    pub fn scaffold(path: impl Into<String>, contents: impl Into<String>) -> Self {
+        GeneratedFile {
+            overwrite: false,
+            ..GeneratedFile::new(path, contents)
+        }
+    }
+
+    /// Writes the file below `root`, creating missing directories
+    ///
+    /// Returns `false` when the file is a scaffold that already exists and was
+    /// left untouched.
+    pub fn write(&self, root: &Path) -> io::Result<bool> {
+        let path = root.join(&self.path);
+        if !self.overwrite && path.exists() {
+            return Ok(false);
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, &self.contents)?;
+        Ok(true)
+    }
+}
+
+/// A code generation target
+pub trait Generator {
+    /// Short name of the target, e.g. `typescript-types`
+    fn name(&self) -> &str;
+
+    fn generate(&self, model: &ApiModel) -> Vec<GeneratedFile>;
+}
+
+/// Compare generated files with the expected outputs in
+/// `tests/generators/{case}`, or write them there when `RUSTVAL_BLESS` is set
+#[cfg(test)]
+pub(crate) fn assert_generated(case: &str, files: &[GeneratedFile]) {
+    fn expected_paths(root: &Path, dir: &Path, paths: &mut Vec<String>) {
+        for entry in std::fs::read_dir(dir).into_iter().flatten().flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                expected_paths(root, &path, paths);
+            } else if let Ok(relative) = path.strip_prefix(root) {
+                paths.push(relative.to_string_lossy().replace('\\', "/"));
+            }
+        }
+    }
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/generators")
+        .join(case);
+    if std::env::var_os("RUSTVAL_BLESS").is_some() {
+        let _ = std::fs::remove_dir_all(&root);
+        for file in files {
+            GeneratedFile::new(file.path.clone(), file.contents.clone())
+                .write(&root)
+                .unwrap();
+        }
+        return;
+    }
+
+    let mut expected = Vec::new();
+    expected_paths(&root, &root, &mut expected);
+    expected.sort();
+    let mut actual: Vec<String> = files.iter().map(|file| file.path.clone()).collect();
+    actual.sort();
+    assert_eq!(actual, expected, "files of {}", case);
+
+    for file in files {
+        let expected = std::fs::read_to_string(root.join(&file.path)).unwrap();
+        if let Some((line, (actual, expected))) = file
+            .contents
+            .lines()
+            .zip(expected.lines())
+            .enumerate()
+            .find(|(_, (actual, expected))| actual != expected)
+        {
+            panic!(
+                "{}/{} differs at line {}\nexpected: {}\n  actual: {}",
+                case,
+                file.path,
+                line + 1,
+                expected,
+                actual
+            );
+        }
+        assert_eq!(
+            file.contents, expected,
+            "{}/{} differs in length",
+            case, file.path
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_keeps_scaffolds() {
+        let root = std::env::temp_dir().join(format!("rustval-write-{}", std::process::id()));
+        let generated = GeneratedFile::new("src/server.ts", "// generated");
+        let scaffold = GeneratedFile::scaffold("src/handlers/listPets.ts", "// stub");
+        assert!(generated.write(&root).unwrap());
+        assert!(scaffold.write(&root).unwrap());
+
+        std::fs::write(root.join("src/handlers/listPets.ts"), "// edited").unwrap();
+        let regenerated = GeneratedFile::new("src/server.ts", "// regenerated");
+        assert!(regenerated.write(&root).unwrap());
+        assert!(!scaffold.write(&root).unwrap());
+        assert_eq!(
+            std::fs::read_to_string(root.join("src/server.ts")).unwrap(),
+            "// regenerated"
+        );
+        assert_eq!(
+            std::fs::read_to_string(root.join("src/handlers/listPets.ts")).unwrap(),
+            "// edited"
+        );
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,176 @@
+use crate::generators::python::types::{Declaration, ModelRenderer, sort_declarations};
+use crate::ir::{ApiModel, Content, Operation, Param, Response, Status};
+use crate::naming::{Casing, Language, NameAllocator, to_identifier, to_pascal_case};
+
+/// The Pydantic models of a model and the annotations every operation uses
+///
+/// Inline bodies and responses of operations are hoisted into the models file
+/// next to the component schemas, so the client only imports names.
+pub(crate) struct PythonApi<'a> {
+    /// Declarations in file order
+    pub declarations: Vec<Declaration>,
+    pub operations: Vec<OperationTypes<'a>>,
+    /// Names imported from `typing` by the models
+    pub typing: Vec<&'static str>,
+    /// Names imported from `pydantic` by the models
+    pub pydantic: Vec<&'static str>,
+    pub uses_datetime: bool,
+    pub uses_uuid: bool,
+    /// Python names that the client may not reuse
+    pub type_names: Vec<String>,
+}
+
+/// How an operation's inputs and outputs are annotated
+pub(crate) struct OperationTypes<'a> {
+    pub operation: &'a Operation,
+    /// snake_case method name
+    pub name: String,
+    /// Parameters of every location with their argument name and annotation,
+    /// path parameters first
+    pub params: Vec<(&'a Param, String, String)>,
+    pub body: Option<BodyType<'a>>,
+    /// Every declared response, successful ones first
+    pub responses: Vec<ResponseType<'a>>,
+}
+
+pub(crate) struct BodyType<'a> {
+    pub content: &'a Content,
+    pub required: bool,
+    pub ty: String,
+}
+
+pub(crate) struct ResponseType<'a> {
+    pub response: &'a Response,
+    /// The content a client reads, preferring JSON, with its annotation
+    pub content: Option<(&'a Content, String)>,
+}
+
+impl OperationTypes<'_> {
+    /// The `Accept` header asking for the media types the responses are read
+    /// as, needed only when a response declares several
+    pub fn accept(&self) -> Option<String> {
+        if self
+            .responses
+            .iter()
+            .all(|response| response.response.contents.len() < 2)
+        {
+            return None;
+        }
+        let mut media_types: Vec<&str> = Vec::new();
+        for (content, _) in self.responses.iter().filter_map(|r| r.content.as_ref()) {
+            if !media_types.contains(&content.media_type.as_str()) {
+                media_types.push(&content.media_type);
+            }
+        }
+        Some(media_types.join(", "))
+    }
+}
+
+impl<'a> PythonApi<'a> {
+    pub fn new(model: &'a ApiModel) -> Self {
+        let mut renderer = ModelRenderer::new(model);
+        let mut declarations = Vec::new();
+        for (name, ty) in &model.types {
+            declarations.push(renderer.component_declaration(name, ty));
+            declarations.extend(renderer.take_hoisted());
+        }
+        let type_names = model
+            .types
+            .keys()
+            .map(|name| renderer.type_name(name).to_string())
+            .collect();
+
+        let mut operations = Vec::new();
+        for operation in &model.operations {
+            let prefix = to_pascal_case(&operation.name);
+            let params: Vec<&Param> = operation
+                .path_params
+                .iter()
+                .chain(&operation.query_params)
+                .chain(&operation.header_params)
+                .chain(&operation.cookie_params)
+                .collect();
+
+            let mut arguments = NameAllocator::new();
+            for reserved in ["self", "body", "httpx"] {
+                arguments.reserve(reserved);
+            }
+            for (i, param) in params.iter().enumerate() {
+                arguments.request(
+                    format!("{:04}", i),
+                    to_identifier(&param.name, Casing::Snake, Language::Python),
+                );
+            }
+            let arguments = arguments.resolve().into_values();
+            let params = params
+                .into_iter()
+                .zip(arguments)
+                .map(|(param, argument)| {
+                    let hint = format!(
+                        "{}{}",
+                        prefix,
+                        to_identifier(&param.name, Casing::Pascal, Language::Python)
+                    );
+                    let ty = renderer.render(&param.ty, &hint);
+                    (param, argument, ty)
+                })
+                .collect();
+
+            let body = operation.body.as_ref().and_then(|body| {
+                let content = preferred_content(&body.contents)?;
+                Some(BodyType {
+                    content,
+                    required: body.required,
+                    ty: renderer.render(&content.ty, &format!("{}Body", prefix)),
+                })
+            });
+            let responses = operation
+                .responses
+                .iter()
+                .map(|response| {
+                    let status = match response.status {
+                        Status::Default => "Default".to_string(),
+                        status => status.to_string(),
+                    };
+                    let content = preferred_content(&response.contents).map(|content| {
+                        let hint = format!("{}{}Response", prefix, status);
+                        (content, renderer.render(&content.ty, &hint))
+                    });
+                    ResponseType { response, content }
+                })
+                .collect();
+            declarations.extend(renderer.take_hoisted());
+            operations.push(OperationTypes {
+                operation,
+                name: to_identifier(&operation.name, Casing::Snake, Language::Python),
+                params,
+                body,
+                responses,
+            });
+        }
+
+        PythonApi {
+            declarations: sort_declarations(declarations),
+            operations,
+            typing: renderer.typing.into_iter().collect(),
+            pydantic: renderer.pydantic.into_iter().collect(),
+            uses_datetime: renderer.uses_datetime,
+            uses_uuid: renderer.uses_uuid,
+            type_names,
+        }
+    }
+}
+
+/// The content to send or read, preferring JSON when several are declared
+pub(crate) fn preferred_content(contents: &[Content]) -> Option<&Content> {
+    contents
+        .iter()
+        .find(|content| is_json(&content.media_type))
+        .or_else(|| contents.first())
+}
+
+/// Whether a media type carries JSON, e.g. `application/problem+json`
+pub(crate) fn is_json(media_type: &str) -> bool {
+    let essence = media_type.split(';').next().unwrap_or_default().trim();
+    essence == "application/json" || essence.ends_with("+json")
+}
@@ -0,0 +1,684 @@
+use std::collections::BTreeSet;
+
+use crate::generators::python::api::{OperationTypes, PythonApi, ResponseType, is_json};
+use crate::generators::python::models::{models_source, top_level_gap};
+use crate::generators::python::types::{string_literal, write_docstring};
+use crate::generators::python::{PythonOptions, header, module_name};
+use crate::generators::{CodeWriter, GeneratedFile, Generator};
+use crate::ir::{ApiModel, ParamLocation, ParamStyle, Status};
+use crate::naming::{NameAllocator, to_pascal_case};
+
+/// Emits an `httpx` client with a synchronous and an asynchronous class, along
+/// with the models and an `__init__.py` exporting both
+///
+/// Every operation becomes a method taking path parameters and the body
+/// positionally and the other parameters as keyword arguments. Methods return
+/// an `ApiResponse` of the declared status and its body, validated with
+/// Pydantic; the return annotation is the union of the declared responses,
+/// whose ranges and `default` have an `int` status. Undeclared statuses raise
+/// an error.
+#[derive(Debug, Clone, Default)]
+pub struct HttpxGenerator {
+    options: PythonOptions,
+}
+
+impl HttpxGenerator {
+    pub fn new(options: PythonOptions) -> Self {
+        HttpxGenerator { options }
+    }
+}
+
+/// Names of the classes and aliases the client module declares
+struct ClientNames {
+    client: String,
+    async_client: String,
+    error: String,
+    response: String,
+    /// The response union of every operation, in operation order
+    unions: Vec<String>,
+}
+
+impl Generator for HttpxGenerator {
+    fn name(&self) -> &str {
+        "python-httpx"
+    }
+
+    fn generate(&self, model: &ApiModel) -> Vec<GeneratedFile> {
+        let api = PythonApi::new(model);
+        let mut names = NameAllocator::new();
+        for name in &api.type_names {
+            names.reserve(name.as_str());
+        }
+        names
+            .request("client", "Client")
+            .request("async", "AsyncClient")
+            .request("error", "ApiError")
+            .request("response", "ApiResponse");
+        for operation in &api.operations {
+            names.request(
+                format!("response#{}", operation.operation.name),
+                format!("{}Response", to_pascal_case(&operation.operation.name)),
+            );
+        }
+        let names = names.resolve();
+        let names = ClientNames {
+            client: names["client"].clone(),
+            async_client: names["async"].clone(),
+            error: names["error"].clone(),
+            response: names["response"].clone(),
+            unions: api
+                .operations
+                .iter()
+                .map(|operation| names[&format!("response#{}", operation.operation.name)].clone())
+                .collect(),
+        };
+
+        let models = module_name(&self.options.models_file);
+        let module = module_name(&self.options.client_file);
+        let mut init = CodeWriter::new("    ");
+        init.line(header(model));
+        init.blank();
+        init.line(format!(
+            "from .{} import {}, {}, {}, {}",
+            module, names.error, names.response, names.async_client, names.client
+        ));
+        init.line(format!("from .{} import *  # noqa: F401,F403", models));
+
+        vec![
+            GeneratedFile::new(self.options.models_file.clone(), models_source(model, &api)),
+            GeneratedFile::new(
+                self.options.client_file.clone(),
+                client_source(model, &api, &models, &names),
+            ),
+            GeneratedFile::new("__init__.py", init.finish()),
+        ]
+    }
+}
+
+fn client_source(model: &ApiModel, api: &PythonApi, models: &str, names: &ClientNames) -> String {
+    let mut typing: BTreeSet<&str> = api.typing.iter().copied().collect();
+    typing.extend([
+        "Any", "Dict", "Generic", "List", "Literal", "Optional", "Tuple", "TypeVar", "Union",
+    ]);
+    let mut pydantic: BTreeSet<&str> = ["BaseModel", "TypeAdapter"].into();
+    if api.pydantic.contains(&"Field") {
+        pydantic.insert("Field");
+    }
+
+    let mut w = CodeWriter::new("    ");
+    w.line(header(model));
+    w.blank();
+    w.line("from __future__ import annotations");
+    w.blank();
+    w.line("import json");
+    w.line("from dataclasses import dataclass");
+    if api.uses_datetime {
+        w.line("import datetime as _datetime");
+    }
+    if api.uses_uuid {
+        w.line("import uuid as _uuid");
+    }
+    w.line(format!(
+        "from typing import {}",
+        typing.into_iter().collect::<Vec<_>>().join(", ")
+    ));
+    w.line("from urllib.parse import quote, urlencode");
+    w.blank();
+    w.line("import httpx");
+    w.line(format!(
+        "from pydantic import {}",
+        pydantic.into_iter().collect::<Vec<_>>().join(", ")
+    ));
+    w.line("from pydantic_core import to_jsonable_python");
+    w.blank();
+    w.line(format!("from .{} import *  # noqa: F401,F403", models));
+
+    let base_url = model
+        .servers
+        .first()
+        .map(|server| server.default_url())
+        .unwrap_or_default();
+    top_level_gap(&mut w);
+    w.line(RESPONSE.replace("$ApiResponse", &names.response));
+    top_level_gap(&mut w);
+    for (operation, union) in api.operations.iter().zip(&names.unions) {
+        let members: Vec<String> = operation
+            .responses
+            .iter()
+            .map(|response| {
+                let (status, ty) = response_annotation(response);
+                format!("{}[{}, {}]", names.response, status, ty)
+            })
+            .collect();
+        w.line(match members.as_slice() {
+            [] => format!("{} = {}[int, Any]", union, names.response),
+            [member] => format!("{} = {}", union, member),
+            members => format!("{} = Union[{}]", union, members.join(", ")),
+        });
+    }
+    for asynchronous in [false, true] {
+        top_level_gap(&mut w);
+        client_class(&mut w, model, api, names, &base_url, asynchronous);
+    }
+    top_level_gap(&mut w);
+    w.line(RUNTIME.replace("$ApiError", &names.error));
+    w.finish()
+}
+
+fn client_class(
+    w: &mut CodeWriter,
+    model: &ApiModel,
+    api: &PythonApi,
+    names: &ClientNames,
+    base_url: &str,
+    asynchronous: bool,
+) {
+    let (name, http, kind) = match asynchronous {
+        false => (&names.client, "httpx.Client", "A synchronous"),
+        true => (&names.async_client, "httpx.AsyncClient", "An asynchronous"),
+    };
+    w.block(format!("class {}:", name), "", |w| {
+        write_docstring(w, &format!("{} client for {}", kind, model.title));
+        w.blank();
+        w.block("def __init__(", ") -> None:", |w| {
+            w.line("self,");
+            w.line(format!("base_url: str = {},", string_literal(base_url)));
+            w.line("*,");
+            w.line(format!("client: Optional[{}] = None,", http));
+            w.line("**kwargs: Any,");
+        });
+        w.indent();
+        write_docstring(
+            w,
+            &format!(
+                "Send requests to `base_url`, or through `client` when given\n\n\
+                 Other keyword arguments configure the `{}`.",
+                http
+            ),
+        );
+        w.line(format!(
+            "self._client = client if client is not None else {}(base_url=base_url, **kwargs)",
+            http
+        ));
+        w.dedent();
+        w.blank();
+        if asynchronous {
+            w.block("async def aclose(self) -> None:", "", |w| {
+                w.line("await self._client.aclose()");
+            });
+            w.block(
+                format!("async def __aenter__(self) -> {}:", name),
+                "",
+                |w| {
+                    w.line("return self");
+                },
+            );
+            w.block("async def __aexit__(self, *args: Any) -> None:", "", |w| {
+                w.line("await self.aclose()");
+            });
+        } else {
+            w.block("def close(self) -> None:", "", |w| {
+                w.line("self._client.close()");
+            });
+            w.block(format!("def __enter__(self) -> {}:", name), "", |w| {
+                w.line("return self");
+            });
+            w.block("def __exit__(self, *args: Any) -> None:", "", |w| {
+                w.line("self.close()");
+            });
+        }
+        for (operation, union) in api.operations.iter().zip(&names.unions) {
+            method(w, operation, union, names, asynchronous);
+        }
+    });
+}
+
+/// How a request body is passed and sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BodyKind {
+    Json,
+    Form,
+    Multipart,
+    Text,
+    Bytes,
+}
+
+fn body_kind(media_type: &str, ty: &str) -> BodyKind {
+    if ty == "bytes" {
+        BodyKind::Bytes
+    } else if is_json(media_type) {
+        BodyKind::Json
+    } else if media_type.starts_with("application/x-www-form-urlencoded") {
+        BodyKind::Form
+    } else if media_type.starts_with("multipart/form-data") {
+        BodyKind::Multipart
+    } else if ty == "str" {
+        BodyKind::Text
+    } else {
+        BodyKind::Bytes
+    }
+}
+
+/// The status and body annotations of a response
+fn response_annotation(response: &ResponseType) -> (String, String) {
+    let status = match response.response.status {
+        Status::Code(code) => format!("Literal[{}]", code),
+        Status::Range(_) | Status::Default => "int".to_string(),
+    };
+    let ty = match &response.content {
+        None => "None".to_string(),
+        Some((content, ty)) if is_json(&content.media_type) => ty.clone(),
+        Some((_, ty)) if ty == "str" => "str".to_string(),
+        Some(_) => "bytes".to_string(),
+    };
+    (status, ty)
+}
+
+fn method(
+    w: &mut CodeWriter,
+    types: &OperationTypes,
+    union: &str,
+    names: &ClientNames,
+    asynchronous: bool,
+) {
+    let operation = types.operation;
+
+    let mut positional = vec!["self".to_string()];
+    let mut keywords = Vec::new();
+    for (param, argument, ty) in &types.params {
+        match param.location {
+            ParamLocation::Path => positional.push(format!("{}: {}", argument, ty)),
+            _ if param.required => keywords.push(format!("{}: {}", argument, ty)),
+            _ => keywords.push(format!("{}: {} = None", argument, optional(ty))),
+        }
+    }
+    let body = types
+        .body
+        .as_ref()
+        .map(|body| (body, body_kind(&body.content.media_type, &body.ty)));
+    if let Some((body, kind)) = body {
+        let ty = match kind {
+            BodyKind::Bytes => "bytes",
+            _ => body.ty.as_str(),
+        };
+        match body.required {
+            true => positional.push(format!("body: {}", ty)),
+            false => positional.push(format!("body: {} = None", optional(ty))),
+        }
+    }
+    let mut arguments = positional;
+    if !keywords.is_empty() {
+        arguments.push("*".to_string());
+        arguments.extend(keywords);
+    }
+
+    w.blank();
+    let def = match asynchronous {
+        true => "async def",
+        false => "def",
+    };
+    w.line(format!(
+        "{} {}({}) -> {}:",
+        def,
+        types.name,
+        arguments.join(", "),
+        union
+    ));
+    w.indent();
+    let mut doc: Vec<&str> = operation
+        .summary
+        .iter()
+        .chain(&operation.description)
+        .map(String::as_str)
+        .collect();
+    doc.dedup();
+    if operation.deprecated {
+        doc.push("Deprecated.");
+    }
+    if !doc.is_empty() {
+        write_docstring(w, &doc.join("\n\n"));
+    }
+
+    let mut request = vec![
+        string_literal(&operation.method.to_ascii_uppercase()),
+        url_expression(types),
+    ];
+    let lists = |location: ParamLocation| -> Vec<String> {
+        types
+            .params
+            .iter()
+            .filter(|(param, ..)| param.location == location)
+            .map(|(param, argument, _)| match location {
+                ParamLocation::Query => format!(
+                    "({}, {}, {}, {})",
+                    string_literal(&param.name),
+                    argument,
+                    string_literal(style_name(param.style)),
+                    if param.explode { "True" } else { "False" }
+                ),
+                _ => format!("({}, {})", string_literal(&param.name), argument),
+            })
+            .collect()
+    };
+    let query = lists(ParamLocation::Query);
+    let mut headers = lists(ParamLocation::Header);
+    let cookies = lists(ParamLocation::Cookie);
+    if !query.is_empty() {
+        request.push(format!("params=_query([{}])", query.join(", ")));
+    }
+    if let Some((body, kind)) = body {
+        match kind {
+            BodyKind::Json => request.push("json=_dump(body)".to_string()),
+            BodyKind::Form => {
+                let styles: Vec<String> = body
+                    .content
+                    .encoding
+                    .iter()
+                    .filter_map(|(name, encoding)| {
+                        Some(format!(
+                            "{}: ({}, {})",
+                            string_literal(name),
+                            string_literal(style_name(encoding.style?)),
+                            if encoding.explode { "True" } else { "False" }
+                        ))
+                    })
+                    .collect();
+                headers.push(format!(
+                    "(\"Content-Type\", {})",
+                    string_literal(&body.content.media_type)
+                ));
+                request.push(format!("content=_form(body, {{{}}})", styles.join(", ")));
+            }
+            BodyKind::Multipart => {
+                let content_types: Vec<String> = body
+                    .content
+                    .encoding
+                    .iter()
+                    .filter_map(|(name, encoding)| {
+                        Some(format!(
+                            "{}: {}",
+                            string_literal(name),
+                            string_literal(encoding.content_type.as_ref()?)
+                        ))
+                    })
+                    .collect();
+                request.push(format!(
+                    "files=_multipart(body, {{{}}})",
+                    content_types.join(", ")
+                ));
+            }
+            BodyKind::Text | BodyKind::Bytes => {
+                headers.push(format!(
+                    "(\"Content-Type\", {})",
+                    string_literal(&body.content.media_type)
+                ));
+                request.push("content=body".to_string());
+            }
+        }
+    }
+    if let Some(accept) = types.accept() {
+        headers.push(format!("(\"Accept\", {})", string_literal(&accept)));
+    }
+    if !headers.is_empty() {
+        request.push(format!("headers=_values([{}])", headers.join(", ")));
+    }
+    if !cookies.is_empty() {
+        request.push(format!("cookies=_values([{}])", cookies.join(", ")));
+    }
+    let call = match asynchronous {
+        true => "await self._client.request",
+        false => "self._client.request",
+    };
+    w.block(format!("response = {}(", call), ")", |w| {
+        for argument in &request {
+            w.line(format!("{},", argument));
+        }
+    });
+    let mut branches: Vec<(u8, String, &ResponseType)> = types
+        .responses
+        .iter()
+        .map(|response| match response.response.status {
+            Status::Code(code) => (0, format!("response.status_code == {}", code), response),
+            Status::Range(range) => (
+                1,
+                format!(
+                    "{} <= response.status_code < {}",
+                    range * 100,
+                    range * 100 + 100
+                ),
+                response,
+            ),
+            Status::Default => (2, String::new(), response),
+        })
+        .collect();
+    branches.sort_by_key(|(order, ..)| *order);
+    let mut fallback = false;
+    for (order, condition, response) in branches {
+        let (status, ty) = response_annotation(response);
+        let data = match &response.content {
+            None => "None".to_string(),
+            Some((content, _)) if is_json(&content.media_type) => {
+                format!("TypeAdapter({}).validate_json(response.content)", ty)
+            }
+            Some(_) if ty == "str" => "response.text".to_string(),
+            Some(_) => "response.content".to_string(),
+        };
+        let value = match response.response.status {
+            Status::Code(code) => code.to_string(),
+            _ => "response.status_code".to_string(),
+        };
+        let result = format!(
+            "return {}[{}, {}]({}, {}, response)",
+            names.response, status, ty, value, data
+        );
+        if order == 2 {
+            fallback = true;
+            w.line(result);
+        } else {
+            w.line(format!("if {}:", condition));
+            w.indent();
+            w.line(result);
+            w.dedent();
+        }
+    }
+    if !fallback {
+        w.line(format!("raise {}(response)", names.error));
+    }
+    w.dedent();
+}
+
+/// An annotation that admits `None`
+fn optional(ty: &str) -> String {
+    if ty == "Any" || ty.starts_with("Optional[") {
+        ty.to_string()
+    } else {
+        format!("Optional[{}]", ty)
+    }
+}
+
+/// The path of an operation, formatting in its path parameters
+fn url_expression(types: &OperationTypes) -> String {
+    let mut template = String::new();
+    let mut values = Vec::new();
+    let mut rest = types.operation.path.as_str();
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}').map(|end| start + end) else {
+            break;
+        };
+        template.push_str(&rest[..start]);
+        let name = &rest[start + 1..end];
+        let param = types
+            .params
+            .iter()
+            .find(|(param, ..)| param.location == ParamLocation::Path && param.name == name);
+        match param {
+            Some((param, argument, _)) => {
+                template.push_str("{}");
+                values.push(format!(
+                    "_path({}, {}, {}, {})",
+                    string_literal(&param.name),
+                    argument,
+                    string_literal(style_name(param.style)),
+                    if param.explode { "True" } else { "False" }
+                ));
+            }
+            None => template.push_str(&format!("{{{{{}}}}}", name)),
+        }
+        rest = &rest[end + 1..];
+    }
+    template.push_str(rest);
+    match values.is_empty() {
+        true => string_literal(&template.replace("{{", "{").replace("}}", "}")),
+        false => format!(
+            "{}.format({})",
+            string_literal(&template),
+            values.join(", ")
+        ),
+    }
+}
+
+fn style_name(style: ParamStyle) -> &'static str {
+    match style {
+        ParamStyle::Simple => "simple",
+        ParamStyle::Label => "label",
+        ParamStyle::Matrix => "matrix",
+        ParamStyle::Form => "form",
+        ParamStyle::SpaceDelimited => "spaceDelimited",
+        ParamStyle::PipeDelimited => "pipeDelimited",
+        ParamStyle::DeepObject => "deepObject",
+    }
+}
+
+/// The generic response class; it is named `$ApiResponse` since a component
+/// may take its name
+const RESPONSE: &str = r#"_Status = TypeVar("_Status")
+_Data = TypeVar("_Data")
+
+
+@dataclass
+class $ApiResponse(Generic[_Status, _Data]):
+    """A response with a status the spec declares and its validated body"""
+
+    status_code: _Status
+    data: _Data
+    response: httpx.Response"#;
+
+/// The error class and the functions both clients call; the error is named
+/// `$ApiError` since a component may take its name
+const RUNTIME: &str = r#"class $ApiError(Exception):
+    """A response with a status the spec does not declare"""
+
+    def __init__(self, response: httpx.Response) -> None:
+        super().__init__(f"unexpected status {response.status_code}: {response.text}")
+        self.response = response
+        self.status_code = response.status_code
+
+
+def _dump(value: Any) -> Any:
+    """The JSON-compatible form of a value, leaving out model fields that are None"""
+    if isinstance(value, BaseModel):
+        return value.model_dump(mode="json", by_alias=True, exclude_none=True)
+    if isinstance(value, list):
+        return [_dump(item) for item in value]
+    if isinstance(value, dict):
+        return {key: _dump(item) for key, item in value.items()}
+    return to_jsonable_python(value, by_alias=True)
+
+
+def _form(body: Any, styles: Dict[str, Tuple[str, bool]]) -> Optional[str]:
+    """A form body encoded like query parameters, in the exploded `form` style
+    unless `styles` gives the style and explode of a property"""
+    if body is None:
+        return None
+    params = [(name, value, *styles.get(name, ("form", True))) for name, value in _dump(body).items()]
+    return urlencode(_query(params))
+
+
+def _multipart(
+    body: Any, content_types: Dict[str, str]
+) -> Optional[List[Tuple[str, Tuple[Optional[str], Any, Optional[str]]]]]:
+    """The parts of a multipart body: one per file and per item of other lists,
+    except for properties sent as JSON, with the content type of `content_types`"""
+    if body is None:
+        return None
+    values = body.model_dump(by_alias=True, exclude_none=True) if isinstance(body, BaseModel) else body
+    parts = []
+    for name, value in values.items():
+        content_type = content_types.get(name)
+        as_json = content_type is not None and "json" in content_type
+        for item in value if isinstance(value, list) and not as_json else [value]:
+            if isinstance(item, bytes):
+                parts.append((name, (name, item, content_type or "application/octet-stream")))
+            elif as_json or isinstance(item, (dict, list)):
+                parts.append((name, (None, json.dumps(_dump(item)), content_type or "application/json")))
+            else:
+                parts.append((name, (None, _scalar(_dump(item)), content_type)))
+    return parts
+
+
+def _scalar(value: Any) -> str:
+    if isinstance(value, bool):
+        return "true" if value else "false"
+    return "" if value is None else str(value)
+
+
+def _path(name: str, value: Any, style: str, explode: bool) -> str:
+    """A path parameter serialized according to its `style` and `explode`"""
+    value = _dump(value)
+    if isinstance(value, list):
+        parts = [quote(_scalar(item), safe="") for item in value]
+    elif isinstance(value, dict) and explode:
+        parts = [f"{quote(key, safe='')}={quote(_scalar(item), safe='')}" for key, item in value.items()]
+    elif isinstance(value, dict):
+        parts = [quote(_scalar(part), safe="") for pair in value.items() for part in pair]
+    else:
+        parts = [quote(_scalar(value), safe="")]
+    if style == "label":
+        return "." + ("." if explode else ",").join(parts)
+    if style == "matrix" and explode and isinstance(value, list):
+        return "".join(f";{name}={part}" for part in parts)
+    if style == "matrix" and explode and isinstance(value, dict):
+        return "".join(f";{part}" for part in parts)
+    if style == "matrix":
+        return f";{name}=" + ",".join(parts)
+    return ",".join(parts)
+
+
+def _query(params: List[Tuple[str, Any, str, bool]]) -> List[Tuple[str, str]]:
+    """Query parameters serialized according to their `style` and `explode`,
+    leaving out `None`"""
+    query = []
+    for name, value, style, explode in params:
+        value = _dump(value)
+        if value is None:
+            continue
+        if isinstance(value, list) and explode and style == "form":
+            query.extend((name, _scalar(item)) for item in value)
+        elif isinstance(value, list):
+            separator = {"spaceDelimited": " ", "pipeDelimited": "|"}.get(style, ",")
+            query.append((name, separator.join(_scalar(item) for item in value)))
+        elif isinstance(value, dict) and style == "deepObject":
+            query.extend((f"{name}[{key}]", _scalar(item)) for key, item in value.items())
+        elif isinstance(value, dict) and explode:
+            query.extend((key, _scalar(item)) for key, item in value.items())
+        elif isinstance(value, dict):
+            query.append((name, ",".join(_scalar(part) for pair in value.items() for part in pair)))
+        else:
+            query.append((name, _scalar(value)))
+    return query
+
+
+def _values(params: List[Tuple[str, Any]]) -> Dict[str, str]:
+    """Header or cookie values in the `simple` style, leaving out `None`"""
+    values = {}
+    for name, value in params:
+        value = _dump(value)
+        if value is None:
+            continue
+        if isinstance(value, list):
+            values[name] = ",".join(_scalar(item) for item in value)
+        elif isinstance(value, dict):
+            values[name] = ",".join(_scalar(part) for pair in value.items() for part in pair)
+        else:
+            values[name] = _scalar(value)
+    return values"#;
@@ -0,0 +1,208 @@
+//! Python code generation
+//!
+//! The generators in this module share a [`PythonOptions`] value. Models are
+//! Pydantic v2 classes; [`HttpxGenerator`] writes them next to the client so
+//! that the output forms one package.
+
+mod api;
+mod httpx;
+mod models;
+mod types;
+
+use crate::ir::ApiModel;
+
+// Re-export the public API
+pub use httpx::HttpxGenerator;
+pub use models::PydanticGenerator;
+
+/// Options shared by all Python generators
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PythonOptions {
+    /// File the models are written to
+    pub models_file: String,
+    /// File the HTTP client is written to
+    pub client_file: String,
+}
+
+impl Default for PythonOptions {
+    fn default() -> Self {
+        PythonOptions {
+            models_file: "models.py".to_string(),
+            client_file: "client.py".to_string(),
+        }
+    }
+}
+
+/// The banner at the top of every generated file
+pub(crate) fn header(model: &ApiModel) -> String {
+    let mut lines = vec![
+        "# Generated by rustval. Do not edit manually.".to_string(),
+        format!("# {}", model.title),
+    ];
+    if let Some(description) = &model.description {
+        lines.extend(
+            description
+                .trim()
+                .lines()
+                .map(|line| format!("# {}", line).trim_end().to_string()),
+        );
+    }
+    lines.push(format!("# OpenAPI spec version: {}", model.version));
+    lines.join("\n")
+}
+
+/// The module a generated file defines, e.g. `models` for `api/models.py`
+pub(crate) fn module_name(file: &str) -> String {
+    let name = file.rsplit('/').next().unwrap_or(file);
+    name.strip_suffix(".py").unwrap_or(name).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Generator, assert_generated};
+    use openapiv3::OpenAPI;
+
+    fn model(spec: &str) -> ApiModel {
+        let spec: OpenAPI = serde_yaml::from_str(spec).unwrap();
+        ApiModel::from_spec(&spec)
+    }
+
+    const PETS: &str = r##"
+openapi: 3.0.3
+info: { title: Pets, version: "1.0" }
+servers:
+  - url: https://pets.example.com/v1
+paths:
+  /pets:
+    get:
+      operationId: listPets
+      summary: List all pets
+      parameters:
+        - { name: limit, in: query, schema: { type: integer, minimum: 1 } }
+        - { name: X-Request-Id, in: header, required: true, schema: { type: string } }
+      responses:
+        "200":
+          description: The pets
+          content:
+            application/json:
+              schema: { type: array, items: { $ref: "#/components/schemas/Pet" } }
+    post:
+      operationId: createPet
+      requestBody:
+        required: true
+        content:
+          application/json:
+            schema: { $ref: "#/components/schemas/Pet" }
+      responses:
+        "201": { description: Created }
+  /pets/{petId}/photo:
+    put:
+      operationId: uploadPhoto
+      parameters:
+        - { name: petId, in: path, required: true, schema: { type: integer } }
+      requestBody:
+        content:
+          image/png:
+            schema: { type: string, format: binary }
+      responses:
+        "200":
+          description: The caption
+          content:
+            text/plain:
+              schema: { type: string }
+components:
+  schemas:
+    Pet:
+      oneOf:
+        - $ref: "#/components/schemas/Cat"
+        - $ref: "#/components/schemas/Dog"
+      discriminator:
+        propertyName: petType
+        mapping:
+          cat: "#/components/schemas/Cat"
+          dog: "#/components/schemas/Dog"
+    Cat:
+      description: A cat
+      allOf:
+        - $ref: "#/components/schemas/Base"
+        - type: object
+          properties:
+            lives: { type: integer, minimum: 0, maximum: 9 }
+    Dog:
+      type: object
+      required: [petType]
+      properties:
+        petType: { type: string }
+        good-boy: { type: boolean }
+    Base:
+      type: object
+      required: [petType, name]
+      properties:
+        petType: { type: string }
+        name: { type: string, minLength: 1, pattern: "^\\w+$" }
+        born: { type: string, format: date-time }
+        size: { type: string, enum: [small, large] }
+        json: { type: string }
+    Node:
+      type: object
+      required: [value]
+      properties:
+        value: { type: string }
+        children: { type: array, items: { $ref: "#/components/schemas/Node" } }
+    Tags: { type: array, items: { type: string }, maxItems: 5 }
+"##;
+
+    #[test]
+    fn test_pydantic_generator() {
+        let files = PydanticGenerator::default().generate(&model(PETS));
+        assert_generated("python/pydantic", &files);
+    }
+
+    #[test]
+    fn test_httpx_generator() {
+        let files = HttpxGenerator::default().generate(&model(PETS));
+        assert_generated("python/httpx", &files);
+
+        // Form and multipart bodies follow the encoding of their properties
+        let files = HttpxGenerator::default().generate(&model(
+            r#"
+openapi: 3.0.3
+info: { title: Forms, version: "1.0" }
+paths:
+  /upload:
+    post:
+      operationId: upload
+      requestBody:
+        required: true
+        content:
+          multipart/form-data:
+            schema:
+              type: object
+              properties:
+                file: { type: string, format: binary }
+                meta: { type: object, properties: { title: { type: string } } }
+            encoding:
+              meta: { contentType: application/json }
+      responses:
+        "204": { description: Done }
+  /login:
+    post:
+      operationId: login
+      requestBody:
+        required: true
+        content:
+          application/x-www-form-urlencoded:
+            schema:
+              type: object
+              properties:
+                scopes: { type: array, items: { type: string } }
+            encoding:
+              scopes: { style: spaceDelimited, explode: false }
+      responses:
+        "204": { description: Done }
+"#,
+        ));
+        assert_generated("python/httpx-bodies", &files);
+    }
+}
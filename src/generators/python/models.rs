@@ -0,0 +1,82 @@
+use crate::generators::python::api::PythonApi;
+use crate::generators::python::{PythonOptions, header};
+use crate::generators::{CodeWriter, GeneratedFile, Generator};
+use crate::ir::ApiModel;
+
+/// Emits Pydantic v2 models for every named type and for the inline bodies and
+/// responses of operations
+///
+/// Non-required properties are `Optional` and default to `None`; properties
+/// whose name is not a valid field name get an alias. Enums are `Literal`s, a
+/// `oneOf` of models with a discriminator is an `Annotated` union discriminated
+/// on a `Literal` field, and constraints are checked through `Field`.
+#[derive(Debug, Clone, Default)]
+pub struct PydanticGenerator {
+    options: PythonOptions,
+}
+
+impl PydanticGenerator {
+    pub fn new(options: PythonOptions) -> Self {
+        PydanticGenerator { options }
+    }
+}
+
+impl Generator for PydanticGenerator {
+    fn name(&self) -> &str {
+        "python-pydantic"
+    }
+
+    fn generate(&self, model: &ApiModel) -> Vec<GeneratedFile> {
+        let api = PythonApi::new(model);
+        vec![GeneratedFile::new(
+            self.options.models_file.clone(),
+            models_source(model, &api),
+        )]
+    }
+}
+
+pub(crate) fn models_source(model: &ApiModel, api: &PythonApi) -> String {
+    let mut w = CodeWriter::new("    ");
+    w.line(header(model));
+    w.blank();
+    w.line("from __future__ import annotations");
+    w.blank();
+    if api.uses_datetime {
+        w.line("import datetime as _datetime");
+    }
+    if api.uses_uuid {
+        w.line("import uuid as _uuid");
+    }
+    if !api.typing.is_empty() {
+        w.line(format!("from typing import {}", api.typing.join(", ")));
+    }
+    w.blank();
+    if !api.pydantic.is_empty() {
+        w.line(format!("from pydantic import {}", api.pydantic.join(", ")));
+    }
+    for declaration in &api.declarations {
+        top_level_gap(&mut w);
+        w.line(&declaration.source);
+    }
+
+    // Annotations may name classes declared further down the file
+    let classes: Vec<&str> = api
+        .declarations
+        .iter()
+        .filter(|declaration| declaration.class)
+        .map(|declaration| declaration.name.as_str())
+        .collect();
+    if !classes.is_empty() {
+        top_level_gap(&mut w);
+        for class in classes {
+            w.line(format!("{}.model_rebuild()", class));
+        }
+    }
+    w.finish()
+}
+
+/// The two blank lines PEP 8 puts around top-level definitions
+pub(crate) fn top_level_gap(w: &mut CodeWriter) {
+    w.blank();
+    w.line("");
+}
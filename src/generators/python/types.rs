@@ -0,0 +1,729 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use indexmap::IndexMap;
+use serde_json::Value;
+
+use crate::generators::CodeWriter;
+use crate::ir::{ApiModel, ObjectDef, Primitive, Property, TypeDef, TypeKind};
+use crate::naming::{Casing, Language, NameAllocator, component_type_names, to_identifier};
+
+/// `BaseModel` attributes that a field would shadow
+const MODEL_ATTRIBUTES: &[&str] = &[
+    "construct",
+    "copy",
+    "dict",
+    "json",
+    "model_config",
+    "model_fields",
+    "parse_obj",
+    "parse_raw",
+    "schema",
+    "schema_json",
+    "update_forward_refs",
+    "validate",
+];
+
+/// Renders [`TypeDef`]s as Python annotations and declares the Pydantic models
+/// they need
+///
+/// Objects become `BaseModel` classes; inline ones are hoisted into classes
+/// named after where they were found, e.g. `PetOwner` for the `owner` property
+/// of `Pet`. `allOf` members that are models become base classes.
+pub(crate) struct ModelRenderer<'a> {
+    model: &'a ApiModel,
+    /// Python names of the component schemas
+    names: IndexMap<String, String>,
+    /// Every declared name, to keep hoisted names unique
+    used: HashSet<String>,
+    /// The discriminator values of models that are variants of a discriminated
+    /// union, by component and property; the field becomes a `Literal`
+    tags: HashMap<String, IndexMap<String, Vec<String>>>,
+    /// While an alias is rendered: its component, whose recursive references
+    /// are quoted, and the names its value needs at import time
+    alias: Option<(Option<String>, Vec<String>)>,
+    hoisted: Vec<Declaration>,
+    /// Every hoisted type with its name, so that identical inline types share
+    /// one class
+    hoisted_types: Vec<(TypeDef, String)>,
+    /// Names imported from `typing`
+    pub typing: BTreeSet<&'static str>,
+    /// Names imported from `pydantic`
+    pub pydantic: BTreeSet<&'static str>,
+    pub uses_datetime: bool,
+    pub uses_uuid: bool,
+}
+
+/// A module-level statement of the models file
+pub(crate) struct Declaration {
+    pub name: String,
+    /// Names that must be declared first: the bases of a class, or the names
+    /// an alias evaluates
+    pub requires: Vec<String>,
+    /// Whether this is a `BaseModel` class, which is rebuilt once every name
+    /// is declared
+    pub class: bool,
+    pub source: String,
+}
+
+impl<'a> ModelRenderer<'a> {
+    pub fn new(model: &'a ApiModel) -> Self {
+        let names = component_type_names(model.types.keys().map(String::as_str), Language::Python);
+        let used = names.values().cloned().collect();
+        let mut renderer = ModelRenderer {
+            model,
+            names,
+            used,
+            tags: HashMap::new(),
+            alias: None,
+            hoisted: Vec::new(),
+            hoisted_types: Vec::new(),
+            typing: BTreeSet::new(),
+            pydantic: BTreeSet::new(),
+            uses_datetime: false,
+            uses_uuid: false,
+        };
+        for ty in model.types.values() {
+            if let TypeKind::Union {
+                variants,
+                discriminator: Some(discriminator),
+                ..
+            } = &ty.kind
+                && renderer.is_discriminated(variants)
+            {
+                for (value, target) in &discriminator.mapping {
+                    renderer
+                        .tags
+                        .entry(target.clone())
+                        .or_default()
+                        .entry(discriminator.property_name.clone())
+                        .or_default()
+                        .push(value.clone());
+                }
+            }
+        }
+        renderer
+    }
+
+    /// The Python name of a component schema
+    pub fn type_name<'n>(&'n self, component: &'n str) -> &'n str {
+        self.names.get(component).map_or(component, String::as_str)
+    }
+
+    /// A name that no other declaration uses yet
+    pub fn allocate(&mut self, name: &str) -> String {
+        let mut candidate = name.to_string();
+        let mut suffix = 2;
+        while self.used.contains(&candidate) || Language::Python.is_reserved(&candidate) {
+            candidate = format!("{}{}", name, suffix);
+            suffix += 1;
+        }
+        self.used.insert(candidate.clone());
+        candidate
+    }
+
+    /// The declarations hoisted since the last call
+    pub fn take_hoisted(&mut self) -> Vec<Declaration> {
+        std::mem::take(&mut self.hoisted)
+    }
+
+    /// The declaration of a component schema
+    pub fn component_declaration(&mut self, component: &str, ty: &TypeDef) -> Declaration {
+        let name = self.type_name(component).to_string();
+        let tags = self.tags.get(component).cloned().unwrap_or_default();
+        self.declaration(&name, Some(component), ty, &tags)
+    }
+
+    fn declaration(
+        &mut self,
+        name: &str,
+        component: Option<&str>,
+        ty: &TypeDef,
+        tags: &IndexMap<String, Vec<String>>,
+    ) -> Declaration {
+        match &ty.kind {
+            TypeKind::Object(object) if !object.properties.is_empty() && !ty.nullable => {
+                self.class(name, ty, &[], object, tags)
+            }
+            TypeKind::Intersection(members) if !ty.nullable => match self.inheritance(members) {
+                Some((bases, object)) => self.class(name, ty, &bases, &object, tags),
+                None => match self.merge(members) {
+                    Some(object) => self.class(name, ty, &[], &object, tags),
+                    None => self.alias(name, component, ty),
+                },
+            },
+            _ => self.alias(name, component, ty),
+        }
+    }
+
+    /// `Name = annotation`, with the constraints of the type
+    fn alias(&mut self, name: &str, component: Option<&str>, ty: &TypeDef) -> Declaration {
+        let outer = self
+            .alias
+            .replace((component.map(str::to_string), Vec::new()));
+        let mut annotation = self.render(ty, name);
+        let (_, requires) = std::mem::replace(&mut self.alias, outer).unwrap_or_default();
+        let constraints = constraint_arguments(ty);
+        if !constraints.is_empty() {
+            self.typing.insert("Annotated");
+            self.pydantic.insert("Field");
+            annotation = format!(
+                "Annotated[{}, Field({})]",
+                annotation,
+                constraints.join(", ")
+            );
+        }
+        let mut w = CodeWriter::new("    ");
+        write_comment(&mut w, ty.description.as_deref());
+        w.line(format!("{} = {}", name, annotation));
+        Declaration {
+            name: name.to_string(),
+            requires,
+            class: false,
+            source: w.finish().trim_end().to_string(),
+        }
+    }
+
+    fn class(
+        &mut self,
+        name: &str,
+        ty: &TypeDef,
+        bases: &[String],
+        object: &ObjectDef,
+        tags: &IndexMap<String, Vec<String>>,
+    ) -> Declaration {
+        let alias = self.alias.take();
+        self.pydantic.insert("BaseModel");
+        let fields = field_names(
+            object
+                .properties
+                .iter()
+                .map(|property| property.name.as_str()),
+        );
+
+        let mut lines = Vec::new();
+        // Discriminators inherited from a base class are overridden with their values
+        for (property, values) in tags {
+            if !object.properties.iter().any(|p| &p.name == property) {
+                lines.push(self.tag_field(&field_name(property), property, values));
+            }
+        }
+        let mut aliased = false;
+        for (property, field) in object.properties.iter().zip(&fields) {
+            aliased |= field != &property.name;
+            match tags.get(&property.name) {
+                Some(values) => lines.push(self.tag_field(field, &property.name, values)),
+                None => {
+                    let hint = format!(
+                        "{}{}",
+                        name,
+                        to_identifier(&property.name, Casing::Pascal, Language::Python)
+                    );
+                    lines.push(self.field(field, property, &hint));
+                }
+            }
+        }
+
+        let mut config = Vec::new();
+        if aliased {
+            config.push("populate_by_name=True".to_string());
+        }
+        if object.additional_properties.is_some() {
+            config.push("extra=\"allow\"".to_string());
+        }
+
+        let mut w = CodeWriter::new("    ");
+        let bases_list = match bases.is_empty() {
+            true => "BaseModel".to_string(),
+            false => bases.join(", "),
+        };
+        w.block(format!("class {}({}):", name, bases_list), "", |w| {
+            let mut empty = true;
+            if let Some(description) = &ty.description {
+                write_docstring(w, description);
+                empty = false;
+            }
+            if !config.is_empty() {
+                self.pydantic.insert("ConfigDict");
+                if !empty {
+                    w.blank();
+                }
+                w.line(format!("model_config = ConfigDict({})", config.join(", ")));
+                empty = false;
+            }
+            if !lines.is_empty() {
+                if !empty {
+                    w.blank();
+                }
+                for line in &lines {
+                    w.line(line);
+                }
+                empty = false;
+            }
+            if empty {
+                w.line("pass");
+            }
+        });
+        self.alias = alias;
+        Declaration {
+            name: name.to_string(),
+            requires: bases.to_vec(),
+            class: true,
+            source: w.finish().trim_end().to_string(),
+        }
+    }
+
+    /// `field: annotation = default`
+    fn field(&mut self, field: &str, property: &Property, hint: &str) -> String {
+        let ty = &property.ty;
+        let mut annotation = self.render(ty, hint);
+        if !property.required && !ty.nullable && annotation != "Any" {
+            self.typing.insert("Optional");
+            annotation = format!("Optional[{}]", annotation);
+        }
+
+        let mut arguments = Vec::new();
+        if !property.required {
+            arguments.push(ty.default.as_ref().map_or("None".to_string(), literal));
+        }
+        if field != property.name {
+            arguments.push(format!("alias={}", string_literal(&property.name)));
+        }
+        if let Some(description) = &ty.description {
+            arguments.push(format!(
+                "description={}",
+                string_literal(description.trim())
+            ));
+        }
+        arguments.extend(constraint_arguments(ty));
+
+        match arguments.as_slice() {
+            [] => format!("{}: {}", field, annotation),
+            [default] if !property.required => format!("{}: {} = {}", field, annotation, default),
+            _ => {
+                self.pydantic.insert("Field");
+                format!(
+                    "{}: {} = Field({})",
+                    field,
+                    annotation,
+                    arguments.join(", ")
+                )
+            }
+        }
+    }
+
+    /// A discriminator field, holding one of the values mapped to its model
+    fn tag_field(&mut self, field: &str, property: &str, values: &[String]) -> String {
+        self.typing.insert("Literal");
+        let literals: Vec<String> = values.iter().map(|value| string_literal(value)).collect();
+        let annotation = format!("Literal[{}]", literals.join(", "));
+        let mut arguments = Vec::new();
+        if let [value] = literals.as_slice() {
+            arguments.push(value.clone());
+        }
+        if field != property {
+            arguments.push(format!("alias={}", string_literal(property)));
+        }
+        match arguments.as_slice() {
+            [] => format!("{}: {}", field, annotation),
+            [default] if values.len() == 1 => format!("{}: {} = {}", field, annotation, default),
+            _ => {
+                self.pydantic.insert("Field");
+                format!(
+                    "{}: {} = Field({})",
+                    field,
+                    annotation,
+                    arguments.join(", ")
+                )
+            }
+        }
+    }
+
+    /// A type annotation, hoisting inline models under `hint`
+    pub fn render(&mut self, ty: &TypeDef, hint: &str) -> String {
+        let rendered = self.render_kind(ty, hint);
+        if ty.nullable && rendered != "Any" {
+            self.typing.insert("Optional");
+            format!("Optional[{}]", rendered)
+        } else {
+            rendered
+        }
+    }
+
+    fn render_kind(&mut self, ty: &TypeDef, hint: &str) -> String {
+        match &ty.kind {
+            TypeKind::Primitive { primitive, format } => {
+                let rendered = primitive_type(*primitive, format.as_deref());
+                self.uses_datetime |= rendered.starts_with("_datetime.");
+                self.uses_uuid |= rendered.starts_with("_uuid.");
+                rendered.to_string()
+            }
+            TypeKind::Array(items) => {
+                self.typing.insert("List");
+                format!("List[{}]", self.render(items, &format!("{}Item", hint)))
+            }
+            TypeKind::Object(object) if !object.properties.is_empty() => self.hoist(hint, ty),
+            TypeKind::Object(object) => {
+                self.typing.insert("Dict");
+                let value = match &object.additional_properties {
+                    Some(value) => self.render(value, &format!("{}Value", hint)),
+                    None => {
+                        self.typing.insert("Any");
+                        "Any".to_string()
+                    }
+                };
+                format!("Dict[str, {}]", value)
+            }
+            TypeKind::Enum { values, .. } if !values.is_empty() => {
+                self.typing.insert("Literal");
+                let values: Vec<String> = values.iter().map(literal).collect();
+                format!("Literal[{}]", values.join(", "))
+            }
+            TypeKind::Union { variants, .. } if variants.len() == 1 => {
+                self.render(&variants[0], hint)
+            }
+            TypeKind::Union {
+                variants,
+                discriminator,
+                ..
+            } => {
+                self.typing.insert("Union");
+                let rendered: Vec<String> = variants
+                    .iter()
+                    .enumerate()
+                    .map(|(i, variant)| self.render(variant, &format!("{}{}", hint, i + 1)))
+                    .collect();
+                let union = format!("Union[{}]", rendered.join(", "));
+                match discriminator {
+                    Some(discriminator) if self.is_discriminated(variants) => {
+                        self.typing.insert("Annotated");
+                        self.pydantic.insert("Field");
+                        format!(
+                            "Annotated[{}, Field(discriminator={})]",
+                            union,
+                            string_literal(&field_name(&discriminator.property_name))
+                        )
+                    }
+                    _ => union,
+                }
+            }
+            TypeKind::Intersection(_) => self.hoist(hint, ty),
+            TypeKind::Reference(component) => {
+                let name = self.type_name(component).to_string();
+                let recursive = match &self.alias {
+                    Some((Some(root), _)) => self.reaches(component, root),
+                    _ => false,
+                };
+                match &mut self.alias {
+                    Some(_) if recursive => string_literal(&name),
+                    Some((_, requires)) => {
+                        requires.push(name.clone());
+                        name
+                    }
+                    None => name,
+                }
+            }
+            TypeKind::Enum { .. } | TypeKind::Any => {
+                self.typing.insert("Any");
+                "Any".to_string()
+            }
+        }
+    }
+
+    /// Declare an inline model under a fresh name derived from `hint`
+    fn hoist(&mut self, hint: &str, ty: &TypeDef) -> String {
+        let mut ty = ty.clone();
+        ty.nullable = false;
+        let name = match self
+            .hoisted_types
+            .iter()
+            .find(|(hoisted, _)| *hoisted == ty)
+        {
+            Some((_, name)) => name.clone(),
+            None => {
+                let name = self.allocate(hint);
+                let declaration = self.declaration(&name, None, &ty, &IndexMap::new());
+                self.hoisted.push(declaration);
+                self.hoisted_types.push((ty, name.clone()));
+                name
+            }
+        };
+        if let Some((_, requires)) = &mut self.alias {
+            requires.push(name.clone());
+        }
+        name
+    }
+
+    /// Whether `from` refers to `to`, directly or through other named types
+    fn reaches(&self, from: &str, to: &str) -> bool {
+        let mut stack = vec![from];
+        let mut visited = HashSet::new();
+        while let Some(name) = stack.pop() {
+            if name == to {
+                return true;
+            }
+            if visited.insert(name)
+                && let Some(ty) = self.model.type_def(name)
+            {
+                stack.extend(ty.references());
+            }
+        }
+        false
+    }
+
+    /// Whether a component is declared as a `BaseModel` class
+    fn is_class(&self, component: &str) -> bool {
+        self.model
+            .type_def(component)
+            .is_some_and(|ty| !ty.nullable && self.declares_class(ty))
+    }
+
+    fn declares_class(&self, ty: &TypeDef) -> bool {
+        match &ty.kind {
+            TypeKind::Object(object) => !object.properties.is_empty(),
+            TypeKind::Intersection(members) => {
+                self.inheritance(members).is_some() || self.merge(members).is_some()
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether Pydantic can discriminate a union: every variant must be a model
+    fn is_discriminated(&self, variants: &[TypeDef]) -> bool {
+        variants.iter().all(|variant| match &variant.kind {
+            TypeKind::Reference(name) => !variant.nullable && self.is_class(name),
+            _ => false,
+        })
+    }
+
+    /// The base classes and own properties of an `allOf` whose members are
+    /// models or inline objects
+    fn inheritance(&self, members: &[TypeDef]) -> Option<(Vec<String>, ObjectDef)> {
+        let mut bases = Vec::new();
+        let mut object = ObjectDef::default();
+        for member in members {
+            match &member.kind {
+                TypeKind::Reference(name) if !member.nullable && self.is_class(name) => {
+                    bases.push(self.type_name(name).to_string());
+                }
+                TypeKind::Object(own) if !member.nullable => {
+                    object.properties.extend(own.properties.iter().cloned());
+                    if own.additional_properties.is_some() {
+                        object.additional_properties = own.additional_properties.clone();
+                    }
+                }
+                _ => return None,
+            }
+        }
+        (!bases.is_empty()).then_some((bases, object))
+    }
+
+    /// The properties of every object among `allOf` members, or `None` when
+    /// there are none
+    fn merge(&self, members: &[TypeDef]) -> Option<ObjectDef> {
+        let mut merged = ObjectDef::default();
+        let mut found = false;
+        for member in members {
+            let Some(object) = self.object_of(member, &mut HashSet::new()) else {
+                continue;
+            };
+            found = true;
+            for property in object.properties {
+                match merged
+                    .properties
+                    .iter_mut()
+                    .find(|existing| existing.name == property.name)
+                {
+                    Some(existing) => existing.required |= property.required,
+                    None => merged.properties.push(property),
+                }
+            }
+            if object.additional_properties.is_some() {
+                merged.additional_properties = object.additional_properties;
+            }
+        }
+        found.then_some(merged)
+    }
+
+    fn object_of(&self, ty: &TypeDef, visited: &mut HashSet<String>) -> Option<ObjectDef> {
+        match &ty.kind {
+            TypeKind::Object(object) => Some(object.clone()),
+            TypeKind::Reference(name) if visited.insert(name.clone()) => {
+                self.object_of(self.model.type_def(name)?, visited)
+            }
+            TypeKind::Intersection(members) => {
+                let mut merged = ObjectDef::default();
+                let mut found = false;
+                for member in members {
+                    if let Some(object) = self.object_of(member, visited) {
+                        found = true;
+                        merged.properties.extend(object.properties);
+                    }
+                }
+                found.then_some(merged)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Order declarations so that classes follow their base classes and aliases
+/// the names they evaluate
+pub(crate) fn sort_declarations(declarations: Vec<Declaration>) -> Vec<Declaration> {
+    let mut pending = declarations;
+    let mut sorted: Vec<Declaration> = Vec::new();
+    while !pending.is_empty() {
+        let declared: HashSet<String> = sorted.iter().map(|d| d.name.clone()).collect();
+        let waiting: HashSet<String> = pending.iter().map(|d| d.name.clone()).collect();
+        let ready = pending.iter().position(|declaration| {
+            declaration
+                .requires
+                .iter()
+                .all(|name| declared.contains(name) || !waiting.contains(name))
+        });
+        // Recursive references are quoted, so a cycle means the spec is
+        // inconsistent; keep its order
+        sorted.push(pending.remove(ready.unwrap_or(0)));
+    }
+    sorted
+}
+
+/// Unique snake_case field names that shadow neither keywords nor `BaseModel`
+/// attributes
+pub(crate) fn field_names<'p>(properties: impl Iterator<Item = &'p str>) -> Vec<String> {
+    let mut names = NameAllocator::new();
+    for (i, property) in properties.enumerate() {
+        names.request(format!("{:04}", i), field_name(property));
+    }
+    names.resolve().into_values().collect()
+}
+
+fn field_name(property: &str) -> String {
+    let name = to_identifier(property, Casing::Snake, Language::Python);
+    if MODEL_ATTRIBUTES.contains(&name.as_str()) || name.starts_with("model_") {
+        format!("{}_", name)
+    } else {
+        name
+    }
+}
+
+/// `Field` arguments for the constraints Pydantic checks on a type
+fn constraint_arguments(ty: &TypeDef) -> Vec<String> {
+    let constraints = &ty.constraints;
+    let mut arguments = Vec::new();
+    match &ty.kind {
+        TypeKind::Primitive {
+            primitive: Primitive::String,
+            format,
+        } if format.as_deref() != Some("binary") => {
+            if let Some(min) = constraints.min_length {
+                arguments.push(format!("min_length={}", min));
+            }
+            if let Some(max) = constraints.max_length {
+                arguments.push(format!("max_length={}", max));
+            }
+            if let Some(pattern) = &constraints.pattern {
+                arguments.push(format!("pattern={}", string_literal(pattern)));
+            }
+        }
+        TypeKind::Primitive {
+            primitive: Primitive::Integer | Primitive::Number,
+            ..
+        } => {
+            if let Some(minimum) = constraints.minimum {
+                let key = if constraints.exclusive_minimum {
+                    "gt"
+                } else {
+                    "ge"
+                };
+                arguments.push(format!("{}={}", key, minimum));
+            }
+            if let Some(maximum) = constraints.maximum {
+                let key = if constraints.exclusive_maximum {
+                    "lt"
+                } else {
+                    "le"
+                };
+                arguments.push(format!("{}={}", key, maximum));
+            }
+            if let Some(multiple) = constraints.multiple_of {
+                arguments.push(format!("multiple_of={}", multiple));
+            }
+        }
+        TypeKind::Array(_) => {
+            if let Some(min) = constraints.min_items {
+                arguments.push(format!("min_length={}", min));
+            }
+            if let Some(max) = constraints.max_items {
+                arguments.push(format!("max_length={}", max));
+            }
+        }
+        _ => {}
+    }
+    arguments
+}
+
+/// The Python type of a primitive
+pub(crate) fn primitive_type(primitive: Primitive, format: Option<&str>) -> &'static str {
+    match (primitive, format) {
+        (Primitive::String, Some("date-time")) => "_datetime.datetime",
+        (Primitive::String, Some("date")) => "_datetime.date",
+        (Primitive::String, Some("uuid")) => "_uuid.UUID",
+        (Primitive::String, Some("binary")) => "bytes",
+        (Primitive::String, _) => "str",
+        (Primitive::Integer, _) => "int",
+        (Primitive::Number, _) => "float",
+        (Primitive::Boolean, _) => "bool",
+    }
+}
+
+/// A Python literal for a JSON value
+pub(crate) fn literal(value: &Value) -> String {
+    match value {
+        Value::Null => "None".to_string(),
+        Value::Bool(true) => "True".to_string(),
+        Value::Bool(false) => "False".to_string(),
+        Value::Number(number) => number.to_string(),
+        Value::String(string) => string_literal(string),
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(literal).collect();
+            format!("[{}]", items.join(", "))
+        }
+        Value::Object(map) => {
+            let entries: Vec<String> = map
+                .iter()
+                .map(|(key, value)| format!("{}: {}", string_literal(key), literal(value)))
+                .collect();
+            format!("{{{}}}", entries.join(", "))
+        }
+    }
+}
+
+/// A double-quoted Python string; JSON escapes are valid Python escapes
+pub(crate) fn string_literal(text: &str) -> String {
+    Value::from(text).to_string()
+}
+
+pub(crate) fn write_docstring(w: &mut CodeWriter, text: &str) {
+    let text = text
+        .trim()
+        .replace('\\', "\\\\")
+        .replace("\"\"\"", "\\\"\\\"\\\"");
+    let mut lines = text.lines();
+    let first = lines.next().unwrap_or_default();
+    let rest: Vec<&str> = lines.collect();
+    if rest.is_empty() {
+        w.line(format!("\"\"\"{}\"\"\"", first));
+        return;
+    }
+    w.line(format!("\"\"\"{}", first));
+    for line in rest {
+        w.line(line.trim_end());
+    }
+    w.line("\"\"\"");
+}
+
+fn write_comment(w: &mut CodeWriter, description: Option<&str>) {
+    for line in description.iter().flat_map(|text| text.trim().lines()) {
+        w.line(format!("# {}", line).trim_end());
+    }
+}
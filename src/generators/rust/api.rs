@@ -0,0 +1,493 @@
+use crate::generators::CodeWriter;
+use crate::generators::rust::RustOptions;
+use crate::generators::rust::types::{TypeRenderer, field_names};
+use crate::ir::{
+    ApiModel, Content, ObjectDef, Operation, Param, ParamStyle, Primitive, Property, Response,
+    Status, TypeDef, TypeKind,
+};
+use crate::naming::{Casing, Language, NameAllocator, to_identifier, to_pascal_case};
+
+/// The Rust declarations of a model and the types every operation uses
+///
+/// Clients and servers share one set of models, so the declarations needed by
+/// operations, such as parameter structs and inline bodies, are prepared here
+/// once together with the component schemas.
+pub(crate) struct RustApi<'a> {
+    /// Declarations in file order
+    pub declarations: Vec<String>,
+    pub operations: Vec<OperationTypes<'a>>,
+    /// Whether a declaration mentions `HashMap`
+    pub uses_map: bool,
+    /// Rust names that generated clients and servers may not reuse
+    pub type_names: Vec<String>,
+}
+
+/// How an operation's inputs and outputs are typed
+pub(crate) struct OperationTypes<'a> {
+    pub operation: &'a Operation,
+    /// snake_case method name
+    pub name: String,
+    /// Path parameters with their argument name and type
+    pub path_params: Vec<(&'a Param, String, String)>,
+    pub query: Option<ParamsStruct<'a>>,
+    pub headers: Option<ParamsStruct<'a>>,
+    pub body: Option<BodyType<'a>>,
+    /// Every declared response, successful ones first
+    pub responses: Vec<ResponseType<'a>>,
+}
+
+/// A struct generated for the query or header parameters of an operation
+pub(crate) struct ParamsStruct<'a> {
+    pub name: String,
+    /// Parameters with their field name
+    pub fields: Vec<(&'a Param, String)>,
+}
+
+pub(crate) struct BodyType<'a> {
+    pub content: &'a Content,
+    pub required: bool,
+    pub ty: String,
+    /// The parts of a `multipart/form-data` body whose type is a struct
+    pub parts: Option<Vec<FormPart<'a>>>,
+    /// The field of that struct holding additional properties
+    pub additional_field: Option<String>,
+}
+
+/// A property of a multipart body and how it is sent
+pub(crate) struct FormPart<'a> {
+    pub name: String,
+    pub field: String,
+    pub ty: TypeDef,
+    /// Whether the field is an `Option`
+    pub optional: bool,
+    pub kind: PartKind,
+    /// The first concrete media type of the `contentType` of the property's
+    /// encoding, which may list several, e.g. `image/png, image/*`
+    pub content_type: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PartKind {
+    /// Bytes sent as a file
+    File,
+    /// A list of files, sent as a part each
+    Files,
+    /// A value sent as text, or as JSON when it is an object or array
+    Value,
+    /// A list of scalars, sent as a part each
+    Values,
+}
+
+pub(crate) struct ResponseType<'a> {
+    pub response: &'a Response,
+    /// The content a server writes, preferring JSON, with its type
+    pub content: Option<(&'a Content, String)>,
+    /// Every declared content with its type, in declaration order
+    pub contents: Vec<(&'a Content, String)>,
+}
+
+impl<'a> OperationTypes<'a> {
+    /// The `Accept` header listing the media types of the responses, needed
+    /// only when a response declares several
+    pub fn accept(&self) -> Option<String> {
+        if self
+            .responses
+            .iter()
+            .all(|response| response.contents.len() < 2)
+        {
+            return None;
+        }
+        let mut media_types: Vec<&str> = Vec::new();
+        for (content, _) in self.responses.iter().flat_map(|r| &r.contents) {
+            if !media_types.contains(&content.media_type.as_str()) {
+                media_types.push(&content.media_type);
+            }
+        }
+        Some(media_types.join(", "))
+    }
+}
+
+impl<'a> RustApi<'a> {
+    pub fn new(model: &'a ApiModel, options: &'a RustOptions) -> Self {
+        let mut renderer = TypeRenderer::new(model, options);
+        let mut declarations = Vec::new();
+        for (name, ty) in &model.types {
+            declarations.push(renderer.component_declaration(name, ty));
+            declarations.extend(renderer.take_hoisted());
+        }
+        let type_names: Vec<String> = model
+            .types
+            .keys()
+            .map(|name| renderer.type_name(name).to_string())
+            .collect();
+
+        let mut operations = Vec::new();
+        for operation in &model.operations {
+            let prefix = to_pascal_case(&operation.name);
+            let mut types = OperationTypes {
+                operation,
+                name: to_identifier(&operation.name, Casing::Snake, Language::Rust),
+                path_params: Vec::new(),
+                query: None,
+                headers: None,
+                body: None,
+                responses: Vec::new(),
+            };
+
+            let mut arguments = NameAllocator::new();
+            for reserved in ["params", "headers", "body"] {
+                arguments.reserve(reserved);
+            }
+            for (i, param) in operation.path_params.iter().enumerate() {
+                arguments.request(
+                    format!("{:04}", i),
+                    to_identifier(&param.name, Casing::Snake, Language::Rust),
+                );
+            }
+            let arguments = arguments.resolve().into_values();
+            for (param, argument) in operation.path_params.iter().zip(arguments) {
+                let hint = format!(
+                    "{}{}",
+                    prefix,
+                    to_identifier(&param.name, Casing::Pascal, Language::Rust)
+                );
+                let ty = renderer.render(&param.ty, &hint);
+                types.path_params.push((param, argument, ty));
+            }
+            for (suffix, params) in [
+                ("Params", &operation.query_params),
+                ("Headers", &operation.header_params),
+            ] {
+                if params.is_empty() {
+                    continue;
+                }
+                let name = renderer.allocate(&format!("{}{}", prefix, suffix));
+                declarations.push(params_declaration(&mut renderer, &name, params));
+                let fields = field_names(params.iter().map(|param| param.name.as_str()));
+                let params = ParamsStruct {
+                    name,
+                    fields: params.iter().zip(fields).collect(),
+                };
+                match suffix {
+                    "Params" => types.query = Some(params),
+                    _ => types.headers = Some(params),
+                }
+            }
+            if let Some(body) = &operation.body
+                && let Some(content) = preferred_content(&body.contents)
+            {
+                let ty = renderer.render(&content.ty, &format!("{}Body", prefix));
+                let parts = content
+                    .media_type
+                    .starts_with("multipart/form-data")
+                    .then(|| renderer.struct_fields(&content.ty))
+                    .flatten()
+                    .map(|fields| form_parts(model, content, fields));
+                let additional_field = parts
+                    .as_ref()
+                    .and_then(|_| renderer.additional_field(&content.ty));
+                types.body = Some(BodyType {
+                    content,
+                    required: body.required,
+                    ty,
+                    parts,
+                    additional_field,
+                });
+            }
+            for response in operation.responses.iter() {
+                let status = match response.status {
+                    Status::Default => "Default".to_string(),
+                    status => status.to_string(),
+                };
+                // The preferred content is rendered first to keep the name
+                // of its inline types free of the media type
+                let preferred = preferred_content(&response.contents);
+                let content = preferred.map(|content| {
+                    let hint = format!("{}{}Response", prefix, status);
+                    (content, renderer.render(&content.ty, &hint))
+                });
+                let contents = response
+                    .contents
+                    .iter()
+                    .map(|other| match &content {
+                        Some((content, ty)) if std::ptr::eq(*content, other) => (other, ty.clone()),
+                        _ => {
+                            let media = to_pascal_case(media_essence(&other.media_type));
+                            let hint = format!("{}{}{}Response", prefix, status, media);
+                            (other, renderer.render(&other.ty, &hint))
+                        }
+                    })
+                    .collect();
+                types.responses.push(ResponseType {
+                    response,
+                    content,
+                    contents,
+                });
+            }
+            declarations.extend(renderer.take_hoisted());
+            operations.push(types);
+        }
+
+        RustApi {
+            declarations,
+            operations,
+            uses_map: renderer.uses_map,
+            type_names,
+        }
+    }
+}
+
+/// The content to send or read, preferring JSON when several are declared
+pub(crate) fn preferred_content(contents: &[Content]) -> Option<&Content> {
+    contents
+        .iter()
+        .find(|content| is_json(&content.media_type))
+        .or_else(|| contents.first())
+}
+
+/// Whether a media type carries JSON, e.g. `application/problem+json`
+pub(crate) fn is_json(media_type: &str) -> bool {
+    let essence = media_essence(media_type);
+    essence == "application/json" || essence.ends_with("+json")
+}
+
+/// A media type without its parameters, e.g. `text/plain` for
+/// `text/plain; charset=utf-8`
+pub(crate) fn media_essence(media_type: &str) -> &str {
+    media_type.split(';').next().unwrap_or_default().trim()
+}
+
+/// The name of a parameter style as written in the spec
+pub(crate) fn style_name(style: ParamStyle) -> &'static str {
+    match style {
+        ParamStyle::Simple => "simple",
+        ParamStyle::Label => "label",
+        ParamStyle::Matrix => "matrix",
+        ParamStyle::Form => "form",
+        ParamStyle::SpaceDelimited => "spaceDelimited",
+        ParamStyle::PipeDelimited => "pipeDelimited",
+        ParamStyle::DeepObject => "deepObject",
+    }
+}
+
+/// How a request body is passed and sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BodyKind {
+    Json,
+    Form,
+    Text,
+    Bytes,
+}
+
+/// How a response body is read or written
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ResponseKind {
+    Empty,
+    Json,
+    Text,
+    Bytes,
+}
+
+pub(crate) fn body_kind(media_type: &str, ty: &str) -> BodyKind {
+    if ty == "Vec<u8>" {
+        BodyKind::Bytes
+    } else if is_json(media_type) {
+        BodyKind::Json
+    } else if media_type.starts_with("application/x-www-form-urlencoded") {
+        BodyKind::Form
+    } else if ty == "String" {
+        BodyKind::Text
+    } else {
+        BodyKind::Bytes
+    }
+}
+
+/// The type of a response body and how it is carried
+pub(crate) fn response_kind(response: &ResponseType) -> (ResponseKind, String) {
+    match &response.content {
+        Some((content, ty)) => content_kind(content, ty),
+        None => (ResponseKind::Empty, "()".to_string()),
+    }
+}
+
+/// The type of a response content and how it is carried
+pub(crate) fn content_kind(content: &Content, ty: &str) -> (ResponseKind, String) {
+    if ty == "Vec<u8>" {
+        (ResponseKind::Bytes, ty.to_string())
+    } else if is_json(&content.media_type) {
+        (ResponseKind::Json, ty.to_string())
+    } else if ty == "String" {
+        (ResponseKind::Text, ty.to_string())
+    } else {
+        (ResponseKind::Bytes, "Vec<u8>".to_string())
+    }
+}
+
+/// The variant of a status in a response enum, e.g. `NotFound`, `Status299`
+/// or `Status2XX`
+pub(crate) fn variant_name(status: Status) -> String {
+    match status {
+        Status::Code(code) => match status_constant(code) {
+            Some(constant) => to_pascal_case(&constant.to_lowercase()),
+            None => format!("Status{}", code),
+        },
+        Status::Range(range) => format!("Status{}XX", range),
+        Status::Default => "Default".to_string(),
+    }
+}
+
+/// The name of the `StatusCode` constant of a status code
+pub(crate) fn status_constant(code: u16) -> Option<&'static str> {
+    Some(match code {
+        100 => "CONTINUE",
+        101 => "SWITCHING_PROTOCOLS",
+        102 => "PROCESSING",
+        200 => "OK",
+        201 => "CREATED",
+        202 => "ACCEPTED",
+        203 => "NON_AUTHORITATIVE_INFORMATION",
+        204 => "NO_CONTENT",
+        205 => "RESET_CONTENT",
+        206 => "PARTIAL_CONTENT",
+        207 => "MULTI_STATUS",
+        208 => "ALREADY_REPORTED",
+        226 => "IM_USED",
+        300 => "MULTIPLE_CHOICES",
+        301 => "MOVED_PERMANENTLY",
+        302 => "FOUND",
+        303 => "SEE_OTHER",
+        304 => "NOT_MODIFIED",
+        305 => "USE_PROXY",
+        307 => "TEMPORARY_REDIRECT",
+        308 => "PERMANENT_REDIRECT",
+        400 => "BAD_REQUEST",
+        401 => "UNAUTHORIZED",
+        402 => "PAYMENT_REQUIRED",
+        403 => "FORBIDDEN",
+        404 => "NOT_FOUND",
+        405 => "METHOD_NOT_ALLOWED",
+        406 => "NOT_ACCEPTABLE",
+        407 => "PROXY_AUTHENTICATION_REQUIRED",
+        408 => "REQUEST_TIMEOUT",
+        409 => "CONFLICT",
+        410 => "GONE",
+        411 => "LENGTH_REQUIRED",
+        412 => "PRECONDITION_FAILED",
+        413 => "PAYLOAD_TOO_LARGE",
+        414 => "URI_TOO_LONG",
+        415 => "UNSUPPORTED_MEDIA_TYPE",
+        416 => "RANGE_NOT_SATISFIABLE",
+        417 => "EXPECTATION_FAILED",
+        418 => "IM_A_TEAPOT",
+        421 => "MISDIRECTED_REQUEST",
+        422 => "UNPROCESSABLE_ENTITY",
+        423 => "LOCKED",
+        424 => "FAILED_DEPENDENCY",
+        426 => "UPGRADE_REQUIRED",
+        428 => "PRECONDITION_REQUIRED",
+        429 => "TOO_MANY_REQUESTS",
+        431 => "REQUEST_HEADER_FIELDS_TOO_LARGE",
+        451 => "UNAVAILABLE_FOR_LEGAL_REASONS",
+        500 => "INTERNAL_SERVER_ERROR",
+        501 => "NOT_IMPLEMENTED",
+        502 => "BAD_GATEWAY",
+        503 => "SERVICE_UNAVAILABLE",
+        504 => "GATEWAY_TIMEOUT",
+        505 => "HTTP_VERSION_NOT_SUPPORTED",
+        506 => "VARIANT_ALSO_NEGOTIATES",
+        507 => "INSUFFICIENT_STORAGE",
+        508 => "LOOP_DETECTED",
+        510 => "NOT_EXTENDED",
+        511 => "NETWORK_AUTHENTICATION_REQUIRED",
+        _ => return None,
+    })
+}
+
+/// How each field of a multipart body is sent, following its encoding
+fn form_parts<'a>(
+    model: &ApiModel,
+    content: &'a Content,
+    fields: Vec<(Property, String)>,
+) -> Vec<FormPart<'a>> {
+    fields
+        .into_iter()
+        .map(|(property, field)| {
+            let content_type = content
+                .encoding
+                .get(&property.name)
+                .and_then(|encoding| encoding.content_type.as_deref())
+                .and_then(|content_type| {
+                    content_type
+                        .split(',')
+                        .map(str::trim)
+                        .find(|media_type| !media_type.is_empty() && !media_type.contains('*'))
+                });
+            let ty = resolve(model, &property.ty);
+            let kind = match &ty.kind {
+                _ if is_binary(ty) => PartKind::File,
+                TypeKind::Array(items) if is_binary(resolve(model, items)) => PartKind::Files,
+                // A JSON part carries the whole list
+                _ if content_type.is_some_and(is_json) => PartKind::Value,
+                TypeKind::Array(items) => match resolve(model, items).kind {
+                    TypeKind::Primitive { .. } | TypeKind::Enum { .. } => PartKind::Values,
+                    _ => PartKind::Value,
+                },
+                _ => PartKind::Value,
+            };
+            let nullable = property.ty.nullable || ty.nullable;
+            FormPart {
+                optional: (!property.required || nullable)
+                    && !matches!(property.ty.kind, TypeKind::Any),
+                name: property.name,
+                field,
+                ty: property.ty,
+                kind,
+                content_type,
+            }
+        })
+        .collect()
+}
+
+/// The type a reference points to, following chains of references
+fn resolve<'t>(model: &'t ApiModel, ty: &'t TypeDef) -> &'t TypeDef {
+    match &ty.kind {
+        TypeKind::Reference(name) => model
+            .type_def(name)
+            .map_or(ty, |target| resolve(model, target)),
+        _ => ty,
+    }
+}
+
+fn is_binary(ty: &TypeDef) -> bool {
+    matches!(
+        &ty.kind,
+        TypeKind::Primitive {
+            primitive: Primitive::String,
+            format: Some(format),
+        } if format == "binary"
+    )
+}
+
+/// A struct of query or header parameters, deriving `Default` when every
+/// parameter is optional
+fn params_declaration(renderer: &mut TypeRenderer, name: &str, params: &[Param]) -> String {
+    let object = ObjectDef {
+        properties: params
+            .iter()
+            .map(|param| {
+                let mut ty = param.ty.clone();
+                ty.description = param.description.clone().or(ty.description);
+                Property {
+                    name: param.name.clone(),
+                    required: param.required,
+                    ty,
+                }
+            })
+            .collect(),
+        additional_properties: None,
+    };
+    let mut w = CodeWriter::new("    ");
+    let default = params.iter().all(|param| !param.required);
+    renderer.write_struct(&mut w, name, &object, &[], default);
+    w.finish().trim_end().to_string()
+}
@@ -0,0 +1,1240 @@
+use std::collections::BTreeSet;
+
+use indexmap::IndexMap;
+
+use crate::generators::rust::api::{
+    BodyKind, BodyType, FormPart, OperationTypes, ParamsStruct, PartKind, ResponseKind, RustApi,
+    body_kind, is_json, response_kind, status_constant, style_name, variant_name,
+};
+use crate::generators::rust::models::models_source;
+use crate::generators::rust::types::write_doc;
+use crate::generators::rust::{RustOptions, header, module_name};
+use crate::generators::{CodeWriter, GeneratedFile, Generator};
+use crate::ir::{ApiModel, ParamStyle, Primitive, Property, Status, TypeDef, TypeKind};
+use crate::naming::{Casing, Language, NameAllocator, to_identifier, to_pascal_case};
+
+/// Emits an `axum` server: a trait with one async method per operation, a
+/// response enum per operation and a `router` serving an implementation,
+/// along with the models and a `mod.rs` declaring both modules
+///
+/// Trait methods receive path parameters, the body and the structs of the
+/// query and header parameters, which implement `FromRequestParts` and decode
+/// every parameter according to its style. Form bodies are decoded the same
+/// way, following their encoding, except for objects in the `form` style, and
+/// multipart bodies whose type is a struct are read part by part. A path segment mixing parameters with text, e.g.
+/// `{id}.json`, is captured whole and split by its handler. Each response
+/// enum has a variant per declared status; ranges and `default` carry the
+/// status code. The generated code needs `axum`, with the `multipart` feature
+/// for multipart bodies, `serde` and `serde_json`.
+#[derive(Debug, Clone, Default)]
+pub struct AxumGenerator {
+    options: RustOptions,
+}
+
+impl AxumGenerator {
+    pub fn new(options: RustOptions) -> Self {
+        AxumGenerator { options }
+    }
+}
+
+impl Generator for AxumGenerator {
+    fn name(&self) -> &str {
+        "rust-axum"
+    }
+
+    fn generate(&self, model: &ApiModel) -> Vec<GeneratedFile> {
+        let api = RustApi::new(model, &self.options);
+        let mut names = NameAllocator::new();
+        for name in &api.type_names {
+            names.reserve(name.as_str());
+        }
+        for params in api.operations.iter().flat_map(params_structs) {
+            names.reserve(params.name.as_str());
+        }
+        names
+            .request("api", "Api")
+            .request("rejection", "Rejection");
+        for operation in &api.operations {
+            names.request(
+                response_key(&operation.name),
+                format!("{}Response", to_pascal_case(&operation.operation.name)),
+            );
+        }
+        let names = names.resolve();
+
+        let models = module_name(&self.options.models_file);
+        let module = module_name(&self.options.server_file);
+        let mut root = CodeWriter::new("    ");
+        root.line(header(model));
+        root.blank();
+        root.line(format!("pub mod {};", models));
+        root.line(format!("pub mod {};", module));
+        root.blank();
+        root.line(format!("pub use {}::{{{}, router}};", module, names["api"]));
+
+        vec![
+            GeneratedFile::new(self.options.models_file.clone(), models_source(model, &api)),
+            GeneratedFile::new(
+                self.options.server_file.clone(),
+                server_source(model, &api, &models, &names),
+            ),
+            GeneratedFile::new("mod.rs", root.finish()),
+        ]
+    }
+}
+
+fn response_key(operation: &str) -> String {
+    format!("response#{}", operation)
+}
+
+fn params_structs<'o, 'a>(
+    operation: &'o OperationTypes<'a>,
+) -> impl Iterator<Item = &'o ParamsStruct<'a>> {
+    operation.query.iter().chain(&operation.headers)
+}
+
+/// Functions the generated server calls, emitted only when used
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Helper {
+    QueryPairs,
+    QueryValues,
+    HeaderValues,
+    ParamValue,
+    FromParams,
+    DeepObject,
+    FormPairs,
+    MultipartParts,
+    PartFile,
+    PartFiles,
+    PartValue,
+    SegmentValues,
+    PathParam,
+}
+
+impl Helper {
+    /// Whether the helper returns the rejection
+    fn rejects(&self) -> bool {
+        matches!(
+            self,
+            Helper::FromParams | Helper::PartFile | Helper::PartValue | Helper::PathParam
+        )
+    }
+}
+
+fn server_source(
+    model: &ApiModel,
+    api: &RustApi,
+    models: &str,
+    names: &IndexMap<String, String>,
+) -> String {
+    let (trait_name, rejection) = (&names["api"], &names["rejection"]);
+    let mut handlers = NameAllocator::new();
+    for reserved in HELPER_NAMES {
+        handlers.reserve(*reserved);
+    }
+    for operation in &api.operations {
+        handlers.request(operation.name.clone(), operation.name.clone());
+    }
+    let handlers = handlers.resolve();
+
+    let mut helpers = BTreeSet::new();
+    let mut body = CodeWriter::new("    ");
+    body.line(format!("/// The operations of {}", model.title));
+    body.line("///");
+    body.line("/// [`router`] serves an implementation.");
+    body.block(
+        format!("pub trait {}: Send + Sync + 'static {{", trait_name),
+        "}",
+        |w| {
+            for (i, operation) in api.operations.iter().enumerate() {
+                if i > 0 {
+                    w.blank();
+                }
+                trait_method(w, operation, &names[&response_key(&operation.name)]);
+            }
+        },
+    );
+    for operation in &api.operations {
+        body.blank();
+        body.line(response_enum(
+            operation,
+            &names[&response_key(&operation.name)],
+            trait_name,
+        ));
+    }
+    let structs: Vec<(&ParamsStruct, bool)> = api
+        .operations
+        .iter()
+        .flat_map(|operation| {
+            let query = operation.query.iter().map(|params| (params, true));
+            query.chain(operation.headers.iter().map(|params| (params, false)))
+        })
+        .collect();
+    let mut extractors = CodeWriter::new("    ");
+    for (params, query) in structs {
+        extractors.blank();
+        extractors.line(params_extractor(
+            model,
+            params,
+            query,
+            rejection,
+            &mut helpers,
+        ));
+    }
+    let mut routes = CodeWriter::new("    ");
+    for operation in &api.operations {
+        routes.blank();
+        handler(
+            &mut routes,
+            model,
+            operation,
+            trait_name,
+            &handlers[&operation.name],
+            &names[&response_key(&operation.name)],
+            &mut helpers,
+        );
+    }
+    if helpers.iter().any(|helper| helper.rejects()) {
+        body.blank();
+        body.line(REJECTION.replace("$Rejection", rejection));
+    }
+    let extractors = extractors.finish();
+    if !extractors.is_empty() {
+        body.blank();
+        body.line(extractors.trim_end());
+    }
+    body.blank();
+    router(&mut body, api, trait_name, &handlers);
+    body.blank();
+    body.line(routes.finish().trim_end());
+    for helper in &helpers {
+        body.blank();
+        body.line(helper_source(*helper).replace("$Rejection", rejection));
+    }
+
+    let mut w = CodeWriter::new("    ");
+    w.line(header(model));
+    w.blank();
+    w.line("#[allow(unused_imports)]");
+    w.line(format!("use super::{}::*;", models));
+    w.blank();
+    w.line(body.finish());
+    w.finish().trim_end().to_string() + "\n"
+}
+
+/// The type a trait method takes the body as
+fn body_argument(operation: &OperationTypes) -> Option<(BodyKind, String)> {
+    let body = operation.body.as_ref()?;
+    let kind = body_kind(&body.content.media_type, &body.ty);
+    let ty = match kind {
+        _ if body.parts.is_some() => body.ty.clone(),
+        BodyKind::Json | BodyKind::Form => body.ty.clone(),
+        BodyKind::Text => "String".to_string(),
+        BodyKind::Bytes => "Vec<u8>".to_string(),
+    };
+    if body.required {
+        Some((kind, ty))
+    } else {
+        Some((kind, format!("Option<{}>", ty)))
+    }
+}
+
+fn trait_method(w: &mut CodeWriter, operation: &OperationTypes, response: &str) {
+    let mut arguments = vec!["&self".to_string()];
+    for (_, name, ty) in &operation.path_params {
+        arguments.push(format!("{}: {}", name, ty));
+    }
+    if let Some((_, ty)) = body_argument(operation) {
+        arguments.push(format!("body: {}", ty));
+    }
+    if let Some(params) = &operation.query {
+        arguments.push(format!("params: {}", params.name));
+    }
+    if let Some(headers) = &operation.headers {
+        arguments.push(format!("headers: {}", headers.name));
+    }
+
+    let mut doc: Vec<&str> = operation
+        .operation
+        .summary
+        .iter()
+        .chain(&operation.operation.description)
+        .map(String::as_str)
+        .collect();
+    doc.dedup();
+    write_doc(
+        w,
+        Some(&doc.join("\n\n"))
+            .filter(|doc| !doc.is_empty())
+            .map(String::as_str),
+    );
+    w.line(format!(
+        "fn {}({}) -> impl std::future::Future<Output = {}> + Send;",
+        operation.name,
+        arguments.join(", "),
+        response
+    ));
+}
+
+/// A variant of a response enum
+struct Variant {
+    name: String,
+    /// The status constant; `None` for ranges and `default`, whose variants
+    /// carry the status
+    status: Option<String>,
+    kind: ResponseKind,
+    ty: String,
+    media_type: String,
+}
+
+/// The enum of an operation's responses and its `IntoResponse` impl
+fn response_enum(operation: &OperationTypes, name: &str, trait_name: &str) -> String {
+    let variants: Vec<Variant> = operation
+        .responses
+        .iter()
+        .map(|response| {
+            let status = match response.response.status {
+                Status::Code(code) => Some(match status_constant(code) {
+                    Some(constant) => format!("axum::http::StatusCode::{}", constant),
+                    None => format!(
+                        "axum::http::StatusCode::from_u16({}).unwrap_or(axum::http::StatusCode::INTERNAL_SERVER_ERROR)",
+                        code
+                    ),
+                }),
+                Status::Range(_) | Status::Default => None,
+            };
+            let (kind, ty) = response_kind(response);
+            let media_type = response
+                .content
+                .as_ref()
+                .map(|(content, _)| content.media_type.clone())
+                .unwrap_or_default();
+            Variant {
+                name: variant_name(response.response.status),
+                status,
+                kind,
+                ty,
+                media_type,
+            }
+        })
+        .collect();
+
+    let mut w = CodeWriter::new("    ");
+    w.line(format!(
+        "/// The responses of [`{}::{}`]",
+        trait_name, operation.name
+    ));
+    w.line("#[derive(Debug)]");
+    w.block(format!("pub enum {} {{", name), "}", |w| {
+        for (response, variant) in operation.responses.iter().zip(&variants) {
+            write_doc(
+                w,
+                Some(response.response.description.as_str()).filter(|doc| !doc.trim().is_empty()),
+            );
+            let mut fields = Vec::new();
+            if variant.status.is_none() {
+                fields.push("axum::http::StatusCode");
+            }
+            if variant.kind != ResponseKind::Empty {
+                fields.push(&variant.ty);
+            }
+            if fields.is_empty() {
+                w.line(format!("{},", variant.name));
+            } else {
+                w.line(format!("{}({}),", variant.name, fields.join(", ")));
+            }
+        }
+    });
+    w.blank();
+    w.block(
+        format!("impl axum::response::IntoResponse for {} {{", name),
+        "}",
+        |w| {
+            w.block(
+                "fn into_response(self) -> axum::response::Response {",
+                "}",
+                |w| {
+                    if variants.is_empty() {
+                        w.line("match self {}");
+                        return;
+                    }
+                    w.block("match self {", "}", |w| {
+                        for variant in &variants {
+                            let name = &variant.name;
+                            let (pattern, status) = match (&variant.status, variant.kind) {
+                                (Some(status), ResponseKind::Empty) => {
+                                    (name.clone(), status.clone())
+                                }
+                                (Some(status), _) => (format!("{}(body)", name), status.clone()),
+                                (None, ResponseKind::Empty) => {
+                                    (format!("{}(status)", name), "status".to_string())
+                                }
+                                (None, _) => {
+                                    (format!("{}(status, body)", name), "status".to_string())
+                                }
+                            };
+                            let response = match variant.kind {
+                                ResponseKind::Empty => status,
+                                ResponseKind::Json => format!("({}, axum::Json(body))", status),
+                                ResponseKind::Text | ResponseKind::Bytes => format!(
+                                    "({}, [(axum::http::header::CONTENT_TYPE, {:?})], body)",
+                                    status, variant.media_type
+                                ),
+                            };
+                            w.line(format!(
+                                "Self::{} => {}.into_response(),",
+                                pattern, response
+                            ));
+                        }
+                    });
+                },
+            );
+        },
+    );
+    w.finish().trim_end().to_string()
+}
+
+/// The `FromRequestParts` impl of a query or header parameter struct
+fn params_extractor(
+    model: &ApiModel,
+    params: &ParamsStruct,
+    query: bool,
+    rejection: &str,
+    helpers: &mut BTreeSet<Helper>,
+) -> String {
+    helpers.insert(Helper::ParamValue);
+    helpers.insert(Helper::FromParams);
+    if query {
+        helpers.insert(Helper::QueryPairs);
+    } else {
+        helpers.insert(Helper::HeaderValues);
+    }
+
+    let mut w = CodeWriter::new("    ");
+    w.block(
+        format!(
+            "impl<S: Send + Sync> axum::extract::FromRequestParts<S> for {} {{",
+            params.name
+        ),
+        "}",
+        |w| {
+            w.line(format!("type Rejection = {};", rejection));
+            w.blank();
+            w.block(
+                format!(
+                    "async fn from_request_parts(parts: &mut axum::http::request::Parts, _state: &S) -> Result<Self, {}> {{",
+                    rejection
+                ),
+                "}",
+                |w| {
+                    if query {
+                        w.line("let query = query_pairs(parts);");
+                    }
+                    w.block("from_params(vec![", "])", |w| {
+                        for (param, _) in &params.fields {
+                            let value = if query {
+                                pair_value(
+                                    model,
+                                    "query",
+                                    &param.name,
+                                    &param.ty,
+                                    param.style,
+                                    param.explode,
+                                    helpers,
+                                )
+                            } else {
+                                let (kind, array) = param_kind(model, &param.ty);
+                                format!(
+                                    "param_value(header_values(parts, {:?}), {:?}, {}, {:?}, {})",
+                                    param.name,
+                                    kind,
+                                    array,
+                                    style_name(param.style),
+                                    param.explode
+                                )
+                            };
+                            w.line(format!("({:?}, {}),", param.name, value));
+                        }
+                    });
+                },
+            );
+        },
+    );
+    w.finish().trim_end().to_string()
+}
+
+/// The JSON kind of a parameter's values and whether it is an array
+fn param_kind(model: &ApiModel, ty: &TypeDef) -> (&'static str, bool) {
+    match &ty.kind {
+        TypeKind::Array(items) => (param_kind(model, items).0, true),
+        TypeKind::Primitive { primitive, .. } => (primitive_kind(*primitive), false),
+        TypeKind::Enum { values, .. } => match values.first() {
+            Some(serde_json::Value::Number(_)) => ("number", false),
+            Some(serde_json::Value::Bool(_)) => ("boolean", false),
+            _ => ("string", false),
+        },
+        TypeKind::Union { variants, .. } if variants.len() == 1 => param_kind(model, &variants[0]),
+        TypeKind::Reference(name) => match model.type_def(name) {
+            Some(ty) if !model.is_recursive(name) => param_kind(model, ty),
+            _ => ("string", false),
+        },
+        _ => ("string", false),
+    }
+}
+
+fn primitive_kind(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::String => "string",
+        Primitive::Integer => "integer",
+        Primitive::Number => "number",
+        Primitive::Boolean => "boolean",
+    }
+}
+
+/// `router`, registering the handler of every operation by path
+fn router(
+    w: &mut CodeWriter,
+    api: &RustApi,
+    trait_name: &str,
+    handlers: &IndexMap<String, String>,
+) {
+    let mut paths: IndexMap<String, Vec<String>> = IndexMap::new();
+    for operation in &api.operations {
+        paths
+            .entry(route_path(&operation.operation.path))
+            .or_default()
+            .push(format!(
+                "{}({}::<T>)",
+                operation.operation.method.to_lowercase(),
+                handlers[&operation.name]
+            ));
+    }
+    w.line(format!(
+        "/// A router serving every operation of [`{}`] with `api`",
+        trait_name
+    ));
+    w.block(
+        format!(
+            "pub fn router<T: {}>(api: T) -> axum::Router {{",
+            trait_name
+        ),
+        "}",
+        |w| {
+            w.line("axum::Router::new()");
+            w.indent();
+            for (path, methods) in &paths {
+                w.line(format!(
+                    ".route({:?}, axum::routing::{})",
+                    path,
+                    methods.join(".")
+                ));
+            }
+            w.line(".with_state(std::sync::Arc::new(api))");
+            w.dedent();
+        },
+    );
+}
+
+/// A path segment mixing parameters with text, e.g. `{id}.json`, which axum
+/// captures whole for the handler to split
+struct AffixedSegment {
+    /// The name the segment is captured as
+    capture: String,
+    /// The text around and between the parameters
+    literals: Vec<String>,
+    /// The names of the parameters, in order
+    params: Vec<String>,
+}
+
+/// The text and the parameter names of a path segment, e.g. `["", ".json"]`
+/// and `["id"]` for `{id}.json`
+fn segment_parts(segment: &str) -> (Vec<String>, Vec<String>) {
+    let (mut literals, mut params) = (Vec::new(), Vec::new());
+    let mut rest = segment;
+    while let Some(start) = rest.find('{')
+        && let Some(end) = rest[start..].find('}')
+    {
+        literals.push(rest[..start].to_string());
+        params.push(rest[start + 1..start + end].to_string());
+        rest = &rest[start + end + 1..];
+    }
+    literals.push(rest.to_string());
+    (literals, params)
+}
+
+/// The parameter a path segment consists of, or the segment as captured whole
+/// when it mixes parameters with text; `None` without parameters
+fn path_segment(segment: &str) -> Option<Result<String, AffixedSegment>> {
+    let (literals, params) = segment_parts(segment);
+    match params.as_slice() {
+        [] => None,
+        [param] if literals.iter().all(String::is_empty) => Some(Ok(param.clone())),
+        _ => Some(Err(AffixedSegment {
+            capture: to_identifier(
+                &segment.replace(['{', '}'], ""),
+                Casing::Snake,
+                Language::Rust,
+            ),
+            literals,
+            params,
+        })),
+    }
+}
+
+/// The path axum routes an operation by, capturing affixed segments whole
+/// since axum allows a single parameter and no text in a captured segment
+fn route_path(path: &str) -> String {
+    path.split('/')
+        .map(|segment| match path_segment(segment) {
+            Some(Err(affixed)) => format!("{{{}}}", affixed.capture),
+            _ => segment.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// The JSON value of a query or form field, decoded from the name-value
+/// pairs in `pairs` according to its style
+fn pair_value(
+    model: &ApiModel,
+    pairs: &str,
+    name: &str,
+    ty: &TypeDef,
+    style: ParamStyle,
+    explode: bool,
+    helpers: &mut BTreeSet<Helper>,
+) -> String {
+    helpers.insert(Helper::ParamValue);
+    if style == ParamStyle::DeepObject {
+        helpers.insert(Helper::DeepObject);
+        let kinds: Vec<String> = object_properties(model, ty)
+            .into_iter()
+            .map(|property| {
+                let (kind, array) = param_kind(model, &property.ty);
+                format!("({:?}, {:?}, {})", property.name, kind, array)
+            })
+            .collect();
+        return format!(
+            "deep_object(&{}, {:?}, &[{}])",
+            pairs,
+            name,
+            kinds.join(", ")
+        );
+    }
+    helpers.insert(Helper::QueryValues);
+    let (kind, array) = param_kind(model, ty);
+    format!(
+        "param_value(query_values(&{}, {:?}), {:?}, {}, {:?}, {})",
+        pairs,
+        name,
+        kind,
+        array,
+        style_name(style),
+        explode
+    )
+}
+
+/// The properties of an object type, following references and merging
+/// intersections
+fn object_properties<'m>(model: &'m ApiModel, ty: &'m TypeDef) -> Vec<&'m Property> {
+    match &ty.kind {
+        TypeKind::Object(object) => object.properties.iter().collect(),
+        TypeKind::Intersection(members) => members
+            .iter()
+            .flat_map(|member| object_properties(model, member))
+            .collect(),
+        TypeKind::Reference(name) if !model.is_recursive(name) => model
+            .type_def(name)
+            .map(|ty| object_properties(model, ty))
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+/// The `from_params` call deserializing a form body from its pairs in `body`
+fn form_value(model: &ApiModel, body: &BodyType, helpers: &mut BTreeSet<Helper>) -> Vec<String> {
+    let properties = object_properties(model, &body.content.ty);
+    if properties.is_empty() {
+        return vec![
+            "from_params(body.iter().map(|(name, value)| (name.as_str(), Some(serde_json::Value::from(value.as_str())))).collect())?".to_string(),
+        ];
+    }
+    let mut lines = vec!["from_params(vec![".to_string()];
+    for property in properties {
+        let encoding = body.content.encoding.get(&property.name);
+        let style = encoding
+            .and_then(|encoding| encoding.style)
+            .unwrap_or(ParamStyle::Form);
+        let explode = encoding.is_none_or(|encoding| encoding.explode);
+        lines.push(format!(
+            "    ({:?}, {}),",
+            property.name,
+            pair_value(
+                model,
+                "body",
+                &property.name,
+                &property.ty,
+                style,
+                explode,
+                helpers
+            )
+        ));
+    }
+    lines.push("])?".to_string());
+    lines
+}
+
+/// The struct literal building a multipart body from its parts in `body`
+fn multipart_value(
+    model: &ApiModel,
+    body: &BodyType,
+    parts: &[FormPart],
+    helpers: &mut BTreeSet<Helper>,
+) -> Vec<String> {
+    let mut lines = vec![format!("{} {{", body.ty)];
+    for part in parts {
+        let value = match (part.kind, part.optional) {
+            (PartKind::File, false) => {
+                helpers.insert(Helper::PartFile);
+                format!("part_file(&body, {:?})?", part.name)
+            }
+            (PartKind::File, true) => {
+                helpers.insert(Helper::PartFiles);
+                format!("part_files(&body, {:?}).into_iter().next()", part.name)
+            }
+            (PartKind::Files, false) => {
+                helpers.insert(Helper::PartFiles);
+                format!("part_files(&body, {:?})", part.name)
+            }
+            (PartKind::Files, true) => {
+                helpers.insert(Helper::PartFiles);
+                format!(
+                    "Some(part_files(&body, {:?})).filter(|files| !files.is_empty())",
+                    part.name
+                )
+            }
+            (PartKind::Value | PartKind::Values, _) => {
+                helpers.insert(Helper::PartValue);
+                helpers.insert(Helper::ParamValue);
+                let (kind, array) = param_kind(model, &part.ty);
+                // Clients send objects and JSON encodings as JSON text
+                let json = part.content_type.is_some_and(is_json)
+                    || part.kind == PartKind::Value
+                        && !object_properties(model, &part.ty).is_empty()
+                    || part.kind == PartKind::Value && array;
+                format!(
+                    "part_value(&body, {:?}, {:?}, {}, {})?",
+                    part.name, kind, array, json
+                )
+            }
+        };
+        lines.push(format!("    {}: {},", part.field, value));
+    }
+    if let Some(field) = &body.additional_field {
+        lines.push(format!("    {}: Default::default(),", field));
+    }
+    lines.push("}".to_string());
+    lines
+}
+
+/// The axum handler extracting an operation's inputs and calling the trait
+///
+/// Handlers that decode a body or split an affixed path segment themselves
+/// return the response of a failure as their error.
+fn handler(
+    w: &mut CodeWriter,
+    model: &ApiModel,
+    operation: &OperationTypes,
+    trait_name: &str,
+    name: &str,
+    response: &str,
+    helpers: &mut BTreeSet<Helper>,
+) {
+    let mut extractors =
+        vec!["axum::extract::State(api): axum::extract::State<std::sync::Arc<T>>".to_string()];
+    let mut statements: Vec<String> = Vec::new();
+    let mut arguments = Vec::new();
+
+    // axum matches tuple elements to captured segments in URL order
+    let path_param = |name: &str| {
+        operation
+            .path_params
+            .iter()
+            .find(|(param, ..)| param.name == name)
+    };
+    let mut captures = Vec::new();
+    for segment in operation.operation.path.split('/').filter_map(path_segment) {
+        match segment {
+            Ok(param) => {
+                if let Some((_, name, ty)) = path_param(&param) {
+                    captures.push((name.clone(), ty.clone()));
+                }
+            }
+            Err(affixed) => {
+                helpers.insert(Helper::SegmentValues);
+                let literals: Vec<String> = affixed
+                    .literals
+                    .iter()
+                    .map(|literal| format!("{:?}", literal))
+                    .collect();
+                statements.push(format!(
+                    "let {capture} = segment_values(&{capture}, &[{}])?;",
+                    literals.join(", "),
+                    capture = affixed.capture
+                ));
+                for (i, param) in affixed.params.iter().enumerate() {
+                    let Some((param, name, _)) = path_param(param) else {
+                        continue;
+                    };
+                    helpers.insert(Helper::PathParam);
+                    helpers.insert(Helper::ParamValue);
+                    let (kind, array) = param_kind(model, &param.ty);
+                    statements.push(format!(
+                        "let {} = path_param({}[{}], {:?}, {}, {:?}, {})?;",
+                        name,
+                        affixed.capture,
+                        i,
+                        kind,
+                        array,
+                        style_name(param.style),
+                        param.explode
+                    ));
+                }
+                captures.push((affixed.capture, "String".to_string()));
+            }
+        }
+    }
+    match captures.as_slice() {
+        [] => {}
+        [(name, ty)] => extractors.push(format!(
+            "axum::extract::Path({}): axum::extract::Path<{}>",
+            name, ty
+        )),
+        captures => {
+            let names: Vec<&str> = captures.iter().map(|(name, _)| name.as_str()).collect();
+            let types: Vec<&str> = captures.iter().map(|(_, ty)| ty.as_str()).collect();
+            extractors.push(format!(
+                "axum::extract::Path(({})): axum::extract::Path<({})>",
+                names.join(", "),
+                types.join(", ")
+            ));
+        }
+    }
+    arguments.extend(
+        operation
+            .path_params
+            .iter()
+            .map(|(_, name, _)| name.clone()),
+    );
+    if let Some(params) = &operation.query {
+        extractors.push(format!("params: {}", params.name));
+    }
+    if let Some(headers) = &operation.headers {
+        extractors.push(format!("headers: {}", headers.name));
+    }
+    if let Some(body) = &operation.body
+        && let Some(parts) = &body.parts
+    {
+        helpers.insert(Helper::MultipartParts);
+        let value = multipart_value(model, body, parts, helpers);
+        if body.required {
+            extractors.push("body: axum::extract::Multipart".to_string());
+            statements.push("let body = multipart_parts(body).await?;".to_string());
+            statements.extend(wrap(&value, "", "let body = ", ";"));
+        } else {
+            extractors.push("body: Option<axum::extract::Multipart>".to_string());
+            statements.push("let body = match body {".to_string());
+            statements.push("    Some(body) => {".to_string());
+            statements.push("        let body = multipart_parts(body).await?;".to_string());
+            statements.extend(wrap(&value, "        ", "Some(", ")"));
+            statements.push("    }".to_string());
+            statements.push("    None => None,".to_string());
+            statements.push("};".to_string());
+        }
+        arguments.push("body".to_string());
+    } else if let (Some(body), Some((kind, _))) = (&operation.body, body_argument(operation)) {
+        let (extractor, argument) = match (kind, body.required) {
+            (BodyKind::Json, true) => {
+                (format!("axum::Json(body): axum::Json<{}>", body.ty), "body")
+            }
+            (BodyKind::Json, false) => (
+                format!("body: Option<axum::Json<{}>>", body.ty),
+                "body.map(|axum::Json(body)| body)",
+            ),
+            (BodyKind::Form, required) => {
+                helpers.insert(Helper::FormPairs);
+                helpers.insert(Helper::FromParams);
+                let value = form_value(model, body, helpers);
+                if required {
+                    statements.push(
+                        "let body = form_pairs(body).await?.unwrap_or_default();".to_string(),
+                    );
+                    statements.extend(wrap(&value, "", "let body = ", ";"));
+                } else {
+                    statements.push("let body = match form_pairs(body).await? {".to_string());
+                    statements.extend(wrap(&value, "    ", "Some(body) => Some(", "),"));
+                    statements.push("    None => None,".to_string());
+                    statements.push("};".to_string());
+                }
+                ("body: axum::extract::Request".to_string(), "body")
+            }
+            (BodyKind::Text, true) => ("body: String".to_string(), "body"),
+            (BodyKind::Text, false) => (
+                "body: String".to_string(),
+                "(!body.is_empty()).then_some(body)",
+            ),
+            (BodyKind::Bytes, true) => ("body: axum::body::Bytes".to_string(), "body.to_vec()"),
+            (BodyKind::Bytes, false) => (
+                "body: axum::body::Bytes".to_string(),
+                "(!body.is_empty()).then(|| body.to_vec())",
+            ),
+        };
+        extractors.push(extractor);
+        arguments.push(argument.to_string());
+    }
+    if operation.query.is_some() {
+        arguments.push("params".to_string());
+    }
+    if operation.headers.is_some() {
+        arguments.push("headers".to_string());
+    }
+
+    let call = format!("api.{}({}).await", operation.name, arguments.join(", "));
+    let response = if statements.is_empty() {
+        response.to_string()
+    } else {
+        format!("Result<{}, axum::response::Response>", response)
+    };
+    w.block(
+        format!(
+            "async fn {}<T: {}>({}) -> {} {{",
+            name,
+            trait_name,
+            extractors.join(", "),
+            response
+        ),
+        "}",
+        |w| {
+            if statements.is_empty() {
+                w.line(call);
+                return;
+            }
+            for statement in &statements {
+                w.line(statement);
+            }
+            w.line(format!("Ok({})", call));
+        },
+    );
+}
+
+/// The lines of a multi-line expression, indented, with `prefix` before its
+/// first line and `suffix` after its last
+fn wrap(value: &[String], indent: &str, prefix: &str, suffix: &str) -> Vec<String> {
+    let last = value.len().saturating_sub(1);
+    value
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let prefix = if i == 0 { prefix } else { "" };
+            let suffix = if i == last { suffix } else { "" };
+            format!("{}{}{}{}", indent, prefix, line, suffix)
+        })
+        .collect()
+}
+
+/// Names of the functions the server declares besides the handlers
+const HELPER_NAMES: &[&str] = &[
+    "router",
+    "query_pairs",
+    "query_values",
+    "header_values",
+    "param_value",
+    "from_params",
+    "deep_object",
+    "form_pairs",
+    "multipart_parts",
+    "part_file",
+    "part_files",
+    "part_value",
+    "segment_values",
+    "path_param",
+];
+
+/// The rejection and the helpers mention it as `$Rejection`, since its name
+/// may be taken by a component
+const REJECTION: &str = r#"/// The rejection of a request whose parameters do not match the spec
+#[derive(Debug)]
+pub struct $Rejection(pub String);
+
+impl axum::response::IntoResponse for $Rejection {
+    fn into_response(self) -> axum::response::Response {
+        (axum::http::StatusCode::BAD_REQUEST, self.0).into_response()
+    }
+}
+
+impl From<$Rejection> for axum::response::Response {
+    fn from(rejection: $Rejection) -> Self {
+        axum::response::IntoResponse::into_response(rejection)
+    }
+}"#;
+
+fn helper_source(helper: Helper) -> &'static str {
+    match helper {
+        Helper::QueryPairs => QUERY_PAIRS,
+        Helper::QueryValues => QUERY_VALUES,
+        Helper::HeaderValues => HEADER_VALUES,
+        Helper::ParamValue => PARAM_VALUE,
+        Helper::FromParams => FROM_PARAMS,
+        Helper::DeepObject => DEEP_OBJECT,
+        Helper::FormPairs => FORM_PAIRS,
+        Helper::MultipartParts => MULTIPART_PARTS,
+        Helper::PartFile => PART_FILE,
+        Helper::PartFiles => PART_FILES,
+        Helper::PartValue => PART_VALUE,
+        Helper::SegmentValues => SEGMENT_VALUES,
+        Helper::PathParam => PATH_PARAM,
+    }
+}
+
+const QUERY_PAIRS: &str = r#"/// The decoded name-value pairs of the query string
+fn query_pairs(parts: &axum::http::request::Parts) -> Vec<(String, String)> {
+    axum::extract::Query::<Vec<(String, String)>>::try_from_uri(&parts.uri)
+        .map(|axum::extract::Query(pairs)| pairs)
+        .unwrap_or_default()
+}"#;
+
+const QUERY_VALUES: &str = r#"/// Every value of a query parameter, in order
+fn query_values<'a>(query: &'a [(String, String)], name: &str) -> Vec<&'a str> {
+    query
+        .iter()
+        .filter(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+        .collect()
+}"#;
+
+const HEADER_VALUES: &str = r#"/// Every value of a header, in order
+fn header_values<'a>(parts: &'a axum::http::request::Parts, name: &str) -> Vec<&'a str> {
+    parts
+        .headers
+        .get_all(name)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .collect()
+}"#;
+
+const PARAM_VALUE: &str = r#"/// Convert the raw values of a parameter to JSON according to the `kind` of
+/// its items, its `style` and `explode`; `None` when it is absent
+fn param_value(
+    values: Vec<&str>,
+    kind: &str,
+    array: bool,
+    style: &str,
+    explode: bool,
+) -> Option<serde_json::Value> {
+    let item = |value: &str| match kind {
+        "integer" | "number" => value
+            .parse::<serde_json::Number>()
+            .map_or_else(|_| serde_json::Value::from(value), serde_json::Value::Number),
+        "boolean" => match value {
+            "true" => serde_json::Value::Bool(true),
+            "false" => serde_json::Value::Bool(false),
+            _ => serde_json::Value::from(value),
+        },
+        _ => serde_json::Value::from(value),
+    };
+    if !array {
+        return values.first().map(|value| item(value));
+    }
+    if values.is_empty() {
+        return None;
+    }
+    let separator = match style {
+        "spaceDelimited" => " ",
+        "pipeDelimited" => "|",
+        _ => ",",
+    };
+    let items = values
+        .iter()
+        .flat_map(|value| {
+            if explode && style == "form" {
+                vec![*value]
+            } else {
+                value.split(separator).collect()
+            }
+        })
+        .map(item)
+        .collect();
+    Some(serde_json::Value::Array(items))
+}"#;
+
+const FROM_PARAMS: &str = r#"/// Deserialize parameters from their JSON values, rejecting the request when
+/// they do not match
+fn from_params<T: serde::de::DeserializeOwned>(
+    params: Vec<(&str, Option<serde_json::Value>)>,
+) -> Result<T, $Rejection> {
+    let map = params
+        .into_iter()
+        .filter_map(|(name, value)| Some((name.to_string(), value?)))
+        .collect();
+    serde_json::from_value(serde_json::Value::Object(map)).map_err(|error| $Rejection(error.to_string()))
+}"#;
+
+const DEEP_OBJECT: &str = r#"/// The properties of a `deepObject` parameter, gathered from its
+/// `name[property]` pairs and converted according to the `kinds` of their
+/// items; `None` when it is absent
+fn deep_object(
+    query: &[(String, String)],
+    name: &str,
+    kinds: &[(&str, &str, bool)],
+) -> Option<serde_json::Value> {
+    let mut properties: Vec<(&str, Vec<&str>)> = Vec::new();
+    for (key, value) in query {
+        let Some(property) = key
+            .strip_prefix(name)
+            .and_then(|key| key.strip_prefix('['))
+            .and_then(|key| key.strip_suffix(']'))
+        else {
+            continue;
+        };
+        match properties.iter_mut().find(|(existing, _)| *existing == property) {
+            Some((_, values)) => values.push(value),
+            None => properties.push((property, vec![value])),
+        }
+    }
+    if properties.is_empty() {
+        return None;
+    }
+    let object = properties
+        .into_iter()
+        .filter_map(|(property, values)| {
+            let (kind, array) = kinds
+                .iter()
+                .find(|(name, ..)| *name == property)
+                .map_or(("string", false), |(_, kind, array)| (*kind, *array));
+            Some((property.to_string(), param_value(values, kind, array, "form", true)?))
+        })
+        .collect();
+    Some(serde_json::Value::Object(object))
+}"#;
+
+const FORM_PAIRS: &str = r#"/// The decoded name-value pairs of a form body; `None` when it is empty
+async fn form_pairs(
+    request: axum::extract::Request,
+) -> Result<Option<Vec<(String, String)>>, axum::response::Response> {
+    use axum::extract::FromRequest;
+    use axum::response::IntoResponse;
+
+    let (parts, body) = request.into_parts();
+    let body = axum::body::Bytes::from_request(axum::extract::Request::from_parts(parts.clone(), body), &())
+        .await
+        .map_err(IntoResponse::into_response)?;
+    if body.is_empty() {
+        return Ok(None);
+    }
+    let request = axum::extract::Request::from_parts(parts, body.into());
+    let axum::Form(pairs) = axum::Form::from_request(request, &())
+        .await
+        .map_err(IntoResponse::into_response)?;
+    Ok(Some(pairs))
+}"#;
+
+const MULTIPART_PARTS: &str = r#"/// The name and content of every part of a multipart body, in order
+async fn multipart_parts(
+    mut multipart: axum::extract::Multipart,
+) -> Result<Vec<(String, axum::body::Bytes)>, axum::response::Response> {
+    use axum::response::IntoResponse;
+
+    let mut parts = Vec::new();
+    while let Some(field) = multipart.next_field().await.map_err(IntoResponse::into_response)? {
+        let name = field.name().unwrap_or_default().to_string();
+        parts.push((name, field.bytes().await.map_err(IntoResponse::into_response)?));
+    }
+    Ok(parts)
+}"#;
+
+const PART_FILE: &str = r#"/// The content of a required file part, rejecting the request when it is
+/// missing
+fn part_file(parts: &[(String, axum::body::Bytes)], name: &str) -> Result<Vec<u8>, $Rejection> {
+    parts
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, content)| content.to_vec())
+        .ok_or_else(|| $Rejection(format!("missing part `{}`", name)))
+}"#;
+
+const PART_FILES: &str = r#"/// The content of every part named `name`, in order
+fn part_files(parts: &[(String, axum::body::Bytes)], name: &str) -> Vec<Vec<u8>> {
+    parts
+        .iter()
+        .filter(|(key, _)| key == name)
+        .map(|(_, content)| content.to_vec())
+        .collect()
+}"#;
+
+const PART_VALUE: &str = r#"/// Deserialize a property of a multipart body from its parts: a JSON part
+/// carries the whole value, otherwise the text of each part is converted
+/// according to the `kind` of its items
+fn part_value<T: serde::de::DeserializeOwned>(
+    parts: &[(String, axum::body::Bytes)],
+    name: &str,
+    kind: &str,
+    array: bool,
+    json: bool,
+) -> Result<T, $Rejection> {
+    let reject = |error: &dyn std::fmt::Display| $Rejection(format!("part `{}`: {}", name, error));
+    let mut values = Vec::new();
+    for (_, content) in parts.iter().filter(|(key, _)| key == name) {
+        values.push(std::str::from_utf8(content).map_err(|error| reject(&error))?);
+    }
+    let value = if json {
+        match values.first() {
+            Some(value) => Some(serde_json::from_str(value).map_err(|error| reject(&error))?),
+            None => None,
+        }
+    } else {
+        param_value(values, kind, array, "form", true)
+    };
+    serde_json::from_value(value.unwrap_or_default()).map_err(|error| reject(&error))
+}"#;
+
+const SEGMENT_VALUES: &str = r#"/// The values of the parameters sharing a path segment with the `literals`
+/// around and between them, e.g. `["7"]` for `7.json` and `["", ".json"]`;
+/// `NOT_FOUND` when the segment does not match
+fn segment_values<'a>(
+    segment: &'a str,
+    literals: &[&str],
+) -> Result<Vec<&'a str>, axum::response::Response> {
+    use axum::response::IntoResponse;
+
+    let not_found = || axum::http::StatusCode::NOT_FOUND.into_response();
+    let (first, rest) = literals.split_first().ok_or_else(not_found)?;
+    let (last, middle) = rest.split_last().ok_or_else(not_found)?;
+    let mut rest = segment
+        .strip_prefix(first)
+        .and_then(|rest| rest.strip_suffix(last))
+        .ok_or_else(not_found)?;
+    let mut values = Vec::new();
+    for literal in middle {
+        let (value, tail) = rest.split_once(literal).ok_or_else(not_found)?;
+        values.push(value);
+        rest = tail;
+    }
+    values.push(rest);
+    if values.iter().any(|value| value.is_empty()) {
+        return Err(not_found());
+    }
+    Ok(values)
+}"#;
+
+const PATH_PARAM: &str = r#"/// Deserialize a path parameter from its text according to the `kind` of
+/// its items, its `style` and `explode`
+fn path_param<T: serde::de::DeserializeOwned>(
+    value: &str,
+    kind: &str,
+    array: bool,
+    style: &str,
+    explode: bool,
+) -> Result<T, $Rejection> {
+    let value = param_value(vec![value], kind, array, style, explode).unwrap_or_default();
+    serde_json::from_value(value).map_err(|error| $Rejection(error.to_string()))
+}"#;
@@ -0,0 +1,391 @@
+//! Rust code generation
+//!
+//! The generators in this module share a [`RustOptions`] value. Models are
+//! `serde` structs and enums; [`ReqwestGenerator`] and [`AxumGenerator`] write
+//! them next to the client or server so that the output can be dropped into a
+//! crate as one module.
+
+mod api;
+mod axum;
+mod models;
+mod reqwest;
+mod types;
+
+use crate::ir::ApiModel;
+
+// Re-export the public API
+pub use axum::AxumGenerator;
+pub use models::ModelsGenerator;
+pub use reqwest::ReqwestGenerator;
+
+/// Options shared by all Rust generators
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RustOptions {
+    /// File the models are written to
+    pub models_file: String,
+    /// File the HTTP client is written to
+    pub client_file: String,
+    /// File the server trait and router are written to
+    pub server_file: String,
+    /// Type `date-time` and `date` strings with `chrono`, which needs its
+    /// `serde` feature
+    pub chrono: bool,
+    /// Type `uuid` strings with `uuid::Uuid`, which needs its `serde` feature
+    pub uuid: bool,
+}
+
+impl Default for RustOptions {
+    fn default() -> Self {
+        RustOptions {
+            models_file: "models.rs".to_string(),
+            client_file: "client.rs".to_string(),
+            server_file: "server.rs".to_string(),
+            chrono: false,
+            uuid: false,
+        }
+    }
+}
+
+/// The banner at the top of every generated file
+pub(crate) fn header(model: &ApiModel) -> String {
+    let mut lines = vec![
+        "//! Generated by rustval. Do not edit manually.".to_string(),
+        format!("//! {}", model.title),
+    ];
+    if let Some(description) = &model.description {
+        lines.extend(
+            description
+                .trim()
+                .lines()
+                .map(|line| format!("//! {}", line).trim_end().to_string()),
+        );
+    }
+    lines.push(format!("//! OpenAPI spec version: {}", model.version));
+    lines.join("\n")
+}
+
+/// The module a generated file declares, e.g. `models` for `src/models.rs`
+pub(crate) fn module_name(file: &str) -> String {
+    let name = file.rsplit('/').next().unwrap_or(file);
+    name.strip_suffix(".rs").unwrap_or(name).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Generator, assert_generated};
+    use openapiv3::OpenAPI;
+
+    fn model(spec: &str) -> ApiModel {
+        let spec: OpenAPI = serde_yaml::from_str(spec).unwrap();
+        ApiModel::from_spec(&spec)
+    }
+
+    const PETS: &str = r##"
+openapi: 3.0.3
+info: { title: Pets, version: "1.0", description: The pet store }
+paths:
+  /pets:
+    get:
+      operationId: listPets
+      summary: List all pets
+      parameters:
+        - { name: limit, in: query, schema: { type: integer, format: int32 } }
+        - { name: tags, in: query, schema: { type: array, items: { type: string } } }
+        - { name: X-Request-Id, in: header, required: true, schema: { type: string } }
+      responses:
+        "200":
+          description: The pets
+          content:
+            application/json:
+              schema: { type: array, items: { $ref: "#/components/schemas/Pet" } }
+    post:
+      operationId: createPet
+      requestBody:
+        required: true
+        content:
+          application/json:
+            schema: { $ref: "#/components/schemas/Pet" }
+      responses:
+        "201": { description: Created }
+  /pets/{petId}:
+    get:
+      operationId: getPet
+      deprecated: true
+      parameters:
+        - { name: petId, in: path, required: true, schema: { type: string, format: uuid } }
+      responses:
+        "200":
+          description: The pet
+          content:
+            application/json:
+              schema: { $ref: "#/components/schemas/Pet" }
+        default:
+          description: An error
+          content:
+            application/json:
+              schema: { $ref: "#/components/schemas/Error" }
+components:
+  schemas:
+    Pet:
+      oneOf:
+        - $ref: "#/components/schemas/Cat"
+        - $ref: "#/components/schemas/Dog"
+      discriminator:
+        propertyName: petType
+        mapping:
+          cat: "#/components/schemas/Cat"
+          dog: "#/components/schemas/Dog"
+    Cat:
+      allOf:
+        - $ref: "#/components/schemas/Base"
+        - type: object
+          properties:
+            lives: { type: integer, minimum: 0, maximum: 9 }
+    Dog:
+      allOf:
+        - $ref: "#/components/schemas/Base"
+        - type: object
+          properties:
+            good-boy: { type: boolean }
+    Base:
+      type: object
+      required: [petType, name]
+      properties:
+        petType: { type: string }
+        name: { type: string, description: The name of the pet }
+        born: { type: string, format: date-time }
+        size: { type: string, enum: [small, large] }
+    Node:
+      type: object
+      required: [value]
+      properties:
+        value: { type: string }
+        next: { $ref: "#/components/schemas/Node" }
+        children: { type: array, items: { $ref: "#/components/schemas/Node" } }
+    Error:
+      type: object
+      properties:
+        message: { type: string }
+"##;
+
+    #[test]
+    fn test_models_generator() {
+        let model = model(PETS);
+        let files = ModelsGenerator::default().generate(&model);
+        assert_generated("rust/models", &files);
+
+        let options = RustOptions {
+            chrono: true,
+            uuid: true,
+            ..RustOptions::default()
+        };
+        let files = ReqwestGenerator::new(options).generate(&model);
+        assert_generated("rust/reqwest-chrono-uuid", &files);
+    }
+
+    #[test]
+    fn test_reqwest_generator() {
+        let model = model(PETS);
+        let files = ReqwestGenerator::default().generate(&model);
+        // The `Error` component keeps its name and the client's error moves aside
+        assert_generated("rust/reqwest", &files);
+
+        // Ranges carry the status and several media types are read by `Content-Type`
+        let files = ReqwestGenerator::default().generate(&self::model(
+            r#"
+openapi: 3.0.3
+info: { title: Files, version: "1.0" }
+paths:
+  /files/{id}:
+    get:
+      operationId: getFile
+      parameters:
+        - { name: id, in: path, required: true, schema: { type: string } }
+      responses:
+        "200":
+          description: The file
+          content:
+            application/json: { schema: { type: object, properties: { name: { type: string } } } }
+            text/csv: { schema: { type: string } }
+            application/pdf: { schema: { type: string, format: binary } }
+        "204": { description: Empty }
+        "5XX":
+          description: Failure
+          content:
+            text/plain: { schema: { type: string } }
+"#,
+        ));
+        assert_generated("rust/reqwest-negotiation", &files);
+
+        // Multipart bodies send a part per property, with the first concrete media
+        // type of its encoding, and form bodies their styles
+        let files = ReqwestGenerator::default().generate(&self::model(
+            r#"
+openapi: 3.0.3
+info: { title: Forms, version: "1.0" }
+paths:
+  /upload:
+    post:
+      operationId: upload
+      requestBody:
+        required: true
+        content:
+          multipart/form-data:
+            schema:
+              type: object
+              required: [file]
+              properties:
+                file: { type: string, format: binary }
+                avatar: { type: string, format: binary }
+                attachments: { type: array, items: { type: string, format: binary } }
+                meta: { type: object, properties: { title: { type: string } } }
+                tags: { type: array, items: { type: string } }
+            encoding:
+              file: { contentType: image/png }
+              avatar: { contentType: "image/*, image/jpeg, image/png" }
+      responses:
+        "204": { description: Done }
+  /login:
+    post:
+      operationId: login
+      requestBody:
+        content:
+          application/x-www-form-urlencoded:
+            schema:
+              type: object
+              properties:
+                scopes: { type: array, items: { type: string } }
+            encoding:
+              scopes: { style: spaceDelimited, explode: false }
+      responses:
+        "204": { description: Done }
+"#,
+        ));
+        assert_generated("rust/reqwest-forms", &files);
+    }
+
+    #[test]
+    fn test_axum_generator() {
+        let model = model(PETS);
+        let files = AxumGenerator::default().generate(&model);
+        assert_generated("rust/axum", &files);
+    }
+
+    #[test]
+    fn test_axum_generator_decodes_bodies_and_segments() {
+        let model = model(
+            r##"
+openapi: 3.0.3
+info: { title: Forms, version: "1.0" }
+paths:
+  /trees/{id}.json:
+    get:
+      operationId: getTree
+      parameters:
+        - { name: id, in: path, required: true, schema: { type: integer } }
+        - name: filter
+          in: query
+          style: deepObject
+          schema: { type: object, properties: { depth: { type: integer } } }
+      responses:
+        "204": { description: Done }
+  /login:
+    post:
+      operationId: login
+      requestBody:
+        content:
+          application/x-www-form-urlencoded:
+            schema:
+              type: object
+              properties:
+                user: { type: string }
+                scopes: { type: array, items: { type: string } }
+            encoding:
+              scopes: { style: spaceDelimited, explode: false }
+      responses:
+        "204": { description: Done }
+  /upload:
+    post:
+      operationId: upload
+      requestBody:
+        required: true
+        content:
+          multipart/form-data:
+            schema:
+              type: object
+              required: [file]
+              properties:
+                file: { type: string, format: binary }
+                meta: { type: object, properties: { title: { type: string } } }
+                tags: { type: array, items: { type: string } }
+      responses:
+        "204": { description: Done }
+"##,
+        );
+        let files = AxumGenerator::default().generate(&model);
+        assert_generated("rust/axum-decoding", &files);
+    }
+
+    #[test]
+    #[ignore = "needs the crates of the generated code in the cargo cache"]
+    fn test_generated_code_compiles() {
+        let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+        let cases = [
+            "reqwest",
+            "reqwest-chrono-uuid",
+            "reqwest-negotiation",
+            "reqwest-forms",
+            "axum",
+            "axum-decoding",
+        ];
+        let check = root.join("target/generated-check");
+        let _ = std::fs::remove_dir_all(&check);
+        std::fs::create_dir_all(check.join("src")).unwrap();
+        std::fs::write(
+            check.join("Cargo.toml"),
+            r#"[package]
+name = "generated-check"
+version = "0.0.0"
+edition = "2024"
+
+[workspace]
+
+[dependencies]
+axum = { version = "0.8", features = ["multipart"] }
+chrono = { version = "0.4", features = ["serde"] }
+reqwest = { version = "0.12", default-features = false, features = ["json", "multipart"] }
+serde = { version = "1", features = ["derive"] }
+serde_json = "1"
+serde_urlencoded = "0.7"
+uuid = { version = "1", features = ["serde"] }
+"#,
+        )
+        .unwrap();
+
+        let mut lib = String::from("#![deny(warnings)]\n");
+        for case in cases {
+            let module = case.replace('-', "_");
+            lib.push_str(&format!("pub mod {};\n", module));
+            let dir = check.join("src").join(&module);
+            std::fs::create_dir_all(&dir).unwrap();
+            for entry in std::fs::read_dir(root.join("tests/generators/rust").join(case)).unwrap() {
+                let entry = entry.unwrap();
+                std::fs::copy(entry.path(), dir.join(entry.file_name())).unwrap();
+            }
+        }
+        std::fs::write(check.join("src/lib.rs"), lib).unwrap();
+
+        let output = std::process::Command::new(env!("CARGO"))
+            .args(["check", "--offline", "--quiet"])
+            .current_dir(&check)
+            .env_remove("CARGO_TARGET_DIR")
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use crate::generators::rust::api::RustApi;
+use crate::generators::rust::{RustOptions, header};
+use crate::generators::{CodeWriter, GeneratedFile, Generator};
+use crate::ir::ApiModel;
+
+/// Emits `serde` structs and enums for every named type and for the inline
+/// parameters, bodies and responses of operations
+///
+/// Non-required properties become `Option` fields that are skipped when
+/// `None`, and properties whose name is not a valid field name are renamed.
+/// A `oneOf` with a discriminator becomes an internally tagged enum; the
+/// structs of its variants leave the discriminator property to the enum.
+/// Recursive fields are boxed.
+#[derive(Debug, Clone, Default)]
+pub struct ModelsGenerator {
+    options: RustOptions,
+}
+
+impl ModelsGenerator {
+    pub fn new(options: RustOptions) -> Self {
+        ModelsGenerator { options }
+    }
+}
+
+impl Generator for ModelsGenerator {
+    fn name(&self) -> &str {
+        "rust-models"
+    }
+
+    fn generate(&self, model: &ApiModel) -> Vec<GeneratedFile> {
+        let api = RustApi::new(model, &self.options);
+        vec![GeneratedFile::new(
+            self.options.models_file.clone(),
+            models_source(model, &api),
+        )]
+    }
+}
+
+pub(crate) fn models_source(model: &ApiModel, api: &RustApi) -> String {
+    let mut w = CodeWriter::new("    ");
+    w.line(header(model));
+    w.blank();
+    if api.uses_map {
+        w.line("use std::collections::HashMap;");
+        w.blank();
+    }
+    w.line("use serde::{Deserialize, Serialize};");
+    for declaration in &api.declarations {
+        w.blank();
+        w.line(declaration);
+    }
+    w.finish()
+}
@@ -0,0 +1,893 @@
+use std::collections::BTreeSet;
+
+use indexmap::IndexMap;
+
+use crate::generators::rust::api::{
+    BodyKind, FormPart, OperationTypes, PartKind, ResponseKind, ResponseType, RustApi, body_kind,
+    content_kind, media_essence, response_kind, style_name, variant_name,
+};
+use crate::generators::rust::models::models_source;
+use crate::generators::rust::types::write_doc;
+use crate::generators::rust::{RustOptions, header, module_name};
+use crate::generators::{CodeWriter, GeneratedFile, Generator};
+use crate::ir::{ApiModel, Status};
+use crate::naming::{Casing, Language, NameAllocator, to_identifier, to_pascal_case};
+
+/// Emits an async `reqwest` client with one method per operation, along with
+/// the models and a `mod.rs` declaring both modules
+///
+/// Methods take path parameters as arguments, followed by the body and structs
+/// of the query and header parameters. They return an enum with a variant per
+/// declared status, whose ranges and `default` carry the status code, and turn
+/// undeclared statuses into an error. A response declaring several media
+/// types carries an enum with a variant per media type, chosen by the
+/// `Content-Type` the server answers with. Multipart bodies send a part per
+/// property, honoring the `contentType` of its encoding. The
+/// generated code needs `reqwest` with the `json` feature, and `multipart` for
+/// multipart bodies, `serde` and `serde_json`.
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestGenerator {
+    options: RustOptions,
+}
+
+impl ReqwestGenerator {
+    pub fn new(options: RustOptions) -> Self {
+        ReqwestGenerator { options }
+    }
+}
+
+impl Generator for ReqwestGenerator {
+    fn name(&self) -> &str {
+        "rust-reqwest"
+    }
+
+    fn generate(&self, model: &ApiModel) -> Vec<GeneratedFile> {
+        let api = RustApi::new(model, &self.options);
+        let mut names = NameAllocator::new();
+        for name in &api.type_names {
+            names.reserve(name.as_str());
+        }
+        names.request("client", "Client").request("error", "Error");
+        for operation in &api.operations {
+            let prefix = to_pascal_case(&operation.operation.name);
+            names.request(response_key(&operation.name), format!("{}Response", prefix));
+            for (i, response) in operation.responses.iter().enumerate() {
+                if response.contents.len() > 1 {
+                    names.request(
+                        content_key(&operation.name, i),
+                        format!(
+                            "{}{}Content",
+                            prefix,
+                            variant_name(response.response.status)
+                        ),
+                    );
+                }
+            }
+        }
+        let names = names.resolve();
+        let (client, error) = (&names["client"], &names["error"]);
+
+        let models = module_name(&self.options.models_file);
+        let module = module_name(&self.options.client_file);
+        let mut root = CodeWriter::new("    ");
+        root.line(header(model));
+        root.blank();
+        root.line(format!("pub mod {};", module));
+        root.line(format!("pub mod {};", models));
+        root.blank();
+        root.line(format!("pub use {}::{{{}, {}}};", module, client, error));
+
+        vec![
+            GeneratedFile::new(self.options.models_file.clone(), models_source(model, &api)),
+            GeneratedFile::new(
+                self.options.client_file.clone(),
+                client_source(model, &api, &models, &names),
+            ),
+            GeneratedFile::new("mod.rs", root.finish()),
+        ]
+    }
+}
+
+fn response_key(operation: &str) -> String {
+    format!("response#{}", operation)
+}
+
+fn content_key(operation: &str, response: usize) -> String {
+    format!("content#{}#{}", operation, response)
+}
+
+/// The variants of the enum of a response declaring several media types,
+/// named after them, e.g. `ApplicationJson`
+fn content_variants(response: &ResponseType) -> Vec<String> {
+    let mut variants = NameAllocator::new();
+    for (i, (content, _)) in response.contents.iter().enumerate() {
+        variants.request(
+            format!("{:04}", i),
+            to_identifier(
+                media_essence(&content.media_type),
+                Casing::Pascal,
+                Language::Rust,
+            ),
+        );
+    }
+    variants.resolve().into_values().collect()
+}
+
+/// Functions the generated client calls, emitted only when used
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Helper {
+    Unexpected,
+    MediaType,
+    PathParam,
+    AppendQuery,
+    HeaderValue,
+    FormPairs,
+    FilePart,
+    TextPart,
+    Scalar,
+    Encode,
+}
+
+fn client_source(
+    model: &ApiModel,
+    api: &RustApi,
+    models: &str,
+    names: &IndexMap<String, String>,
+) -> String {
+    let (client, error) = (&names["client"], &names["error"]);
+    let mut helpers = BTreeSet::new();
+    let mut body = CodeWriter::new("    ");
+    body.line(format!("/// A client for {}", model.title));
+    body.line("#[derive(Debug, Clone)]");
+    body.block(format!("pub struct {} {{", client), "}", |w| {
+        w.line("base_url: String,");
+        w.line("http: reqwest::Client,");
+    });
+    body.blank();
+    body.block(format!("impl {} {{", client), "}", |w| {
+        w.line("/// A client sending requests to `base_url`, e.g. `https://api.example.com/v1`");
+        w.block(
+            "pub fn new(base_url: impl Into<String>) -> Self {",
+            "}",
+            |w| {
+                w.line("Self::with_client(base_url, reqwest::Client::new())");
+            },
+        );
+        w.blank();
+        w.line("/// A client sending requests through a configured `reqwest::Client`");
+        w.block(
+            "pub fn with_client(base_url: impl Into<String>, http: reqwest::Client) -> Self {",
+            "}",
+            |w| {
+                w.line("let base_url = base_url.into().trim_end_matches('/').to_string();");
+                w.line(format!("{} {{ base_url, http }}", client));
+            },
+        );
+        w.blank();
+        w.block("pub fn base_url(&self) -> &str {", "}", |w| {
+            w.line("&self.base_url");
+        });
+        for operation in &api.operations {
+            w.blank();
+            method(w, operation, names, error, &mut helpers);
+        }
+    });
+    for operation in &api.operations {
+        body.blank();
+        body.line(response_enum(operation, names, client));
+    }
+    body.blank();
+    body.line(error_source(error));
+
+    // Helpers pull in the helpers they call
+    if helpers.contains(&Helper::PathParam) {
+        helpers.insert(Helper::Encode);
+    }
+    if helpers.contains(&Helper::FormPairs) {
+        helpers.insert(Helper::AppendQuery);
+    }
+    if helpers.iter().any(|helper| {
+        matches!(
+            helper,
+            Helper::PathParam | Helper::AppendQuery | Helper::HeaderValue | Helper::TextPart
+        )
+    }) {
+        helpers.insert(Helper::Scalar);
+    }
+    for helper in &helpers {
+        body.blank();
+        body.line(helper_source(*helper, error));
+    }
+
+    let mut w = CodeWriter::new("    ");
+    w.line(header(model));
+    w.blank();
+    if helpers.iter().any(|helper| {
+        matches!(
+            helper,
+            Helper::PathParam | Helper::AppendQuery | Helper::HeaderValue | Helper::TextPart
+        )
+    }) {
+        w.line("use serde::Serialize;");
+        w.blank();
+    }
+    w.line("#[allow(unused_imports)]");
+    w.line(format!("use super::{}::*;", models));
+    w.blank();
+    w.line(body.finish());
+    w.finish().trim_end().to_string() + "\n"
+}
+
+fn method(
+    w: &mut CodeWriter,
+    types: &OperationTypes,
+    names: &IndexMap<String, String>,
+    error: &str,
+    helpers: &mut BTreeSet<Helper>,
+) {
+    let operation = types.operation;
+    let response = &names[&response_key(&types.name)];
+
+    let mut arguments = vec!["&self".to_string()];
+    for (_, name, ty) in &types.path_params {
+        let ty = if ty == "String" { "&str" } else { ty.as_str() };
+        arguments.push(format!("{}: {}", name, ty));
+    }
+    let body_kind = types
+        .body
+        .as_ref()
+        .map(|body| body_kind(&body.content.media_type, &body.ty));
+    if let (Some(body), Some(kind)) = (&types.body, body_kind) {
+        let ty = match kind {
+            _ if body.parts.is_some() => format!("&{}", body.ty),
+            BodyKind::Json | BodyKind::Form => format!("&{}", body.ty),
+            BodyKind::Text => "&str".to_string(),
+            BodyKind::Bytes => "&[u8]".to_string(),
+        };
+        if body.required {
+            arguments.push(format!("body: {}", ty));
+        } else {
+            arguments.push(format!("body: Option<{}>", ty));
+        }
+    }
+    if let Some(params) = &types.query {
+        arguments.push(format!("params: &{}", params.name));
+    }
+    if let Some(headers) = &types.headers {
+        arguments.push(format!("headers: &{}", headers.name));
+    }
+    let mut doc: Vec<&str> = operation
+        .summary
+        .iter()
+        .chain(&operation.description)
+        .map(String::as_str)
+        .collect();
+    doc.dedup();
+    write_doc(
+        w,
+        Some(&doc.join("\n\n"))
+            .filter(|doc| !doc.is_empty())
+            .map(String::as_str),
+    );
+    if operation.deprecated {
+        w.line("#[deprecated]");
+    }
+    w.block(
+        format!(
+            "pub async fn {}({}) -> Result<{}, {}> {{",
+            types.name,
+            arguments.join(", "),
+            response,
+            error
+        ),
+        "}",
+        |w| {
+            let (format, values) = url_format(types, helpers);
+            w.line(format!(
+                "let url = format!({:?}, self.base_url{});",
+                format,
+                values
+                    .iter()
+                    .map(|value| format!(", {}", value))
+                    .collect::<String>()
+            ));
+            w.line(format!(
+                "let request = self.http.request(reqwest::Method::{}, url);",
+                operation.method.to_ascii_uppercase()
+            ));
+            if let Some(params) = &types.query {
+                helpers.insert(Helper::AppendQuery);
+                w.line("let mut query = Vec::new();");
+                for (param, field) in &params.fields {
+                    w.line(format!(
+                        "append_query(&mut query, {:?}, &params.{}, {:?}, {});",
+                        param.name,
+                        field,
+                        style_name(param.style),
+                        param.explode
+                    ));
+                }
+                w.line("let request = request.query(&query);");
+            }
+            if let Some(headers) = &types.headers {
+                helpers.insert(Helper::HeaderValue);
+                for (param, field) in &headers.fields {
+                    w.line(format!(
+                        "let request = match header_value(&headers.{}) {{",
+                        field
+                    ));
+                    w.indent();
+                    w.line(format!(
+                        "Some(value) => request.header({:?}, value),",
+                        param.name
+                    ));
+                    w.line("None => request,");
+                    w.dedent();
+                    w.line("};");
+                }
+            }
+            if let (Some(body), Some(kind)) = (&types.body, body_kind) {
+                let media_type = &body.content.media_type;
+                if let Some(parts) = &body.parts {
+                    for part in parts {
+                        helpers.insert(match part.kind {
+                            PartKind::File | PartKind::Files => Helper::FilePart,
+                            PartKind::Value | PartKind::Values => Helper::TextPart,
+                        });
+                    }
+                    if body.required {
+                        write_form(w, parts);
+                        w.line("let request = request.multipart(form);");
+                    } else {
+                        w.block("let request = match body {", "};", |w| {
+                            w.block("Some(body) => {", "}", |w| {
+                                write_form(w, parts);
+                                w.line("request.multipart(form)");
+                            });
+                            w.line("None => request,");
+                        });
+                    }
+                } else {
+                    let send = match kind {
+                        BodyKind::Json => "request.json(body)".to_string(),
+                        BodyKind::Form => {
+                            helpers.insert(Helper::FormPairs);
+                            let styles: Vec<String> = body
+                                .content
+                                .encoding
+                                .iter()
+                                .filter_map(|(name, encoding)| {
+                                    Some(format!(
+                                        "({:?}, {:?}, {})",
+                                        name,
+                                        style_name(encoding.style?),
+                                        encoding.explode
+                                    ))
+                                })
+                                .collect();
+                            format!("request.form(&form_pairs(body, &[{}]))", styles.join(", "))
+                        }
+                        BodyKind::Text => format!(
+                            "request.header(\"Content-Type\", {:?}).body(body.to_string())",
+                            media_type
+                        ),
+                        BodyKind::Bytes => format!(
+                            "request.header(\"Content-Type\", {:?}).body(body.to_vec())",
+                            media_type
+                        ),
+                    };
+                    if body.required {
+                        w.line(format!("let request = {};", send));
+                    } else {
+                        w.block("let request = match body {", "};", |w| {
+                            w.line(format!("Some(body) => {},", send));
+                            w.line("None => request,");
+                        });
+                    }
+                }
+            }
+            if let Some(accept) = types.accept() {
+                w.line(format!(
+                    "let request = request.header(\"Accept\", {:?});",
+                    accept
+                ));
+            }
+            w.line("let response = request.send().await?;");
+            let mut arms = Vec::new();
+            let mut fallback = false;
+            let mut carries_status = false;
+            for (i, response_type) in types.responses.iter().enumerate() {
+                let (pattern, order) = match response_type.response.status {
+                    Status::Code(code) => (code.to_string(), 0),
+                    Status::Range(range) => (format!("{}..={}", range * 100, range * 100 + 99), 1),
+                    Status::Default => ("_".to_string(), 2),
+                };
+                fallback |= order == 2;
+                carries_status |= order > 0;
+                let mut fields = Vec::new();
+                if order > 0 {
+                    fields.push("status");
+                }
+                let negotiated = response_type.contents.len() > 1;
+                if negotiated {
+                    fields.push("content");
+                } else {
+                    fields.push(read_body(response_kind(response_type).0));
+                }
+                fields.retain(|field| !field.is_empty());
+                let variant = variant_name(response_type.response.status);
+                let value = if fields.is_empty() {
+                    format!("{}::{}", response, variant)
+                } else {
+                    format!("{}::{}({})", response, variant, fields.join(", "))
+                };
+                if !negotiated {
+                    arms.push((order, format!("{} => Ok({}),", pattern, value)));
+                    continue;
+                }
+
+                // The last media type reads whatever else the server sends
+                helpers.insert(Helper::MediaType);
+                let content = &names[&content_key(&types.name, i)];
+                let mut arm = CodeWriter::new("    ");
+                arm.line(format!("{} => {{", pattern));
+                arm.indent();
+                let variants = content_variants(response_type);
+                let last = variants.len() - 1;
+                for (j, ((media, ty), variant)) in
+                    response_type.contents.iter().zip(&variants).enumerate()
+                {
+                    let read = format!(
+                        "{}::{}({})",
+                        content,
+                        variant,
+                        read_body(content_kind(media, ty).0)
+                    );
+                    if j == 0 {
+                        arm.line(format!(
+                            "let content = if is_media_type(&response, {:?}) {{",
+                            media.media_type
+                        ));
+                    } else if j < last {
+                        arm.line(format!(
+                            "}} else if is_media_type(&response, {:?}) {{",
+                            media.media_type
+                        ));
+                    } else {
+                        arm.line("} else {");
+                    }
+                    arm.indent();
+                    arm.line(read);
+                    arm.dedent();
+                }
+                arm.line("};");
+                arm.line(format!("Ok({})", value));
+                arm.dedent();
+                arm.line("}");
+                arms.push((order, arm.finish().trim_end().to_string()));
+            }
+            arms.sort_by_key(|(order, _)| *order);
+            if !fallback {
+                helpers.insert(Helper::Unexpected);
+                arms.push((3, "_ => Err(unexpected(response).await),".to_string()));
+            }
+            let scrutinee = if carries_status {
+                w.line("let status = response.status();");
+                "status.as_u16()"
+            } else {
+                "response.status().as_u16()"
+            };
+            w.block(format!("match {} {{", scrutinee), "}", |w| {
+                for (_, arm) in arms {
+                    w.line(arm);
+                }
+            });
+        },
+    );
+}
+
+/// The expression reading a response body of a kind
+fn read_body(kind: ResponseKind) -> &'static str {
+    match kind {
+        ResponseKind::Empty => "",
+        ResponseKind::Json => "response.json().await?",
+        ResponseKind::Text => "response.text().await?",
+        ResponseKind::Bytes => "response.bytes().await?.to_vec()",
+    }
+}
+
+/// The enum of an operation's responses, followed by the enums of the
+/// responses declaring several media types
+fn response_enum(
+    operation: &OperationTypes,
+    names: &IndexMap<String, String>,
+    client: &str,
+) -> String {
+    let name = &names[&response_key(&operation.name)];
+    let mut w = CodeWriter::new("    ");
+    w.line(format!(
+        "/// The responses of [`{}::{}`]",
+        client, operation.name
+    ));
+    w.line("#[derive(Debug)]");
+    w.block(format!("pub enum {} {{", name), "}", |w| {
+        for (i, response) in operation.responses.iter().enumerate() {
+            write_doc(
+                w,
+                Some(response.response.description.as_str()).filter(|doc| !doc.trim().is_empty()),
+            );
+            let mut fields = Vec::new();
+            if !matches!(response.response.status, Status::Code(_)) {
+                fields.push("reqwest::StatusCode".to_string());
+            }
+            let (kind, ty) = response_kind(response);
+            if response.contents.len() > 1 {
+                fields.push(names[&content_key(&operation.name, i)].clone());
+            } else if kind != ResponseKind::Empty {
+                fields.push(ty);
+            }
+            let variant = variant_name(response.response.status);
+            if fields.is_empty() {
+                w.line(format!("{},", variant));
+            } else {
+                w.line(format!("{}({}),", variant, fields.join(", ")));
+            }
+        }
+    });
+    for (i, response) in operation.responses.iter().enumerate() {
+        if response.contents.len() < 2 {
+            continue;
+        }
+        w.blank();
+        w.line(format!(
+            "/// The content of [`{}::{}`] by media type",
+            name,
+            variant_name(response.response.status)
+        ));
+        w.line("#[derive(Debug)]");
+        let content = &names[&content_key(&operation.name, i)];
+        w.block(format!("pub enum {} {{", content), "}", |w| {
+            for ((media, ty), variant) in response.contents.iter().zip(content_variants(response)) {
+                w.line(format!("/// `{}`", media.media_type));
+                w.line(format!("{}({}),", variant, content_kind(media, ty).1));
+            }
+        });
+    }
+    w.finish().trim_end().to_string()
+}
+
+/// A `format!` string for an operation's URL and the expressions filling it
+fn url_format(types: &OperationTypes, helpers: &mut BTreeSet<Helper>) -> (String, Vec<String>) {
+    let mut format = String::from("{}");
+    let mut values = Vec::new();
+    let mut rest = types.operation.path.as_str();
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}').map(|end| start + end) else {
+            break;
+        };
+        format.push_str(&rest[..start]);
+        let name = &rest[start + 1..end];
+        match types
+            .path_params
+            .iter()
+            .find(|(param, ..)| param.name == name)
+        {
+            Some((param, argument, _)) => {
+                helpers.insert(Helper::PathParam);
+                format.push_str("{}");
+                values.push(format!(
+                    "path_param({:?}, &{}, {:?}, {})",
+                    param.name,
+                    argument,
+                    style_name(param.style),
+                    param.explode
+                ));
+            }
+            None => format.push_str(&format!("{{{{{}}}}}", name)),
+        }
+        rest = &rest[end + 1..];
+    }
+    format.push_str(rest);
+    (format, values)
+}
+
+/// The error enum and the helpers mention it as `$Error`, since its name may
+/// be taken by a component
+fn error_source(error: &str) -> String {
+    ERROR.replace("$Error", error)
+}
+
+const ERROR: &str = r#"/// The error of a client method
+#[derive(Debug)]
+pub enum $Error {
+    /// The request could not be sent or the response could not be read
+    Request(reqwest::Error),
+    /// The server answered with a status the spec does not declare
+    Status {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+}
+
+impl std::fmt::Display for $Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            $Error::Request(error) => write!(f, "request failed: {}", error),
+            $Error::Status { status, body } => write!(f, "unexpected status {}: {}", status, body),
+        }
+    }
+}
+
+impl std::error::Error for $Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            $Error::Request(error) => Some(error),
+            $Error::Status { .. } => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for $Error {
+    fn from(error: reqwest::Error) -> Self {
+        $Error::Request(error)
+    }
+}"#;
+
+/// Build `form` from the parts of a multipart `body`
+fn write_form(w: &mut CodeWriter, parts: &[FormPart]) {
+    w.line("let mut form = reqwest::multipart::Form::new();");
+    for part in parts {
+        let content_type = match part.content_type {
+            Some(content_type) => format!("Some({:?})", content_type),
+            None => "None".to_string(),
+        };
+        let value = if part.optional {
+            "value".to_string()
+        } else {
+            format!("&body.{}", part.field)
+        };
+        let append = |w: &mut CodeWriter, value: &str| match part.kind {
+            PartKind::File => {
+                w.line(format!(
+                    "form = form.part({:?}, file_part({:?}, {}, {})?);",
+                    part.name, part.name, value, content_type
+                ));
+            }
+            PartKind::Value => {
+                w.line(format!(
+                    "form = form.part({:?}, text_part({}, {})?);",
+                    part.name, value, content_type
+                ));
+            }
+            PartKind::Files | PartKind::Values => {
+                w.line(format!("for item in {} {{", value));
+                w.indent();
+                w.line(format!(
+                    "form = form.part({:?}, {}?);",
+                    part.name,
+                    if part.kind == PartKind::Files {
+                        format!("file_part({:?}, item, {})", part.name, content_type)
+                    } else {
+                        format!("text_part(item, {})", content_type)
+                    }
+                ));
+                w.dedent();
+                w.line("}");
+            }
+        };
+        if part.optional {
+            w.line(format!("if let Some(value) = &body.{} {{", part.field));
+            w.indent();
+            append(w, &value);
+            w.dedent();
+            w.line("}");
+        } else {
+            append(w, &value);
+        }
+    }
+}
+
+fn helper_source(helper: Helper, error: &str) -> String {
+    match helper {
+        Helper::Unexpected => UNEXPECTED.replace("$Error", error),
+        Helper::MediaType => MEDIA_TYPE.to_string(),
+        Helper::PathParam => PATH_PARAM.to_string(),
+        Helper::AppendQuery => APPEND_QUERY.to_string(),
+        Helper::HeaderValue => HEADER_VALUE.to_string(),
+        Helper::FormPairs => FORM_PAIRS.to_string(),
+        Helper::FilePart => FILE_PART.to_string(),
+        Helper::TextPart => TEXT_PART.to_string(),
+        Helper::Scalar => SCALAR.to_string(),
+        Helper::Encode => ENCODE.to_string(),
+    }
+}
+
+const UNEXPECTED: &str = r#"/// The error of a response whose status the spec does not declare
+async fn unexpected(response: reqwest::Response) -> $Error {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    $Error::Status { status, body }
+}"#;
+
+const MEDIA_TYPE: &str = r#"/// Whether the `Content-Type` of a response is a declared media type, which
+/// may be a range such as `text/*`
+fn is_media_type(response: &reqwest::Response, media_type: &str) -> bool {
+    let essence = |media_type: &str| {
+        let essence = media_type.split(';').next().unwrap_or_default();
+        essence.trim().to_ascii_lowercase()
+    };
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(essence)
+        .unwrap_or_default();
+    let declared = essence(media_type);
+    match declared.strip_suffix("/*") {
+        Some("*") => true,
+        Some(kind) => content_type.split('/').next() == Some(kind),
+        None => content_type == declared,
+    }
+}"#;
+
+const PATH_PARAM: &str = r#"/// Serialize a path parameter according to its `style` and `explode`
+fn path_param<T: Serialize>(name: &str, value: &T, style: &str, explode: bool) -> String {
+    let value = serde_json::to_value(value).unwrap_or_default();
+    let parts: Vec<String> = match &value {
+        serde_json::Value::Array(items) => items.iter().map(|item| encode(&scalar(item))).collect(),
+        serde_json::Value::Object(map) if explode => map
+            .iter()
+            .map(|(key, value)| format!("{}={}", encode(key), encode(&scalar(value))))
+            .collect(),
+        serde_json::Value::Object(map) => map
+            .iter()
+            .flat_map(|(key, value)| [encode(key), encode(&scalar(value))])
+            .collect(),
+        value => vec![encode(&scalar(value))],
+    };
+    match style {
+        "label" if explode => format!(".{}", parts.join(".")),
+        "label" => format!(".{}", parts.join(",")),
+        "matrix" if explode && value.is_array() => {
+            parts.iter().map(|part| format!(";{}={}", name, part)).collect()
+        }
+        "matrix" if explode && value.is_object() => {
+            parts.iter().map(|part| format!(";{}", part)).collect()
+        }
+        "matrix" => format!(";{}={}", name, parts.join(",")),
+        _ => parts.join(","),
+    }
+}"#;
+
+const APPEND_QUERY: &str = r#"/// Serialize a query parameter according to its `style` and `explode`;
+/// `None` adds nothing
+fn append_query<T: Serialize>(
+    query: &mut Vec<(String, String)>,
+    name: &str,
+    value: &T,
+    style: &str,
+    explode: bool,
+) {
+    match serde_json::to_value(value).unwrap_or_default() {
+        serde_json::Value::Null => {}
+        serde_json::Value::Array(items) if explode && style == "form" => {
+            for item in &items {
+                query.push((name.to_string(), scalar(item)));
+            }
+        }
+        serde_json::Value::Array(items) => {
+            let separator = match style {
+                "spaceDelimited" => " ",
+                "pipeDelimited" => "|",
+                _ => ",",
+            };
+            let items: Vec<String> = items.iter().map(scalar).collect();
+            query.push((name.to_string(), items.join(separator)));
+        }
+        serde_json::Value::Object(map) if style == "deepObject" => {
+            for (key, value) in &map {
+                query.push((format!("{}[{}]", name, key), scalar(value)));
+            }
+        }
+        serde_json::Value::Object(map) if explode => {
+            for (key, value) in &map {
+                query.push((key.clone(), scalar(value)));
+            }
+        }
+        serde_json::Value::Object(map) => {
+            let pairs: Vec<String> = map
+                .iter()
+                .flat_map(|(key, value)| [key.clone(), scalar(value)])
+                .collect();
+            query.push((name.to_string(), pairs.join(",")));
+        }
+        value => query.push((name.to_string(), scalar(&value))),
+    }
+}"#;
+
+const HEADER_VALUE: &str = r#"/// Serialize a header parameter in the `simple` style; `None` sends no header
+fn header_value<T: Serialize>(value: &T) -> Option<String> {
+    match serde_json::to_value(value).ok()? {
+        serde_json::Value::Null => None,
+        serde_json::Value::Array(items) => {
+            Some(items.iter().map(scalar).collect::<Vec<_>>().join(","))
+        }
+        serde_json::Value::Object(map) => Some(
+            map.iter()
+                .flat_map(|(key, value)| [key.clone(), scalar(value)])
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+        value => Some(scalar(&value)),
+    }
+}"#;
+
+const FORM_PAIRS: &str = r#"/// Serialize a form body's properties like `form` query parameters, or by
+/// the `style` and `explode` of their encoding
+fn form_pairs<T: Serialize>(body: &T, styles: &[(&str, &str, bool)]) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    if let serde_json::Value::Object(map) = serde_json::to_value(body).unwrap_or_default() {
+        for (name, value) in &map {
+            let (style, explode) = styles
+                .iter()
+                .find(|(property, _, _)| property == name)
+                .map_or(("form", true), |(_, style, explode)| (*style, *explode));
+            append_query(&mut pairs, name, value, style, explode);
+        }
+    }
+    pairs
+}"#;
+
+const FILE_PART: &str = r#"/// A multipart file, `application/octet-stream` unless its encoding says otherwise
+fn file_part(
+    name: &str,
+    bytes: &[u8],
+    content_type: Option<&str>,
+) -> Result<reqwest::multipart::Part, reqwest::Error> {
+    reqwest::multipart::Part::bytes(bytes.to_vec())
+        .file_name(name.to_string())
+        .mime_str(content_type.unwrap_or("application/octet-stream"))
+}"#;
+
+const TEXT_PART: &str = r#"/// A multipart value: objects, arrays and JSON encodings as JSON, anything
+/// else as plain text
+fn text_part<T: Serialize>(
+    value: &T,
+    content_type: Option<&str>,
+) -> Result<reqwest::multipart::Part, reqwest::Error> {
+    let value = serde_json::to_value(value).unwrap_or_default();
+    let json = value.is_object()
+        || value.is_array()
+        || content_type.is_some_and(|content_type| content_type.contains("json"));
+    let part = if json {
+        reqwest::multipart::Part::text(value.to_string())
+            .mime_str(content_type.unwrap_or("application/json"))?
+    } else {
+        reqwest::multipart::Part::text(scalar(&value))
+    };
+    match content_type {
+        Some(content_type) if !json => part.mime_str(content_type),
+        _ => Ok(part),
+    }
+}"#;
+
+const SCALAR: &str = r#"/// The text of a JSON value, without quotes for strings
+fn scalar(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(text) => text.clone(),
+        serde_json::Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}"#;
+
+const ENCODE: &str = r#"/// Percent-encode everything but unreserved characters
+fn encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            byte => format!("%{:02X}", byte),
+        })
+        .collect()
+}"#;
@@ -0,0 +1,591 @@
+use std::collections::{HashMap, HashSet};
+
+use indexmap::IndexMap;
+use serde_json::Value;
+
+use crate::generators::CodeWriter;
+use crate::generators::rust::RustOptions;
+use crate::ir::{ApiModel, Discriminator, ObjectDef, Primitive, Property, TypeDef, TypeKind};
+use crate::naming::{Casing, Language, NameAllocator, component_type_names, to_identifier};
+
+/// Extensions that name enum variants, in order of precedence
+const NAME_EXTENSIONS: &[&str] = &["x-enum-varnames", "x-enumNames"];
+
+/// Renders IR types as Rust types and declarations
+///
+/// Rust has no anonymous structs or enums, so inline objects, string enums and
+/// unions are hoisted into declarations named after where they were found, e.g.
+/// `PetOwner` for the `owner` property of `Pet`.
+pub(crate) struct TypeRenderer<'a> {
+    model: &'a ApiModel,
+    options: &'a RustOptions,
+    /// Rust names of the component schemas
+    names: IndexMap<String, String>,
+    /// Every declared type name, to keep hoisted names unique
+    used: HashSet<String>,
+    /// Discriminator properties of structs that are variants of a tagged enum,
+    /// which carries the property instead
+    tags: HashMap<String, Vec<String>>,
+    /// The component being declared, whose self-references are boxed
+    root: Option<String>,
+    /// Declarations of hoisted types, in order of creation
+    hoisted: Vec<String>,
+    /// Every hoisted type with its name, so that the copies `allOf` makes of
+    /// inline types share one declaration
+    hoisted_types: Vec<(TypeDef, String)>,
+    /// Whether a declaration mentions `HashMap`
+    pub uses_map: bool,
+}
+
+impl<'a> TypeRenderer<'a> {
+    pub fn new(model: &'a ApiModel, options: &'a RustOptions) -> Self {
+        let names = component_type_names(model.types.keys().map(String::as_str), Language::Rust);
+        let used = names.values().cloned().collect();
+        let mut renderer = TypeRenderer {
+            model,
+            options,
+            names,
+            used,
+            tags: HashMap::new(),
+            root: None,
+            hoisted: Vec::new(),
+            hoisted_types: Vec::new(),
+            uses_map: false,
+        };
+        for ty in model.types.values() {
+            if let TypeKind::Union {
+                variants,
+                discriminator: Some(discriminator),
+                ..
+            } = &ty.kind
+                && renderer.is_taggable(variants)
+            {
+                for variant in variants {
+                    if let TypeKind::Reference(name) = &variant.kind {
+                        renderer
+                            .tags
+                            .entry(name.clone())
+                            .or_default()
+                            .push(discriminator.property_name.clone());
+                    }
+                }
+            }
+        }
+        renderer
+    }
+
+    /// The Rust name of a component schema
+    pub fn type_name<'n>(&'n self, component: &'n str) -> &'n str {
+        self.names.get(component).map_or(component, String::as_str)
+    }
+
+    /// A type name that no other declaration uses yet
+    pub fn allocate(&mut self, name: &str) -> String {
+        let mut candidate = name.to_string();
+        let mut suffix = 2;
+        while !self.used.insert(candidate.clone()) {
+            candidate = format!("{}{}", name, suffix);
+            suffix += 1;
+        }
+        candidate
+    }
+
+    /// The declarations hoisted since the last call
+    pub fn take_hoisted(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.hoisted)
+    }
+
+    /// The declaration of a component schema
+    pub fn component_declaration(&mut self, component: &str, ty: &TypeDef) -> String {
+        self.root = Some(component.to_string());
+        let name = self.type_name(component).to_string();
+        let skip = self.tags.get(component).cloned().unwrap_or_default();
+        let declaration = self.declaration(&name, ty, &skip);
+        self.root = None;
+        declaration
+    }
+
+    /// A declaration with documentation: a struct, an enum or a type alias
+    pub fn declaration(&mut self, name: &str, ty: &TypeDef, skip: &[String]) -> String {
+        let mut w = CodeWriter::new("    ");
+        write_doc(&mut w, ty.description.as_deref());
+        match &ty.kind {
+            TypeKind::Object(object) if !object.properties.is_empty() => {
+                self.write_struct(&mut w, name, object, skip, false);
+            }
+            TypeKind::Intersection(members) => match self.merge(members) {
+                Some(object) => self.write_struct(&mut w, name, &object, skip, false),
+                None => {
+                    w.line(format!("pub type {} = serde_json::Value;", name));
+                }
+            },
+            TypeKind::Enum {
+                primitive: Primitive::String,
+                values,
+            } => write_enum(&mut w, name, ty, values),
+            TypeKind::Union {
+                variants,
+                discriminator,
+                ..
+            } if variants.len() > 1 => match discriminator {
+                Some(discriminator) if self.is_taggable(variants) => {
+                    self.write_tagged(&mut w, name, variants, discriminator)
+                }
+                _ => self.write_untagged(&mut w, name, variants),
+            },
+            _ => {
+                let mut ty = ty.clone();
+                ty.nullable = false;
+                let rendered = self.render(&ty, name);
+                w.line(format!("pub type {} = {};", name, rendered));
+            }
+        }
+        w.finish().trim_end().to_string()
+    }
+
+    /// A type expression, hoisting inline declarations under `hint`
+    pub fn render(&mut self, ty: &TypeDef, hint: &str) -> String {
+        self.render_in(ty, hint, false)
+    }
+
+    /// `indirect` is set inside collections, which need no `Box` to be recursive
+    fn render_in(&mut self, ty: &TypeDef, hint: &str, indirect: bool) -> String {
+        let rendered = self.render_kind(ty, hint, indirect);
+        let target_nullable = match &ty.kind {
+            TypeKind::Reference(name) => self.model.type_def(name).is_some_and(|ty| ty.nullable),
+            _ => false,
+        };
+        if (ty.nullable || target_nullable) && !matches!(ty.kind, TypeKind::Any) {
+            format!("Option<{}>", rendered)
+        } else {
+            rendered
+        }
+    }
+
+    fn render_kind(&mut self, ty: &TypeDef, hint: &str, indirect: bool) -> String {
+        match &ty.kind {
+            TypeKind::Primitive { primitive, format } => {
+                primitive_type(*primitive, format.as_deref(), self.options).to_string()
+            }
+            TypeKind::Array(items) => {
+                format!(
+                    "Vec<{}>",
+                    self.render_in(items, &format!("{}Item", hint), true)
+                )
+            }
+            TypeKind::Object(object) if object.properties.is_empty() => {
+                match &object.additional_properties {
+                    Some(additional) if !matches!(additional.kind, TypeKind::Any) => {
+                        self.uses_map = true;
+                        let value = self.render_in(additional, &format!("{}Value", hint), true);
+                        format!("HashMap<String, {}>", value)
+                    }
+                    _ => "serde_json::Map<String, serde_json::Value>".to_string(),
+                }
+            }
+            TypeKind::Enum {
+                primitive: Primitive::String,
+                ..
+            }
+            | TypeKind::Object(_)
+            | TypeKind::Intersection(_) => self.hoist(hint, ty),
+            TypeKind::Enum {
+                primitive, values, ..
+            } => match primitive {
+                Primitive::Integer | Primitive::Number => {
+                    let format = match values.iter().all(|value| value.is_i64()) {
+                        true => None,
+                        false => Some("double".to_string()),
+                    };
+                    primitive_type(*primitive, format.as_deref(), self.options).to_string()
+                }
+                _ => primitive_type(*primitive, None, self.options).to_string(),
+            },
+            TypeKind::Union { variants, .. } if variants.len() == 1 => {
+                self.render_in(&variants[0], hint, indirect)
+            }
+            TypeKind::Union { .. } => self.hoist(hint, ty),
+            TypeKind::Reference(name) => {
+                let rust_name = self.type_name(name).to_string();
+                let recursive = self
+                    .root
+                    .as_deref()
+                    .is_some_and(|root| self.reaches(name, root));
+                if recursive && !indirect {
+                    format!("Box<{}>", rust_name)
+                } else {
+                    rust_name
+                }
+            }
+            TypeKind::Any => "serde_json::Value".to_string(),
+        }
+    }
+
+    /// Declare an inline type under a fresh name derived from `hint`
+    fn hoist(&mut self, hint: &str, ty: &TypeDef) -> String {
+        let mut ty = ty.clone();
+        ty.nullable = false;
+        if let Some((_, name)) = self
+            .hoisted_types
+            .iter()
+            .find(|(hoisted, _)| *hoisted == ty)
+        {
+            return name.clone();
+        }
+        let name = self.allocate(hint);
+        let declaration = self.declaration(&name, &ty, &[]);
+        self.hoisted.push(declaration);
+        self.hoisted_types.push((ty, name.clone()));
+        name
+    }
+
+    /// Whether `from` refers to `to`, directly or through other named types
+    fn reaches(&self, from: &str, to: &str) -> bool {
+        let mut stack = vec![from];
+        let mut visited = HashSet::new();
+        while let Some(name) = stack.pop() {
+            if name == to {
+                return true;
+            }
+            if visited.insert(name)
+                && let Some(ty) = self.model.type_def(name)
+            {
+                stack.extend(ty.references());
+            }
+        }
+        false
+    }
+
+    /// Whether a union can be an internally tagged enum: every variant must be
+    /// a reference to a struct
+    fn is_taggable(&self, variants: &[TypeDef]) -> bool {
+        variants.iter().all(|variant| match &variant.kind {
+            TypeKind::Reference(name) if !variant.nullable => {
+                self.model.type_def(name).is_some_and(|ty| {
+                    !ty.nullable
+                        && match &ty.kind {
+                            TypeKind::Object(object) => !object.properties.is_empty(),
+                            TypeKind::Intersection(members) => self.merge(members).is_some(),
+                            _ => false,
+                        }
+                })
+            }
+            _ => false,
+        })
+    }
+
+    /// The properties of every object among `allOf` members, or `None` when
+    /// there are none
+    fn merge(&self, members: &[TypeDef]) -> Option<ObjectDef> {
+        let mut merged = ObjectDef::default();
+        let mut found = false;
+        for member in members {
+            let Some(object) = self.object_of(member) else {
+                continue;
+            };
+            found = true;
+            for property in object.properties {
+                match merged
+                    .properties
+                    .iter_mut()
+                    .find(|existing| existing.name == property.name)
+                {
+                    Some(existing) => existing.required |= property.required,
+                    None => merged.properties.push(property),
+                }
+            }
+            if merged.additional_properties.is_none() {
+                merged.additional_properties = object.additional_properties;
+            }
+        }
+        found.then_some(merged)
+    }
+
+    /// The properties of the struct an object type is declared as, with
+    /// their field names
+    pub fn struct_fields(&self, ty: &TypeDef) -> Option<Vec<(Property, String)>> {
+        let (object, skip) = match &ty.kind {
+            TypeKind::Reference(name) => {
+                let target = self.model.type_def(name)?;
+                if matches!(target.kind, TypeKind::Reference(_)) {
+                    return self.struct_fields(target);
+                }
+                let skip = self.tags.get(name).cloned().unwrap_or_default();
+                (self.object_of(target)?, skip)
+            }
+            _ => (self.object_of(ty)?, Vec::new()),
+        };
+        if object.properties.is_empty() {
+            return None;
+        }
+        let properties: Vec<Property> = object
+            .properties
+            .into_iter()
+            .filter(|property| !skip.contains(&property.name))
+            .collect();
+        let fields = field_names(properties.iter().map(|property| property.name.as_str()));
+        Some(properties.into_iter().zip(fields).collect())
+    }
+
+    /// The field of the struct an object type is declared as that holds its
+    /// additional properties, if it has any
+    pub fn additional_field(&self, ty: &TypeDef) -> Option<String> {
+        self.object_of(ty)?.additional_properties.as_ref()?;
+        let fields: Vec<String> = self
+            .struct_fields(ty)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(_, field)| field)
+            .collect();
+        Some(unique_field(&fields, "additional_properties"))
+    }
+
+    fn object_of(&self, ty: &TypeDef) -> Option<ObjectDef> {
+        match &ty.kind {
+            TypeKind::Object(object) => Some(object.clone()),
+            TypeKind::Intersection(members) => self.merge(members),
+            TypeKind::Reference(name) => self.object_of(self.model.type_def(name)?),
+            _ => None,
+        }
+    }
+
+    /// A struct with one field per property, plus a flattened map for
+    /// additional properties
+    pub fn write_struct(
+        &mut self,
+        w: &mut CodeWriter,
+        name: &str,
+        object: &ObjectDef,
+        skip: &[String],
+        default: bool,
+    ) {
+        let properties: Vec<&Property> = object
+            .properties
+            .iter()
+            .filter(|property| !skip.contains(&property.name))
+            .collect();
+        let fields = field_names(properties.iter().map(|property| property.name.as_str()));
+
+        let mut lines = Vec::new();
+        for (property, field) in properties.iter().zip(&fields) {
+            let hint = format!(
+                "{}{}",
+                name,
+                to_identifier(&property.name, Casing::Pascal, Language::Rust)
+            );
+            let mut ty = self.render(&property.ty, &hint);
+            let optional = !property.required;
+            if optional && !ty.starts_with("Option<") && !matches!(property.ty.kind, TypeKind::Any)
+            {
+                ty = format!("Option<{}>", ty);
+            }
+            let mut attributes = Vec::new();
+            if *field != property.name {
+                attributes.push(format!("rename = {:?}", property.name));
+            }
+            if optional && ty.starts_with("Option<") {
+                attributes.push("default".to_string());
+                attributes.push("skip_serializing_if = \"Option::is_none\"".to_string());
+            } else if optional {
+                attributes.push("default".to_string());
+                attributes.push("skip_serializing_if = \"serde_json::Value::is_null\"".to_string());
+            }
+            lines.push((
+                property.ty.description.clone(),
+                attributes,
+                format!("pub {}: {},", field, ty),
+            ));
+        }
+        if let Some(additional) = &object.additional_properties {
+            self.uses_map = true;
+            let value = self.render_in(additional, &format!("{}Value", name), true);
+            let field = unique_field(&fields, "additional_properties");
+            lines.push((
+                None,
+                vec!["flatten".to_string()],
+                format!("pub {}: HashMap<String, {}>,", field, value),
+            ));
+        }
+
+        let derives = if default {
+            "Debug, Clone, Default, PartialEq, Serialize, Deserialize"
+        } else {
+            "Debug, Clone, PartialEq, Serialize, Deserialize"
+        };
+        w.line(format!("#[derive({})]", derives));
+        w.block(format!("pub struct {} {{", name), "}", |w| {
+            for (description, attributes, field) in &lines {
+                write_doc(w, description.as_deref());
+                if !attributes.is_empty() {
+                    w.line(format!("#[serde({})]", attributes.join(", ")));
+                }
+                w.line(field);
+            }
+        });
+    }
+
+    /// An internally tagged enum with a variant per discriminator value
+    fn write_tagged(
+        &mut self,
+        w: &mut CodeWriter,
+        name: &str,
+        variants: &[TypeDef],
+        discriminator: &Discriminator,
+    ) {
+        let mut lines = Vec::new();
+        for variant in variants {
+            let TypeKind::Reference(target) = &variant.kind else {
+                continue;
+            };
+            let values: Vec<&String> = discriminator
+                .mapping
+                .iter()
+                .filter(|(_, mapped)| *mapped == target)
+                .map(|(value, _)| value)
+                .collect();
+            let Some((value, aliases)) = values.split_first() else {
+                continue;
+            };
+            let mut attribute = format!("#[serde(rename = {:?}", value);
+            for alias in aliases {
+                attribute.push_str(&format!(", alias = {:?}", alias));
+            }
+            attribute.push_str(")]");
+            let ty = self.render(variant, target);
+            lines.push((attribute, format!("{}({}),", self.type_name(target), ty)));
+        }
+
+        w.line("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]");
+        w.line(format!("#[serde(tag = {:?})]", discriminator.property_name));
+        w.block(format!("pub enum {} {{", name), "}", |w| {
+            for (attribute, variant) in &lines {
+                w.line(attribute);
+                w.line(variant);
+            }
+        });
+    }
+
+    /// An untagged enum, whose variants are tried in declaration order
+    fn write_untagged(&mut self, w: &mut CodeWriter, name: &str, variants: &[TypeDef]) {
+        let mut taken = HashSet::new();
+        let mut lines = Vec::new();
+        for variant in variants {
+            let base = match &variant.kind {
+                TypeKind::Reference(target) => self.type_name(target).to_string(),
+                TypeKind::Primitive { primitive, .. } => primitive_name(*primitive).to_string(),
+                TypeKind::Enum { .. } => "Enum".to_string(),
+                TypeKind::Array(_) => "Array".to_string(),
+                TypeKind::Object(_) => "Object".to_string(),
+                TypeKind::Union { .. } | TypeKind::Intersection(_) => "Variant".to_string(),
+                TypeKind::Any => "Value".to_string(),
+            };
+            let mut variant_name = base.clone();
+            let mut suffix = 2;
+            while !taken.insert(variant_name.clone()) {
+                variant_name = format!("{}{}", base, suffix);
+                suffix += 1;
+            }
+            let ty = self.render(variant, &format!("{}{}", name, variant_name));
+            lines.push(format!("{}({}),", variant_name, ty));
+        }
+
+        w.line("#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]");
+        w.line("#[serde(untagged)]");
+        w.block(format!("pub enum {} {{", name), "}", |w| {
+            for line in &lines {
+                w.line(line);
+            }
+        });
+    }
+}
+
+/// A unit enum with a variant per value
+fn write_enum(w: &mut CodeWriter, name: &str, ty: &TypeDef, values: &[Value]) {
+    let declared = NAME_EXTENSIONS
+        .iter()
+        .filter_map(|extension| ty.extensions.get(*extension)?.as_array())
+        .find(|names| names.len() == values.len());
+    let mut names = NameAllocator::new();
+    for (i, value) in values.iter().enumerate() {
+        let name = declared
+            .and_then(|declared| declared[i].as_str())
+            .or(value.as_str())
+            .unwrap_or_default();
+        let variant = if name.is_empty() {
+            "Empty".to_string()
+        } else {
+            to_identifier(name, Casing::Pascal, Language::Rust)
+        };
+        names.request(format!("{:04}", i), variant);
+    }
+
+    w.line("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]");
+    w.block(format!("pub enum {} {{", name), "}", |w| {
+        for (value, variant) in values.iter().zip(names.resolve().into_values()) {
+            let value = value.as_str().unwrap_or_default();
+            w.line(format!("#[serde(rename = {:?})]", value));
+            w.line(format!("{},", variant));
+        }
+    });
+}
+
+/// Unique snake_case field names for properties, in order
+pub(crate) fn field_names<'p>(properties: impl Iterator<Item = &'p str>) -> Vec<String> {
+    let mut names = NameAllocator::new();
+    let properties: Vec<&str> = properties.collect();
+    for (i, property) in properties.iter().enumerate() {
+        names.request(
+            format!("{:04}", i),
+            to_identifier(property, Casing::Snake, Language::Rust),
+        );
+    }
+    names.resolve().into_values().collect()
+}
+
+fn unique_field(fields: &[String], name: &str) -> String {
+    let mut candidate = name.to_string();
+    while fields.contains(&candidate) {
+        candidate.push('_');
+    }
+    candidate
+}
+
+/// The Rust type of a primitive; `chrono` and `uuid` types only when enabled
+pub(crate) fn primitive_type(
+    primitive: Primitive,
+    format: Option<&str>,
+    options: &RustOptions,
+) -> &'static str {
+    match (primitive, format) {
+        (Primitive::String, Some("date-time")) if options.chrono => "chrono::DateTime<chrono::Utc>",
+        (Primitive::String, Some("date")) if options.chrono => "chrono::NaiveDate",
+        (Primitive::String, Some("uuid")) if options.uuid => "uuid::Uuid",
+        (Primitive::String, Some("binary")) => "Vec<u8>",
+        (Primitive::String, _) => "String",
+        (Primitive::Integer, Some("int32")) => "i32",
+        (Primitive::Integer, _) => "i64",
+        (Primitive::Number, Some("float")) => "f32",
+        (Primitive::Number, _) => "f64",
+        (Primitive::Boolean, _) => "bool",
+    }
+}
+
+fn primitive_name(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::String => "String",
+        Primitive::Number => "Number",
+        Primitive::Integer => "Integer",
+        Primitive::Boolean => "Boolean",
+    }
+}
+
+/// `///` lines for a description
+pub(crate) fn write_doc(w: &mut CodeWriter, description: Option<&str>) {
+    for line in description.iter().flat_map(|text| text.trim().lines()) {
+        if line.trim().is_empty() {
+            w.line("///");
+        } else {
+            w.line(format!("/// {}", line.trim_end()));
+        }
+    }
+}
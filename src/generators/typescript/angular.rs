@@ -0,0 +1,228 @@
+use std::collections::BTreeSet;
+
+use indexmap::IndexMap;
+
+use crate::generators::typescript::client::{
+    ClientRenderer, identifiers, operation_types, optional_access, parameters, style_name,
+    write_operation_jsdoc,
+};
+use crate::generators::typescript::runtime::{Helper, helpers_source};
+use crate::generators::typescript::types::{literal, property_key};
+use crate::generators::typescript::{TypeScriptOptions, TypesGenerator, header};
+use crate::generators::{CodeWriter, GeneratedFile, Generator};
+use crate::ir::{ApiModel, Operation};
+use crate::naming::{to_kebab_case, to_pascal_case};
+
+/// Emits an injectable Angular service per tag wrapping `HttpClient`, along
+/// with the models file
+///
+/// Operations go to the service of their first tag, untagged ones to
+/// `DefaultService`. Every method emits the status-keyed response union of its
+/// operation together with the response headers; error statuses are emitted
+/// rather than thrown, and bodies are read according to their `Content-Type`.
+#[derive(Debug, Clone, Default)]
+pub struct AngularGenerator {
+    options: TypeScriptOptions,
+}
+
+impl AngularGenerator {
+    pub fn new(options: TypeScriptOptions) -> Self {
+        AngularGenerator { options }
+    }
+}
+
+impl Generator for AngularGenerator {
+    fn name(&self) -> &str {
+        "typescript-angular"
+    }
+
+    fn generate(&self, model: &ApiModel) -> Vec<GeneratedFile> {
+        let mut services: IndexMap<&str, Vec<&Operation>> = IndexMap::new();
+        for operation in &model.operations {
+            let tag = operation.tags.first().map_or("default", String::as_str);
+            services.entry(tag).or_default().push(operation);
+        }
+
+        let client = ClientRenderer::new(model, &self.options);
+        let mut files = TypesGenerator::new(self.options.clone()).generate(model);
+        for (tag, operations) in services {
+            files.push(GeneratedFile::new(
+                format!("{}.service.ts", to_kebab_case(tag)),
+                service(model, &client, tag, &operations),
+            ));
+        }
+        files
+    }
+}
+
+fn service(
+    model: &ApiModel,
+    client: &ClientRenderer,
+    tag: &str,
+    operations: &[&Operation],
+) -> String {
+    let mut helpers = BTreeSet::new();
+
+    let mut body = CodeWriter::default();
+    for operation in operations {
+        for declaration in client.declarations(operation) {
+            body.line(declaration);
+            body.blank();
+        }
+    }
+    body.line("@Injectable({ providedIn: 'root' })");
+    body.block(
+        format!("export class {}Service {{", to_pascal_case(tag)),
+        "}",
+        |w| {
+            w.line("private readonly http = inject(HttpClient);");
+            for operation in operations {
+                w.blank();
+                method(w, client, operation, &mut helpers);
+            }
+        },
+    );
+    let body = body.finish();
+
+    let used = identifiers(&body);
+    let http: Vec<&str> = [
+        "HttpClient",
+        "HttpErrorResponse",
+        "HttpHeaders",
+        "HttpParams",
+    ]
+    .into_iter()
+    .filter(|name| {
+        used.contains(name)
+            || (*name == "HttpErrorResponse" && helpers.contains(&Helper::HttpResponse))
+            || (*name == "HttpHeaders" && helpers.contains(&Helper::HttpHeaders))
+            || (*name == "HttpParams" && helpers.contains(&Helper::HttpParam))
+    })
+    .collect();
+    let mut imports = vec![
+        "import { Injectable, inject } from '@angular/core';".to_string(),
+        format!(
+            "import {{ {} }} from '@angular/common/http';",
+            http.join(", ")
+        ),
+    ];
+    if helpers.contains(&Helper::HttpResponse) {
+        imports.push("import type { HttpResponse } from '@angular/common/http';".to_string());
+        imports.push("import { catchError, of, switchMap, throwError } from 'rxjs';".to_string());
+    }
+    imports.push("import type { Observable } from 'rxjs';".to_string());
+    imports.extend(client.model_import(operations.iter().flat_map(|op| operation_types(op))));
+
+    let mut w = CodeWriter::default();
+    w.line(header(model));
+    w.blank();
+    w.line(imports.join("\n"));
+    for helper in helpers_source(helpers) {
+        w.blank();
+        w.line(helper);
+    }
+    w.blank();
+    w.line(body);
+    w.finish().trim_end().to_string() + "\n"
+}
+
+/// A service method, reading every response as a `Blob` that
+/// `readHttpResponse` parses according to its `Content-Type`
+fn method(
+    w: &mut CodeWriter,
+    client: &ClientRenderer,
+    operation: &Operation,
+    helpers: &mut BTreeSet<Helper>,
+) {
+    helpers.insert(Helper::HttpResponse);
+    let result = format!(
+        "{} & {{ headers: HttpHeaders }}",
+        client.response_type_name(operation)
+    );
+
+    write_operation_jsdoc(w, operation);
+    w.block(
+        format!(
+            "{}({}): Observable<{}> {{",
+            client.function_name(operation),
+            parameters(&client.arguments(operation)),
+            result
+        ),
+        "}",
+        |w| {
+            w.line(format!(
+                "const url = `{}`;",
+                client.path_template(operation, helpers)
+            ));
+            if !operation.query_params.is_empty() {
+                helpers.insert(Helper::HttpParam);
+                w.line("let httpParams = new HttpParams();");
+                for param in &operation.query_params {
+                    w.line(format!(
+                        "httpParams = appendHttpParam(httpParams, {}, {}, {}, {});",
+                        literal(&param.name.as_str().into()),
+                        optional_access("params", &param.name),
+                        literal(&style_name(param.style).into()),
+                        param.explode
+                    ));
+                }
+            }
+
+            let request_body = client.request_body(operation, helpers, false);
+            let mut request_headers = Vec::new();
+            if let Some(content_type) = request_body
+                .as_ref()
+                .and_then(|body| body.content_type.as_ref())
+            {
+                request_headers.push(("Content-Type", literal(&content_type.as_str().into())));
+            }
+            if let Some(accept) = client.accept(operation) {
+                request_headers.push(("Accept", literal(&accept.into())));
+            }
+            let mut fields = Vec::new();
+            if !operation.query_params.is_empty() {
+                fields.push("params: httpParams".to_string());
+            }
+            if !operation.header_params.is_empty() {
+                helpers.insert(Helper::HttpHeaders);
+                let mut headers = "toHttpHeaders(headers)".to_string();
+                for (name, value) in &request_headers {
+                    headers.push_str(&format!(".set({}, {})", literal(&(*name).into()), value));
+                }
+                fields.push(format!("headers: {}", headers));
+            } else if !request_headers.is_empty() {
+                let entries: Vec<String> = request_headers
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", property_key(name), value))
+                    .collect();
+                fields.push(format!(
+                    "headers: new HttpHeaders({{ {} }})",
+                    entries.join(", ")
+                ));
+            }
+            if let Some(request_body) = &request_body {
+                if request_body.expression == "body" {
+                    fields.push("body".to_string());
+                } else {
+                    fields.push(format!("body: {}", request_body.expression));
+                }
+            }
+            fields.push("observe: 'response' as const".to_string());
+            fields.push("responseType: 'blob' as const".to_string());
+
+            w.block("const requestOptions = {", "};", |w| {
+                for field in &fields {
+                    w.line(format!("{},", field));
+                }
+            });
+            w.line(format!(
+                "const response = this.http.request({}, url, requestOptions);",
+                literal(&operation.method.to_uppercase().into())
+            ));
+            w.line(format!(
+                "return readHttpResponse(response) as Observable<{}>;",
+                result
+            ));
+        },
+    );
+}
@@ -0,0 +1,184 @@
+use std::collections::BTreeSet;
+
+use crate::generators::typescript::client::{
+    Argument, ClientRenderer, is_json, parameters, write_operation_jsdoc,
+};
+use crate::generators::typescript::request::{Transport, client_module};
+use crate::generators::typescript::runtime::Helper;
+use crate::generators::typescript::types::literal;
+use crate::generators::typescript::{Mutator, TypeScriptOptions};
+use crate::generators::{CodeWriter, GeneratedFile, Generator};
+use crate::ir::{ApiModel, Operation};
+
+/// Emits one function per operation calling axios, or the configured
+/// [`Mutator`] in its place
+///
+/// Parameter and body types are those of the normalized model, which resolves
+/// them through [`ParameterResolver`](crate::resolve_ref::ParameterResolver),
+/// [`RequestBodyResolver`](crate::resolve_ref::RequestBodyResolver) and
+/// [`ResponseResolver`](crate::resolve_ref::ResponseResolver). Functions resolve
+/// to the status-keyed response union of their operation together with the
+/// response headers, so axios does not reject on declared error statuses. A
+/// mutator receives the data type of the successful responses instead and
+/// decides itself how to treat statuses.
+#[derive(Debug, Clone, Default)]
+pub struct AxiosGenerator {
+    options: TypeScriptOptions,
+}
+
+impl AxiosGenerator {
+    pub fn new(options: TypeScriptOptions) -> Self {
+        AxiosGenerator { options }
+    }
+}
+
+impl Generator for AxiosGenerator {
+    fn name(&self) -> &str {
+        "typescript-axios"
+    }
+
+    fn generate(&self, model: &ApiModel) -> Vec<GeneratedFile> {
+        let transport = match &self.options.mutator {
+            Some(mutator) => Transport::Mutator(mutator),
+            None => Transport::Axios,
+        };
+        vec![GeneratedFile::new(
+            self.options.client_file.clone(),
+            client_module(
+                model,
+                &self.options,
+                transport,
+                |_| Vec::new(),
+                |_, _, _| Vec::new(),
+            ),
+        )]
+    }
+}
+
+pub(crate) fn axios_function(
+    client: &ClientRenderer,
+    operation: &Operation,
+    mutator: Option<&Mutator>,
+    helpers: &mut BTreeSet<Helper>,
+) -> String {
+    let mut arguments = client.arguments(operation);
+    let options = match mutator {
+        Some(mutator) if mutator.request_options => {
+            Some(format!("SecondParameter<typeof {}>", mutator.name))
+        }
+        Some(_) => None,
+        None => Some("AxiosRequestConfig".to_string()),
+    };
+    if let Some(options) = &options {
+        arguments.push(Argument::new("options", options, true));
+    }
+
+    let body = client.request_body(operation, helpers, false);
+    let mut headers = Vec::new();
+    if !operation.header_params.is_empty() {
+        headers.push("...headers".to_string());
+    }
+    if let Some(content_type) = body.as_ref().and_then(|body| body.content_type.as_ref()) {
+        headers.push(format!("'Content-Type': '{}'", content_type));
+    }
+    if let Some(accept) = client.accept(operation) {
+        headers.push(format!("Accept: {}", literal(&accept.into())));
+    }
+    if mutator.is_none() && !headers.is_empty() {
+        headers.push("...options?.headers".to_string());
+    }
+    // Bodies that are neither JSON nor text are typed as `Blob`
+    let mut contents = operation
+        .responses
+        .iter()
+        .flat_map(|response| &response.contents)
+        .peekable();
+    let binary = contents.peek().is_some()
+        && contents.all(|content| {
+            !is_json(&content.media_type) && !content.media_type.starts_with("text/")
+        });
+    let config = |w: &mut CodeWriter| {
+        if mutator.is_none() {
+            w.line("...options,");
+        }
+        w.line(format!("url: {},", client.url_call(operation)));
+        w.line(format!("method: '{}',", operation.method.to_uppercase()));
+        if !headers.is_empty() {
+            w.line(format!("headers: {{ {} }},", headers.join(", ")));
+        }
+        if let Some(body) = &body {
+            w.line(format!("data: {},", body.expression));
+        }
+        if mutator.is_none() {
+            if binary {
+                w.line("responseType: 'blob',");
+            }
+            w.line("validateStatus: () => true,");
+        }
+    };
+
+    let mut w = CodeWriter::default();
+    write_operation_jsdoc(&mut w, operation);
+    match mutator {
+        Some(mutator) => {
+            w.block(
+                format!(
+                    "export const {} = ({}) => {{",
+                    client.function_name(operation),
+                    parameters(&arguments)
+                ),
+                "};",
+                |w| {
+                    w.block(
+                        format!(
+                            "return {}<{}>({{",
+                            mutator.name,
+                            client.success_type(operation)
+                        ),
+                        if options.is_some() {
+                            "}, options);"
+                        } else {
+                            "});"
+                        },
+                        config,
+                    );
+                },
+            );
+        }
+        None => {
+            let result = format!(
+                "{} & {{ headers: AxiosResponse['headers'] }}",
+                client.response_type_name(operation)
+            );
+            w.block(
+                format!(
+                    "export const {} = async ({}): Promise<{}> => {{",
+                    client.function_name(operation),
+                    parameters(&arguments),
+                    result
+                ),
+                "};",
+                |w| {
+                    w.block("const res = await axios.request({", "});", config);
+                    // Axios reads an empty body as an empty string or `Blob`
+                    w.line(
+                        "const data = res.data === '' || [204, 205, 304].includes(res.status) ? undefined : res.data;",
+                    );
+                    let mut fields = vec!["status: res.status", "data", "headers: res.headers"];
+                    if client.negotiates(operation) {
+                        w.line(
+                            "const contentType = String(res.headers['content-type'] ?? '').split(';')[0].trim().toLowerCase();",
+                        );
+                        fields.insert(1, "contentType");
+                    }
+                    w.line(format!(
+                        "return {{ {} }} as {};",
+                        fields.join(", "),
+                        result
+                    ));
+                },
+            );
+        }
+    }
+    w.finish().trim_end().to_string()
+}
@@ -0,0 +1,606 @@
+use std::collections::{BTreeSet, HashSet};
+
+use indexmap::IndexMap;
+
+use crate::generators::CodeWriter;
+use crate::generators::typescript::TypeScriptOptions;
+use crate::generators::typescript::runtime::Helper;
+use crate::generators::typescript::types::{TypeRenderer, literal, property_key, write_jsdoc};
+use crate::ir::{
+    ApiModel, Content, ObjectDef, Operation, Param, ParamStyle, Property, Status, TypeDef, TypeKind,
+};
+use crate::naming::{Casing, Language, NameAllocator, to_identifier, to_pascal_case};
+
+/// Names, signatures and URL builders shared by the TypeScript clients
+pub(crate) struct ClientRenderer<'a> {
+    pub types: TypeRenderer,
+    options: &'a TypeScriptOptions,
+    /// Client-local type names keyed by `{operation}#{suffix}`
+    names: IndexMap<String, String>,
+}
+
+/// A parameter of a generated client function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Argument {
+    pub name: String,
+    pub ty: String,
+    pub optional: bool,
+}
+
+impl Argument {
+    pub fn new(name: impl Into<String>, ty: impl Into<String>, optional: bool) -> Self {
+        Argument {
+            name: name.into(),
+            ty: ty.into(),
+            optional,
+        }
+    }
+}
+
+/// How a client sends the request body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RequestBody {
+    pub expression: String,
+    pub content_type: Option<String>,
+}
+
+const LOCAL_TYPES: &[&str] = &[
+    "Params",
+    "Headers",
+    "Body",
+    "Response",
+    "QueryResult",
+    "MutationResult",
+    "Context",
+    "Handler",
+];
+
+impl<'a> ClientRenderer<'a> {
+    pub fn new(model: &ApiModel, options: &'a TypeScriptOptions) -> Self {
+        let types = TypeRenderer::new(model, options);
+        let mut names = NameAllocator::new();
+        for name in model.types.keys() {
+            names.reserve(types.type_name(name));
+        }
+        for operation in &model.operations {
+            for suffix in LOCAL_TYPES {
+                names.request(
+                    format!("{}#{}", operation.name, suffix),
+                    format!("{}{}", to_pascal_case(&operation.name), suffix),
+                );
+            }
+        }
+        ClientRenderer {
+            types,
+            options,
+            names: names.resolve(),
+        }
+    }
+
+    /// A type name declared for an operation, e.g. `ListPetsParams`
+    pub fn local_name(&self, operation: &Operation, suffix: &str) -> String {
+        self.names[&format!("{}#{}", operation.name, suffix)].clone()
+    }
+
+    /// The function name of an operation
+    pub fn function_name(&self, operation: &Operation) -> String {
+        to_identifier(&operation.name, Casing::Camel, Language::TypeScript)
+    }
+
+    /// The name of the function building an operation's URL
+    pub fn url_function_name(&self, operation: &Operation) -> String {
+        format!("get{}Url", to_pascal_case(&operation.name))
+    }
+
+    /// The name of the query parameters type, e.g. `ListPetsParams`
+    pub fn params_type_name(&self, operation: &Operation) -> String {
+        self.local_name(operation, "Params")
+    }
+
+    /// The name of the header parameters type, e.g. `ListPetsHeaders`
+    pub fn headers_type_name(&self, operation: &Operation) -> String {
+        self.local_name(operation, "Headers")
+    }
+
+    /// The name of the status-keyed response union, e.g. `ListPetsResponse`
+    pub fn response_type_name(&self, operation: &Operation) -> String {
+        self.local_name(operation, "Response")
+    }
+
+    /// The content a client sends, preferring JSON when several are declared
+    pub fn body_content<'o>(&self, operation: &'o Operation) -> Option<&'o Content> {
+        let contents = &operation.body.as_ref()?.contents;
+        contents
+            .iter()
+            .find(|content| is_json(&content.media_type))
+            .or_else(|| contents.first())
+    }
+
+    /// The TypeScript type of the request body
+    pub fn body_type(&self, operation: &Operation) -> Option<String> {
+        let content = self.body_content(operation)?;
+        Some(match &content.ty.kind {
+            TypeKind::Object(_) => self.local_name(operation, "Body"),
+            _ => self.types.render(&content.ty),
+        })
+    }
+
+    /// Identifiers for the path parameters, in declaration order
+    pub fn path_arguments<'o>(&self, operation: &'o Operation) -> Vec<(&'o Param, String)> {
+        let mut names = NameAllocator::new();
+        for reserved in ["params", "headers", "body", "options"] {
+            names.reserve(reserved);
+        }
+        for (i, param) in operation.path_params.iter().enumerate() {
+            names.request(
+                format!("{:04}", i),
+                to_identifier(&param.name, Casing::Camel, Language::TypeScript),
+            );
+        }
+        operation
+            .path_params
+            .iter()
+            .zip(names.resolve().into_values())
+            .collect()
+    }
+
+    /// The arguments of a client function: path parameters, body, query
+    /// parameters and headers
+    ///
+    /// Cookie parameters are left to the browser.
+    pub fn arguments(&self, operation: &Operation) -> Vec<Argument> {
+        let mut arguments: Vec<Argument> = self
+            .path_arguments(operation)
+            .into_iter()
+            .map(|(param, name)| Argument::new(name, self.types.render(&param.ty), false))
+            .collect();
+        if let Some(ty) = self.body_type(operation) {
+            let required = operation.body.as_ref().is_some_and(|body| body.required);
+            arguments.push(Argument::new("body", ty, !required));
+        }
+        if !operation.query_params.is_empty() {
+            arguments.push(Argument::new(
+                "params",
+                self.params_type_name(operation),
+                operation.query_params.iter().all(|param| !param.required),
+            ));
+        }
+        if !operation.header_params.is_empty() {
+            arguments.push(Argument::new(
+                "headers",
+                self.headers_type_name(operation),
+                operation.header_params.iter().all(|param| !param.required),
+            ));
+        }
+        arguments
+    }
+
+    /// The types declared next to an operation's functions
+    pub fn declarations(&self, operation: &Operation) -> Vec<String> {
+        let mut declarations = self.request_declarations(operation);
+        declarations.push(self.response_declaration(operation));
+        declarations
+    }
+
+    /// The types of an operation's query parameters, headers and inline body
+    fn request_declarations(&self, operation: &Operation) -> Vec<String> {
+        let mut declarations = Vec::new();
+        if !operation.query_params.is_empty() {
+            declarations.push(
+                self.params_declaration(self.params_type_name(operation), &operation.query_params),
+            );
+        }
+        if !operation.header_params.is_empty() {
+            declarations.push(
+                self.params_declaration(
+                    self.headers_type_name(operation),
+                    &operation.header_params,
+                ),
+            );
+        }
+        if let Some(content) = self.body_content(operation)
+            && matches!(content.ty.kind, TypeKind::Object(_))
+        {
+            declarations.push(
+                self.types
+                    .declaration(&self.local_name(operation, "Body"), &content.ty),
+            );
+        }
+        declarations
+    }
+
+    fn params_declaration(&self, name: String, params: &[Param]) -> String {
+        let object = ObjectDef {
+            properties: params
+                .iter()
+                .map(|param| {
+                    let mut ty = param.ty.clone();
+                    ty.description = param.description.clone().or(ty.description);
+                    ty.deprecated |= param.deprecated;
+                    Property {
+                        name: param.name.clone(),
+                        required: param.required,
+                        ty,
+                    }
+                })
+                .collect(),
+            additional_properties: None,
+        };
+        format!(
+            "export type {} = {};",
+            name,
+            self.types.object_type(&object)
+        )
+    }
+
+    /// The status-keyed union of an operation's responses
+    ///
+    /// Declared codes have a literal status, ranges and `default` a `number`
+    /// one. Responses declaring several media types get a member per media
+    /// type, told apart by `contentType`.
+    fn response_declaration(&self, operation: &Operation) -> String {
+        let mut variants: Vec<String> = Vec::new();
+        for response in operation.responses.iter() {
+            let status = match response.status {
+                Status::Code(code) => code.to_string(),
+                Status::Range(_) | Status::Default => "number".to_string(),
+            };
+            match response.contents.as_slice() {
+                [] => variants.push(format!("{{ status: {}; data: void }}", status)),
+                [content] => variants.push(format!(
+                    "{{ status: {}; data: {} }}",
+                    status,
+                    self.response_data(content)
+                )),
+                contents => variants.extend(contents.iter().map(|content| {
+                    format!(
+                        "{{ status: {}; contentType: {}; data: {} }}",
+                        status,
+                        literal(&content.media_type.as_str().into()),
+                        self.response_data(content)
+                    )
+                })),
+            }
+        }
+        let union = if variants.is_empty() {
+            "{ status: number; data: unknown }".to_string()
+        } else {
+            variants.join(" | ")
+        };
+        format!(
+            "export type {} = {};",
+            self.response_type_name(operation),
+            union
+        )
+    }
+
+    /// The type of a response body as `parseBody` reads it: JSON is parsed,
+    /// `text/*` is a string and anything else a `Blob`
+    fn response_data(&self, content: &Content) -> String {
+        if is_json(&content.media_type) {
+            self.types.render(&content.ty)
+        } else if content.media_type.starts_with("text/") {
+            "string".to_string()
+        } else {
+            "Blob".to_string()
+        }
+    }
+
+    /// Whether a response declares several media types, so that the client
+    /// asks for them and reports which one it got
+    pub fn negotiates(&self, operation: &Operation) -> bool {
+        operation
+            .responses
+            .iter()
+            .any(|response| response.contents.len() > 1)
+    }
+
+    /// The `Accept` header listing every media type the responses declare,
+    /// sent only when the operation [negotiates](Self::negotiates)
+    pub fn accept(&self, operation: &Operation) -> Option<String> {
+        if !self.negotiates(operation) {
+            return None;
+        }
+        let mut media_types: Vec<&str> = Vec::new();
+        for content in operation.responses.iter().flat_map(|r| &r.contents) {
+            if !media_types.contains(&content.media_type.as_str()) {
+                media_types.push(&content.media_type);
+            }
+        }
+        Some(media_types.join(", "))
+    }
+
+    /// The union of the data types of the successful responses
+    pub fn success_type(&self, operation: &Operation) -> String {
+        let mut types: Vec<String> = Vec::new();
+        for response in &operation.responses.success {
+            let data = response
+                .contents
+                .iter()
+                .find(|content| is_json(&content.media_type))
+                .or_else(|| response.contents.first())
+                .map_or("void".to_string(), |content| self.types.render(&content.ty));
+            if !types.contains(&data) {
+                types.push(data);
+            }
+        }
+        if types.is_empty() {
+            "unknown".to_string()
+        } else {
+            types.join(" | ")
+        }
+    }
+
+    /// The request body expression and the `Content-Type` to send with it
+    ///
+    /// Form bodies leave the content type to the HTTP client so that the
+    /// multipart boundary is set. JSON is passed through unless `stringify_json`.
+    pub fn request_body(
+        &self,
+        operation: &Operation,
+        helpers: &mut BTreeSet<Helper>,
+        stringify_json: bool,
+    ) -> Option<RequestBody> {
+        let content = self.body_content(operation)?;
+        let optional = !operation.body.as_ref().is_some_and(|body| body.required);
+        let media_type = content.media_type.as_str();
+        let (encoded, content_type) = if is_json(media_type) {
+            let encoded = if stringify_json {
+                "JSON.stringify(body)"
+            } else {
+                "body"
+            };
+            (encoded.to_string(), Some(media_type.to_string()))
+        } else if media_type.starts_with("multipart/form-data") {
+            helpers.insert(Helper::FormData);
+            let content_types: Vec<String> = content
+                .encoding
+                .iter()
+                .filter_map(|(name, encoding)| {
+                    let content_type = encoding.content_type.as_ref()?;
+                    Some(format!(
+                        "{}: {}",
+                        property_key(name),
+                        literal(&content_type.as_str().into())
+                    ))
+                })
+                .collect();
+            (form_call("toFormData", &content_types), None)
+        } else if media_type.starts_with("application/x-www-form-urlencoded") {
+            helpers.insert(Helper::UrlSearchParams);
+            let styles: Vec<String> = content
+                .encoding
+                .iter()
+                .filter_map(|(name, encoding)| {
+                    let style = encoding.style?;
+                    Some(format!(
+                        "{}: {{ style: '{}', explode: {} }}",
+                        property_key(name),
+                        style_name(style),
+                        encoding.explode
+                    ))
+                })
+                .collect();
+            (form_call("toUrlSearchParams", &styles), None)
+        } else {
+            ("body".to_string(), Some(media_type.to_string()))
+        };
+        let expression = if optional && encoded != "body" {
+            format!("body === undefined ? undefined : {}", encoded)
+        } else {
+            encoded
+        };
+        Some(RequestBody {
+            expression,
+            content_type,
+        })
+    }
+
+    /// A call of the URL function with the matching arguments
+    pub fn url_call(&self, operation: &Operation) -> String {
+        let arguments: Vec<String> = self
+            .arguments(operation)
+            .into_iter()
+            .filter(|argument| argument.name != "body" && argument.name != "headers")
+            .map(|argument| argument.name)
+            .collect();
+        format!(
+            "{}({})",
+            self.url_function_name(operation),
+            arguments.join(", ")
+        )
+    }
+
+    /// The template literal body of an operation's URL without the query
+    /// string, with the path parameters serialized
+    pub fn path_template(&self, operation: &Operation, helpers: &mut BTreeSet<Helper>) -> String {
+        let mut path = escape_template(&self.options.base_url);
+        let mut template = escape_template(&operation.path);
+        for (param, name) in self.path_arguments(operation) {
+            let value = if param.style == ParamStyle::Simple
+                && matches!(
+                    param.ty.kind,
+                    TypeKind::Primitive { .. } | TypeKind::Enum { .. }
+                ) {
+                format!("${{encodeURIComponent(String({}))}}", name)
+            } else {
+                helpers.insert(Helper::PathParam);
+                format!(
+                    "${{serializePathParam({}, {}, {}, {})}}",
+                    literal(&param.name.as_str().into()),
+                    name,
+                    literal(&style_name(param.style).into()),
+                    param.explode
+                )
+            };
+            template = template.replace(&format!("{{{}}}", param.name), &value);
+        }
+        path.push_str(&template);
+        path
+    }
+
+    /// The arrow function returning an operation's URL with its query string
+    pub fn url_function(&self, operation: &Operation, helpers: &mut BTreeSet<Helper>) -> String {
+        let path = self.path_template(operation, helpers);
+        let mut arguments: Vec<Argument> = self
+            .arguments(operation)
+            .into_iter()
+            .filter(|argument| argument.name != "body" && argument.name != "headers")
+            .collect();
+        if let Some(params) = arguments.iter_mut().find(|a| a.name == "params") {
+            params.optional = true;
+        }
+
+        let mut w = CodeWriter::default();
+        w.block(
+            format!(
+                "export const {} = ({}) => {{",
+                self.url_function_name(operation),
+                parameters(&arguments)
+            ),
+            "};",
+            |w| {
+                if operation.query_params.is_empty() {
+                    w.line(format!("return `{}`;", path));
+                    return;
+                }
+                helpers.insert(Helper::QueryParam);
+                w.line("const query = new URLSearchParams();");
+                for param in &operation.query_params {
+                    w.line(format!(
+                        "appendQueryParam(query, {}, {}, {}, {});",
+                        literal(&param.name.as_str().into()),
+                        optional_access("params", &param.name),
+                        literal(&style_name(param.style).into()),
+                        param.explode
+                    ));
+                }
+                w.line("const search = query.toString();");
+                w.line(format!(
+                    "return `{}${{search ? `?${{search}}` : ''}}`;",
+                    path
+                ));
+            },
+        );
+        w.finish().trim_end().to_string()
+    }
+
+    /// The import of every model type the given types refer to
+    pub fn model_import<'t>(&self, types: impl IntoIterator<Item = &'t TypeDef>) -> Option<String> {
+        let names: BTreeSet<&str> = types
+            .into_iter()
+            .flat_map(TypeDef::references)
+            .map(|name| self.types.type_name(name))
+            .collect();
+        if names.is_empty() {
+            return None;
+        }
+        let module = self.options.models_file.trim_end_matches(".ts");
+        Some(format!(
+            "import type {{ {} }} from './{}';",
+            names.into_iter().collect::<Vec<_>>().join(", "),
+            module
+        ))
+    }
+}
+
+/// Every type an operation's client code mentions
+pub(crate) fn operation_types(operation: &Operation) -> impl Iterator<Item = &TypeDef> {
+    operation
+        .params()
+        .map(|param| &param.ty)
+        .chain(
+            operation
+                .body
+                .iter()
+                .flat_map(|body| body.contents.iter().map(|content| &content.ty)),
+        )
+        .chain(
+            operation
+                .responses
+                .iter()
+                .flat_map(|response| response.contents.iter().map(|content| &content.ty)),
+        )
+}
+
+/// JSDoc for a client function: summary, description and deprecation
+pub(crate) fn write_operation_jsdoc(w: &mut CodeWriter, operation: &Operation) {
+    let mut lines: Vec<String> = operation
+        .summary
+        .iter()
+        .chain(&operation.description)
+        .flat_map(|text| text.trim().lines())
+        .map(String::from)
+        .collect();
+    if operation.deprecated {
+        lines.push("@deprecated".to_string());
+    }
+    write_jsdoc(w, &lines);
+}
+
+/// A parameter list; optional arguments before required ones take `| undefined`
+pub(crate) fn parameters(arguments: &[Argument]) -> String {
+    let last_required = arguments.iter().rposition(|argument| !argument.optional);
+    arguments
+        .iter()
+        .enumerate()
+        .map(|(i, argument)| match (argument.optional, last_required) {
+            (true, Some(last)) if i < last => {
+                format!("{}: {} | undefined", argument.name, argument.ty)
+            }
+            (true, _) => format!("{}?: {}", argument.name, argument.ty),
+            (false, _) => format!("{}: {}", argument.name, argument.ty),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Every identifier-like token in generated code, to decide what to import
+pub(crate) fn identifiers(code: &str) -> HashSet<&str> {
+    code.split(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '$'))
+        .filter(|token| !token.is_empty())
+        .collect()
+}
+
+/// A call encoding the body, passing the per-property encoding when declared
+fn form_call(function: &str, encoding: &[String]) -> String {
+    if encoding.is_empty() {
+        format!("{}(body)", function)
+    } else {
+        format!("{}(body, {{ {} }})", function, encoding.join(", "))
+    }
+}
+
+/// Whether a media type carries JSON, e.g. `application/problem+json`
+pub(crate) fn is_json(media_type: &str) -> bool {
+    let essence = media_type.split(';').next().unwrap_or_default().trim();
+    essence == "application/json" || essence.ends_with("+json")
+}
+
+/// Optional property access, e.g. `params?.limit` or `params?.['page[size]']`
+pub(crate) fn optional_access(object: &str, name: &str) -> String {
+    let key = property_key(name);
+    if key.starts_with('\'') {
+        format!("{}?.[{}]", object, key)
+    } else {
+        format!("{}?.{}", object, key)
+    }
+}
+
+pub(crate) fn style_name(style: ParamStyle) -> &'static str {
+    match style {
+        ParamStyle::Simple => "simple",
+        ParamStyle::Label => "label",
+        ParamStyle::Matrix => "matrix",
+        ParamStyle::Form => "form",
+        ParamStyle::SpaceDelimited => "spaceDelimited",
+        ParamStyle::PipeDelimited => "pipeDelimited",
+        ParamStyle::DeepObject => "deepObject",
+    }
+}
+
+fn escape_template(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('`', "\\`")
+        .replace("${", "\\${")
+}
@@ -0,0 +1,157 @@
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use crate::generators::CodeWriter;
+use crate::generators::typescript::types::{jsdoc, literal, property_key, write_jsdoc};
+use crate::ir::TypeDef;
+
+/// How named enums are emitted
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum EnumStyle {
+    /// `export type Status = 'active' | 'inactive';`
+    Union,
+    /// An `as const` object plus a type derived from its values
+    #[default]
+    ConstObject,
+    /// A native `export enum`
+    Enum,
+}
+
+/// Extensions that name enum members, in order of precedence
+const NAME_EXTENSIONS: &[&str] = &["x-enum-varnames", "x-enumNames"];
+const DESCRIPTION_EXTENSION: &str = "x-enum-descriptions";
+
+/// The declaration of a named enum in the configured style
+pub(crate) fn enum_declaration(
+    name: &str,
+    ty: &TypeDef,
+    values: &[Value],
+    style: EnumStyle,
+) -> String {
+    let mut w = CodeWriter::default();
+    write_jsdoc(&mut w, &jsdoc(ty));
+    let null = if ty.nullable { " | null" } else { "" };
+
+    if style == EnumStyle::Union {
+        let literals: Vec<String> = values.iter().map(literal).collect();
+        w.line(format!(
+            "export type {} = {}{};",
+            name,
+            literals.join(" | "),
+            null
+        ));
+        return w.finish().trim_end().to_string();
+    }
+
+    let names = member_names(ty, values);
+    let descriptions = extension_strings(ty, DESCRIPTION_EXTENSION);
+    let members = |w: &mut CodeWriter, separator: &str| {
+        for (i, (member, value)) in names.iter().zip(values).enumerate() {
+            if let Some(description) = descriptions.get(i).filter(|d| !d.is_empty()) {
+                write_jsdoc(w, std::slice::from_ref(description));
+            }
+            w.line(format!("{}{}{},", member, separator, literal(value)));
+        }
+    };
+
+    match style {
+        EnumStyle::ConstObject => {
+            w.line(format!(
+                "export type {0} = typeof {0}[keyof typeof {0}]{1};",
+                name, null
+            ));
+            w.blank();
+            w.line("// eslint-disable-next-line @typescript-eslint/no-redeclare");
+            w.block(format!("export const {} = {{", name), "} as const;", |w| {
+                members(w, ": ")
+            });
+        }
+        EnumStyle::Enum | EnumStyle::Union => {
+            w.block(format!("export enum {} {{", name), "}", |w| {
+                members(w, " = ")
+            });
+        }
+    }
+    w.finish().trim_end().to_string()
+}
+
+/// Member names from `x-enum-varnames`/`x-enumNames`, or synthesized from values
+///
+/// Strings that are valid identifiers are used as they are, others are
+/// sanitized; numbers become `NUMBER_1`, `NUMBER_MINUS_1` and so on. Duplicates
+/// get a numeric suffix in declaration order.
+pub(crate) fn member_names(ty: &TypeDef, values: &[Value]) -> Vec<String> {
+    let declared = NAME_EXTENSIONS
+        .iter()
+        .map(|extension| extension_strings(ty, extension))
+        .find(|names| names.len() == values.len())
+        .unwrap_or_default();
+
+    let mut taken = HashSet::new();
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let base = match declared.get(i) {
+                Some(name) => property_key(name),
+                None => synthesized_name(value),
+            };
+            let mut name = base.clone();
+            let mut suffix = 2;
+            while !taken.insert(name.clone()) {
+                name = format!("{}{}", base, suffix);
+                suffix += 1;
+            }
+            name
+        })
+        .collect()
+}
+
+fn synthesized_name(value: &Value) -> String {
+    match value {
+        Value::String(string) => {
+            let sanitized: String = string
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() || c == '$' {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect();
+            if sanitized.is_empty() {
+                "EMPTY".to_string()
+            } else if sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+                format!("_{}", sanitized)
+            } else {
+                sanitized
+            }
+        }
+        Value::Number(number) => {
+            let number = number.to_string();
+            let digits = match number.strip_prefix('-') {
+                Some(positive) => format!("MINUS_{}", positive),
+                None => number,
+            };
+            format!("NUMBER_{}", digits.replace(['.', '+'], "_"))
+        }
+        Value::Bool(true) => "TRUE".to_string(),
+        Value::Bool(false) => "FALSE".to_string(),
+        _ => "NULL".to_string(),
+    }
+}
+
+fn extension_strings(ty: &TypeDef, extension: &str) -> Vec<String> {
+    ty.extensions
+        .get(extension)
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .map(|item| item.as_str().map(String::from).unwrap_or_default())
+                .collect()
+        })
+        .unwrap_or_default()
+}
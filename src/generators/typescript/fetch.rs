@@ -0,0 +1,117 @@
+use std::collections::BTreeSet;
+
+use crate::generators::typescript::TypeScriptOptions;
+use crate::generators::typescript::client::{
+    Argument, ClientRenderer, parameters, write_operation_jsdoc,
+};
+use crate::generators::typescript::request::{Transport, client_module};
+use crate::generators::typescript::runtime::Helper;
+use crate::generators::typescript::types::literal;
+use crate::generators::{CodeWriter, GeneratedFile, Generator};
+use crate::ir::{ApiModel, Operation};
+
+/// Emits one async function per operation calling the native `fetch`
+///
+/// Each function resolves to the status-keyed response union of its operation
+/// together with the response headers; non-2XX responses are returned, not
+/// thrown. Operations whose responses declare several media types send them
+/// as `Accept` and report the one received as `contentType`.
+#[derive(Debug, Clone, Default)]
+pub struct FetchGenerator {
+    options: TypeScriptOptions,
+}
+
+impl FetchGenerator {
+    pub fn new(options: TypeScriptOptions) -> Self {
+        FetchGenerator { options }
+    }
+}
+
+impl Generator for FetchGenerator {
+    fn name(&self) -> &str {
+        "typescript-fetch"
+    }
+
+    fn generate(&self, model: &ApiModel) -> Vec<GeneratedFile> {
+        vec![GeneratedFile::new(
+            self.options.client_file.clone(),
+            client_module(
+                model,
+                &self.options,
+                Transport::Fetch,
+                |_| Vec::new(),
+                |_, _, _| Vec::new(),
+            ),
+        )]
+    }
+}
+
+pub(crate) fn fetch_function(
+    client: &ClientRenderer,
+    operation: &Operation,
+    helpers: &mut BTreeSet<Helper>,
+) -> String {
+    helpers.insert(Helper::ParseBody);
+    let mut arguments = client.arguments(operation);
+    let url = client.url_call(operation);
+    arguments.push(Argument::new("options", "RequestInit", true));
+    let result = format!(
+        "{} & {{ headers: Headers }}",
+        client.response_type_name(operation)
+    );
+
+    let mut w = CodeWriter::default();
+    write_operation_jsdoc(&mut w, operation);
+    w.block(
+        format!(
+            "export const {} = async ({}): Promise<{}> => {{",
+            client.function_name(operation),
+            parameters(&arguments),
+            result
+        ),
+        "};",
+        |w| {
+            w.line("const requestHeaders = new Headers(options?.headers);");
+            if !operation.header_params.is_empty() {
+                helpers.insert(Helper::Headers);
+                w.line("setHeaders(requestHeaders, headers);");
+            }
+            if let Some(accept) = client.accept(operation) {
+                w.line(format!(
+                    "requestHeaders.set('Accept', {});",
+                    literal(&accept.into())
+                ));
+            }
+            let body = client.request_body(operation, helpers, true);
+            if let Some(content_type) = body.as_ref().and_then(|b| b.content_type.as_ref()) {
+                w.line(format!(
+                    "requestHeaders.set('Content-Type', '{}');",
+                    content_type
+                ));
+            }
+
+            w.block(format!("const res = await fetch({}, {{", url), "});", |w| {
+                w.line("...options,");
+                w.line(format!("method: '{}',", operation.method.to_uppercase()));
+                w.line("headers: requestHeaders,");
+                if let Some(body) = body {
+                    w.line(format!("body: {},", body.expression));
+                }
+            });
+            w.line("const data = await parseBody(res);");
+            let mut fields = vec!["status: res.status", "data", "headers: res.headers"];
+            if client.negotiates(operation) {
+                w.line(
+                    "const contentType = res.headers.get('Content-Type')?.split(';')[0].trim().toLowerCase();",
+                );
+                fields.insert(1, "contentType");
+            }
+            w.line(format!(
+                "return {{ {} }} as {};",
+                fields.join(", "),
+                result
+            ));
+        },
+    );
+    w.finish().trim_end().to_string()
+}
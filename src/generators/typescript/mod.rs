@@ -0,0 +1,1019 @@
+//! TypeScript code generation
+//!
+//! The generators in this module share a [`TypeScriptOptions`] value so that
+//! clients import their types from the file written by [`TypesGenerator`].
+
+mod angular;
+mod axios;
+mod client;
+mod enums;
+mod fetch;
+mod msw;
+mod query;
+mod request;
+mod runtime;
+mod server;
+mod swr;
+mod types;
+mod zod;
+
+use indexmap::IndexMap;
+
+use crate::generators::CodeWriter;
+use crate::generators::typescript::types::write_jsdoc;
+use crate::ir::ApiModel;
+
+// Re-export the public API
+pub use angular::AngularGenerator;
+pub use axios::AxiosGenerator;
+pub use enums::EnumStyle;
+pub use fetch::FetchGenerator;
+pub use msw::MswGenerator;
+pub use query::{QueryFramework, QueryGenerator};
+pub use server::{ServerFramework, ServerGenerator};
+pub use swr::SwrGenerator;
+pub use types::TypesGenerator;
+pub use zod::ZodGenerator;
+
+/// Options shared by all TypeScript generators
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeScriptOptions {
+    /// File the type declarations are written to
+    pub models_file: String,
+    /// How named enums are declared; inline enums are always literal unions
+    pub enum_style: EnumStyle,
+    /// File the HTTP client is written to
+    pub client_file: String,
+    /// Prefix of every request URL, e.g. `https://api.example.com/v1`
+    pub base_url: String,
+    /// The HTTP client that hook generators build on
+    pub http_client: HttpClient,
+    /// A custom function performing the HTTP calls instead of axios
+    pub mutator: Option<Mutator>,
+    pub query: QueryOptions,
+    pub msw: MswOptions,
+    pub server: ServerOptions,
+    pub zod: ZodOptions,
+}
+
+impl Default for TypeScriptOptions {
+    fn default() -> Self {
+        TypeScriptOptions {
+            models_file: "model.ts".to_string(),
+            enum_style: EnumStyle::default(),
+            client_file: "client.ts".to_string(),
+            base_url: String::new(),
+            http_client: HttpClient::default(),
+            mutator: None,
+            query: QueryOptions::default(),
+            msw: MswOptions::default(),
+            server: ServerOptions::default(),
+            zod: ZodOptions::default(),
+        }
+    }
+}
+
+/// Options of the query hook generators
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryOptions {
+    /// Emit `useSuspenseQuery` variants of query hooks
+    pub suspense: bool,
+    /// Query parameter carrying the page or cursor; queries declaring it get
+    /// `useInfiniteQuery` variants
+    pub infinite_query_param: Option<String>,
+}
+
+impl Default for QueryOptions {
+    fn default() -> Self {
+        QueryOptions {
+            suspense: true,
+            infinite_query_param: None,
+        }
+    }
+}
+
+/// Options of the MSW handler generator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MswOptions {
+    /// File the handlers and mock factories are written to
+    pub file: String,
+    /// How many levels of a recursive type are generated before optional
+    /// properties are left out and arrays are empty
+    pub max_depth: usize,
+    /// Length of generated arrays without `minItems`/`maxItems`
+    pub array_length: (usize, usize),
+    /// TypeScript expressions used instead of generated values, keyed by
+    /// property name or by `Type.property`, e.g. `"Pet.name" => "'Rex'"`
+    pub overrides: IndexMap<String, String>,
+}
+
+impl Default for MswOptions {
+    fn default() -> Self {
+        MswOptions {
+            file: "handlers.ts".to_string(),
+            max_depth: 3,
+            array_length: (1, 10),
+            overrides: IndexMap::new(),
+        }
+    }
+}
+
+/// Options of the server stub generators
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerOptions {
+    /// File the routes, context types and validators are written to
+    pub file: String,
+    /// Directory of the handler stubs, relative to the server file
+    pub handlers_dir: String,
+}
+
+impl Default for ServerOptions {
+    fn default() -> Self {
+        ServerOptions {
+            file: "server.ts".to_string(),
+            handlers_dir: "handlers".to_string(),
+        }
+    }
+}
+
+/// Options of the Zod schema generator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZodOptions {
+    /// File the schemas are written to
+    pub file: String,
+}
+
+impl Default for ZodOptions {
+    fn default() -> Self {
+        ZodOptions {
+            file: "schemas.ts".to_string(),
+        }
+    }
+}
+
+/// The HTTP client used by generated request functions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum HttpClient {
+    /// The native `fetch`
+    #[default]
+    Fetch,
+    Axios,
+}
+
+/// A user-supplied function that replaces the generated HTTP call
+///
+/// The function receives an axios-style request config (`url`, `method`,
+/// `headers`, `data`) and is called with the type of the successful response
+/// data as its type argument, e.g. `customInstance<Pet[]>(config)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mutator {
+    /// Module to import the function from, as written in the import statement
+    pub path: String,
+    /// Name of the exported function
+    pub name: String,
+    /// Whether the function takes request options as a second argument, which
+    /// generated functions then accept as their last parameter
+    pub request_options: bool,
+}
+
+impl Mutator {
+    pub fn new(path: impl Into<String>, name: impl Into<String>) -> Self {
+        Mutator {
+            path: path.into(),
+            name: name.into(),
+            request_options: false,
+        }
+    }
+
+    pub fn with_request_options(mut self) -> Self {
+        self.request_options = true;
+        self
+    }
+}
+
+/// The banner at the top of every generated file
+pub(crate) fn header(model: &ApiModel) -> String {
+    let mut lines = vec![
+        "Generated by rustval. Do not edit manually.".to_string(),
+        model.title.clone(),
+    ];
+    if let Some(description) = &model.description {
+        lines.extend(
+            description
+                .trim()
+                .lines()
+                .map(|line| line.trim_end().to_string()),
+        );
+    }
+    lines.push(format!("OpenAPI spec version: {}", model.version));
+    let mut w = CodeWriter::default();
+    write_jsdoc(&mut w, &lines);
+    w.finish().trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::{Generator, assert_generated};
+    use openapiv3::OpenAPI;
+
+    fn model(spec: &str) -> ApiModel {
+        let spec: OpenAPI = serde_yaml::from_str(spec).unwrap();
+        ApiModel::from_spec(&spec)
+    }
+
+    const PETS: &str = r##"
+openapi: 3.0.3
+info: { title: Pets, version: "1.0" }
+paths: {}
+components:
+  schemas:
+    Pet:
+      oneOf:
+        - $ref: "#/components/schemas/Cat"
+        - $ref: "#/components/schemas/Dog"
+      discriminator:
+        propertyName: petType
+        mapping:
+          cat: "#/components/schemas/Cat"
+          dog: "#/components/schemas/Dog"
+    Cat:
+      description: A cat. Purrs.
+      allOf:
+        - $ref: "#/components/schemas/Base"
+        - type: object
+          properties:
+            lives: { type: integer, minimum: 0, maximum: 9 }
+    Dog:
+      allOf:
+        - $ref: "#/components/schemas/Base"
+    Base:
+      type: object
+      required: [petType, name]
+      properties:
+        petType: { type: string }
+        name: { type: string, minLength: 1, description: "The name */ of the pet" }
+        id: { type: integer, format: int64, readOnly: true }
+        x-tag: { type: string, nullable: true, deprecated: true }
+        tags:
+          type: array
+          items: { type: string, enum: [a, b] }
+        owner:
+          type: object
+          properties:
+            email: { type: string }
+    Labels:
+      type: object
+      additionalProperties: { type: string }
+    Scores:
+      type: object
+      properties:
+        total: { type: integer }
+      additionalProperties: { type: number }
+    MaybeName:
+      type: string
+      nullable: true
+    Anything: {}
+    Photo: { type: string, format: binary }
+"##;
+
+    #[test]
+    fn test_header() {
+        let model = model(
+            r#"
+openapi: 3.0.3
+info:
+  title: Pets
+  version: "1.0"
+  description: |
+    Pet store */ with trailing comment
+
+    Second paragraph
+paths: {}
+"#,
+        );
+        assert_eq!(
+            header(&model),
+            "/**\n * Generated by rustval. Do not edit manually.\n * Pets\n * Pet store *\\/ with trailing comment\n *\n * Second paragraph\n * OpenAPI spec version: 1.0\n */"
+        );
+    }
+
+    #[test]
+    fn test_types_generator() {
+        let files = TypesGenerator::default().generate(&model(PETS));
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path, "model.ts");
+        assert_eq!(
+            files[0].contents,
+            r#"/**
+ * Generated by rustval. Do not edit manually.
+ * Pets
+ * OpenAPI spec version: 1.0
+ */
+
+export type Pet = Cat & { petType: 'cat' } | Dog & { petType: 'dog' };
+
+/**
+ * A cat. Purrs.
+ */
+export type Cat = Base & {
+  /**
+   * @minimum 0
+   * @maximum 9
+   */
+  lives?: number;
+};
+
+export type Dog = Base;
+
+export interface Base {
+  petType: string;
+  /**
+   * The name *\/ of the pet
+   * @minLength 1
+   */
+  name: string;
+  readonly id?: number;
+  /**
+   * @deprecated
+   */
+  'x-tag'?: string | null;
+  tags?: ('a' | 'b')[];
+  owner?: {
+    email?: string;
+  };
+}
+
+export interface Labels {
+  [key: string]: string;
+}
+
+export interface Scores {
+  total?: number;
+  [key: string]: number | undefined;
+}
+
+export type MaybeName = string | null;
+
+export type Anything = unknown;
+
+export type Photo = Blob;
+"#
+        );
+    }
+
+    const ENUMS: &str = r#"
+openapi: 3.0.3
+info: { title: Enums, version: "1.0" }
+paths: {}
+components:
+  schemas:
+    Status:
+      type: string
+      enum: [active, in-progress, 3d]
+      x-enum-descriptions: [Currently active, "", Rendered]
+    Priority:
+      type: integer
+      enum: [1, -1, 2]
+    Level:
+      type: integer
+      enum: [1, 2]
+      x-enum-varnames: [Low, High]
+"#;
+
+    fn declarations(style: EnumStyle) -> String {
+        let options = TypeScriptOptions {
+            enum_style: style,
+            ..Default::default()
+        };
+        let files = TypesGenerator::new(options).generate(&model(ENUMS));
+        let contents = &files[0].contents;
+        contents[contents.find(" */\n").unwrap() + 4..].to_string()
+    }
+
+    #[test]
+    fn test_enum_styles() {
+        assert_eq!(
+            declarations(EnumStyle::Union),
+            r#"
+export type Status = 'active' | 'in-progress' | '3d';
+
+export type Priority = 1 | -1 | 2;
+
+export type Level = 1 | 2;
+"#
+        );
+        assert_eq!(
+            declarations(EnumStyle::ConstObject),
+            r#"
+export type Status = typeof Status[keyof typeof Status];
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const Status = {
+  /**
+   * Currently active
+   */
+  active: 'active',
+  in_progress: 'in-progress',
+  /**
+   * Rendered
+   */
+  _3d: '3d',
+} as const;
+
+export type Priority = typeof Priority[keyof typeof Priority];
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const Priority = {
+  NUMBER_1: 1,
+  NUMBER_MINUS_1: -1,
+  NUMBER_2: 2,
+} as const;
+
+export type Level = typeof Level[keyof typeof Level];
+
+// eslint-disable-next-line @typescript-eslint/no-redeclare
+export const Level = {
+  Low: 1,
+  High: 2,
+} as const;
+"#
+        );
+        assert!(
+            declarations(EnumStyle::Enum)
+                .contains("export enum Level {\n  Low = 1,\n  High = 2,\n}\n")
+        );
+    }
+
+    const PETSTORE: &str = r##"
+openapi: 3.0.3
+info: { title: Petstore, version: "1.0" }
+paths:
+  /pets:
+    get:
+      operationId: listPets
+      summary: List all pets
+      parameters:
+        - { name: limit, in: query, schema: { type: integer } }
+        - name: tags
+          in: query
+          style: pipeDelimited
+          explode: false
+          schema: { type: array, items: { type: string } }
+        - { name: X-Request-Id, in: header, required: true, schema: { type: string } }
+      responses:
+        "200":
+          description: A list of pets
+          content:
+            application/json:
+              schema: { type: array, items: { $ref: "#/components/schemas/Pet" } }
+        default:
+          description: Error
+          content:
+            application/json:
+              schema: { $ref: "#/components/schemas/Error" }
+    post:
+      operationId: createPet
+      requestBody:
+        required: true
+        content:
+          application/json:
+            schema:
+              type: object
+              required: [name]
+              properties:
+                name: { type: string }
+      responses:
+        "201": { description: Created }
+  /pets/{petId}/photo:
+    put:
+      operationId: uploadPhoto
+      parameters:
+        - { name: petId, in: path, required: true, schema: { type: string } }
+      requestBody:
+        content:
+          multipart/form-data:
+            schema: { $ref: "#/components/schemas/Photo" }
+            encoding:
+              file: { contentType: image/png }
+      responses:
+        "204": { description: Uploaded }
+components:
+  schemas:
+    Pet:
+      type: object
+      properties:
+        name: { type: string }
+    Photo:
+      type: object
+      properties:
+        file: { type: string, format: binary }
+    Error:
+      type: object
+      properties:
+        message: { type: string }
+"##;
+
+    #[test]
+    fn test_fetch_generator() {
+        let files = FetchGenerator::default().generate(&model(PETSTORE));
+        assert_generated("typescript/fetch", &files);
+
+        // Several media types are asked for and told apart by `contentType`
+        let files = FetchGenerator::default().generate(&model(
+            r#"
+openapi: 3.0.3
+info: { title: Files, version: "1.0" }
+paths:
+  /files/{id}:
+    get:
+      operationId: getFile
+      parameters:
+        - { name: id, in: path, required: true, schema: { type: string } }
+      responses:
+        "200":
+          description: The file
+          content:
+            application/json: { schema: { type: array, items: { type: string } } }
+            text/csv: { schema: { type: string } }
+            application/pdf: { schema: { type: string, format: binary } }
+        "4XX":
+          description: Failure
+          content:
+            text/plain: { schema: { type: string } }
+        default:
+          description: Anything else
+"#,
+        ));
+        assert_generated("typescript/fetch-negotiation", &files);
+
+        // Form bodies are encoded by the style of each property's encoding
+        let files = FetchGenerator::default().generate(&model(
+            r#"
+openapi: 3.0.3
+info: { title: Forms, version: "1.0" }
+paths:
+  /login:
+    post:
+      operationId: login
+      requestBody:
+        required: true
+        content:
+          application/x-www-form-urlencoded:
+            schema:
+              type: object
+              properties:
+                scopes: { type: array, items: { type: string } }
+            encoding:
+              scopes: { style: spaceDelimited, explode: false }
+      responses:
+        "204": { description: Done }
+"#,
+        ));
+        assert_generated("typescript/fetch-form", &files);
+    }
+
+    #[test]
+    fn test_axios_generator() {
+        let files = AxiosGenerator::default().generate(&model(PETSTORE));
+        assert_generated("typescript/axios", &files);
+
+        let options = TypeScriptOptions {
+            mutator: Some(
+                Mutator::new("./custom-instance", "customInstance").with_request_options(),
+            ),
+            ..Default::default()
+        };
+        let files = AxiosGenerator::new(options).generate(&model(PETSTORE));
+        assert_generated("typescript/axios-mutator", &files);
+    }
+
+    #[test]
+    fn test_react_query_generator() {
+        let options = TypeScriptOptions {
+            query: QueryOptions {
+                infinite_query_param: Some("limit".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let files = QueryGenerator::new(QueryFramework::React, options).generate(&model(PETSTORE));
+        assert_generated("typescript/react-query", &files);
+    }
+
+    #[test]
+    fn test_query_frameworks() {
+        for (framework, case) in [
+            (QueryFramework::Vue, "typescript/vue-query"),
+            (QueryFramework::Svelte, "typescript/svelte-query"),
+            (QueryFramework::Solid, "typescript/solid-query"),
+        ] {
+            let files = QueryGenerator::new(framework, TypeScriptOptions::default())
+                .generate(&model(PETSTORE));
+            assert_generated(case, &files);
+        }
+    }
+
+    #[test]
+    fn test_swr_generator() {
+        let files = SwrGenerator::default().generate(&model(PETSTORE));
+        assert_generated("typescript/swr", &files);
+    }
+
+    #[test]
+    fn test_angular_generator() {
+        let files = AngularGenerator::default().generate(&model(PETSTORE));
+        assert_generated("typescript/angular", &files);
+
+        let tagged = model(
+            r#"
+openapi: 3.0.0
+info:
+  title: Tags
+  version: "1.0"
+paths:
+  /users/me:
+    get:
+      operationId: getMe
+      tags: [user accounts, admin]
+      responses:
+        "200":
+          description: OK
+          content:
+            text/plain:
+              schema:
+                type: string
+            application/json:
+              schema:
+                type: string
+        "2XX":
+          description: Accepted
+        default:
+          description: Failure
+          content:
+            application/json:
+              schema:
+                type: object
+                properties:
+                  message:
+                    type: string
+"#,
+        );
+        let files = AngularGenerator::default().generate(&tagged);
+        assert_generated("typescript/angular-tags", &files);
+    }
+
+    #[test]
+    fn test_zod_generator() {
+        let spec = r##"
+openapi: 3.0.0
+info:
+  title: Zoo
+  version: "1.0"
+paths:
+  /animals/{id}:
+    get:
+      operationId: getAnimal
+      parameters:
+        - name: id
+          in: path
+          required: true
+          schema:
+            type: string
+            format: uuid
+        - name: depth
+          in: query
+          schema:
+            type: integer
+            minimum: 0
+            maximum: 10
+      responses:
+        "200":
+          description: OK
+          content:
+            application/json:
+              schema:
+                $ref: "#/components/schemas/Animal"
+components:
+  schemas:
+    Animal:
+      oneOf:
+        - $ref: "#/components/schemas/Cat"
+        - $ref: "#/components/schemas/Dog"
+      discriminator:
+        propertyName: kind
+        mapping:
+          cat: "#/components/schemas/Cat"
+          dog: "#/components/schemas/Dog"
+    Cat:
+      type: object
+      required: [kind, name]
+      properties:
+        kind:
+          type: string
+          enum: [cat]
+        name:
+          type: string
+          minLength: 1
+          maxLength: 50
+          pattern: "^[A-Z]"
+        email:
+          type: string
+          format: email
+          nullable: true
+    Dog:
+      type: object
+      required: [kind]
+      properties:
+        kind:
+          type: string
+          enum: [dog]
+        tags:
+          type: array
+          maxItems: 3
+          items:
+            type: string
+            enum: [good, loud]
+    Node:
+      type: object
+      properties:
+        children:
+          type: array
+          items:
+            $ref: "#/components/schemas/Node"
+"##;
+        let files = ZodGenerator::default().generate(&model(spec));
+        assert_generated("typescript/zod", &files);
+
+        // Variants whose discriminator is a plain string are pinned to their
+        // mapping values, recursive ones through an intersection
+        let files = ZodGenerator::default().generate(&model(
+            r##"
+openapi: 3.0.0
+info: { title: Shapes, version: "1.0" }
+paths: {}
+components:
+  schemas:
+    Shape:
+      oneOf:
+        - $ref: "#/components/schemas/Circle"
+        - $ref: "#/components/schemas/Square"
+      discriminator:
+        propertyName: type
+        mapping:
+          round: "#/components/schemas/Circle"
+          disc: "#/components/schemas/Circle"
+    Circle:
+      type: object
+      required: [type]
+      properties:
+        type: { type: string }
+    Square:
+      type: object
+      required: [type]
+      properties:
+        type: { type: string }
+        inner: { $ref: "#/components/schemas/Tree" }
+    Tree:
+      oneOf:
+        - $ref: "#/components/schemas/Leaf"
+        - $ref: "#/components/schemas/Branch"
+      discriminator:
+        propertyName: type
+    Leaf:
+      type: object
+      properties:
+        type: { type: string }
+    Branch:
+      type: object
+      properties:
+        type: { type: string }
+        children: { type: array, items: { $ref: "#/components/schemas/Tree" } }
+"##,
+        ));
+        assert_generated("typescript/zod-discriminators", &files);
+
+        let files = ZodGenerator::default().generate(&model(PETSTORE));
+        assert_generated("typescript/zod-petstore", &files);
+    }
+
+    #[test]
+    fn test_msw_generator() {
+        let files = MswGenerator::default().generate(&model(PETSTORE));
+        assert_generated("typescript/msw", &files);
+
+        let spec = r##"
+openapi: 3.0.0
+info:
+  title: Tree
+  version: "1.0"
+paths: {}
+components:
+  schemas:
+    Node:
+      type: object
+      required: [id, kind, created]
+      properties:
+        id:
+          type: string
+          format: uuid
+        kind:
+          type: string
+          enum: [leaf, branch]
+        created:
+          type: string
+          format: date-time
+        weight:
+          type: integer
+          minimum: 0
+          exclusiveMaximum: true
+          maximum: 100
+        label:
+          type: string
+          nullable: true
+          maxLength: 5
+        owner:
+          type: string
+        children:
+          type: array
+          maxItems: 3
+          items:
+            $ref: "#/components/schemas/Node"
+"##;
+        let mut options = TypeScriptOptions::default();
+        options
+            .msw
+            .overrides
+            .insert("Node.owner".to_string(), "'admin'".to_string());
+        let files = MswGenerator::new(options).generate(&model(spec));
+        assert_generated("typescript/msw-overrides", &files);
+
+        // Object literals are returned in parentheses, parameters inside a
+        // segment become route parameters and union variants get their tag
+        let files = MswGenerator::default().generate(&model(
+            r##"
+openapi: 3.0.0
+info: { title: Zoo, version: "1.0" }
+paths:
+  /session:
+    post:
+      operationId: login
+      responses:
+        "200":
+          description: OK
+          content:
+            application/json:
+              schema:
+                type: object
+                properties:
+                  token: { type: string, format: uuid }
+  /animals/{id}.json:
+    get:
+      operationId: getAnimal
+      parameters:
+        - { name: id, in: path, required: true, schema: { type: string } }
+      responses:
+        "200":
+          description: OK
+          content:
+            application/json:
+              schema: { $ref: "#/components/schemas/Animal" }
+components:
+  schemas:
+    Animal:
+      oneOf:
+        - $ref: "#/components/schemas/Cat"
+        - $ref: "#/components/schemas/Dog"
+      discriminator:
+        propertyName: kind
+        mapping:
+          cat: "#/components/schemas/Cat"
+    Cat:
+      type: object
+      properties:
+        kind: { type: string }
+    Dog:
+      type: object
+      properties:
+        kind: { type: string }
+"##,
+        ));
+        assert_generated("typescript/msw-routes", &files);
+    }
+
+    #[test]
+    fn test_server_generator() {
+        let files = ServerGenerator::default().generate(&model(PETSTORE));
+        assert_generated("typescript/hono", &files);
+        // Handler stubs are scaffolds
+        assert!(files[0].overwrite);
+        assert!(files[1..].iter().all(|file| !file.overwrite));
+
+        let options = TypeScriptOptions {
+            server: ServerOptions {
+                file: "src/app.ts".to_string(),
+                handlers_dir: "routes/pets".to_string(),
+            },
+            ..Default::default()
+        };
+        let files =
+            ServerGenerator::new(ServerFramework::Express, options).generate(&model(PETSTORE));
+        assert_generated("typescript/express", &files);
+
+        // deepObject parameters are assembled, form values are coerced and
+        // lists and JSON parts are normalized before validation
+        let files = ServerGenerator::default().generate(&model(
+            r##"
+openapi: 3.0.3
+info: { title: Forms, version: "1.0" }
+paths:
+  /trees:
+    get:
+      operationId: listTrees
+      parameters:
+        - name: filter
+          in: query
+          style: deepObject
+          explode: true
+          schema: { type: object, properties: { depth: { type: integer } } }
+      responses:
+        "204": { description: Done }
+  /upload:
+    post:
+      operationId: upload
+      requestBody:
+        required: true
+        content:
+          multipart/form-data:
+            schema:
+              type: object
+              properties:
+                files: { type: array, items: { type: string, format: binary } }
+                meta: { $ref: "#/components/schemas/Meta" }
+                labels: { type: array, items: { type: string } }
+                count: { type: integer }
+            encoding:
+              labels: { contentType: application/json }
+      responses:
+        "204": { description: Done }
+  /login:
+    post:
+      operationId: login
+      requestBody:
+        content:
+          application/x-www-form-urlencoded:
+            schema:
+              type: object
+              properties:
+                scopes: { type: array, items: { type: string } }
+                ids: { type: array, items: { type: integer } }
+                filter: { type: object, properties: { depth: { type: integer } } }
+            encoding:
+              scopes: { style: spaceDelimited, explode: false }
+              ids: { style: pipeDelimited }
+              filter: { style: deepObject, explode: true }
+      responses:
+        "204": { description: Done }
+components:
+  schemas:
+    Meta: { type: object, properties: { title: { type: string } } }
+"##,
+        ));
+        assert_generated("typescript/hono-forms", &files);
+    }
+
+    #[test]
+    #[ignore = "needs tsc on the PATH"]
+    fn test_generated_client_compiles() {
+        let model = model(PETSTORE);
+        let check =
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("target/generated-check-ts");
+        let _ = std::fs::remove_dir_all(&check);
+        for file in TypesGenerator::default()
+            .generate(&model)
+            .into_iter()
+            .chain(FetchGenerator::default().generate(&model))
+        {
+            file.write(&check).unwrap();
+        }
+
+        let output = std::process::Command::new("tsc")
+            .args([
+                "--noEmit",
+                "--strict",
+                "--target",
+                "es2020",
+                "--lib",
+                "es2020,dom",
+                "client.ts",
+            ])
+            .current_dir(&check)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stdout)
+        );
+    }
+}
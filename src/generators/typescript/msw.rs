@@ -0,0 +1,604 @@
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
+use serde_json::Value;
+
+use crate::generators::typescript::client::{ClientRenderer, is_json};
+use crate::generators::typescript::types::{literal, property_key};
+use crate::generators::typescript::{MswOptions, TypeScriptOptions, header};
+use crate::generators::{CodeWriter, GeneratedFile, Generator};
+use crate::ir::{
+    ApiModel, Constraints, Content, Discriminator, ObjectDef, Operation, Primitive, Status,
+    TypeDef, TypeKind,
+};
+use crate::naming::{NameAllocator, to_pascal_case};
+
+/// Emits MSW request handlers returning faker-generated data
+///
+/// Every named type gets a `get{Name}Mock()` factory; object factories accept
+/// overrides for individual properties. Every operation gets a handler for its
+/// first successful response, and `handlers` lists them all for `setupWorker`
+/// or `setupServer`. See [`MswOptions`] for array lengths, the recursion limit
+/// and per-property overrides.
+#[derive(Debug, Clone, Default)]
+pub struct MswGenerator {
+    options: TypeScriptOptions,
+}
+
+impl MswGenerator {
+    pub fn new(options: TypeScriptOptions) -> Self {
+        MswGenerator { options }
+    }
+}
+
+impl Generator for MswGenerator {
+    fn name(&self) -> &str {
+        "typescript-msw"
+    }
+
+    fn generate(&self, model: &ApiModel) -> Vec<GeneratedFile> {
+        let client = ClientRenderer::new(model, &self.options);
+        let mocks = MockRenderer::new(model, &client, &self.options.msw);
+
+        let mut body = CodeWriter::default();
+        for (name, ty) in &model.types {
+            body.blank();
+            body.line(mocks.factory(name, ty));
+        }
+        let mut handlers = Vec::new();
+        for operation in &model.operations {
+            for code in mocks.handler(operation) {
+                body.blank();
+                body.line(code);
+            }
+            handlers.push(format!("{}()", mocks.names[&handler_key(operation)]));
+        }
+        body.blank();
+        body.block("export const handlers = [", "];", |w| {
+            for handler in &handlers {
+                w.line(format!("{},", handler));
+            }
+        });
+
+        let mut w = CodeWriter::default();
+        w.line(header(model));
+        w.blank();
+        w.line("import { faker } from '@faker-js/faker';");
+        w.line("import { HttpResponse, http } from 'msw';");
+        let types = model
+            .types
+            .keys()
+            .map(|name| TypeDef::new(TypeKind::Reference(name.clone())))
+            .collect::<Vec<_>>();
+        let responses = model
+            .operations
+            .iter()
+            .filter_map(|operation| response_content(operation).map(|(_, content)| &content.ty));
+        if let Some(import) = client.model_import(types.iter().chain(responses)) {
+            w.line(import);
+        }
+        w.blank();
+        w.line(body.finish().trim_start());
+        vec![GeneratedFile::new(
+            self.options.msw.file.clone(),
+            w.finish().trim_end().to_string() + "\n",
+        )]
+    }
+}
+
+fn factory_key(name: &str) -> String {
+    format!("type#{}", name)
+}
+
+fn response_key(operation: &Operation) -> String {
+    format!("response#{}", operation.name)
+}
+
+fn handler_key(operation: &Operation) -> String {
+    format!("handler#{}", operation.name)
+}
+
+/// The status and content of the response a handler returns
+fn response_content(operation: &Operation) -> Option<(u16, &Content)> {
+    let response = operation.responses.success.first()?;
+    let status = match response.status {
+        Status::Code(code) => code,
+        Status::Range(_) | Status::Default => 200,
+    };
+    let content = response
+        .contents
+        .iter()
+        .find(|content| is_json(&content.media_type))
+        .or_else(|| response.contents.first())?;
+    Some((status, content))
+}
+
+/// The depth of the value being generated, as seen by the generated code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Depth {
+    /// Inside a factory, which receives its `depth`
+    Parameter,
+    /// Inside a factory, below a value already guarded by the depth limit
+    Guarded,
+    /// At the top of a response mock
+    Root,
+}
+
+impl Depth {
+    fn next(&self) -> &'static str {
+        match self {
+            Depth::Parameter | Depth::Guarded => "depth + 1",
+            Depth::Root => "1",
+        }
+    }
+}
+
+/// Renders IR types as expressions producing fake data
+struct MockRenderer<'a> {
+    model: &'a ApiModel,
+    client: &'a ClientRenderer<'a>,
+    options: &'a MswOptions,
+    /// Function names keyed by [`factory_key`], [`response_key`] and
+    /// [`handler_key`]
+    names: IndexMap<String, String>,
+    recursive: HashSet<&'a str>,
+}
+
+impl<'a> MockRenderer<'a> {
+    fn new(model: &'a ApiModel, client: &'a ClientRenderer<'a>, options: &'a MswOptions) -> Self {
+        let mut names = NameAllocator::new();
+        for name in model.types.keys() {
+            names.request(
+                factory_key(name),
+                format!("get{}Mock", to_pascal_case(client.types.type_name(name))),
+            );
+        }
+        for operation in &model.operations {
+            let pascal = to_pascal_case(&operation.name);
+            names.request(
+                response_key(operation),
+                format!("get{}ResponseMock", pascal),
+            );
+            names.request(handler_key(operation), format!("get{}MockHandler", pascal));
+        }
+        MockRenderer {
+            model,
+            client,
+            options,
+            names: names.resolve(),
+            recursive: model
+                .types
+                .keys()
+                .map(String::as_str)
+                .filter(|name| model.is_recursive(name))
+                .collect(),
+        }
+    }
+
+    /// Whether the factory of a named type accepts property overrides
+    fn takes_overrides(&self, name: &str) -> bool {
+        self.model.type_def(name).is_some_and(|ty| {
+            !ty.nullable && matches!(ty.kind, TypeKind::Object(_) | TypeKind::Intersection(_))
+        })
+    }
+
+    /// Whether the factory of a named type takes the current depth, which is
+    /// the case whenever it calls other factories
+    fn takes_depth(&self, name: &str) -> bool {
+        self.model
+            .type_def(name)
+            .is_some_and(|ty| !ty.references().is_empty())
+    }
+
+    fn is_recursive(&self, ty: &TypeDef) -> bool {
+        ty.references()
+            .iter()
+            .any(|name| self.recursive.contains(name))
+    }
+
+    /// Guard a value that may recurse with the depth limit
+    fn limit(&self, ty: &TypeDef, depth: Depth, value: String, fallback: &str) -> String {
+        if depth == Depth::Parameter && self.is_recursive(ty) {
+            format!(
+                "depth < {} ? {} : {}",
+                self.options.max_depth, value, fallback
+            )
+        } else {
+            value
+        }
+    }
+
+    fn factory(&self, name: &str, ty: &TypeDef) -> String {
+        let type_name = self.client.types.type_name(name);
+        let mut parameters = Vec::new();
+        if self.takes_overrides(name) {
+            parameters.push(format!("overrides: Partial<{}> = {{}}", type_name));
+        }
+        if self.takes_depth(name) {
+            parameters.push("depth = 0".to_string());
+        }
+        let value = match &ty.kind {
+            TypeKind::Object(object) if !ty.nullable => {
+                format!(
+                    "({})",
+                    self.object(object, Some(name), Depth::Parameter, true)
+                )
+            }
+            TypeKind::Intersection(parts) if !ty.nullable => {
+                let mut spread: Vec<String> = parts
+                    .iter()
+                    .map(|part| format!("...{}", self.render(part, Depth::Parameter)))
+                    .collect();
+                spread.push("...overrides".to_string());
+                format!("({{ {} }})", spread.join(", "))
+            }
+            _ => self.value(ty, Some(name), Depth::Parameter),
+        };
+        format!(
+            "export const {} = ({}): {} => {};",
+            self.names[&factory_key(name)],
+            parameters.join(", "),
+            type_name,
+            arrow_body(value)
+        )
+    }
+
+    /// The response mock and the handler of an operation
+    fn handler(&self, operation: &Operation) -> Vec<String> {
+        let route = literal(&format!("*{}", route_path(&operation.path)).into());
+        let handler_name = &self.names[&handler_key(operation)];
+
+        let mut code = Vec::new();
+        let mut w = CodeWriter::default();
+        match response_content(operation) {
+            Some((status, content)) => {
+                let ty = self.client.types.render(&content.ty);
+                let mock = &self.names[&response_key(operation)];
+                code.push(format!(
+                    "export const {} = (): {} => {};",
+                    mock,
+                    ty,
+                    arrow_body(self.render(&content.ty, Depth::Root))
+                ));
+                let data = format!("overrideResponse ?? {}()", mock);
+                let response = if is_json(&content.media_type) {
+                    format!("HttpResponse.json({}, {{ status: {} }})", data, status)
+                } else if content.media_type.starts_with("text/") {
+                    format!("HttpResponse.text({}, {{ status: {} }})", data, status)
+                } else {
+                    format!(
+                        "new HttpResponse({}, {{ status: {}, headers: {{ 'Content-Type': {} }} }})",
+                        data,
+                        status,
+                        literal(&content.media_type.as_str().into())
+                    )
+                };
+                w.block(
+                    format!(
+                        "export const {} = (overrideResponse?: {}) => {{",
+                        handler_name, ty
+                    ),
+                    "};",
+                    |w| {
+                        w.block(
+                            format!("return http.{}({}, () => {{", operation.method, route),
+                            "});",
+                            |w| {
+                                w.line(format!("return {};", response));
+                            },
+                        );
+                    },
+                );
+            }
+            None => {
+                let status = match operation.responses.success.first().map(|r| r.status) {
+                    Some(Status::Code(code)) => code,
+                    _ => 200,
+                };
+                w.block(
+                    format!("export const {} = () => {{", handler_name),
+                    "};",
+                    |w| {
+                        w.block(
+                            format!("return http.{}({}, () => {{", operation.method, route),
+                            "});",
+                            |w| {
+                                w.line(format!(
+                                    "return new HttpResponse(null, {{ status: {} }});",
+                                    status
+                                ));
+                            },
+                        );
+                    },
+                );
+            }
+        }
+        code.push(w.finish().trim_end().to_string());
+        code
+    }
+
+    /// A value for a type, picking `null` at random when it is nullable
+    fn render(&self, ty: &TypeDef, depth: Depth) -> String {
+        self.value(ty, None, depth)
+    }
+
+    fn value(&self, ty: &TypeDef, owner: Option<&str>, depth: Depth) -> String {
+        let value = self.value_kind(ty, owner, depth);
+        if ty.nullable && !matches!(ty.kind, TypeKind::Any) {
+            format!("faker.helpers.arrayElement([{}, null])", value)
+        } else {
+            value
+        }
+    }
+
+    fn value_kind(&self, ty: &TypeDef, owner: Option<&str>, depth: Depth) -> String {
+        match &ty.kind {
+            TypeKind::Primitive { primitive, format } => {
+                primitive_mock(*primitive, format.as_deref(), &ty.constraints)
+            }
+            TypeKind::Enum { values, .. } => enum_mock(values),
+            TypeKind::Array(items) => {
+                let (default_min, default_max) = self.options.array_length;
+                let min = ty.constraints.min_items.unwrap_or(default_min);
+                let max = ty.constraints.max_items.unwrap_or(default_max.max(min));
+                let value = format!(
+                    "Array.from({{ length: faker.number.int({{ min: {}, max: {} }}) }}, () => {})",
+                    min.min(max),
+                    max,
+                    arrow_body(self.render(items, depth))
+                );
+                if ty.constraints.min_items.unwrap_or(0) == 0 {
+                    self.limit(items, depth, value, "[]")
+                } else {
+                    value
+                }
+            }
+            TypeKind::Object(object) => self.object(object, owner, depth, false),
+            TypeKind::Union {
+                variants,
+                discriminator,
+                ..
+            } => {
+                let values: Vec<String> = variants
+                    .iter()
+                    .map(|variant| {
+                        let value = self.render(variant, depth);
+                        match discriminator.as_ref().and_then(|d| tag_mock(d, variant)) {
+                            Some(tag) => format!("{{ ...{}, {} }}", value, tag),
+                            None => value,
+                        }
+                    })
+                    .collect();
+                match values.len() {
+                    1 => values.into_iter().next().unwrap_or_default(),
+                    _ => format!("faker.helpers.arrayElement([{}])", values.join(", ")),
+                }
+            }
+            TypeKind::Intersection(parts) => {
+                let spread: Vec<String> = parts
+                    .iter()
+                    .map(|part| format!("...{}", self.render(part, depth)))
+                    .collect();
+                format!("{{ {} }}", spread.join(", "))
+            }
+            TypeKind::Reference(name) => {
+                let mut arguments = Vec::new();
+                if self.takes_depth(name) {
+                    if self.takes_overrides(name) {
+                        arguments.push("{}");
+                    }
+                    arguments.push(depth.next());
+                }
+                format!(
+                    "{}({})",
+                    self.names[&factory_key(name)],
+                    arguments.join(", ")
+                )
+            }
+            TypeKind::Any => "{}".to_string(),
+        }
+    }
+
+    /// An object literal; factories of named objects spread their overrides
+    fn object(
+        &self,
+        object: &ObjectDef,
+        owner: Option<&str>,
+        depth: Depth,
+        overrides: bool,
+    ) -> String {
+        if object.properties.is_empty() && !overrides {
+            return match &object.additional_properties {
+                Some(additional) => format!(
+                    "{{ [faker.string.alphanumeric(5)]: {} }}",
+                    self.render(additional, depth)
+                ),
+                None => "{}".to_string(),
+            };
+        }
+
+        let mut w = CodeWriter::default();
+        w.block("{", "}", |w| {
+            for property in &object.properties {
+                let configured = owner
+                    .and_then(|owner| {
+                        self.options
+                            .overrides
+                            .get(&format!("{}.{}", owner, property.name))
+                    })
+                    .or_else(|| self.options.overrides.get(&property.name));
+                let value = match configured {
+                    Some(value) => value.clone(),
+                    None if property.required => self.render(&property.ty, depth),
+                    None => {
+                        let inner = if depth == Depth::Parameter && self.is_recursive(&property.ty)
+                        {
+                            Depth::Guarded
+                        } else {
+                            depth
+                        };
+                        self.limit(
+                            &property.ty,
+                            depth,
+                            format!(
+                                "faker.helpers.arrayElement([{}, undefined])",
+                                self.render(&property.ty, inner)
+                            ),
+                            "undefined",
+                        )
+                    }
+                };
+                w.line(format!("{}: {},", property_key(&property.name), value));
+            }
+            if overrides {
+                w.line("...overrides,");
+            }
+        });
+        w.finish().trim_end().to_string()
+    }
+}
+
+/// An MSW route path, with `:name` for every `{name}` of the OpenAPI path,
+/// e.g. `/trees/:id.json` for `/trees/{id}.json`
+fn route_path(path: &str) -> String {
+    let mut route = String::new();
+    let mut rest = path;
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        route.push_str(&rest[..start]);
+        route.push(':');
+        route.push_str(&rest[start + 1..start + end]);
+        rest = &rest[start + end + 1..];
+    }
+    route.push_str(rest);
+    route
+}
+
+/// The body of an arrow function returning `value`, whose object literals
+/// would otherwise be read as a block
+fn arrow_body(value: String) -> String {
+    if value.starts_with('{') {
+        format!("({})", value)
+    } else {
+        value
+    }
+}
+
+/// The discriminator property of a union variant set to the values its
+/// mapping gives it, e.g. `kind: 'cat' as const`
+fn tag_mock(discriminator: &Discriminator, variant: &TypeDef) -> Option<String> {
+    let TypeKind::Reference(target) = &variant.kind else {
+        return None;
+    };
+    if variant.nullable {
+        return None;
+    }
+    let values: Vec<Value> = discriminator
+        .mapping
+        .iter()
+        .filter(|(_, name)| *name == target)
+        .map(|(value, _)| Value::from(value.as_str()))
+        .collect();
+    let value = match values.as_slice() {
+        [] => return None,
+        [value] => format!("{} as const", literal(value)),
+        values => enum_mock(values),
+    };
+    Some(format!(
+        "{}: {}",
+        property_key(&discriminator.property_name),
+        value
+    ))
+}
+
+fn primitive_mock(primitive: Primitive, format: Option<&str>, constraints: &Constraints) -> String {
+    match primitive {
+        Primitive::String => string_mock(format, constraints),
+        Primitive::Integer => {
+            let mut options = Vec::new();
+            if let Some(minimum) = constraints.minimum {
+                let minimum = if constraints.exclusive_minimum {
+                    minimum.floor() + 1.0
+                } else {
+                    minimum.ceil()
+                };
+                options.push(format!("min: {}", minimum));
+            }
+            if let Some(maximum) = constraints.maximum {
+                let maximum = if constraints.exclusive_maximum {
+                    maximum.ceil() - 1.0
+                } else {
+                    maximum.floor()
+                };
+                options.push(format!("max: {}", maximum));
+            }
+            if let Some(multiple_of) = constraints.multiple_of {
+                options.push(format!("multipleOf: {}", multiple_of));
+            }
+            number_call("faker.number.int", &options)
+        }
+        Primitive::Number => {
+            let mut options = Vec::new();
+            if let Some(minimum) = constraints.minimum {
+                options.push(format!("min: {}", minimum));
+            }
+            if let Some(maximum) = constraints.maximum {
+                options.push(format!("max: {}", maximum));
+            }
+            number_call("faker.number.float", &options)
+        }
+        Primitive::Boolean => "faker.datatype.boolean()".to_string(),
+    }
+}
+
+fn number_call(function: &str, options: &[String]) -> String {
+    if options.is_empty() {
+        format!("{}()", function)
+    } else {
+        format!("{}({{ {} }})", function, options.join(", "))
+    }
+}
+
+fn string_mock(format: Option<&str>, constraints: &Constraints) -> String {
+    let value = match format {
+        Some("binary") => "new Blob([faker.string.alpha(10)])",
+        Some("date-time") => "faker.date.past().toISOString()",
+        Some("date") => "faker.date.past().toISOString().slice(0, 10)",
+        Some("email") => "faker.internet.email()",
+        Some("uuid") => "faker.string.uuid()",
+        Some("uri" | "url") => "faker.internet.url()",
+        Some("hostname") => "faker.internet.domainName()",
+        Some("ipv4") => "faker.internet.ipv4()",
+        Some("ipv6") => "faker.internet.ipv6()",
+        _ => "",
+    };
+    if !value.is_empty() {
+        return value.to_string();
+    }
+    if let Some(pattern) = &constraints.pattern {
+        return format!(
+            "faker.helpers.fromRegExp({})",
+            literal(&pattern.as_str().into())
+        );
+    }
+    let min = constraints.min_length.unwrap_or(10);
+    let max = constraints.max_length.unwrap_or(min.max(20));
+    format!(
+        "faker.string.alpha({{ length: {{ min: {}, max: {} }} }})",
+        min.min(max),
+        max
+    )
+}
+
+fn enum_mock(values: &[Value]) -> String {
+    if let [value] = values {
+        return literal(value);
+    }
+    let values: Vec<String> = values.iter().map(literal).collect();
+    format!(
+        "faker.helpers.arrayElement([{}] as const)",
+        values.join(", ")
+    )
+}
@@ -0,0 +1,89 @@
+/// The TanStack Query adapter hooks are generated for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum QueryFramework {
+    /// `@tanstack/react-query`
+    #[default]
+    React,
+    /// `@tanstack/vue-query`; parameters accept refs and keys are computed
+    Vue,
+    /// `@tanstack/svelte-query`; hooks are `create*` functions returning stores
+    Svelte,
+    /// `@tanstack/solid-query`; hooks take accessors and pass options as a
+    /// function
+    Solid,
+}
+
+/// The kinds of hooks generated for an operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum HookKind {
+    Query,
+    Suspense,
+    Infinite,
+    Mutation,
+}
+
+impl QueryFramework {
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            QueryFramework::React => "react",
+            QueryFramework::Vue => "vue",
+            QueryFramework::Svelte => "svelte",
+            QueryFramework::Solid => "solid",
+        }
+    }
+
+    pub(crate) fn package(&self) -> String {
+        format!("@tanstack/{}-query", self.name())
+    }
+
+    /// Prefix of generated hooks, `use` or `create`
+    pub(crate) fn hook_prefix(&self) -> &'static str {
+        match self {
+            QueryFramework::React | QueryFramework::Vue => "use",
+            QueryFramework::Svelte | QueryFramework::Solid => "create",
+        }
+    }
+
+    pub(crate) fn supports_suspense(&self) -> bool {
+        *self == QueryFramework::React
+    }
+
+    /// Whether the hooks attach the query key to the returned query
+    pub(crate) fn attaches_query_key(&self) -> bool {
+        matches!(self, QueryFramework::React | QueryFramework::Svelte)
+    }
+
+    /// The adapter's function for a kind of hook, e.g. `useQuery`
+    pub(crate) fn hook(&self, kind: HookKind) -> String {
+        let name = match kind {
+            HookKind::Query => "Query",
+            HookKind::Suspense => "SuspenseQuery",
+            HookKind::Infinite => "InfiniteQuery",
+            HookKind::Mutation => "Mutation",
+        };
+        format!("{}{}", self.hook_prefix(), name)
+    }
+
+    /// The type of the options of a kind of hook, e.g. `UseQueryOptions`
+    pub(crate) fn options_type(&self, kind: HookKind) -> String {
+        format!("{}Options", self.type_stem(kind))
+    }
+
+    /// The type returned by a kind of hook, e.g. `UseQueryResult`
+    pub(crate) fn result_type(&self, kind: HookKind) -> String {
+        let suffix = match self {
+            QueryFramework::Vue => "ReturnType",
+            _ => "Result",
+        };
+        format!("{}{}", self.type_stem(kind), suffix)
+    }
+
+    fn type_stem(&self, kind: HookKind) -> String {
+        let hook = self.hook(kind);
+        let mut chars = hook.chars();
+        chars
+            .next()
+            .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+            .unwrap_or_default()
+    }
+}
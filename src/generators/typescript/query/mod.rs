@@ -0,0 +1,640 @@
+//! TanStack Query hooks for React, Vue, Svelte and Solid
+//!
+//! Every adapter shares the same structure: a query key factory, a query
+//! options factory and a hook per `GET` operation, and a mutation options
+//! factory and a hook for every other operation. The [`QueryFramework`] decides
+//! the names of hooks and types and how parameters are made reactive.
+
+mod framework;
+
+use crate::generators::typescript::client::{
+    Argument, ClientRenderer, identifiers, optional_access, parameters, write_operation_jsdoc,
+};
+use crate::generators::typescript::request::{Transport, client_module};
+use crate::generators::typescript::types::{literal, property_key};
+use crate::generators::typescript::{QueryOptions, TypeScriptOptions};
+use crate::generators::{CodeWriter, GeneratedFile, Generator};
+use crate::ir::{ApiModel, Operation};
+use crate::naming::to_pascal_case;
+
+use framework::HookKind;
+
+// Re-export the public API
+pub use framework::QueryFramework;
+
+/// Emits TanStack Query hooks on top of generated request functions
+///
+/// `useSuspenseQuery` variants (React only) and `useInfiniteQuery` variants are
+/// added as configured in [`QueryOptions`].
+#[derive(Debug, Clone, Default)]
+pub struct QueryGenerator {
+    framework: QueryFramework,
+    options: TypeScriptOptions,
+}
+
+impl QueryGenerator {
+    pub fn new(framework: QueryFramework, options: TypeScriptOptions) -> Self {
+        QueryGenerator { framework, options }
+    }
+}
+
+impl Generator for QueryGenerator {
+    fn name(&self) -> &str {
+        match self.framework {
+            QueryFramework::React => "typescript-react-query",
+            QueryFramework::Vue => "typescript-vue-query",
+            QueryFramework::Svelte => "typescript-svelte-query",
+            QueryFramework::Solid => "typescript-solid-query",
+        }
+    }
+
+    fn generate(&self, model: &ApiModel) -> Vec<GeneratedFile> {
+        let transport = Transport::new(&self.options);
+        let framework = self.framework;
+        let query = &self.options.query;
+        let contents = client_module(
+            model,
+            &self.options,
+            transport,
+            |body| imports(framework, body),
+            |client, operation, _| {
+                let hooks = Hooks {
+                    client,
+                    operation,
+                    transport,
+                    framework,
+                };
+                if !is_query(operation) {
+                    return hooks.mutation();
+                }
+                let mut code = hooks.query(Variant::Query);
+                if query.suspense && framework.supports_suspense() {
+                    code.extend(hooks.query(Variant::Suspense));
+                }
+                if let Some(param) = infinite_param(operation, query) {
+                    code.extend(hooks.query(Variant::Infinite(param)));
+                }
+                code
+            },
+        );
+        vec![GeneratedFile::new(
+            self.options.client_file.clone(),
+            contents,
+        )]
+    }
+}
+
+fn is_query(operation: &Operation) -> bool {
+    operation.method == "get"
+}
+
+fn infinite_param<'a>(operation: &Operation, options: &'a QueryOptions) -> Option<&'a str> {
+    let param = options.infinite_query_param.as_deref()?;
+    operation
+        .query_params
+        .iter()
+        .any(|query| query.name == param)
+        .then_some(param)
+}
+
+/// Imports of everything the rendered hooks refer to
+fn imports(framework: QueryFramework, body: &str) -> Vec<String> {
+    let used = identifiers(body);
+    let kinds = [
+        HookKind::Query,
+        HookKind::Suspense,
+        HookKind::Infinite,
+        HookKind::Mutation,
+    ];
+    let values: Vec<String> = kinds.iter().map(|kind| framework.hook(*kind)).collect();
+    let mut types: Vec<String> = [
+        "InfiniteData",
+        "MutationFunction",
+        "QueryFunction",
+        "QueryKey",
+    ]
+    .iter()
+    .map(|name| name.to_string())
+    .collect();
+    for kind in kinds {
+        types.push(framework.options_type(kind));
+        types.push(framework.result_type(kind));
+    }
+
+    let mut imports = Vec::new();
+    let mut import = |names: Vec<String>, keyword: &str, module: &str| {
+        let mut names: Vec<String> = names
+            .into_iter()
+            .filter(|name| used.contains(name.as_str()))
+            .collect();
+        names.sort();
+        if !names.is_empty() {
+            imports.push(format!(
+                "import {}{{ {} }} from '{}';",
+                keyword,
+                names.join(", "),
+                module
+            ));
+        }
+    };
+    import(values, "", &framework.package());
+    import(types, "type ", &framework.package());
+    match framework {
+        QueryFramework::Vue => {
+            import(vec!["computed".into(), "unref".into()], "", "vue");
+            import(vec!["MaybeRef".into()], "type ", "vue");
+        }
+        QueryFramework::Solid => import(vec!["Accessor".into()], "type ", "solid-js"),
+        QueryFramework::React | QueryFramework::Svelte => {}
+    }
+    imports
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Variant<'a> {
+    Query,
+    Suspense,
+    /// Paginated by the named query parameter
+    Infinite(&'a str),
+}
+
+impl Variant<'_> {
+    fn suffix(&self) -> &'static str {
+        match self {
+            Variant::Query => "",
+            Variant::Suspense => "Suspense",
+            Variant::Infinite(_) => "Infinite",
+        }
+    }
+
+    fn kind(&self) -> HookKind {
+        match self {
+            Variant::Query => HookKind::Query,
+            Variant::Suspense => HookKind::Suspense,
+            Variant::Infinite(_) => HookKind::Infinite,
+        }
+    }
+}
+
+/// The hooks of a single operation
+struct Hooks<'a> {
+    client: &'a ClientRenderer<'a>,
+    operation: &'a Operation,
+    transport: Transport<'a>,
+    framework: QueryFramework,
+}
+
+impl Hooks<'_> {
+    fn pascal(&self) -> String {
+        to_pascal_case(&self.operation.name)
+    }
+
+    /// The request function's arguments without its trailing options
+    fn arguments(&self) -> Vec<Argument> {
+        self.client.arguments(self.operation)
+    }
+
+    fn names(&self) -> Vec<String> {
+        self.arguments().into_iter().map(|a| a.name).collect()
+    }
+
+    /// Arguments of the factories; Vue accepts refs
+    fn factory_arguments(&self, arguments: Vec<Argument>) -> Vec<Argument> {
+        arguments
+            .into_iter()
+            .map(|mut argument| {
+                if self.framework == QueryFramework::Vue {
+                    argument.ty = format!("MaybeRef<{}>", argument.ty);
+                }
+                argument
+            })
+            .collect()
+    }
+
+    /// Arguments of the query hooks; Vue accepts refs and Solid accessors
+    fn hook_arguments(&self) -> Vec<Argument> {
+        self.arguments()
+            .into_iter()
+            .map(|mut argument| {
+                argument.ty = match self.framework {
+                    QueryFramework::Vue => format!("MaybeRef<{}>", argument.ty),
+                    QueryFramework::Solid if argument.optional => {
+                        format!("Accessor<{} | undefined>", argument.ty)
+                    }
+                    QueryFramework::Solid => format!("Accessor<{}>", argument.ty),
+                    QueryFramework::React | QueryFramework::Svelte => argument.ty,
+                };
+                argument
+            })
+            .collect()
+    }
+
+    /// The current value of a factory argument
+    fn value(&self, name: &str) -> String {
+        match self.framework {
+            QueryFramework::Vue => format!("unref({})", name),
+            _ => name.to_string(),
+        }
+    }
+
+    /// Wrap an expression the framework should re-evaluate reactively
+    fn reactive(&self, expression: String) -> String {
+        match self.framework {
+            QueryFramework::Vue => format!("computed(() => {})", expression),
+            _ => expression,
+        }
+    }
+
+    /// A call of the request function, `signal` being in scope when supported
+    fn request_call(&self, arguments: &[String], signal: bool) -> String {
+        let mut arguments = arguments.to_vec();
+        match (
+            self.transport.options_type(),
+            signal && self.transport.supports_signal(),
+        ) {
+            (Some(_), true) => arguments.push("{ signal, ...requestOptions }".to_string()),
+            (Some(_), false) => arguments.push("requestOptions".to_string()),
+            (None, _) => {}
+        }
+        format!(
+            "{}({})",
+            self.client.function_name(self.operation),
+            arguments.join(", ")
+        )
+    }
+
+    /// The `options` parameter type of the hooks and factories
+    fn options_type(&self, key: &str, hook_options: &str) -> String {
+        match self.transport.options_type() {
+            Some(request) => format!("{{ {}?: {}; request?: {} }}", key, hook_options, request),
+            None => format!("{{ {}?: {} }}", key, hook_options),
+        }
+    }
+
+    fn destructure_options(&self, key: &str, w: &mut CodeWriter) {
+        if self.transport.options_type().is_some() {
+            w.line(format!(
+                "const {{ {0}: {0}Options, request: requestOptions }} = options ?? {{}};",
+                key
+            ));
+        } else {
+            w.line(format!(
+                "const {{ {0}: {0}Options }} = options ?? {{}};",
+                key
+            ));
+        }
+    }
+
+    /// `& { queryKey: QueryKey }`, except for Vue whose keys are computed refs
+    fn with_query_key(&self, ty: String) -> String {
+        if self.framework != QueryFramework::Vue {
+            format!("{} & {{ queryKey: QueryKey }}", ty)
+        } else {
+            ty
+        }
+    }
+
+    fn query(&self, variant: Variant) -> Vec<String> {
+        let pascal = self.pascal();
+        let result = self.client.local_name(self.operation, "QueryResult");
+        let mut code = Vec::new();
+
+        let key_arguments: Vec<Argument> = self
+            .arguments()
+            .into_iter()
+            .filter(|argument| argument.name != "body" && argument.name != "headers")
+            .map(|mut argument| {
+                argument.optional |= argument.name == "params";
+                argument
+            })
+            .collect();
+        let key_names: Vec<String> = key_arguments.iter().map(|a| a.name.clone()).collect();
+        let key_call = format!("get{}QueryKey({})", pascal, key_names.join(", "));
+
+        if variant == Variant::Query {
+            code.push(format!(
+                "export type {} = NonNullable<Awaited<ReturnType<typeof {}>>>;",
+                result,
+                self.client.function_name(self.operation)
+            ));
+
+            let mut w = CodeWriter::default();
+            w.block(
+                format!(
+                    "export const get{}QueryKey = ({}) => {{",
+                    pascal,
+                    parameters(&self.factory_arguments(key_arguments))
+                ),
+                "};",
+                |w| {
+                    let mut parts = vec![self.key_path()];
+                    if key_names.iter().any(|name| name == "params") {
+                        let params = self.value("params");
+                        parts.push(format!("...({0} ? [{0}] : [])", params));
+                    }
+                    w.line(format!("return [{}] as const;", parts.join(", ")));
+                },
+            );
+            code.push(w.finish().trim_end().to_string());
+        }
+
+        let kind = variant.kind();
+        let (type_params, hook_options) = match variant {
+            Variant::Infinite(param) => {
+                let page = self.page_param_type(param);
+                (
+                    format!(
+                        "<TData = InfiniteData<{}, {}>, TError = unknown>",
+                        result, page
+                    ),
+                    format!(
+                        "{}<{}, TError, TData, QueryKey, {}>",
+                        self.framework.options_type(kind),
+                        result,
+                        page
+                    ),
+                )
+            }
+            Variant::Query | Variant::Suspense => (
+                format!("<TData = {}, TError = unknown>", result),
+                format!(
+                    "{}<{}, TError, TData>",
+                    self.framework.options_type(kind),
+                    result
+                ),
+            ),
+        };
+        let options_type = self.options_type("query", &format!("Partial<{}>", hook_options));
+        let options = Argument::new("options", &options_type, true);
+        let mut arguments = self.factory_arguments(self.arguments());
+        arguments.push(options.clone());
+        let values: Vec<String> = self.names().iter().map(|name| self.value(name)).collect();
+        let factory = format!("get{}{}QueryOptions", pascal, variant.suffix());
+
+        let mut w = CodeWriter::default();
+        w.block(
+            format!(
+                "export const {} = {}({}) => {{",
+                factory,
+                type_params,
+                parameters(&arguments)
+            ),
+            "};",
+            |w| {
+                self.destructure_options("query", w);
+                match variant {
+                    Variant::Infinite(param) => {
+                        w.line(format!(
+                            "const queryKey = queryOptions?.queryKey ?? {};",
+                            self.reactive(format!("['infinite', ...{}]", key_call))
+                        ));
+                        let page = self.page_param_type(param);
+                        let params = self.value("params");
+                        let paged: Vec<String> = values
+                            .iter()
+                            .map(|value| {
+                                if *value == params {
+                                    format!(
+                                        "{{ ...{}, {}: pageParam ?? {} }}",
+                                        params,
+                                        property_key(param),
+                                        optional_access(&params, param)
+                                    )
+                                } else {
+                                    value.clone()
+                                }
+                            })
+                            .collect();
+                        w.line(format!(
+                            "const queryFn: QueryFunction<{}, QueryKey, {}> = ({{ signal, pageParam }}) => {};",
+                            result,
+                            page,
+                            self.request_call(&paged, true)
+                        ));
+                    }
+                    Variant::Query | Variant::Suspense => {
+                        w.line(format!(
+                            "const queryKey = queryOptions?.queryKey ?? {};",
+                            self.reactive(key_call.clone())
+                        ));
+                        w.line(format!(
+                            "const queryFn: QueryFunction<{}> = ({{ signal }}) => {};",
+                            result,
+                            self.request_call(&values, true)
+                        ));
+                    }
+                }
+                let enabled = match (self.enabled(), variant) {
+                    (Some(enabled), Variant::Query | Variant::Infinite(_)) => {
+                        format!(" enabled: {},", enabled)
+                    }
+                    _ => String::new(),
+                };
+                w.line(format!(
+                    "return {{ queryKey, queryFn,{} ...queryOptions }} as {};",
+                    enabled,
+                    self.with_query_key(hook_options.clone())
+                ));
+            },
+        );
+        code.push(w.finish().trim_end().to_string());
+
+        let mut hook_arguments = self.hook_arguments();
+        hook_arguments.push(options);
+        let result_type = format!("{}<TData, TError>", self.framework.result_type(kind));
+        let result_type = if self.framework.attaches_query_key() {
+            self.with_query_key(result_type)
+        } else {
+            result_type
+        };
+        let mut w = CodeWriter::default();
+        write_operation_jsdoc(&mut w, self.operation);
+        w.block(
+            format!(
+                "export const {}{}{} = {}({}): {} => {{",
+                self.framework.hook_prefix(),
+                pascal,
+                variant.suffix(),
+                type_params,
+                parameters(&hook_arguments),
+                result_type
+            ),
+            "};",
+            |w| {
+                let hook = self.framework.hook(kind);
+                match self.framework {
+                    QueryFramework::React | QueryFramework::Svelte => {
+                        let mut names = self.names();
+                        names.push("options".to_string());
+                        w.line(format!(
+                            "const queryOptions = {}({});",
+                            factory,
+                            names.join(", ")
+                        ));
+                        w.line(format!(
+                            "const query = {}(queryOptions) as {};",
+                            hook, result_type
+                        ));
+                        w.line("query.queryKey = queryOptions.queryKey;");
+                        w.line("return query;");
+                    }
+                    QueryFramework::Vue => {
+                        let mut names = self.names();
+                        names.push("options".to_string());
+                        w.line(format!(
+                            "return {}({}({})) as {};",
+                            hook,
+                            factory,
+                            names.join(", "),
+                            result_type
+                        ));
+                    }
+                    QueryFramework::Solid => {
+                        let mut values: Vec<String> = self
+                            .arguments()
+                            .iter()
+                            .map(|argument| {
+                                if argument.optional {
+                                    format!("{}?.()", argument.name)
+                                } else {
+                                    format!("{}()", argument.name)
+                                }
+                            })
+                            .collect();
+                        values.push("options".to_string());
+                        w.line(format!(
+                            "return {}(() => {}({})) as {};",
+                            hook,
+                            factory,
+                            values.join(", "),
+                            result_type
+                        ));
+                    }
+                }
+            },
+        );
+        code.push(w.finish().trim_end().to_string());
+        code
+    }
+
+    /// The type of the page parameter, e.g. `ListPetsParams['cursor']`
+    fn page_param_type(&self, param: &str) -> String {
+        format!(
+            "{}[{}]",
+            self.client.params_type_name(self.operation),
+            literal(&param.into())
+        )
+    }
+
+    /// The query key's path with path parameters interpolated
+    fn key_path(&self) -> String {
+        let mut path = self.operation.path.clone();
+        for (param, name) in self.client.path_arguments(self.operation) {
+            path = path.replace(
+                &format!("{{{}}}", param.name),
+                &format!("${{{}}}", self.value(&name)),
+            );
+        }
+        format!("`{}`", path)
+    }
+
+    /// Queries wait for their path parameters
+    fn enabled(&self) -> Option<String> {
+        let values: Vec<String> = self
+            .client
+            .path_arguments(self.operation)
+            .into_iter()
+            .map(|(_, name)| self.value(&name))
+            .collect();
+        (!values.is_empty()).then(|| self.reactive(format!("!!({})", values.join(" && "))))
+    }
+
+    fn mutation(&self) -> Vec<String> {
+        let pascal = self.pascal();
+        let result = self.client.local_name(self.operation, "MutationResult");
+        let arguments = self.arguments();
+        let names = self.names();
+        let variables = if arguments.is_empty() {
+            "void".to_string()
+        } else {
+            let fields: Vec<String> = arguments
+                .iter()
+                .map(|argument| {
+                    format!(
+                        "{}{}: {}",
+                        argument.name,
+                        if argument.optional { "?" } else { "" },
+                        argument.ty
+                    )
+                })
+                .collect();
+            format!("{{ {} }}", fields.join("; "))
+        };
+        let mutation_options = format!(
+            "{}<{}, TError, {}, TContext>",
+            self.framework.options_type(HookKind::Mutation),
+            result,
+            variables
+        );
+        let options_type = self.options_type("mutation", &mutation_options);
+        let parameter = parameters(&[Argument::new("options", &options_type, true)]);
+
+        let mut code = vec![format!(
+            "export type {} = NonNullable<Awaited<ReturnType<typeof {}>>>;",
+            result,
+            self.client.function_name(self.operation)
+        )];
+
+        let mut w = CodeWriter::default();
+        w.block(
+            format!(
+                "export const get{}MutationOptions = <TError = unknown, TContext = unknown>({}): {} => {{",
+                pascal, parameter, mutation_options
+            ),
+            "};",
+            |w| {
+                self.destructure_options("mutation", w);
+                let props = if names.is_empty() {
+                    "()".to_string()
+                } else {
+                    format!("({{ {} }})", names.join(", "))
+                };
+                w.line(format!(
+                    "const mutationFn: MutationFunction<{}, {}> = {} => {};",
+                    result,
+                    variables,
+                    props,
+                    self.request_call(&names, false)
+                ));
+                w.line("return { mutationFn, ...mutationOptions };");
+            },
+        );
+        code.push(w.finish().trim_end().to_string());
+
+        let hook = self.framework.hook(HookKind::Mutation);
+        let factory = format!("get{}MutationOptions(options)", pascal);
+        let mut w = CodeWriter::default();
+        write_operation_jsdoc(&mut w, self.operation);
+        w.block(
+            format!(
+                "export const {}{} = <TError = unknown, TContext = unknown>({}): {}<{}, TError, {}, TContext> => {{",
+                self.framework.hook_prefix(),
+                pascal,
+                parameter,
+                self.framework.result_type(HookKind::Mutation),
+                result,
+                variables
+            ),
+            "};",
+            |w| {
+                if self.framework == QueryFramework::Solid {
+                    w.line(format!("return {}(() => {});", hook, factory));
+                } else {
+                    w.line(format!("return {}({});", hook, factory));
+                }
+            },
+        );
+        code.push(w.finish().trim_end().to_string());
+        code
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::BTreeSet;
+
+use crate::generators::CodeWriter;
+use crate::generators::typescript::axios::axios_function;
+use crate::generators::typescript::client::{ClientRenderer, operation_types};
+use crate::generators::typescript::fetch::fetch_function;
+use crate::generators::typescript::runtime::{Helper, helpers_source};
+use crate::generators::typescript::{HttpClient, Mutator, TypeScriptOptions, header};
+use crate::ir::{ApiModel, Operation};
+
+/// How generated request functions perform the HTTP call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Transport<'a> {
+    Fetch,
+    Axios,
+    Mutator(&'a Mutator),
+}
+
+impl<'a> Transport<'a> {
+    /// The mutator when one is configured, otherwise the configured HTTP client
+    pub fn new(options: &'a TypeScriptOptions) -> Self {
+        match (&options.mutator, options.http_client) {
+            (Some(mutator), _) => Transport::Mutator(mutator),
+            (None, HttpClient::Fetch) => Transport::Fetch,
+            (None, HttpClient::Axios) => Transport::Axios,
+        }
+    }
+
+    pub fn imports(&self) -> Vec<String> {
+        match self {
+            Transport::Fetch => Vec::new(),
+            Transport::Axios => vec![
+                "import axios from 'axios';".to_string(),
+                "import type { AxiosRequestConfig, AxiosResponse } from 'axios';".to_string(),
+            ],
+            Transport::Mutator(mutator) => vec![format!(
+                "import {{ {} }} from '{}';",
+                mutator.name, mutator.path
+            )],
+        }
+    }
+
+    /// Type aliases the request functions refer to
+    pub fn declarations(&self) -> Vec<String> {
+        match self {
+            Transport::Mutator(mutator) if mutator.request_options => vec![
+                "// eslint-disable-next-line @typescript-eslint/no-explicit-any\n\
+                 type SecondParameter<T extends (...args: any) => any> = Parameters<T>[1];"
+                    .to_string(),
+            ],
+            _ => Vec::new(),
+        }
+    }
+
+    /// The type of the trailing `options` parameter of request functions
+    pub fn options_type(&self) -> Option<String> {
+        match self {
+            Transport::Fetch => Some("RequestInit".to_string()),
+            Transport::Axios => Some("AxiosRequestConfig".to_string()),
+            Transport::Mutator(mutator) if mutator.request_options => {
+                Some(format!("SecondParameter<typeof {}>", mutator.name))
+            }
+            Transport::Mutator(_) => None,
+        }
+    }
+
+    /// Whether the request options accept an `AbortSignal` as `signal`
+    pub fn supports_signal(&self) -> bool {
+        matches!(self, Transport::Fetch | Transport::Axios)
+    }
+
+    /// The request function of an operation
+    pub fn function(
+        &self,
+        client: &ClientRenderer,
+        operation: &Operation,
+        helpers: &mut BTreeSet<Helper>,
+    ) -> String {
+        match self {
+            Transport::Fetch => fetch_function(client, operation, helpers),
+            Transport::Axios => axios_function(client, operation, None, helpers),
+            Transport::Mutator(mutator) => {
+                axios_function(client, operation, Some(mutator), helpers)
+            }
+        }
+    }
+}
+
+/// A client module: for every operation its types, URL builder and request
+/// function, followed by whatever `extra` renders on top of them
+///
+/// `imports` receives the rendered operations and returns the imports they
+/// need, which come before those of the transport and the models.
+pub(crate) fn client_module(
+    model: &ApiModel,
+    options: &TypeScriptOptions,
+    transport: Transport,
+    imports: impl FnOnce(&str) -> Vec<String>,
+    mut extra: impl FnMut(&ClientRenderer, &Operation, &mut BTreeSet<Helper>) -> Vec<String>,
+) -> String {
+    let client = ClientRenderer::new(model, options);
+    let mut helpers = BTreeSet::new();
+
+    let mut body = CodeWriter::default();
+    for operation in &model.operations {
+        for declaration in client.declarations(operation) {
+            body.blank();
+            body.line(declaration);
+        }
+        body.blank();
+        body.line(client.url_function(operation, &mut helpers));
+        body.blank();
+        body.line(transport.function(&client, operation, &mut helpers));
+        for code in extra(&client, operation, &mut helpers) {
+            body.blank();
+            body.line(code);
+        }
+    }
+
+    let body = body.finish();
+    let mut w = CodeWriter::default();
+    w.line(header(model));
+    let imports: Vec<String> = imports(&body)
+        .into_iter()
+        .chain(transport.imports())
+        .chain(client.model_import(model.operations.iter().flat_map(operation_types)))
+        .collect();
+    if !imports.is_empty() {
+        w.blank();
+        w.line(imports.join("\n"));
+    }
+    for declaration in transport.declarations() {
+        w.blank();
+        w.line(declaration);
+    }
+    for helper in helpers_source(helpers) {
+        w.blank();
+        w.line(helper);
+    }
+    w.blank();
+    w.line(body);
+    w.finish().trim_end().to_string() + "\n"
+}
@@ -0,0 +1,240 @@
+//! Helper functions emitted into generated clients
+//!
+//! Clients include only the helpers they call, as private functions at the top
+//! of the file, so the output has no runtime dependency on rustval.
+
+/// A helper and the helpers it calls
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) enum Helper {
+    PathParam,
+    QueryParam,
+    Headers,
+    FormData,
+    UrlSearchParams,
+    ParseBody,
+    HttpParam,
+    HttpHeaders,
+    HttpResponse,
+}
+
+impl Helper {
+    fn dependencies(&self) -> &'static [Helper] {
+        match self {
+            Helper::UrlSearchParams => &[Helper::QueryParam],
+            _ => &[],
+        }
+    }
+
+    fn source(&self) -> &'static str {
+        match self {
+            Helper::PathParam => PATH_PARAM,
+            Helper::QueryParam => QUERY_PARAM,
+            Helper::Headers => HEADERS,
+            Helper::FormData => FORM_DATA,
+            Helper::UrlSearchParams => URL_SEARCH_PARAMS,
+            Helper::ParseBody => PARSE_BODY,
+            Helper::HttpParam => HTTP_PARAM,
+            Helper::HttpHeaders => HTTP_HEADERS,
+            Helper::HttpResponse => HTTP_RESPONSE,
+        }
+    }
+}
+
+/// The source of `helpers` and their dependencies, in a stable order
+pub(crate) fn helpers_source(helpers: impl IntoIterator<Item = Helper>) -> Vec<&'static str> {
+    let mut needed: Vec<Helper> = Vec::new();
+    for helper in helpers {
+        needed.push(helper);
+        needed.extend(helper.dependencies());
+    }
+    needed.sort();
+    needed.dedup();
+    needed.iter().map(Helper::source).collect()
+}
+
+const PATH_PARAM: &str = r#"const serializePathParam = (
+  name: string,
+  value: unknown,
+  style: 'simple' | 'label' | 'matrix',
+  explode: boolean,
+): string => {
+  const encode = (item: unknown) => encodeURIComponent(String(item));
+  let items: string[];
+  if (Array.isArray(value)) {
+    items = value.map(encode);
+  } else if (value !== null && typeof value === 'object') {
+    items = Object.entries(value)
+      .filter(([, item]) => item !== undefined)
+      .map(([key, item]) =>
+        explode ? `${encode(key)}=${encode(item)}` : `${encode(key)},${encode(item)}`,
+      );
+  } else {
+    items = [encode(value)];
+  }
+  const exploded = explode && (Array.isArray(value) || typeof value === 'object');
+  switch (style) {
+    case 'label':
+      return `.${items.join(exploded ? '.' : ',')}`;
+    case 'matrix':
+      if (exploded) {
+        return items
+          .map((item) => (Array.isArray(value) ? `;${name}=${item}` : `;${item}`))
+          .join('');
+      }
+      return `;${name}=${items.join(',')}`;
+    default:
+      return items.join(',');
+  }
+};"#;
+
+const QUERY_PARAM: &str = r#"const appendQueryParam = (
+  query: URLSearchParams,
+  name: string,
+  value: unknown,
+  style: 'form' | 'spaceDelimited' | 'pipeDelimited' | 'deepObject',
+  explode: boolean,
+): void => {
+  if (value === undefined) {
+    return;
+  }
+  if (Array.isArray(value)) {
+    if (explode) {
+      value.forEach((item) => query.append(name, String(item)));
+      return;
+    }
+    const separator = style === 'spaceDelimited' ? ' ' : style === 'pipeDelimited' ? '|' : ',';
+    query.append(name, value.map(String).join(separator));
+    return;
+  }
+  if (value !== null && typeof value === 'object') {
+    const entries = Object.entries(value).filter(([, item]) => item !== undefined);
+    if (style === 'deepObject') {
+      entries.forEach(([key, item]) => query.append(`${name}[${key}]`, String(item)));
+    } else if (explode) {
+      entries.forEach(([key, item]) => query.append(key, String(item)));
+    } else {
+      query.append(name, entries.flat().map(String).join(','));
+    }
+    return;
+  }
+  query.append(name, String(value));
+};"#;
+
+const HEADERS: &str = r#"const setHeaders = (target: Headers, values: object | undefined): void => {
+  Object.entries(values ?? {}).forEach(([name, value]) => {
+    if (value !== undefined) {
+      target.set(name, Array.isArray(value) ? value.join(',') : String(value));
+    }
+  });
+};"#;
+
+const FORM_DATA: &str = r#"const toFormData = (body: object, contentTypes: Record<string, string> = {}): FormData => {
+  const formData = new FormData();
+  Object.entries(body).forEach(([name, value]) => {
+    if (value === undefined) {
+      return;
+    }
+    const contentType = contentTypes[name];
+    // A JSON part carries the whole value, otherwise every item is a part
+    const items = Array.isArray(value) && !/json/i.test(contentType ?? '') ? value : [value];
+    items.forEach((item) => {
+      if (item instanceof Blob) {
+        formData.append(name, contentType && !item.type ? new Blob([item], { type: contentType }) : item);
+        return;
+      }
+      const text = item !== null && typeof item === 'object' ? JSON.stringify(item) : String(item);
+      formData.append(name, contentType ? new Blob([text], { type: contentType }) : text);
+    });
+  });
+  return formData;
+};"#;
+
+const URL_SEARCH_PARAMS: &str = r#"const toUrlSearchParams = (
+  body: object,
+  encoding: Record<string, { style: 'form' | 'spaceDelimited' | 'pipeDelimited' | 'deepObject'; explode: boolean }> = {},
+): URLSearchParams => {
+  const params = new URLSearchParams();
+  Object.entries(body).forEach(([name, value]) =>
+    appendQueryParam(params, name, value, encoding[name]?.style ?? 'form', encoding[name]?.explode ?? true),
+  );
+  return params;
+};"#;
+
+const PARSE_BODY: &str = r#"const parseBody = async (res: Response): Promise<unknown> => {
+  if ([204, 205, 304].includes(res.status)) {
+    return undefined;
+  }
+  const contentType = res.headers.get('Content-Type') ?? '';
+  if (/json/i.test(contentType)) {
+    const text = await res.text();
+    return text ? JSON.parse(text) : undefined;
+  }
+  if (contentType.startsWith('text/')) {
+    return res.text();
+  }
+  return res.blob();
+};"#;
+
+const HTTP_PARAM: &str = r#"const appendHttpParam = (
+  params: HttpParams,
+  name: string,
+  value: unknown,
+  style: 'form' | 'spaceDelimited' | 'pipeDelimited' | 'deepObject',
+  explode: boolean,
+): HttpParams => {
+  if (value === undefined) {
+    return params;
+  }
+  if (Array.isArray(value)) {
+    if (explode) {
+      return value.reduce((result: HttpParams, item) => result.append(name, String(item)), params);
+    }
+    const separator = style === 'spaceDelimited' ? ' ' : style === 'pipeDelimited' ? '|' : ',';
+    return params.append(name, value.map(String).join(separator));
+  }
+  if (value !== null && typeof value === 'object') {
+    const entries = Object.entries(value).filter(([, item]) => item !== undefined);
+    if (style === 'deepObject') {
+      return entries.reduce((result, [key, item]) => result.append(`${name}[${key}]`, String(item)), params);
+    }
+    if (explode) {
+      return entries.reduce((result, [key, item]) => result.append(key, String(item)), params);
+    }
+    return params.append(name, entries.flat().map(String).join(','));
+  }
+  return params.append(name, String(value));
+};"#;
+
+const HTTP_HEADERS: &str = r#"const toHttpHeaders = (values: object | undefined): HttpHeaders => {
+  return Object.entries(values ?? {}).reduce(
+    (headers, [name, value]) =>
+      value === undefined
+        ? headers
+        : headers.set(name, Array.isArray(value) ? value.join(',') : String(value)),
+    new HttpHeaders(),
+  );
+};"#;
+
+const HTTP_RESPONSE: &str = r#"const readHttpResponse = (
+  response: Observable<HttpResponse<Blob>>,
+): Observable<{ status: number; contentType?: string; data: unknown; headers: HttpHeaders }> =>
+  response.pipe(
+    catchError((error: unknown) =>
+      error instanceof HttpErrorResponse && error.status !== 0 ? of(error) : throwError(() => error),
+    ),
+    switchMap(async (res) => {
+      const body: unknown = res instanceof HttpErrorResponse ? res.error : res.body;
+      const contentType = res.headers.get('Content-Type')?.split(';')[0].trim().toLowerCase();
+      let data: unknown = undefined;
+      if (body instanceof Blob && body.size > 0 && ![204, 205, 304].includes(res.status)) {
+        if (/json/i.test(contentType ?? '')) {
+          data = JSON.parse(await body.text());
+        } else if (contentType?.startsWith('text/')) {
+          data = await body.text();
+        } else {
+          data = body;
+        }
+      }
+      return { status: res.status, contentType, data, headers: res.headers };
+    }),
+  );"#;
@@ -0,0 +1,838 @@
+use indexmap::IndexMap;
+
+use crate::generators::typescript::client::{
+    ClientRenderer, is_json, operation_types, write_operation_jsdoc,
+};
+use crate::generators::typescript::types::{literal, property_key};
+use crate::generators::typescript::zod::ZodRenderer;
+use crate::generators::typescript::{TypeScriptOptions, header};
+use crate::generators::{CodeWriter, GeneratedFile, Generator};
+use crate::ir::{
+    ApiModel, Content, Operation, Param, ParamLocation, ParamStyle, TypeDef, TypeKind,
+};
+use crate::naming::NameAllocator;
+
+/// The web framework server stubs are generated for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ServerFramework {
+    /// `hono`; handlers receive the Hono context as `c`
+    #[default]
+    Hono,
+    /// `express`; handlers receive `req` and `res`. Multipart bodies need a
+    /// parser such as `multer().any()` in front of the router
+    Express,
+}
+
+impl ServerFramework {
+    /// The framework-specific members of a handler context
+    fn context_members(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            ServerFramework::Hono => &[("c", "Context<ServerEnv>")],
+            ServerFramework::Express => &[("req", "Request"), ("res", "Response")],
+        }
+    }
+}
+
+/// Emits route registrations, request validation and handler stubs for a
+/// TypeScript server
+///
+/// The server file declares a `{Op}Context` with the validated `params`,
+/// `query`, `headers` and `body` of every operation, a `{Op}Handler` returning
+/// the status-keyed `{Op}Response`, and Zod schemas that coerce path, query and
+/// header strings before validating them. `deepObject` query parameters are
+/// assembled from their `name[key]` pairs; form bodies are decoded following
+/// their encoding, with a list for every array property, their JSON parts
+/// parsed and the other values coerced. Invalid requests are answered with
+/// `400` and the Zod issues. `createApp` (Hono) or `createRouter` (Express)
+/// registers every route. Each handler is imported from its own stub in
+/// [`ServerOptions::handlers_dir`](crate::generators::typescript::ServerOptions),
+/// which is a scaffold and never overwritten.
+#[derive(Debug, Clone, Default)]
+pub struct ServerGenerator {
+    framework: ServerFramework,
+    options: TypeScriptOptions,
+}
+
+impl ServerGenerator {
+    pub fn new(framework: ServerFramework, options: TypeScriptOptions) -> Self {
+        ServerGenerator { framework, options }
+    }
+}
+
+impl Generator for ServerGenerator {
+    fn name(&self) -> &str {
+        match self.framework {
+            ServerFramework::Hono => "typescript-hono",
+            ServerFramework::Express => "typescript-express",
+        }
+    }
+
+    fn generate(&self, model: &ApiModel) -> Vec<GeneratedFile> {
+        let client = ClientRenderer::new(model, &self.options);
+        let mut schemas = ZodRenderer::new(model, &client);
+        let options = &self.options.server;
+
+        let mut names = NameAllocator::new();
+        for reserved in RUNTIME_NAMES {
+            names.reserve(*reserved);
+        }
+        for operation in &model.operations {
+            let function = client.function_name(operation);
+            names.request(
+                format!("schemas#{}", operation.name),
+                format!("{}Schemas", function),
+            );
+            names.request(format!("handler#{}", operation.name), function);
+        }
+        let names = names.resolve();
+
+        let mut body = CodeWriter::default();
+        let mut runtime = Runtime::default();
+        schemas.write_components(&mut body);
+        for operation in &model.operations {
+            for declaration in client.declarations(operation) {
+                body.blank();
+                body.line(declaration);
+            }
+            body.blank();
+            body.line(self.context_declaration(&client, operation));
+            body.blank();
+            body.line(format!(
+                "export type {handler} = (context: {context}) => {response} | Promise<{response}>;",
+                handler = client.local_name(operation, "Handler"),
+                context = client.local_name(operation, "Context"),
+                response = client.response_type_name(operation),
+            ));
+            body.blank();
+            body.line(request_schemas(
+                &client,
+                &schemas,
+                operation,
+                &names[&format!("schemas#{}", operation.name)],
+                &mut runtime,
+            ));
+        }
+        body.blank();
+        self.write_routes(&mut body, &client, model, &names);
+
+        let mut w = CodeWriter::default();
+        w.line(header(model));
+        w.blank();
+        match self.framework {
+            ServerFramework::Hono => {
+                w.line("import { Hono } from 'hono';");
+                w.line("import type { Context, MiddlewareHandler } from 'hono';");
+                w.line("import type { ContentfulStatusCode, StatusCode } from 'hono/utils/http-status';");
+            }
+            ServerFramework::Express => {
+                w.line("import express from 'express';");
+                w.line("import type { NextFunction, Request, RequestHandler, Response, Router } from 'express';");
+            }
+        }
+        w.line("import { z } from 'zod';");
+        let recursive = schemas.recursive_types();
+        let types = model.operations.iter().flat_map(operation_types);
+        if let Some(import) = client.model_import(recursive.iter().chain(types)) {
+            w.line(import);
+        }
+        for operation in &model.operations {
+            let function = client.function_name(operation);
+            let local = &names[&format!("handler#{}", operation.name)];
+            let imported = if *local == function {
+                function.clone()
+            } else {
+                format!("{} as {}", function, local)
+            };
+            w.line(format!(
+                "import {{ {} }} from './{}/{}';",
+                imported,
+                options.handlers_dir.trim_end_matches('/'),
+                function
+            ));
+        }
+        w.blank();
+        w.line(runtime.source(self.framework));
+        w.line(body.finish().trim_start());
+
+        let mut files = vec![GeneratedFile::new(
+            options.file.clone(),
+            w.finish().trim_end().to_string() + "\n",
+        )];
+        files.extend(
+            model
+                .operations
+                .iter()
+                .map(|operation| self.handler_stub(&client, operation)),
+        );
+        files
+    }
+}
+
+impl ServerGenerator {
+    /// The interface handlers receive, e.g. `ListPetsContext`
+    fn context_declaration(&self, client: &ClientRenderer, operation: &Operation) -> String {
+        let mut w = CodeWriter::default();
+        let name = client.local_name(operation, "Context");
+        w.block(format!("export interface {} {{", name), "}", |w| {
+            let path = client.path_arguments(operation);
+            if !path.is_empty() {
+                let members: Vec<String> = path
+                    .iter()
+                    .map(|(param, name)| {
+                        format!("{}: {}", property_key(name), client.types.render(&param.ty))
+                    })
+                    .collect();
+                w.line(format!("params: {{ {} }};", members.join("; ")));
+            }
+            if !operation.query_params.is_empty() {
+                w.line(format!("query: {};", client.params_type_name(operation)));
+            }
+            if !operation.header_params.is_empty() {
+                w.line(format!("headers: {};", client.headers_type_name(operation)));
+            }
+            if let Some(ty) = client.body_type(operation) {
+                if operation.body.as_ref().is_some_and(|body| body.required) {
+                    w.line(format!("body: {};", ty));
+                } else {
+                    w.line(format!("body: {} | undefined;", ty));
+                }
+            }
+            for (member, ty) in self.framework.context_members() {
+                w.line(format!("{}: {};", member, ty));
+            }
+        });
+        w.finish().trim_end().to_string()
+    }
+
+    /// `createApp` or `createRouter`, registering a route per operation
+    fn write_routes(
+        &self,
+        w: &mut CodeWriter,
+        client: &ClientRenderer,
+        model: &ApiModel,
+        names: &IndexMap<String, String>,
+    ) {
+        let members: Vec<String> = self
+            .framework
+            .context_members()
+            .iter()
+            .map(|(member, _)| literal(&(*member).into()))
+            .collect();
+        let (open, setup, result) = match self.framework {
+            ServerFramework::Hono => (
+                "export function createApp(): Hono<ServerEnv> {",
+                "const app = new Hono<ServerEnv>();",
+                "app",
+            ),
+            ServerFramework::Express => (
+                "export function createRouter(): Router {",
+                "const router = express.Router();",
+                "router",
+            ),
+        };
+        w.block(open, "}", |w| {
+            w.line(setup);
+            if self.framework == ServerFramework::Express {
+                w.line(
+                    "router.use(express.json(), express.urlencoded({ extended: true }), express.text());",
+                );
+            }
+            for operation in &model.operations {
+                let route = format!(
+                    "{}.{}({}, validate({}), ",
+                    result,
+                    operation.method.to_lowercase(),
+                    literal(&route_path(client, operation).into()),
+                    names[&format!("schemas#{}", operation.name)]
+                );
+                let input = format!(
+                    "const input = {} as Omit<{}, {}>;",
+                    match self.framework {
+                        ServerFramework::Hono => "c.get('input')",
+                        ServerFramework::Express => "res.locals.input",
+                    },
+                    client.local_name(operation, "Context"),
+                    members.join(" | ")
+                );
+                let handler = &names[&format!("handler#{}", operation.name)];
+                match self.framework {
+                    ServerFramework::Hono => {
+                        w.block(format!("{}async (c) => {{", route), "});", |w| {
+                            w.line(input);
+                            w.line(format!("return send(c, await {}({{ ...input, c }}));", handler));
+                        });
+                    }
+                    ServerFramework::Express => {
+                        w.block(
+                            format!(
+                                "{}async (req: Request, res: Response, next: NextFunction) => {{",
+                                route
+                            ),
+                            "});",
+                            |w| {
+                                w.block("try {", "} catch (error) {", |w| {
+                                    w.line(input);
+                                    w.line(format!(
+                                        "await send(res, await {}({{ ...input, req, res }}));",
+                                        handler
+                                    ));
+                                });
+                                w.indent();
+                                w.line("next(error);");
+                                w.dedent();
+                                w.line("}");
+                            },
+                        );
+                    }
+                }
+            }
+            w.line(format!("return {};", result));
+        });
+    }
+
+    /// The stub a user implements an operation in
+    fn handler_stub(&self, client: &ClientRenderer, operation: &Operation) -> GeneratedFile {
+        let options = &self.options.server;
+        let handlers_dir = options.handlers_dir.trim_end_matches('/');
+        let server = options.file.rsplit('/').next().unwrap_or(&options.file);
+        let server = server.strip_suffix(".ts").unwrap_or(server);
+        let function = client.function_name(operation);
+
+        let mut w = CodeWriter::default();
+        w.line(
+            "// Scaffolded by rustval. This file is yours to edit; regeneration leaves it alone.",
+        );
+        w.blank();
+        w.line(format!(
+            "import type {{ {} }} from '{}{}';",
+            client.local_name(operation, "Handler"),
+            "../".repeat(handlers_dir.split('/').count()),
+            server
+        ));
+        w.blank();
+        write_operation_jsdoc(&mut w, operation);
+        w.block(
+            format!(
+                "export const {}: {} = async (_context) => {{",
+                function,
+                client.local_name(operation, "Handler")
+            ),
+            "};",
+            |w| {
+                w.line(format!(
+                    "throw new Error({});",
+                    literal(&format!("{} is not implemented", function).into())
+                ));
+            },
+        );
+
+        let path = match options.file.rsplit_once('/') {
+            Some((dir, _)) => format!("{}/{}/{}.ts", dir, handlers_dir, function),
+            None => format!("{}/{}.ts", handlers_dir, function),
+        };
+        GeneratedFile::scaffold(path, w.finish())
+    }
+}
+
+/// The route of an operation, e.g. `/pets/:petId`
+fn route_path(client: &ClientRenderer, operation: &Operation) -> String {
+    let mut path = operation.path.clone();
+    for (param, name) in client.path_arguments(operation) {
+        path = path.replace(&format!("{{{}}}", param.name), &format!(":{}", name));
+    }
+    path
+}
+
+/// The `{op}Schemas` constant validating an operation's request
+fn request_schemas(
+    client: &ClientRenderer,
+    schemas: &ZodRenderer,
+    operation: &Operation,
+    name: &str,
+    runtime: &mut Runtime,
+) -> String {
+    let mut w = CodeWriter::default();
+    w.block(format!("const {}: RequestSchemas = {{", name), "};", |w| {
+        let path = client.path_arguments(operation);
+        if !path.is_empty() {
+            w.block("params: z.object({", "}),", |w| {
+                for (param, name) in &path {
+                    w.line(format!(
+                        "{}: {},",
+                        property_key(name),
+                        schemas.render_coerced(&param.ty)
+                    ));
+                }
+            });
+        }
+        for (part, params) in [
+            ("query", &operation.query_params),
+            ("headers", &operation.header_params),
+        ] {
+            if params.is_empty() {
+                continue;
+            }
+            w.block(format!("{}: z.object({{", part), "}),", |w| {
+                for param in params {
+                    w.line(format!(
+                        "{}: {},",
+                        property_key(&param.name),
+                        param_schema(schemas, param, runtime)
+                    ));
+                }
+            });
+        }
+        let deep_objects: Vec<String> = operation
+            .query_params
+            .iter()
+            .filter(|param| param.style == ParamStyle::DeepObject)
+            .map(|param| literal(&param.name.as_str().into()))
+            .collect();
+        if !deep_objects.is_empty() {
+            w.line(format!("deepObjects: [{}],", deep_objects.join(", ")));
+        }
+        if let Some(content) = client.body_content(operation) {
+            let required = operation.body.as_ref().is_some_and(|body| body.required);
+            let (schema, form) =
+                form_body(schemas, content).unwrap_or_else(|| (schemas.render(&content.ty), None));
+            w.line(format!(
+                "body: {}{},",
+                schema,
+                if required { "" } else { ".optional()" }
+            ));
+            if let Some(form) = form {
+                w.line(format!("form: {},", form));
+            }
+        }
+    });
+    w.finish().trim_end().to_string()
+}
+
+/// The schema of a form body and the properties that arrive as lists or as
+/// JSON, e.g. `{ arrays: ['tags'], json: ['meta'] }`
+///
+/// Form values arrive as strings and are coerced like query parameters. A
+/// property holding a list may arrive as a single value when it has one item,
+/// or as one value joined by the delimiter of its encoding's style, and a
+/// `deepObject` property as `name[key]` fields. Multipart objects are sent as
+/// JSON text, as are the parts whose encoding has a JSON content type, which
+/// then carry the whole value. Objects in the `form` style are not decoded.
+fn form_body(schemas: &ZodRenderer, content: &Content) -> Option<(String, Option<String>)> {
+    let media_type = content.media_type.as_str();
+    let multipart = media_type.starts_with("multipart/form-data");
+    if !multipart && !media_type.starts_with("application/x-www-form-urlencoded") {
+        return None;
+    }
+    let is_object = |ty: &TypeDef| {
+        matches!(
+            schemas.resolve(ty).kind,
+            TypeKind::Object(_) | TypeKind::Intersection(_) | TypeKind::Any
+        )
+    };
+    let (mut arrays, mut json) = (Vec::new(), Vec::new());
+    let (mut delimited, mut deep_objects) = (Vec::new(), Vec::new());
+    let mut coerced = false;
+    for property in schemas.properties(&content.ty) {
+        let name = literal(&property.name.as_str().into());
+        let encoding = content.encoding.get(&property.name);
+        let whole = multipart
+            && encoding
+                .and_then(|encoding| encoding.content_type.as_deref())
+                .is_some_and(is_json);
+        let (array, items_json) = match &schemas.resolve(&property.ty).kind {
+            TypeKind::Array(items) => (!whole, multipart && is_object(items)),
+            _ => (false, multipart && is_object(&property.ty)),
+        };
+        if array {
+            arrays.push(name.clone());
+        }
+        if !multipart && let Some(encoding) = encoding {
+            let separator = match encoding.style {
+                Some(ParamStyle::SpaceDelimited) => Some(" "),
+                Some(ParamStyle::PipeDelimited) => Some("|"),
+                Some(ParamStyle::Form) | None if !encoding.explode => Some(","),
+                _ => None,
+            };
+            if let Some(separator) = separator.filter(|_| array) {
+                delimited.push(format!(
+                    "{}: {}",
+                    property_key(&property.name),
+                    literal(&separator.into())
+                ));
+            }
+            if encoding.style == Some(ParamStyle::DeepObject) {
+                deep_objects.push(name.clone());
+            }
+        }
+        if whole || items_json {
+            json.push(name);
+        } else {
+            coerced |= schemas.render_coerced(&property.ty) != schemas.render(&property.ty);
+        }
+    }
+    let schema = if coerced {
+        schemas.coerced_object(&content.ty, |property| {
+            let name = literal(&property.name.as_str().into());
+            if json.contains(&name) {
+                schemas.render(&property.ty)
+            } else {
+                schemas.render_coerced(&property.ty)
+            }
+        })
+    } else {
+        schemas.render(&content.ty)
+    };
+    let fields = (!arrays.is_empty() || !json.is_empty() || !deep_objects.is_empty()).then(|| {
+        let mut fields = format!(
+            "{{ arrays: [{}], json: [{}]",
+            arrays.join(", "),
+            json.join(", ")
+        );
+        if !delimited.is_empty() {
+            fields.push_str(&format!(", delimited: {{ {} }}", delimited.join(", ")));
+        }
+        if !deep_objects.is_empty() {
+            fields.push_str(&format!(", deepObjects: [{}]", deep_objects.join(", ")));
+        }
+        fields + " }"
+    });
+    Some((schema, fields))
+}
+
+/// The schema of a query or header parameter
+///
+/// Query values arrive as the list of every occurrence and headers as a
+/// single string, so scalars take the first value and delimited arrays are
+/// split before the values are coerced.
+fn param_schema(schemas: &ZodRenderer, param: &Param, runtime: &mut Runtime) -> String {
+    let mut schema = schemas.render_coerced(&param.ty);
+    if !param.required {
+        schema.push_str(".optional()");
+    }
+    let is_array = matches!(param.ty.kind, TypeKind::Array(_));
+    let header = param.location == ParamLocation::Header;
+    if is_array && (header || !param.explode) {
+        runtime.split = true;
+        let separator = match param.style {
+            ParamStyle::SpaceDelimited => " ",
+            ParamStyle::PipeDelimited => "|",
+            _ => ",",
+        };
+        format!(
+            "z.preprocess(split({}), {})",
+            literal(&separator.into()),
+            schema
+        )
+    } else if !is_array && !header {
+        runtime.first = true;
+        format!("z.preprocess(first, {})", schema)
+    } else {
+        schema
+    }
+}
+
+/// Names the runtime declares in the server file
+const RUNTIME_NAMES: &[&str] = &[
+    "z",
+    "Hono",
+    "Context",
+    "MiddlewareHandler",
+    "ContentfulStatusCode",
+    "StatusCode",
+    "express",
+    "NextFunction",
+    "Request",
+    "RequestHandler",
+    "Response",
+    "Router",
+    "ServerEnv",
+    "RequestSchemas",
+    "FormFields",
+    "RequestInput",
+    "RequestReader",
+    "ParseResult",
+    "first",
+    "split",
+    "deepObject",
+    "parseJson",
+    "readForm",
+    "parseRequest",
+    "validate",
+    "readBody",
+    "withFiles",
+    "send",
+    "createApp",
+    "createRouter",
+];
+
+/// Which optional helpers of the runtime the schemas use
+#[derive(Debug, Default)]
+struct Runtime {
+    first: bool,
+    split: bool,
+}
+
+impl Runtime {
+    fn source(&self, framework: ServerFramework) -> String {
+        let mut source = String::from(RUNTIME);
+        if self.first {
+            source.push_str(FIRST);
+        }
+        if self.split {
+            source.push_str(SPLIT);
+        }
+        source.push_str(match framework {
+            ServerFramework::Hono => HONO,
+            ServerFramework::Express => EXPRESS,
+        });
+        source
+    }
+}
+
+const RUNTIME: &str = r#"/** The parts of a request an operation validates */
+interface RequestSchemas {
+  params?: z.ZodTypeAny;
+  query?: z.AnyZodObject;
+  headers?: z.AnyZodObject;
+  body?: z.ZodTypeAny;
+  /** Query parameters in the `deepObject` style, sent as `name[key]=value` */
+  deepObjects?: string[];
+  form?: FormFields;
+}
+
+/** The properties of a form body that are lists, and those sent as JSON */
+interface FormFields {
+  arrays: string[];
+  json: string[];
+  /** Lists sent as one value joined by a delimiter */
+  delimited?: Record<string, string>;
+  /** Objects in the `deepObject` style, sent as `name[key]` fields */
+  deepObjects?: string[];
+}
+
+/** The validated parts of a request */
+interface RequestInput {
+  params?: unknown;
+  query?: unknown;
+  headers?: unknown;
+  body?: unknown;
+}
+
+/** Access to the raw parts of a request */
+interface RequestReader {
+  params: Record<string, string>;
+  query: (name: string) => string[] | undefined;
+  header: (name: string) => string | undefined;
+  /** Every query parameter with its values */
+  entries: () => [string, unknown][];
+  body: () => Promise<unknown>;
+}
+
+type ParseResult = { success: true; input: RequestInput } | { success: false; issues: z.ZodIssue[] };
+
+/** The `name[key]` query parameters of a `deepObject` parameter as an object */
+const deepObject = (name: string, entries: [string, unknown][]): Record<string, unknown> | undefined => {
+  const prefix = `${name}[`;
+  const pairs = entries
+    .filter(([key]) => key.startsWith(prefix) && key.endsWith(']'))
+    .map(([key, value]): [string, unknown] => [key.slice(prefix.length, -1), Array.isArray(value) ? value[0] : value]);
+  return pairs.length === 0 ? undefined : Object.fromEntries(pairs);
+};
+
+const parseJson = async (value: unknown): Promise<unknown> => {
+  const text = value instanceof Blob ? await value.text() : value;
+  if (typeof text !== 'string') {
+    return text;
+  }
+  try {
+    return JSON.parse(text);
+  } catch {
+    return text;
+  }
+};
+
+/** A form body with a list for every array property, its `deepObject` fields assembled and its JSON parts parsed */
+async function readForm(body: unknown, form: FormFields | undefined): Promise<unknown> {
+  if (form === undefined || body === null || typeof body !== 'object') {
+    return body;
+  }
+  const fields: Record<string, unknown> = { ...body };
+  for (const name of form.deepObjects ?? []) {
+    if (fields[name] === undefined) {
+      fields[name] = deepObject(name, Object.entries(fields));
+    }
+  }
+  for (const name of new Set([...form.arrays, ...form.json])) {
+    const value = fields[name];
+    if (value === undefined) {
+      continue;
+    }
+    let items = Array.isArray(value) ? value : [value];
+    const separator = form.delimited?.[name];
+    if (separator !== undefined) {
+      items = items.flatMap((item) => String(item).split(separator));
+    }
+    if (form.json.includes(name)) {
+      items = await Promise.all(items.map(parseJson));
+    }
+    fields[name] = form.arrays.includes(name) ? items : items[0];
+  }
+  return fields;
+}
+
+async function parseRequest(schemas: RequestSchemas, request: RequestReader): Promise<ParseResult> {
+  const issues: z.ZodIssue[] = [];
+  const parse = (part: string, schema: z.ZodTypeAny | undefined, value: unknown) => {
+    if (schema === undefined) {
+      return undefined;
+    }
+    const result = schema.safeParse(value);
+    if (result.success) {
+      return result.data;
+    }
+    issues.push(...result.error.issues.map((issue) => ({ ...issue, path: [part, ...issue.path] })));
+    return undefined;
+  };
+  const pick = (schema: z.AnyZodObject | undefined, read: (name: string) => unknown) =>
+    Object.fromEntries(Object.keys(schema?.shape ?? {}).map((name) => [name, read(name)]));
+  const query = (name: string) =>
+    schemas.deepObjects?.includes(name) ? deepObject(name, request.entries()) : request.query(name);
+  const body = schemas.body === undefined ? undefined : await readForm(await request.body(), schemas.form);
+  const input = {
+    params: parse('params', schemas.params, request.params),
+    query: parse('query', schemas.query, pick(schemas.query, query)),
+    headers: parse('headers', schemas.headers, pick(schemas.headers, request.header)),
+    body: parse('body', schemas.body, body),
+  };
+  return issues.length === 0 ? { success: true, input } : { success: false, issues };
+}
+"#;
+
+const FIRST: &str = r#"
+const first = (value: unknown) => (Array.isArray(value) ? value[0] : value);
+"#;
+
+const SPLIT: &str = r#"
+const split = (separator: string) => (value: unknown) =>
+  Array.isArray(value)
+    ? value.flatMap((item) => String(item).split(separator))
+    : typeof value === 'string'
+      ? value.split(separator)
+      : value;
+"#;
+
+const HONO: &str = r#"
+export type ServerEnv = { Variables: { input: RequestInput } };
+
+const validate =
+  (schemas: RequestSchemas): MiddlewareHandler<ServerEnv> =>
+  async (c, next) => {
+    const result = await parseRequest(schemas, {
+      params: c.req.param() as Record<string, string>,
+      query: (name) => c.req.queries(name),
+      header: (name) => c.req.header(name),
+      entries: () => Object.entries(c.req.queries()),
+      body: () => readBody(c),
+    });
+    if (!result.success) {
+      return c.json({ message: 'Invalid request', issues: result.issues }, 400);
+    }
+    c.set('input', result.input);
+    await next();
+  };
+
+async function readBody(c: Context<ServerEnv>): Promise<unknown> {
+  const type = c.req.header('Content-Type') ?? '';
+  if (type.startsWith('multipart/form-data') || type.startsWith('application/x-www-form-urlencoded')) {
+    return c.req.parseBody({ all: true });
+  }
+  if (type.startsWith('text/')) {
+    return c.req.text();
+  }
+  if (type !== '' && !/[/+]json\b/.test(type)) {
+    return c.req.blob();
+  }
+  const text = await c.req.text();
+  try {
+    return text === '' ? undefined : JSON.parse(text);
+  } catch {
+    return text;
+  }
+}
+
+function send(
+  c: Context<ServerEnv>,
+  response: { status: number; contentType?: string; data: unknown },
+): Response {
+  if (response.data === undefined) {
+    return c.body(null, response.status as StatusCode);
+  }
+  if (response.contentType !== undefined && !/json/i.test(response.contentType)) {
+    return new Response(response.data as BodyInit, {
+      status: response.status,
+      headers: { 'Content-Type': response.contentType },
+    });
+  }
+  return c.json(response.data, response.status as ContentfulStatusCode);
+}
+"#;
+
+const EXPRESS: &str = r#"
+const validate =
+  (schemas: RequestSchemas): RequestHandler =>
+  (req, res, next) => {
+    parseRequest(schemas, {
+      params: req.params,
+      query: (name) => {
+        const value = req.query[name];
+        return value === undefined ? undefined : ([] as unknown[]).concat(value).map(String);
+      },
+      header: (name) => req.get(name),
+      // The extended query parser nests `name[key]` parameters already
+      entries: () =>
+        Object.entries(req.query).flatMap(([key, value]) =>
+          value !== null && typeof value === 'object' && !Array.isArray(value)
+            ? Object.entries(value).map(([inner, item]): [string, unknown] => [`${key}[${inner}]`, item])
+            : [[key, value] as [string, unknown]],
+        ),
+      body: async () => (req.get('Content-Type') === undefined ? undefined : withFiles(req)),
+    }).then((result) => {
+      if (!result.success) {
+        res.status(400).json({ message: 'Invalid request', issues: result.issues });
+        return;
+      }
+      res.locals.input = result.input;
+      next();
+    }, next);
+  };
+
+/** The body of a request with the files `multer` parsed added as `Blob`s */
+function withFiles(req: Request): unknown {
+  const files = (req as Request & { files?: unknown }).files;
+  if (!Array.isArray(files)) {
+    return req.body;
+  }
+  const body: Record<string, unknown> = { ...req.body };
+  for (const file of files as { fieldname: string; buffer: Buffer; mimetype: string }[]) {
+    const blob = new Blob([new Uint8Array(file.buffer)], { type: file.mimetype });
+    const value = body[file.fieldname];
+    body[file.fieldname] = value === undefined ? blob : ([] as unknown[]).concat(value, blob);
+  }
+  return body;
+}
+
+async function send(
+  res: Response,
+  response: { status: number; contentType?: string; data: unknown },
+): Promise<void> {
+  if (response.data === undefined) {
+    res.status(response.status).end();
+  } else if (response.contentType !== undefined && !/json/i.test(response.contentType)) {
+    const data =
+      response.data instanceof Blob ? Buffer.from(await response.data.arrayBuffer()) : response.data;
+    res.status(response.status).type(response.contentType).send(data);
+  } else {
+    res.status(response.status).json(response.data);
+  }
+}
+"#;
@@ -0,0 +1,337 @@
+use crate::generators::typescript::TypeScriptOptions;
+use crate::generators::typescript::client::{
+    Argument, ClientRenderer, identifiers, parameters, write_operation_jsdoc,
+};
+use crate::generators::typescript::request::{Transport, client_module};
+use crate::generators::{CodeWriter, GeneratedFile, Generator};
+use crate::ir::{ApiModel, Operation};
+use crate::naming::to_pascal_case;
+
+/// Emits SWR hooks on top of generated request functions
+///
+/// `GET` operations get a key function and a `useSWR` hook that does not fetch
+/// while a required parameter is `undefined`; every other operation gets a
+/// fetcher factory and a `useSWRMutation` hook whose trigger takes the body.
+#[derive(Debug, Clone, Default)]
+pub struct SwrGenerator {
+    options: TypeScriptOptions,
+}
+
+impl SwrGenerator {
+    pub fn new(options: TypeScriptOptions) -> Self {
+        SwrGenerator { options }
+    }
+}
+
+impl Generator for SwrGenerator {
+    fn name(&self) -> &str {
+        "typescript-swr"
+    }
+
+    fn generate(&self, model: &ApiModel) -> Vec<GeneratedFile> {
+        let transport = Transport::new(&self.options);
+        let contents = client_module(
+            model,
+            &self.options,
+            transport,
+            imports,
+            |client, operation, _| {
+                let hooks = SwrHooks {
+                    client,
+                    operation,
+                    transport,
+                };
+                if operation.method == "get" {
+                    hooks.query()
+                } else {
+                    hooks.mutation()
+                }
+            },
+        );
+        vec![GeneratedFile::new(
+            self.options.client_file.clone(),
+            contents,
+        )]
+    }
+}
+
+fn imports(body: &str) -> Vec<String> {
+    let used = identifiers(body);
+    let mut imports = Vec::new();
+    if used.contains("useSWR") {
+        imports.push("import useSWR from 'swr';".to_string());
+    }
+    let types: Vec<&str> = ["Key", "SWRConfiguration"]
+        .into_iter()
+        .filter(|name| used.contains(name))
+        .collect();
+    if !types.is_empty() {
+        imports.push(format!(
+            "import type {{ {} }} from 'swr';",
+            types.join(", ")
+        ));
+    }
+    if used.contains("useSWRMutation") {
+        imports.push("import useSWRMutation from 'swr/mutation';".to_string());
+        imports.push("import type { SWRMutationConfiguration } from 'swr/mutation';".to_string());
+    }
+    imports
+}
+
+struct SwrHooks<'a> {
+    client: &'a ClientRenderer<'a>,
+    operation: &'a Operation,
+    transport: Transport<'a>,
+}
+
+impl SwrHooks<'_> {
+    fn pascal(&self) -> String {
+        to_pascal_case(&self.operation.name)
+    }
+
+    fn request_call(&self, arguments: &[String]) -> String {
+        let mut arguments = arguments.to_vec();
+        if self.transport.options_type().is_some() {
+            arguments.push("requestOptions".to_string());
+        }
+        format!(
+            "{}({})",
+            self.client.function_name(self.operation),
+            arguments.join(", ")
+        )
+    }
+
+    /// The `options` parameter of the hooks
+    fn options_argument(&self, swr: String) -> Argument {
+        let ty = match self.transport.options_type() {
+            Some(request) => format!("{{ swr?: {}; request?: {} }}", swr, request),
+            None => format!("{{ swr?: {} }}", swr),
+        };
+        Argument::new("options", ty, true)
+    }
+
+    fn destructure_options(&self, w: &mut CodeWriter) {
+        if self.transport.options_type().is_some() {
+            w.line("const { swr: swrOptions, request: requestOptions } = options ?? {};");
+        } else {
+            w.line("const { swr: swrOptions } = options ?? {};");
+        }
+    }
+
+    /// The key: the path with path parameters interpolated, then the query
+    fn key_function(&self, name: &str, arguments: &[Argument]) -> String {
+        let mut path = self.operation.path.clone();
+        for (param, name) in self.client.path_arguments(self.operation) {
+            path = path.replace(&format!("{{{}}}", param.name), &format!("${{{}}}", name));
+        }
+        let mut parts = vec![format!("`{}`", path)];
+        if arguments.iter().any(|argument| argument.name == "params") {
+            parts.push("...(params ? [params] : [])".to_string());
+        }
+        format!(
+            "export const {} = ({}) => [{}] as const;",
+            name,
+            parameters(arguments),
+            parts.join(", ")
+        )
+    }
+
+    fn query(&self) -> Vec<String> {
+        let pascal = self.pascal();
+        let result = self.client.local_name(self.operation, "QueryResult");
+        let arguments = self.client.arguments(self.operation);
+        // Required arguments may be `undefined` until they are known; nothing is
+        // fetched until then, so they are asserted where they are passed on
+        let defined = |argument: &Argument| {
+            if argument.optional {
+                argument.name.clone()
+            } else {
+                format!("{}!", argument.name)
+            }
+        };
+        let names: Vec<String> = arguments.iter().map(defined).collect();
+        let key_arguments: Vec<Argument> = arguments
+            .iter()
+            .filter(|argument| argument.name != "headers")
+            .cloned()
+            .map(|mut argument| {
+                argument.optional |= argument.name == "params";
+                argument
+            })
+            .collect();
+        let key_names: Vec<String> = arguments
+            .iter()
+            .filter(|argument| argument.name != "headers")
+            .map(defined)
+            .collect();
+
+        let mut code = vec![
+            self.key_function(&format!("get{}Key", pascal), &key_arguments),
+            format!(
+                "export type {} = NonNullable<Awaited<ReturnType<typeof {}>>>;",
+                result,
+                self.client.function_name(self.operation)
+            ),
+        ];
+
+        let mut hook_arguments: Vec<Argument> = arguments
+            .iter()
+            .cloned()
+            .map(|mut argument| {
+                if !argument.optional {
+                    argument.ty = format!("{} | undefined", argument.ty);
+                }
+                argument
+            })
+            .collect();
+        hook_arguments.push(self.options_argument(format!(
+            "SWRConfiguration<{}, TError> & {{ swrKey?: Key; enabled?: boolean }}",
+            result
+        )));
+        let mut conditions = vec!["swrOptions?.enabled !== false".to_string()];
+        conditions.extend(
+            arguments
+                .iter()
+                .filter(|argument| !argument.optional)
+                .map(|argument| format!("{} !== undefined", argument.name)),
+        );
+
+        let mut w = CodeWriter::default();
+        write_operation_jsdoc(&mut w, self.operation);
+        w.block(
+            format!(
+                "export const use{} = <TError = unknown>({}) => {{",
+                pascal,
+                parameters(&hook_arguments)
+            ),
+            "};",
+            |w| {
+                self.destructure_options(w);
+                w.line(format!("const isEnabled = {};", conditions.join(" && ")));
+                w.line(format!(
+                    "const swrKey = swrOptions?.swrKey ?? (() => (isEnabled ? get{}Key({}) : null));",
+                    pascal,
+                    key_names.join(", ")
+                ));
+                w.line(format!("const swrFn = () => {};", self.request_call(&names)));
+                w.line(format!(
+                    "const query = useSWR<{}, TError>(swrKey, swrFn, swrOptions);",
+                    result
+                ));
+                w.line("return { swrKey, ...query };");
+            },
+        );
+        code.push(w.finish().trim_end().to_string());
+        code
+    }
+
+    fn mutation(&self) -> Vec<String> {
+        let pascal = self.pascal();
+        let result = self.client.local_name(self.operation, "MutationResult");
+        let arguments = self.client.arguments(self.operation);
+        let body = arguments.iter().find(|argument| argument.name == "body");
+        let extra = body.map_or("never".to_string(), |body| {
+            if body.optional {
+                format!("{} | undefined", body.ty)
+            } else {
+                body.ty.clone()
+            }
+        });
+        let hook_arguments: Vec<Argument> = arguments
+            .iter()
+            .filter(|argument| argument.name != "body")
+            .cloned()
+            .collect();
+        let hook_names: Vec<String> = hook_arguments.iter().map(|a| a.name.clone()).collect();
+        let key_arguments: Vec<Argument> = hook_arguments
+            .iter()
+            .filter(|argument| argument.name != "headers")
+            .cloned()
+            .collect();
+        let key_names: Vec<String> = key_arguments.iter().map(|a| a.name.clone()).collect();
+        let call: Vec<String> = arguments
+            .iter()
+            .map(|argument| {
+                if argument.name == "body" {
+                    "arg".to_string()
+                } else {
+                    argument.name.clone()
+                }
+            })
+            .collect();
+
+        let mut code = vec![
+            self.key_function(&format!("get{}MutationKey", pascal), &key_arguments),
+            format!(
+                "export type {} = NonNullable<Awaited<ReturnType<typeof {}>>>;",
+                result,
+                self.client.function_name(self.operation)
+            ),
+        ];
+
+        let mut fetcher_arguments = hook_arguments.clone();
+        if let Some(options) = self.transport.options_type() {
+            fetcher_arguments.push(Argument::new("requestOptions", options, true));
+        }
+        let mut fetcher_names = hook_names.clone();
+        if self.transport.options_type().is_some() {
+            fetcher_names.push("requestOptions".to_string());
+        }
+        let trigger = if body.is_some() {
+            format!("(_: Key, {{ arg }}: {{ arg: {} }})", extra)
+        } else {
+            "(_: Key)".to_string()
+        };
+        let mut w = CodeWriter::default();
+        w.block(
+            format!(
+                "export const get{}MutationFetcher = ({}) => {{",
+                pascal,
+                parameters(&fetcher_arguments)
+            ),
+            "};",
+            |w| {
+                w.line(format!(
+                    "return {}: Promise<{}> => {};",
+                    trigger,
+                    result,
+                    self.request_call(&call)
+                ));
+            },
+        );
+        code.push(w.finish().trim_end().to_string());
+
+        let mut arguments = hook_arguments;
+        arguments.push(self.options_argument(format!(
+            "SWRMutationConfiguration<{}, TError, Key, {}> & {{ swrKey?: string }}",
+            result, extra
+        )));
+        let mut w = CodeWriter::default();
+        write_operation_jsdoc(&mut w, self.operation);
+        w.block(
+            format!(
+                "export const use{} = <TError = unknown>({}) => {{",
+                pascal,
+                parameters(&arguments)
+            ),
+            "};",
+            |w| {
+                self.destructure_options(w);
+                w.line(format!(
+                    "const swrKey = swrOptions?.swrKey ?? get{}MutationKey({});",
+                    pascal,
+                    key_names.join(", ")
+                ));
+                w.line(format!(
+                    "const swrFn = get{}MutationFetcher({});",
+                    pascal,
+                    fetcher_names.join(", ")
+                ));
+                w.line("const query = useSWRMutation(swrKey, swrFn, swrOptions);");
+                w.line("return { swrKey, ...query };");
+            },
+        );
+        code.push(w.finish().trim_end().to_string());
+        code
+    }
+}
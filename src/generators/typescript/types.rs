@@ -0,0 +1,320 @@
+use indexmap::IndexMap;
+use serde_json::Value;
+
+use crate::generators::typescript::enums::{EnumStyle, enum_declaration};
+use crate::generators::typescript::{TypeScriptOptions, header};
+use crate::generators::{CodeWriter, GeneratedFile, Generator};
+use crate::ir::{ApiModel, ObjectDef, Primitive, TypeDef, TypeKind};
+use crate::naming::{Language, component_type_names};
+
+/// Emits `export interface` and `export type` declarations for every named type
+#[derive(Debug, Clone, Default)]
+pub struct TypesGenerator {
+    options: TypeScriptOptions,
+}
+
+impl TypesGenerator {
+    pub fn new(options: TypeScriptOptions) -> Self {
+        TypesGenerator { options }
+    }
+}
+
+impl Generator for TypesGenerator {
+    fn name(&self) -> &str {
+        "typescript-types"
+    }
+
+    fn generate(&self, model: &ApiModel) -> Vec<GeneratedFile> {
+        let renderer = TypeRenderer::new(model, &self.options);
+        let mut w = CodeWriter::default();
+        w.line(header(model));
+
+        for (name, ty) in &model.types {
+            w.blank();
+            w.line(renderer.declaration(renderer.type_name(name), ty));
+        }
+
+        vec![GeneratedFile::new(
+            self.options.models_file.clone(),
+            w.finish(),
+        )]
+    }
+}
+
+/// Renders IR types as TypeScript type expressions and declarations
+pub(crate) struct TypeRenderer {
+    names: IndexMap<String, String>,
+    enum_style: EnumStyle,
+}
+
+impl TypeRenderer {
+    pub fn new(model: &ApiModel, options: &TypeScriptOptions) -> Self {
+        TypeRenderer {
+            names: component_type_names(
+                model.types.keys().map(String::as_str),
+                Language::TypeScript,
+            ),
+            enum_style: options.enum_style,
+        }
+    }
+
+    /// The TypeScript name of a component schema
+    pub fn type_name<'a>(&'a self, component: &'a str) -> &'a str {
+        self.names.get(component).map_or(component, String::as_str)
+    }
+
+    /// A complete declaration with documentation, e.g. `export interface Pet {...}`
+    pub fn declaration(&self, name: &str, ty: &TypeDef) -> String {
+        if let TypeKind::Enum { values, .. } = &ty.kind {
+            return enum_declaration(name, ty, values, self.enum_style);
+        }
+
+        let mut w = CodeWriter::default();
+        write_jsdoc(&mut w, &jsdoc(ty));
+
+        match &ty.kind {
+            TypeKind::Object(object) if !ty.nullable => {
+                w.line(format!(
+                    "export interface {} {}",
+                    name,
+                    self.object_type(object)
+                ));
+            }
+            _ => {
+                w.line(format!("export type {} = {};", name, self.render(ty)));
+            }
+        }
+        w.finish().trim_end().to_string()
+    }
+
+    /// An inline type expression, including `| null` for nullable types
+    pub fn render(&self, ty: &TypeDef) -> String {
+        let rendered = self.render_kind(ty);
+        if ty.nullable && !matches!(ty.kind, TypeKind::Any) {
+            format!("{} | null", rendered)
+        } else {
+            rendered
+        }
+    }
+
+    /// Render a type so that it can be followed by `[]` or joined with `&`
+    pub fn render_grouped(&self, ty: &TypeDef) -> String {
+        let rendered = self.render(ty);
+        if needs_parens(ty) {
+            format!("({})", rendered)
+        } else {
+            rendered
+        }
+    }
+
+    fn render_kind(&self, ty: &TypeDef) -> String {
+        match &ty.kind {
+            TypeKind::Primitive { primitive, format } => {
+                primitive_type(*primitive, format.as_deref()).to_string()
+            }
+            TypeKind::Array(items) => format!("{}[]", self.render_grouped(items)),
+            TypeKind::Object(object) => self.object_type(object),
+            TypeKind::Enum { values, .. } => {
+                values.iter().map(literal).collect::<Vec<_>>().join(" | ")
+            }
+            TypeKind::Union {
+                variants,
+                discriminator,
+                ..
+            } => variants
+                .iter()
+                .map(|variant| {
+                    let tags = discriminator.as_ref().and_then(|discriminator| {
+                        let TypeKind::Reference(target) = &variant.kind else {
+                            return None;
+                        };
+                        let values: Vec<String> = discriminator
+                            .mapping
+                            .iter()
+                            .filter(|(_, name)| *name == target)
+                            .map(|(value, _)| literal(&Value::from(value.as_str())))
+                            .collect();
+                        (!values.is_empty()).then(|| {
+                            format!(
+                                "{{ {}: {} }}",
+                                property_key(&discriminator.property_name),
+                                values.join(" | ")
+                            )
+                        })
+                    });
+                    match tags {
+                        Some(tags) => format!("{} & {}", self.render_grouped(variant), tags),
+                        None => self.render(variant),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" | "),
+            TypeKind::Intersection(members) => members
+                .iter()
+                .map(|member| self.render_grouped(member))
+                .collect::<Vec<_>>()
+                .join(" & "),
+            TypeKind::Reference(name) => self.type_name(name).to_string(),
+            TypeKind::Any => "unknown".to_string(),
+        }
+    }
+
+    /// An object literal type, one member per line
+    pub fn object_type(&self, object: &ObjectDef) -> String {
+        if object.properties.is_empty() && object.additional_properties.is_none() {
+            return "{ [key: string]: unknown }".to_string();
+        }
+
+        let mut w = CodeWriter::default();
+        w.block("{", "}", |w| {
+            for property in &object.properties {
+                write_jsdoc(w, &jsdoc(&property.ty));
+                w.line(format!(
+                    "{}{}{}: {};",
+                    if property.ty.read_only {
+                        "readonly "
+                    } else {
+                        ""
+                    },
+                    property_key(&property.name),
+                    if property.required { "" } else { "?" },
+                    self.render(&property.ty)
+                ));
+            }
+            if let Some(additional) = &object.additional_properties {
+                w.line(format!(
+                    "[key: string]: {};",
+                    self.index_type(object, additional)
+                ));
+            }
+        });
+        w.finish().trim_end().to_string()
+    }
+
+    /// Every property must be assignable to the index signature, so widen it
+    fn index_type(&self, object: &ObjectDef, additional: &TypeDef) -> String {
+        let mut members = vec![self.render(additional)];
+        if matches!(additional.kind, TypeKind::Any) {
+            return members.remove(0);
+        }
+        let mut optional = false;
+        for property in &object.properties {
+            let rendered = self.render(&property.ty);
+            optional |= !property.required;
+            if !members.contains(&rendered) {
+                members.push(rendered);
+            }
+        }
+        if optional {
+            members.push("undefined".to_string());
+        }
+        members.join(" | ")
+    }
+}
+
+pub(crate) fn primitive_type(primitive: Primitive, format: Option<&str>) -> &'static str {
+    match (primitive, format) {
+        (Primitive::String, Some("binary")) => "Blob",
+        (Primitive::String, _) => "string",
+        (Primitive::Number | Primitive::Integer, _) => "number",
+        (Primitive::Boolean, _) => "boolean",
+    }
+}
+
+fn needs_parens(ty: &TypeDef) -> bool {
+    ty.nullable
+        || match &ty.kind {
+            TypeKind::Union { variants, .. } | TypeKind::Intersection(variants) => {
+                variants.len() > 1
+            }
+            TypeKind::Enum { values, .. } => values.len() > 1,
+            _ => false,
+        }
+}
+
+/// A TypeScript literal for a JSON value
+pub(crate) fn literal(value: &Value) -> String {
+    match value {
+        Value::String(string) => format!("'{}'", string.replace('\\', "\\\\").replace('\'', "\\'")),
+        other => other.to_string(),
+    }
+}
+
+/// A property name, quoted when it is not a valid identifier
+pub(crate) fn property_key(name: &str) -> String {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if valid {
+        name.to_string()
+    } else {
+        literal(&Value::from(name))
+    }
+}
+
+/// JSDoc lines for a type: description, deprecation and constraints
+pub(crate) fn jsdoc(ty: &TypeDef) -> Vec<String> {
+    let mut lines: Vec<String> = ty
+        .description
+        .iter()
+        .flat_map(|description| description.trim().lines())
+        .map(String::from)
+        .collect();
+    if ty.deprecated {
+        lines.push("@deprecated".to_string());
+    }
+
+    let c = &ty.constraints;
+    let mut tag = |name: &str, value: Option<String>| {
+        if let Some(value) = value {
+            lines.push(format!("@{} {}", name, value));
+        }
+    };
+    tag("minLength", c.min_length.map(|v| v.to_string()));
+    tag("maxLength", c.max_length.map(|v| v.to_string()));
+    tag("pattern", c.pattern.clone());
+    let (minimum, maximum) = (c.minimum.map(number), c.maximum.map(number));
+    if c.exclusive_minimum {
+        tag("exclusiveMinimum", minimum);
+    } else {
+        tag("minimum", minimum);
+    }
+    if c.exclusive_maximum {
+        tag("exclusiveMaximum", maximum);
+    } else {
+        tag("maximum", maximum);
+    }
+    tag("multipleOf", c.multiple_of.map(number));
+    tag("minItems", c.min_items.map(|v| v.to_string()));
+    tag("maxItems", c.max_items.map(|v| v.to_string()));
+    tag("uniqueItems", c.unique_items.then(|| "true".to_string()));
+    tag("minProperties", c.min_properties.map(|v| v.to_string()));
+    tag("maxProperties", c.max_properties.map(|v| v.to_string()));
+    lines
+}
+
+pub(crate) fn write_jsdoc(w: &mut CodeWriter, lines: &[String]) {
+    if lines.is_empty() {
+        return;
+    }
+    w.line("/**");
+    for line in lines {
+        let line = line.replace("*/", "*\\/");
+        if line.is_empty() {
+            w.line(" *");
+        } else {
+            w.line(format!(" * {}", line));
+        }
+    }
+    w.line(" */");
+}
+
+fn number(value: f64) -> String {
+    if value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        value.to_string()
+    }
+}
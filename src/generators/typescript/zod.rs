@@ -0,0 +1,553 @@
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
+use serde_json::Value;
+
+use crate::generators::typescript::client::{ClientRenderer, is_json};
+use crate::generators::typescript::types::{literal, property_key};
+use crate::generators::typescript::{TypeScriptOptions, header};
+use crate::generators::{CodeWriter, GeneratedFile, Generator};
+use crate::ir::{
+    ApiModel, Constraints, Discriminator, ObjectDef, Operation, Param, Primitive, Property,
+    TypeDef, TypeKind,
+};
+use crate::naming::{NameAllocator, to_pascal_case};
+
+/// Emits Zod schemas for every named type and for the parameters, body and
+/// response of every operation
+///
+/// Named types become `{Name}Schema`, declared after the types they reference.
+/// References to a type that is part of a cycle go through `z.lazy`; the schemas of
+/// recursive types are annotated with their TypeScript type, which is imported
+/// from the models file. Variants of a discriminated union have their
+/// discriminator property pinned to the values of the mapping, as the
+/// TypeScript types do. Per operation the generator emits `{op}Params`,
+/// `{op}QueryParams`, `{op}Header`, `{op}Body` and `{op}Response` as declared.
+#[derive(Debug, Clone, Default)]
+pub struct ZodGenerator {
+    options: TypeScriptOptions,
+}
+
+impl ZodGenerator {
+    pub fn new(options: TypeScriptOptions) -> Self {
+        ZodGenerator { options }
+    }
+}
+
+impl Generator for ZodGenerator {
+    fn name(&self) -> &str {
+        "typescript-zod"
+    }
+
+    fn generate(&self, model: &ApiModel) -> Vec<GeneratedFile> {
+        let client = ClientRenderer::new(model, &self.options);
+        let mut renderer = ZodRenderer::new(model, &client);
+
+        let mut w = CodeWriter::default();
+        w.line(header(model));
+        w.blank();
+        w.line("import { z } from 'zod';");
+        if let Some(import) = client.model_import(&renderer.recursive_types()) {
+            w.line(import);
+        }
+        renderer.write_components(&mut w);
+
+        for operation in &model.operations {
+            for (name, schema) in renderer.operation_schemas(operation) {
+                w.blank();
+                w.line(format!("export const {} = {};", name, schema));
+            }
+        }
+
+        vec![GeneratedFile::new(
+            self.options.zod.file.clone(),
+            w.finish(),
+        )]
+    }
+}
+
+/// Renders IR types as Zod schema expressions
+pub(crate) struct ZodRenderer<'a> {
+    model: &'a ApiModel,
+    client: &'a ClientRenderer<'a>,
+    /// Schema constant names keyed by component name
+    names: IndexMap<String, String>,
+    /// Named types that can reach themselves through references
+    recursive: HashSet<&'a str>,
+    /// Named types whose schema constant is already declared
+    declared: HashSet<&'a str>,
+}
+
+impl<'a> ZodRenderer<'a> {
+    pub fn new(model: &'a ApiModel, client: &'a ClientRenderer<'a>) -> Self {
+        let mut names = NameAllocator::new();
+        for name in model.types.keys() {
+            names.request(
+                name.clone(),
+                format!("{}Schema", to_pascal_case(client.types.type_name(name))),
+            );
+        }
+        let recursive = model
+            .types
+            .keys()
+            .map(String::as_str)
+            .filter(|name| model.is_recursive(name))
+            .collect();
+        ZodRenderer {
+            model,
+            client,
+            names: names.resolve(),
+            recursive,
+            declared: HashSet::new(),
+        }
+    }
+
+    fn schema_name(&self, component: &str) -> &str {
+        &self.names[component]
+    }
+
+    /// References to the recursive types, whose schemas are annotated with
+    /// their TypeScript type
+    pub fn recursive_types(&self) -> Vec<TypeDef> {
+        let mut names: Vec<&str> = self.recursive.iter().copied().collect();
+        names.sort();
+        names
+            .into_iter()
+            .map(|name| TypeDef::new(TypeKind::Reference(name.to_string())))
+            .collect()
+    }
+
+    /// Declares the schema constant of every named type
+    pub fn write_components(&mut self, w: &mut CodeWriter) {
+        for name in declaration_order(self.model) {
+            let ty = &self.model.types[name];
+            w.blank();
+            let schema = self.render(ty);
+            if self.recursive.contains(name) {
+                w.line(format!(
+                    "export const {}: z.ZodType<{}> = {};",
+                    self.schema_name(name),
+                    self.client.types.type_name(name),
+                    schema
+                ));
+            } else {
+                w.line(format!(
+                    "export const {} = {};",
+                    self.schema_name(name),
+                    schema
+                ));
+            }
+            self.declared.insert(name);
+        }
+    }
+
+    /// Whether a reference can use the schema constant directly
+    fn is_declared(&self, component: &str) -> bool {
+        self.declared.contains(component) && !self.recursive.contains(component)
+    }
+
+    pub fn render(&self, ty: &TypeDef) -> String {
+        let schema = self.render_kind(ty);
+        if ty.nullable && !matches!(ty.kind, TypeKind::Any) {
+            format!("{}.nullable()", schema)
+        } else {
+            schema
+        }
+    }
+
+    /// A schema accepting the strings of a URL or header for `ty`, e.g.
+    /// `z.coerce.number().pipe(z.number().int())`
+    pub fn render_coerced(&self, ty: &TypeDef) -> String {
+        let schema = self.render(ty);
+        if let TypeKind::Array(items) = &ty.kind {
+            let coerced = self.render_coerced(items);
+            return if coerced == self.render(items) {
+                schema
+            } else {
+                format!("z.array({}).pipe({})", coerced, schema)
+            };
+        }
+        if !self.is_recursive(ty)
+            && self
+                .properties(ty)
+                .iter()
+                .any(|property| self.render_coerced(&property.ty) != self.render(&property.ty))
+        {
+            return self.coerced_object(ty, |property| self.render_coerced(&property.ty));
+        }
+        match self.scalar(ty) {
+            Some(Primitive::Integer | Primitive::Number) => {
+                format!("z.coerce.number().pipe({})", schema)
+            }
+            Some(Primitive::Boolean) => format!(
+                "z.enum(['true', 'false']).transform((value) => value === 'true').pipe({})",
+                schema
+            ),
+            _ => schema,
+        }
+    }
+
+    /// A schema for an object whose properties arrive as strings, validating
+    /// each property with `property_schema`
+    ///
+    /// Named and intersected objects are validated once more as a whole.
+    pub fn coerced_object(
+        &self,
+        ty: &TypeDef,
+        property_schema: impl Fn(&Property) -> String,
+    ) -> String {
+        let mut w = CodeWriter::default();
+        let whole = match &ty.kind {
+            TypeKind::Object(object) if object.additional_properties.is_none() => "})".to_string(),
+            _ => format!("}}).passthrough().pipe({})", self.render(ty)),
+        };
+        w.block("z.object({", whole, |w| {
+            for property in self.properties(ty) {
+                let optional = if property.required { "" } else { ".optional()" };
+                w.line(format!(
+                    "{}: {}{},",
+                    property_key(&property.name),
+                    property_schema(&property),
+                    optional
+                ));
+            }
+        });
+        w.finish().trim_end().to_string()
+    }
+
+    /// The properties of an object type, following references and merging
+    /// the members of intersections
+    pub fn properties(&self, ty: &TypeDef) -> Vec<Property> {
+        match &ty.kind {
+            TypeKind::Object(object) => object.properties.clone(),
+            TypeKind::Intersection(parts) => parts
+                .iter()
+                .flat_map(|part| self.properties(part))
+                .collect(),
+            TypeKind::Reference(name) => self
+                .model
+                .type_def(name)
+                .map(|ty| self.properties(ty))
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// A type with its references followed
+    pub fn resolve<'t>(&'t self, ty: &'t TypeDef) -> &'t TypeDef {
+        match &ty.kind {
+            TypeKind::Reference(name) => {
+                self.model.type_def(name).map_or(ty, |ty| self.resolve(ty))
+            }
+            _ => ty,
+        }
+    }
+
+    /// Whether a type references a named type that can reach itself
+    fn is_recursive(&self, ty: &TypeDef) -> bool {
+        ty.references()
+            .iter()
+            .any(|name| self.recursive.contains(name))
+    }
+
+    /// The primitive a type holds, following references
+    fn scalar(&self, ty: &TypeDef) -> Option<Primitive> {
+        match &ty.kind {
+            TypeKind::Primitive { primitive, .. } => Some(*primitive),
+            TypeKind::Enum { values, .. } => match values.first()? {
+                Value::Number(_) => Some(Primitive::Number),
+                Value::Bool(_) => Some(Primitive::Boolean),
+                _ => Some(Primitive::String),
+            },
+            TypeKind::Union { variants, .. } if variants.len() == 1 => self.scalar(&variants[0]),
+            TypeKind::Reference(name) => self.scalar(self.model.type_def(name)?),
+            _ => None,
+        }
+    }
+
+    fn render_kind(&self, ty: &TypeDef) -> String {
+        match &ty.kind {
+            TypeKind::Primitive { primitive, format } => {
+                primitive_schema(*primitive, format.as_deref(), &ty.constraints)
+            }
+            TypeKind::Array(items) => {
+                let mut schema = format!("z.array({})", self.render(items));
+                if let Some(min) = ty.constraints.min_items {
+                    schema.push_str(&format!(".min({})", min));
+                }
+                if let Some(max) = ty.constraints.max_items {
+                    schema.push_str(&format!(".max({})", max));
+                }
+                schema
+            }
+            TypeKind::Object(object) => self.object_schema(object),
+            TypeKind::Enum { values, .. } => enum_schema(values),
+            TypeKind::Union {
+                variants,
+                discriminator,
+                ..
+            } => {
+                if variants.len() == 1 {
+                    return self.render(&variants[0]);
+                }
+                let discriminable = discriminator.is_some() && self.discriminable(variants);
+                let rendered: Vec<String> = variants
+                    .iter()
+                    .map(|variant| {
+                        let schema = self.render(variant);
+                        match discriminator.as_ref().and_then(|d| tag_schema(d, variant)) {
+                            Some(tag) if discriminable => format!("{}.extend({})", schema, tag),
+                            Some(tag) => format!("{}.and(z.object({}))", schema, tag),
+                            None => schema,
+                        }
+                    })
+                    .collect();
+                match discriminator {
+                    Some(discriminator) if discriminable => format!(
+                        "z.discriminatedUnion({}, [{}])",
+                        literal(&discriminator.property_name.as_str().into()),
+                        rendered.join(", ")
+                    ),
+                    _ => format!("z.union([{}])", rendered.join(", ")),
+                }
+            }
+            TypeKind::Intersection(parts) => {
+                let mut rendered = parts.iter().map(|part| self.render(part));
+                let first = rendered.next().unwrap_or_else(|| "z.unknown()".to_string());
+                rendered.fold(first, |schema, part| format!("{}.and({})", schema, part))
+            }
+            TypeKind::Reference(name) => {
+                if self.is_declared(name) {
+                    self.schema_name(name).to_string()
+                } else {
+                    format!("z.lazy(() => {})", self.schema_name(name))
+                }
+            }
+            TypeKind::Any => "z.unknown()".to_string(),
+        }
+    }
+
+    /// `z.discriminatedUnion` takes object schemas only, so every variant must
+    /// reference an object type whose schema is already declared
+    fn discriminable(&self, variants: &[TypeDef]) -> bool {
+        variants.iter().all(|variant| match &variant.kind {
+            TypeKind::Reference(name) if !variant.nullable && self.is_declared(name) => self
+                .model
+                .type_def(name)
+                .is_some_and(|ty| matches!(ty.kind, TypeKind::Object(_)) && !ty.nullable),
+            _ => false,
+        })
+    }
+
+    fn object_schema(&self, object: &ObjectDef) -> String {
+        let additional = object
+            .additional_properties
+            .as_ref()
+            .map(|additional| self.render(additional));
+        if object.properties.is_empty()
+            && let Some(additional) = additional
+        {
+            return format!("z.record(z.string(), {})", additional);
+        }
+
+        let mut w = CodeWriter::default();
+        let close = match &additional {
+            Some(additional) => format!("}}).catchall({})", additional),
+            None => "})".to_string(),
+        };
+        if object.properties.is_empty() {
+            return format!("z.object({{{}", close);
+        }
+        w.block("z.object({", close, |w| {
+            for property in &object.properties {
+                let optional = if property.required { "" } else { ".optional()" };
+                w.line(format!(
+                    "{}: {}{},",
+                    property_key(&property.name),
+                    self.render(&property.ty),
+                    optional
+                ));
+            }
+        });
+        w.finish().trim_end().to_string()
+    }
+
+    fn params_schema(&self, params: &[Param]) -> String {
+        let object = ObjectDef {
+            properties: params
+                .iter()
+                .map(|param| Property {
+                    name: param.name.clone(),
+                    required: param.required,
+                    ty: param.ty.clone(),
+                })
+                .collect(),
+            additional_properties: None,
+        };
+        self.object_schema(&object)
+    }
+
+    /// The schemas of an operation's inputs and successful responses
+    fn operation_schemas(&self, operation: &Operation) -> Vec<(String, String)> {
+        let name = self.client.function_name(operation);
+        let mut schemas = Vec::new();
+        for (suffix, params) in [
+            ("Params", &operation.path_params),
+            ("QueryParams", &operation.query_params),
+            ("Header", &operation.header_params),
+        ] {
+            if !params.is_empty() {
+                schemas.push((format!("{}{}", name, suffix), self.params_schema(params)));
+            }
+        }
+        if let Some(content) = self.client.body_content(operation) {
+            schemas.push((format!("{}Body", name), self.render(&content.ty)));
+        }
+
+        let mut responses: Vec<String> = Vec::new();
+        for response in &operation.responses.success {
+            let content = response
+                .contents
+                .iter()
+                .find(|content| is_json(&content.media_type))
+                .or_else(|| response.contents.first());
+            if let Some(content) = content {
+                let schema = self.render(&content.ty);
+                if !responses.contains(&schema) {
+                    responses.push(schema);
+                }
+            }
+        }
+        match responses.len() {
+            0 => {}
+            1 => schemas.push((format!("{}Response", name), responses.remove(0))),
+            _ => schemas.push((
+                format!("{}Response", name),
+                format!("z.union([{}])", responses.join(", ")),
+            )),
+        }
+        schemas
+    }
+}
+
+/// Named types with the types they reference first, so that only cycles
+/// need `z.lazy`
+fn declaration_order(model: &ApiModel) -> Vec<&str> {
+    fn visit<'a>(
+        model: &'a ApiModel,
+        name: &'a str,
+        visited: &mut HashSet<&'a str>,
+        order: &mut Vec<&'a str>,
+    ) {
+        if !visited.insert(name) {
+            return;
+        }
+        if let Some(ty) = model.type_def(name) {
+            for reference in ty.references() {
+                visit(model, reference, visited, order);
+            }
+            order.push(name);
+        }
+    }
+
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+    for name in model.types.keys() {
+        visit(model, name, &mut visited, &mut order);
+    }
+    order
+}
+
+fn primitive_schema(
+    primitive: Primitive,
+    format: Option<&str>,
+    constraints: &Constraints,
+) -> String {
+    let mut schema = match (primitive, format) {
+        (Primitive::String, Some("binary")) => return "z.instanceof(Blob)".to_string(),
+        (Primitive::String, Some("date-time")) => "z.string().datetime({ offset: true })",
+        (Primitive::String, Some("date")) => "z.string().date()",
+        (Primitive::String, Some("email")) => "z.string().email()",
+        (Primitive::String, Some("uuid")) => "z.string().uuid()",
+        (Primitive::String, Some("uri" | "url")) => "z.string().url()",
+        (Primitive::String, _) => "z.string()",
+        (Primitive::Integer, _) => "z.number().int()",
+        (Primitive::Number, _) => "z.number()",
+        (Primitive::Boolean, _) => return "z.boolean()".to_string(),
+    }
+    .to_string();
+
+    if primitive == Primitive::String {
+        if let Some(min) = constraints.min_length {
+            schema.push_str(&format!(".min({})", min));
+        }
+        if let Some(max) = constraints.max_length {
+            schema.push_str(&format!(".max({})", max));
+        }
+        if let Some(pattern) = &constraints.pattern {
+            schema.push_str(&format!(
+                ".regex(new RegExp({}))",
+                literal(&pattern.as_str().into())
+            ));
+        }
+        return schema;
+    }
+
+    if let Some(minimum) = constraints.minimum {
+        let method = if constraints.exclusive_minimum {
+            "gt"
+        } else {
+            "min"
+        };
+        schema.push_str(&format!(".{}({})", method, minimum));
+    }
+    if let Some(maximum) = constraints.maximum {
+        let method = if constraints.exclusive_maximum {
+            "lt"
+        } else {
+            "max"
+        };
+        schema.push_str(&format!(".{}({})", method, maximum));
+    }
+    if let Some(multiple_of) = constraints.multiple_of {
+        schema.push_str(&format!(".multipleOf({})", multiple_of));
+    }
+    schema
+}
+
+/// The shape pinning a variant's discriminator property to the values its
+/// mapping gives it, e.g. `{ kind: z.literal('cat') }`, as the TypeScript
+/// types do
+fn tag_schema(discriminator: &Discriminator, variant: &TypeDef) -> Option<String> {
+    let TypeKind::Reference(target) = &variant.kind else {
+        return None;
+    };
+    let values: Vec<Value> = discriminator
+        .mapping
+        .iter()
+        .filter(|(_, name)| *name == target)
+        .map(|(value, _)| Value::from(value.as_str()))
+        .collect();
+    (!values.is_empty()).then(|| {
+        format!(
+            "{{ {}: {} }}",
+            property_key(&discriminator.property_name),
+            enum_schema(&values)
+        )
+    })
+}
+
+fn enum_schema(values: &[Value]) -> String {
+    if values.len() == 1 {
+        return format!("z.literal({})", literal(&values[0]));
+    }
+    if values.iter().all(Value::is_string) {
+        let values: Vec<String> = values.iter().map(literal).collect();
+        return format!("z.enum([{}])", values.join(", "));
+    }
+    let literals: Vec<String> = values
+        .iter()
+        .map(|value| format!("z.literal({})", literal(value)))
+        .collect();
+    format!("z.union([{}])", literals.join(", "))
+}
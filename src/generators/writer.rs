@@ -0,0 +1,73 @@
+/// An indentation-aware string builder for generated source code
+#[derive(Debug, Clone)]
+pub struct CodeWriter {
+    buffer: String,
+    indent: usize,
+    unit: &'static str,
+}
+
+impl Default for CodeWriter {
+    fn default() -> Self {
+        Self::new("  ")
+    }
+}
+
+impl CodeWriter {
+    /// Create a writer indenting with `unit`, e.g. two or four spaces
+    pub fn new(unit: &'static str) -> Self {
+        CodeWriter {
+            buffer: String::new(),
+            indent: 0,
+            unit,
+        }
+    }
+
+    /// Write a line at the current indentation; every line of `text` is indented
+    pub fn line(&mut self, text: impl AsRef<str>) -> &mut Self {
+        for line in text.as_ref().split('\n') {
+            if !line.is_empty() {
+                for _ in 0..self.indent {
+                    self.buffer.push_str(self.unit);
+                }
+                self.buffer.push_str(line);
+            }
+            self.buffer.push('\n');
+        }
+        self
+    }
+
+    pub fn blank(&mut self) -> &mut Self {
+        if !self.buffer.is_empty() && !self.buffer.ends_with("\n\n") {
+            self.buffer.push('\n');
+        }
+        self
+    }
+
+    pub fn indent(&mut self) -> &mut Self {
+        self.indent += 1;
+        self
+    }
+
+    pub fn dedent(&mut self) -> &mut Self {
+        self.indent = self.indent.saturating_sub(1);
+        self
+    }
+
+    /// Write `open`, the indented body and `close`
+    pub fn block(
+        &mut self,
+        open: impl AsRef<str>,
+        close: impl AsRef<str>,
+        body: impl FnOnce(&mut Self),
+    ) -> &mut Self {
+        self.line(open);
+        self.indent();
+        body(self);
+        self.dedent();
+        self.line(close)
+    }
+
+    pub fn finish(self) -> String {
+        self.buffer
+    }
+}
@@ -0,0 +1,268 @@
+//! Normalized intermediate representation for code generation
+//!
+//! [`ApiModel`] is built once from a specification and resolves everything a
+//! generator would otherwise look up by hand: parameters are merged and split by
+//! location, request bodies and responses are resolved, and schemas become
+//! [`TypeDef`] trees that only keep references to named component schemas.
+//! All code generators consume this model rather than `openapiv3` types.
+
+mod model;
+mod operation;
+mod types;
+
+// Re-export the public API
+pub use model::{
+    ApiKeyLocation, ApiModel, SecurityKind, SecurityScheme, Server, ServerVariable, Tag,
+};
+pub use operation::{
+    Body, Content, Encoding, Header, Operation, Param, ParamLocation, ParamStyle, Response,
+    Responses, Status,
+};
+pub use types::{Constraints, Discriminator, ObjectDef, Primitive, Property, TypeDef, TypeKind};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openapiv3::OpenAPI;
+
+    const SPEC: &str = r##"
+openapi: 3.0.3
+info: { title: Pets, version: "1.0" }
+servers:
+  - url: https://{region}.example.com/v1
+    variables:
+      region: { default: eu, enum: [eu, us] }
+security:
+  - bearer: []
+tags:
+  - name: pets
+    description: Everything about pets
+paths:
+  /pets/{petId}:
+    parameters:
+      - $ref: "#/components/parameters/PetId"
+      - { name: verbose, in: query, schema: { type: boolean } }
+    get:
+      tags: [pets, store]
+      parameters:
+        - { name: verbose, in: query, required: true, schema: { type: boolean } }
+        - name: fields
+          in: query
+          style: pipeDelimited
+          schema: { type: array, items: { type: string } }
+        - { name: X-Trace, in: header, schema: { type: string, format: uuid } }
+      responses:
+        "200":
+          description: ok
+          headers:
+            X-Rate-Limit: { required: true, schema: { type: integer } }
+          content:
+            application/json:
+              schema: { $ref: "#/components/schemas/Pet" }
+        "404": { $ref: "#/components/responses/NotFound" }
+        default:
+          description: error
+    put:
+      security: []
+      requestBody:
+        required: true
+        content:
+          multipart/form-data:
+            schema:
+              type: object
+              properties:
+                photo: { type: string, format: binary }
+            encoding:
+              photo: { contentType: image/png }
+          application/x-www-form-urlencoded:
+            schema:
+              type: object
+              properties:
+                tags: { type: array, items: { type: string } }
+                ids: { type: array, items: { type: integer } }
+            encoding:
+              tags: { style: form }
+              ids: { style: pipeDelimited }
+      responses:
+        default: { description: done }
+components:
+  securitySchemes:
+    bearer: { type: http, scheme: Bearer, bearerFormat: JWT }
+  parameters:
+    PetId: { name: petId, in: path, required: true, schema: { type: integer, format: int64 } }
+  responses:
+    NotFound: { description: not found }
+  schemas:
+    Pet:
+      oneOf:
+        - $ref: "#/components/schemas/Cat"
+        - $ref: "#/components/schemas/Dog"
+      discriminator:
+        propertyName: kind
+        mapping:
+          kitty: "#/components/schemas/Cat"
+    Cat:
+      allOf:
+        - $ref: "#/components/schemas/Base"
+        - type: object
+          properties:
+            lives: { type: integer, minimum: 0 }
+    Dog:
+      allOf:
+        - $ref: "#/components/schemas/Base"
+      nullable: true
+    Base:
+      type: object
+      required: [kind]
+      properties:
+        kind: { type: string, enum: [cat, dog, null] }
+        tags:
+          type: array
+          items: { type: string }
+          maxItems: 5
+        extra:
+          additionalProperties: { type: number }
+"##;
+
+    fn model() -> ApiModel {
+        let spec: OpenAPI = serde_yaml::from_str(SPEC).unwrap();
+        ApiModel::from_spec(&spec)
+    }
+
+    #[test]
+    fn test_operations() {
+        let model = model();
+        assert_eq!(model.operations.len(), 2);
+
+        let get = &model.operations[0];
+        assert_eq!(get.name, "getPetsPetId");
+        assert_eq!(
+            (get.method.as_str(), get.path.as_str()),
+            ("get", "/pets/{petId}")
+        );
+        assert_eq!(get.path_params[0].name, "petId");
+        assert_eq!(
+            get.path_params[0].ty.kind,
+            TypeKind::Primitive {
+                primitive: Primitive::Integer,
+                format: Some("int64".to_string())
+            }
+        );
+
+        // The operation overrides the path item's `verbose`
+        let query: Vec<(&str, bool, ParamStyle, bool)> = get
+            .query_params
+            .iter()
+            .map(|p| (p.name.as_str(), p.required, p.style, p.explode))
+            .collect();
+        assert_eq!(
+            query,
+            vec![
+                ("verbose", true, ParamStyle::Form, true),
+                ("fields", false, ParamStyle::PipeDelimited, false),
+            ]
+        );
+        assert_eq!(get.header_params[0].name, "X-Trace");
+        assert_eq!(get.security[0]["bearer"], Vec::<String>::new());
+
+        let statuses = |responses: &[Response]| -> Vec<String> {
+            responses.iter().map(|r| r.status.to_string()).collect()
+        };
+        assert_eq!(statuses(&get.responses.success), vec!["200"]);
+        assert_eq!(statuses(&get.responses.errors), vec!["404", "default"]);
+        assert_eq!(get.responses.success[0].headers[0].name, "X-Rate-Limit");
+        assert_eq!(
+            get.responses.success[0].contents[0].ty.kind,
+            TypeKind::Reference("Pet".to_string())
+        );
+        assert_eq!(get.responses.errors[0].description, "not found");
+
+        let put = &model.operations[1];
+        assert!(put.security.is_empty());
+        assert_eq!(put.responses.success[0].status, Status::Default);
+        let body = put.body.as_ref().unwrap();
+        assert!(body.required);
+        assert_eq!(body.contents[0].media_type, "multipart/form-data");
+        assert_eq!(
+            body.contents[0].encoding["photo"].content_type.as_deref(),
+            Some("image/png")
+        );
+        // `explode` defaults to `true` for the `form` style only
+        let encoding = &body.contents[1].encoding;
+        assert!(encoding["tags"].explode);
+        assert!(!encoding["ids"].explode);
+    }
+
+    #[test]
+    fn test_types() {
+        let model = model();
+
+        let TypeKind::Union {
+            variants,
+            exclusive: true,
+            discriminator: Some(discriminator),
+        } = &model.types["Pet"].kind
+        else {
+            panic!("Pet should be a oneOf union");
+        };
+        assert_eq!(variants.len(), 2);
+        let mapping: Vec<(&str, &str)> = discriminator
+            .mapping
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        assert_eq!(mapping, vec![("kitty", "Cat"), ("Dog", "Dog")]);
+
+        let TypeKind::Intersection(members) = &model.types["Cat"].kind else {
+            panic!("Cat should be an intersection");
+        };
+        assert_eq!(members[0].kind, TypeKind::Reference("Base".to_string()));
+        assert_eq!(model.types["Cat"].references(), vec!["Base"]);
+
+        // A single-member allOf collapses into the member
+        let dog = &model.types["Dog"];
+        assert_eq!(dog.kind, TypeKind::Reference("Base".to_string()));
+        assert!(dog.nullable);
+
+        let TypeKind::Object(base) = &model.types["Base"].kind else {
+            panic!("Base should be an object");
+        };
+        let kind = &base.properties[0];
+        assert!(kind.required && kind.ty.nullable);
+        assert_eq!(
+            kind.ty.kind,
+            TypeKind::Enum {
+                primitive: Primitive::String,
+                values: vec!["cat".into(), "dog".into()]
+            }
+        );
+        assert_eq!(base.properties[1].ty.constraints.max_items, Some(5));
+        let TypeKind::Object(extra) = &base.properties[2].ty.kind else {
+            panic!("extra should be a map");
+        };
+        assert!(matches!(
+            extra.additional_properties.as_deref().map(|t| &t.kind),
+            Some(TypeKind::Primitive {
+                primitive: Primitive::Number,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_document() {
+        let model = model();
+        let tags: Vec<&str> = model.tags.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(tags, vec!["pets", "store"]);
+        assert_eq!(model.servers[0].default_url(), "https://eu.example.com/v1");
+        assert_eq!(
+            model.security[0].kind,
+            SecurityKind::Http {
+                scheme: "bearer".to_string(),
+                bearer_format: Some("JWT".to_string())
+            }
+        );
+        assert_eq!(model.operations_by_tag("store").count(), 1);
+        assert!(!model.is_recursive("Pet"));
+    }
+}
@@ -0,0 +1,319 @@
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
+use openapiv3::{APIKeyLocation, OpenAPI, ReferenceOr};
+
+use crate::ir::operation::{Operation, operation};
+use crate::ir::types::{TypeDef, type_def};
+use crate::naming::operation_names;
+use crate::walk::operation_pointer;
+
+/// The normalized view of a specification that code generators work from
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiModel {
+    pub title: String,
+    pub version: String,
+    pub description: Option<String>,
+    /// Operations in path, then method order
+    pub operations: Vec<Operation>,
+    /// Named types from `components.schemas`, keyed by component name
+    pub types: IndexMap<String, TypeDef>,
+    /// Declared tags followed by any tag only used on operations
+    pub tags: Vec<Tag>,
+    pub servers: Vec<Server>,
+    /// Security schemes from `components.securitySchemes`
+    pub security: Vec<SecurityScheme>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tag {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Server {
+    pub url: String,
+    pub description: Option<String>,
+    pub variables: IndexMap<String, ServerVariable>,
+}
+
+impl Server {
+    /// The URL with every variable replaced by its default
+    pub fn default_url(&self) -> String {
+        self.variables
+            .iter()
+            .fold(self.url.clone(), |url, (name, variable)| {
+                url.replace(&format!("{{{}}}", name), &variable.default)
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerVariable {
+    pub default: String,
+    pub values: Vec<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecurityScheme {
+    /// The key in `components.securitySchemes`
+    pub name: String,
+    pub description: Option<String>,
+    pub kind: SecurityKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecurityKind {
+    ApiKey {
+        location: ApiKeyLocation,
+        /// Name of the header, query parameter or cookie
+        parameter_name: String,
+    },
+    Http {
+        /// e.g. `bearer` or `basic`
+        scheme: String,
+        bearer_format: Option<String>,
+    },
+    OAuth2 {
+        /// Scopes of all flows, with their descriptions
+        scopes: IndexMap<String, String>,
+    },
+    OpenIdConnect {
+        url: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiKeyLocation {
+    Query,
+    Header,
+    Cookie,
+}
+
+impl ApiModel {
+    /// Build the model for a specification
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// # use rustval::ir::{ApiModel, TypeKind};
+    /// # let spec: openapiv3::OpenAPI = serde_yaml::from_str(r##"
+    /// # openapi: 3.0.3
+    /// # info: { title: Pets, version: "1.0" }
+    /// # paths:
+    /// #   /pets:
+    /// #     get:
+    /// #       operationId: listPets
+    /// #       responses:
+    /// #         "200":
+    /// #           description: ok
+    /// #           content:
+    /// #             application/json:
+    /// #               schema: { type: array, items: { $ref: "#/components/schemas/Pet" } }
+    /// # components:
+    /// #   schemas:
+    /// #     Pet: { type: object, properties: { name: { type: string } } }
+    /// # "##).unwrap();
+    /// let model = ApiModel::from_spec(&spec);
+    /// let list = &model.operations[0];
+    /// assert_eq!(list.name, "listPets");
+    /// assert_eq!(list.responses.success[0].contents[0].ty.references(), vec!["Pet"]);
+    /// assert!(matches!(model.types["Pet"].kind, TypeKind::Object(_)));
+    /// ```
+    pub fn from_spec(spec: &OpenAPI) -> Self {
+        let names = operation_names(spec);
+        let mut operations = Vec::new();
+        for (path, item) in spec.paths.iter() {
+            let ReferenceOr::Item(path_item) = item else {
+                continue;
+            };
+            for (method, op) in path_item.iter() {
+                let name = names[&operation_pointer(path, method)].clone();
+                operations.push(operation(name, path, method, path_item, op, spec));
+            }
+        }
+
+        let types = spec
+            .components
+            .iter()
+            .flat_map(|components| &components.schemas)
+            .map(|(name, schema)| (name.clone(), type_def(schema, spec)))
+            .collect();
+
+        let mut tags: Vec<Tag> = spec
+            .tags
+            .iter()
+            .map(|tag| Tag {
+                name: tag.name.clone(),
+                description: tag.description.clone(),
+            })
+            .collect();
+        for name in operations.iter().flat_map(|op: &Operation| &op.tags) {
+            if !tags.iter().any(|tag| &tag.name == name) {
+                tags.push(Tag {
+                    name: name.clone(),
+                    description: None,
+                });
+            }
+        }
+
+        ApiModel {
+            title: spec.info.title.clone(),
+            version: spec.info.version.clone(),
+            description: spec.info.description.clone(),
+            operations,
+            types,
+            tags,
+            servers: spec.servers.iter().map(server).collect(),
+            security: security_schemes(spec),
+        }
+    }
+
+    /// Look up a named type
+    pub fn type_def(&self, name: &str) -> Option<&TypeDef> {
+        self.types.get(name)
+    }
+
+    /// Whether a named type can reach itself through references, directly or
+    /// via other named types
+    pub fn is_recursive(&self, name: &str) -> bool {
+        let mut seen = HashSet::new();
+        let mut stack = vec![name];
+        while let Some(current) = stack.pop() {
+            let Some(ty) = self.type_def(current) else {
+                continue;
+            };
+            for reference in ty.references() {
+                if reference == name {
+                    return true;
+                }
+                if seen.insert(reference) {
+                    stack.push(reference);
+                }
+            }
+        }
+        false
+    }
+
+    /// Operations tagged with `tag`
+    pub fn operations_by_tag<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a Operation> {
+        self.operations
+            .iter()
+            .filter(move |op| op.tags.iter().any(|name| name == tag))
+    }
+}
+
+fn server(server: &openapiv3::Server) -> Server {
+    Server {
+        url: server.url.clone(),
+        description: server.description.clone(),
+        variables: server
+            .variables
+            .iter()
+            .flatten()
+            .map(|(name, variable)| {
+                (
+                    name.clone(),
+                    ServerVariable {
+                        default: variable.default.clone(),
+                        values: variable.enumeration.clone(),
+                        description: variable.description.clone(),
+                    },
+                )
+            })
+            .collect(),
+    }
+}
+
+fn security_schemes(spec: &OpenAPI) -> Vec<SecurityScheme> {
+    let Some(components) = &spec.components else {
+        return Vec::new();
+    };
+
+    components
+        .security_schemes
+        .iter()
+        .filter_map(|(name, scheme)| {
+            let ReferenceOr::Item(scheme) = scheme else {
+                return None;
+            };
+            let (description, kind) = match scheme {
+                openapiv3::SecurityScheme::APIKey {
+                    location,
+                    name,
+                    description,
+                    ..
+                } => (
+                    description,
+                    SecurityKind::ApiKey {
+                        location: match location {
+                            APIKeyLocation::Query => ApiKeyLocation::Query,
+                            APIKeyLocation::Header => ApiKeyLocation::Header,
+                            APIKeyLocation::Cookie => ApiKeyLocation::Cookie,
+                        },
+                        parameter_name: name.clone(),
+                    },
+                ),
+                openapiv3::SecurityScheme::HTTP {
+                    scheme,
+                    bearer_format,
+                    description,
+                    ..
+                } => (
+                    description,
+                    SecurityKind::Http {
+                        scheme: scheme.to_ascii_lowercase(),
+                        bearer_format: bearer_format.clone(),
+                    },
+                ),
+                openapiv3::SecurityScheme::OAuth2 {
+                    flows, description, ..
+                } => (
+                    description,
+                    SecurityKind::OAuth2 {
+                        scopes: oauth2_scopes(flows),
+                    },
+                ),
+                openapiv3::SecurityScheme::OpenIDConnect {
+                    open_id_connect_url,
+                    description,
+                    ..
+                } => (
+                    description,
+                    SecurityKind::OpenIdConnect {
+                        url: open_id_connect_url.clone(),
+                    },
+                ),
+            };
+            Some(SecurityScheme {
+                name: name.clone(),
+                description: description.clone(),
+                kind,
+            })
+        })
+        .collect()
+}
+
+/// The flow structs keep their fields private, so read the scopes from JSON
+fn oauth2_scopes(flows: &openapiv3::OAuth2Flows) -> IndexMap<String, String> {
+    let flows = serde_json::to_value(flows).unwrap_or_default();
+    let mut scopes = IndexMap::new();
+    for flow in [
+        "implicit",
+        "password",
+        "clientCredentials",
+        "authorizationCode",
+    ] {
+        let Some(flow_scopes) = flows[flow]["scopes"].as_object() else {
+            continue;
+        };
+        for (scope, description) in flow_scopes {
+            scopes
+                .entry(scope.clone())
+                .or_insert_with(|| description.as_str().unwrap_or_default().to_string());
+        }
+    }
+    scopes
+}
@@ -0,0 +1,385 @@
+use indexmap::IndexMap;
+use openapiv3::{
+    MediaType, OpenAPI, Parameter, ParameterSchemaOrContent, PathItem, PathStyle, QueryStyle,
+    Response as ResponseObject, SecurityRequirement, StatusCode,
+};
+
+use crate::ir::types::{TypeDef, type_def};
+use crate::resolve_ref::{
+    HeaderResolver, OpenApiResolver, ParameterResolver, RequestBodyResolver, ResponseResolver,
+};
+
+/// A single HTTP operation with everything resolved
+#[derive(Debug, Clone, PartialEq)]
+pub struct Operation {
+    /// Unique camelCase name, see [`crate::naming::operation_names`]
+    pub name: String,
+    pub operation_id: Option<String>,
+    /// Lowercase HTTP method, e.g. `get`
+    pub method: String,
+    /// The path template, e.g. `/pets/{petId}`
+    pub path: String,
+    pub summary: Option<String>,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub deprecated: bool,
+    pub path_params: Vec<Param>,
+    pub query_params: Vec<Param>,
+    pub header_params: Vec<Param>,
+    pub cookie_params: Vec<Param>,
+    pub body: Option<Body>,
+    pub responses: Responses,
+    /// Effective security requirements, inherited from the document when the
+    /// operation declares none; empty when the operation is public
+    pub security: Vec<SecurityRequirement>,
+    /// `x-` extensions declared on the operation
+    pub extensions: IndexMap<String, serde_json::Value>,
+}
+
+impl Operation {
+    /// All parameters in path, query, header, cookie order
+    pub fn params(&self) -> impl Iterator<Item = &Param> {
+        self.path_params
+            .iter()
+            .chain(&self.query_params)
+            .chain(&self.header_params)
+            .chain(&self.cookie_params)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParamLocation {
+    Path,
+    Query,
+    Header,
+    Cookie,
+}
+
+/// How a parameter is serialized, see the `style` keyword
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParamStyle {
+    Simple,
+    Label,
+    Matrix,
+    Form,
+    SpaceDelimited,
+    PipeDelimited,
+    DeepObject,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Param {
+    pub name: String,
+    pub location: ParamLocation,
+    pub required: bool,
+    pub description: Option<String>,
+    pub deprecated: bool,
+    pub style: ParamStyle,
+    /// The effective `explode`, defaulting to `true` for `form` only
+    pub explode: bool,
+    pub allow_reserved: bool,
+    /// The media type for parameters declared with `content` instead of `schema`
+    pub content_type: Option<String>,
+    pub ty: TypeDef,
+}
+
+/// A request body with its alternative representations
+#[derive(Debug, Clone, PartialEq)]
+pub struct Body {
+    pub required: bool,
+    pub description: Option<String>,
+    /// One entry per media type, in declaration order
+    pub contents: Vec<Content>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Content {
+    pub media_type: String,
+    pub ty: TypeDef,
+    /// Per-property encoding of `multipart` and form bodies
+    pub encoding: IndexMap<String, Encoding>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Encoding {
+    pub content_type: Option<String>,
+    pub style: Option<ParamStyle>,
+    /// The effective `explode`, `true` for `form` and when no style is given
+    pub explode: bool,
+    pub allow_reserved: bool,
+}
+
+/// Responses split into successful (2XX) and error outcomes
+///
+/// `default` counts as an error unless the operation declares no 2XX response.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Responses {
+    pub success: Vec<Response>,
+    pub errors: Vec<Response>,
+}
+
+impl Responses {
+    /// All responses, successful ones first
+    pub fn iter(&self) -> impl Iterator<Item = &Response> {
+        self.success.iter().chain(&self.errors)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Status {
+    Code(u16),
+    /// A status range such as `4XX`, holding the leading digit
+    Range(u16),
+    Default,
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Code(code) => write!(f, "{}", code),
+            Status::Range(range) => write!(f, "{}XX", range),
+            Status::Default => f.write_str("default"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub status: Status,
+    pub description: String,
+    pub headers: Vec<Header>,
+    /// One entry per media type; empty for responses without a body
+    pub contents: Vec<Content>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Header {
+    pub name: String,
+    pub required: bool,
+    pub description: Option<String>,
+    pub ty: TypeDef,
+}
+
+pub(crate) fn operation(
+    name: String,
+    path: &str,
+    method: &str,
+    path_item: &PathItem,
+    operation: &openapiv3::Operation,
+    spec: &OpenAPI,
+) -> Operation {
+    let mut ir = Operation {
+        name,
+        operation_id: operation.operation_id.clone(),
+        method: method.to_string(),
+        path: path.to_string(),
+        summary: operation.summary.clone(),
+        description: operation.description.clone(),
+        tags: operation.tags.clone(),
+        deprecated: operation.deprecated,
+        path_params: Vec::new(),
+        query_params: Vec::new(),
+        header_params: Vec::new(),
+        cookie_params: Vec::new(),
+        body: operation
+            .request_body
+            .as_ref()
+            .and_then(|body| RequestBodyResolver::new().resolve_reference_or(body, spec))
+            .map(|body| Body {
+                required: body.required,
+                description: body.description.clone(),
+                contents: contents(&body.content, spec),
+            }),
+        responses: responses(&operation.responses, spec),
+        security: operation
+            .security
+            .clone()
+            .or_else(|| spec.security.clone())
+            .unwrap_or_default(),
+        extensions: operation.extensions.clone(),
+    };
+
+    // Operation parameters override path item parameters with the same name and location
+    let resolver = ParameterResolver::new();
+    let mut merged: IndexMap<(String, ParamLocation), Param> = IndexMap::new();
+    for parameter in path_item.parameters.iter().chain(&operation.parameters) {
+        if let Some(parameter) = resolver.resolve_reference_or(parameter, spec) {
+            let param = param(&parameter, spec);
+            merged.insert((param.name.clone(), param.location), param);
+        }
+    }
+    for param in merged.into_values() {
+        match param.location {
+            ParamLocation::Path => ir.path_params.push(param),
+            ParamLocation::Query => ir.query_params.push(param),
+            ParamLocation::Header => ir.header_params.push(param),
+            ParamLocation::Cookie => ir.cookie_params.push(param),
+        }
+    }
+
+    ir
+}
+
+fn param(parameter: &Parameter, spec: &OpenAPI) -> Param {
+    let data = parameter.parameter_data_ref();
+    let (location, style, allow_reserved) = match parameter {
+        Parameter::Path { style, .. } => (ParamLocation::Path, path_style(style), false),
+        Parameter::Query {
+            style,
+            allow_reserved,
+            ..
+        } => (ParamLocation::Query, query_style(style), *allow_reserved),
+        Parameter::Header { .. } => (ParamLocation::Header, ParamStyle::Simple, false),
+        Parameter::Cookie { .. } => (ParamLocation::Cookie, ParamStyle::Form, false),
+    };
+    let (ty, content_type) = match &data.format {
+        ParameterSchemaOrContent::Schema(schema) => (type_def(schema, spec), None),
+        ParameterSchemaOrContent::Content(content) => match content.first() {
+            Some((media_type, content)) => (
+                content
+                    .schema
+                    .as_ref()
+                    .map_or_else(TypeDef::any, |schema| type_def(schema, spec)),
+                Some(media_type.clone()),
+            ),
+            None => (TypeDef::any(), None),
+        },
+    };
+
+    Param {
+        name: data.name.clone(),
+        location,
+        required: data.required || location == ParamLocation::Path,
+        description: data.description.clone(),
+        deprecated: data.deprecated.unwrap_or(false),
+        style,
+        explode: data.explode.unwrap_or(style == ParamStyle::Form),
+        allow_reserved,
+        content_type,
+        ty,
+    }
+}
+
+fn path_style(style: &PathStyle) -> ParamStyle {
+    match style {
+        PathStyle::Simple => ParamStyle::Simple,
+        PathStyle::Label => ParamStyle::Label,
+        PathStyle::Matrix => ParamStyle::Matrix,
+    }
+}
+
+fn query_style(style: &QueryStyle) -> ParamStyle {
+    match style {
+        QueryStyle::Form => ParamStyle::Form,
+        QueryStyle::SpaceDelimited => ParamStyle::SpaceDelimited,
+        QueryStyle::PipeDelimited => ParamStyle::PipeDelimited,
+        QueryStyle::DeepObject => ParamStyle::DeepObject,
+    }
+}
+
+fn contents(content: &IndexMap<String, MediaType>, spec: &OpenAPI) -> Vec<Content> {
+    content
+        .iter()
+        .map(|(media_type, content)| Content {
+            media_type: media_type.clone(),
+            ty: content
+                .schema
+                .as_ref()
+                .map_or_else(TypeDef::any, |schema| type_def(schema, spec)),
+            encoding: content
+                .encoding
+                .iter()
+                .map(|(property, encoding)| {
+                    let style = encoding.style.as_ref().map(query_style);
+                    (
+                        property.clone(),
+                        Encoding {
+                            content_type: encoding.content_type.clone(),
+                            style,
+                            // openapiv3 reads a missing `explode` as `false`, so the
+                            // `form` default of `true` wins over an explicit `false`
+                            explode: style.is_none_or(|style| style == ParamStyle::Form)
+                                || encoding.explode,
+                            allow_reserved: encoding.allow_reserved,
+                        },
+                    )
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+fn responses(responses: &openapiv3::Responses, spec: &OpenAPI) -> Responses {
+    let mut grouped = Responses::default();
+    let resolver = ResponseResolver::new();
+
+    for (status, response) in &responses.responses {
+        let status = match status {
+            StatusCode::Code(code) => Status::Code(*code),
+            StatusCode::Range(range) => Status::Range(*range),
+        };
+        let Some(response) = resolver.resolve_reference_or(response, spec) else {
+            continue;
+        };
+        let response = response_def(status, &response, spec);
+        if is_success(status) {
+            grouped.success.push(response);
+        } else {
+            grouped.errors.push(response);
+        }
+    }
+
+    if let Some(response) = responses
+        .default
+        .as_ref()
+        .and_then(|response| resolver.resolve_reference_or(response, spec))
+    {
+        let response = response_def(Status::Default, &response, spec);
+        if grouped.success.is_empty() {
+            grouped.success.push(response);
+        } else {
+            grouped.errors.push(response);
+        }
+    }
+
+    grouped
+}
+
+fn is_success(status: Status) -> bool {
+    match status {
+        Status::Code(code) => (200..300).contains(&code),
+        Status::Range(range) => range == 2,
+        Status::Default => false,
+    }
+}
+
+fn response_def(status: Status, response: &ResponseObject, spec: &OpenAPI) -> Response {
+    let resolver = HeaderResolver::new();
+    Response {
+        status,
+        description: response.description.clone(),
+        headers: response
+            .headers
+            .iter()
+            .filter(|(name, _)| !name.eq_ignore_ascii_case("content-type"))
+            .filter_map(|(name, header)| {
+                let header = resolver.resolve_reference_or(header, spec)?;
+                let ty = match &header.format {
+                    ParameterSchemaOrContent::Schema(schema) => type_def(schema, spec),
+                    ParameterSchemaOrContent::Content(content) => content
+                        .values()
+                        .next()
+                        .and_then(|content| content.schema.as_ref())
+                        .map_or_else(TypeDef::any, |schema| type_def(schema, spec)),
+                };
+                Some(Header {
+                    name: name.clone(),
+                    required: header.required,
+                    description: header.description.clone(),
+                    ty,
+                })
+            })
+            .collect(),
+        contents: contents(&response.content, spec),
+    }
+}
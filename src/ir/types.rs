@@ -0,0 +1,501 @@
+use indexmap::IndexMap;
+use openapiv3::{
+    AdditionalProperties, AnySchema, OpenAPI, ReferenceOr, Schema, SchemaKind, Type,
+    VariantOrUnknownOrEmpty,
+};
+use serde_json::Value;
+
+use crate::resolve_ref::{OpenApiResolver, SchemaResolver};
+
+const SCHEMA_PREFIX: &str = "#/components/schemas/";
+
+/// A normalized schema
+///
+/// References to `components.schemas` are kept as [`TypeKind::Reference`] so
+/// generators can emit named types; every other construct is inlined.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeDef {
+    pub kind: TypeKind,
+    pub nullable: bool,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub deprecated: bool,
+    pub read_only: bool,
+    pub write_only: bool,
+    pub default: Option<Value>,
+    pub example: Option<Value>,
+    pub constraints: Constraints,
+    /// `x-` extensions declared on the schema
+    pub extensions: IndexMap<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TypeKind {
+    Primitive {
+        primitive: Primitive,
+        /// The declared `format`, e.g. `date-time` or `int64`
+        format: Option<String>,
+    },
+    Array(Box<TypeDef>),
+    Object(ObjectDef),
+    Enum {
+        primitive: Primitive,
+        /// The allowed values, without `null`
+        values: Vec<Value>,
+    },
+    /// `oneOf` (exclusive) or `anyOf`
+    Union {
+        variants: Vec<TypeDef>,
+        exclusive: bool,
+        discriminator: Option<Discriminator>,
+    },
+    /// `allOf`
+    Intersection(Vec<TypeDef>),
+    /// A named type in [`crate::ir::ApiModel::types`]
+    Reference(String),
+    Any,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Primitive {
+    String,
+    Number,
+    Integer,
+    Boolean,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObjectDef {
+    pub properties: Vec<Property>,
+    /// `None` when additional properties are not allowed or not declared,
+    /// [`TypeKind::Any`] for `additionalProperties: true`
+    pub additional_properties: Option<Box<TypeDef>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Property {
+    pub name: String,
+    pub required: bool,
+    pub ty: TypeDef,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Discriminator {
+    pub property_name: String,
+    /// Discriminator value to type name, explicit mappings first and the implicit
+    /// ones (the type name itself) for the remaining variants
+    pub mapping: IndexMap<String, String>,
+}
+
+/// Validation keywords that generators may surface as documentation or checks
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Constraints {
+    pub min_length: Option<usize>,
+    pub max_length: Option<usize>,
+    pub pattern: Option<String>,
+    pub minimum: Option<f64>,
+    pub maximum: Option<f64>,
+    pub exclusive_minimum: bool,
+    pub exclusive_maximum: bool,
+    pub multiple_of: Option<f64>,
+    pub min_items: Option<usize>,
+    pub max_items: Option<usize>,
+    pub unique_items: bool,
+    pub min_properties: Option<usize>,
+    pub max_properties: Option<usize>,
+}
+
+impl Constraints {
+    pub fn is_empty(&self) -> bool {
+        *self == Constraints::default()
+    }
+}
+
+impl TypeDef {
+    pub fn new(kind: TypeKind) -> Self {
+        TypeDef {
+            kind,
+            nullable: false,
+            title: None,
+            description: None,
+            deprecated: false,
+            read_only: false,
+            write_only: false,
+            default: None,
+            example: None,
+            constraints: Constraints::default(),
+            extensions: IndexMap::new(),
+        }
+    }
+
+    pub fn any() -> Self {
+        TypeDef::new(TypeKind::Any)
+    }
+
+    /// Names of all types referenced from this tree, in order of appearance
+    pub fn references(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        self.collect_references(&mut names);
+        names
+    }
+
+    fn collect_references<'a>(&'a self, names: &mut Vec<&'a str>) {
+        match &self.kind {
+            TypeKind::Reference(name) => {
+                if !names.contains(&name.as_str()) {
+                    names.push(name);
+                }
+            }
+            TypeKind::Array(items) => items.collect_references(names),
+            TypeKind::Object(object) => {
+                for property in &object.properties {
+                    property.ty.collect_references(names);
+                }
+                if let Some(additional) = &object.additional_properties {
+                    additional.collect_references(names);
+                }
+            }
+            TypeKind::Union { variants, .. } | TypeKind::Intersection(variants) => {
+                for variant in variants {
+                    variant.collect_references(names);
+                }
+            }
+            TypeKind::Primitive { .. } | TypeKind::Enum { .. } | TypeKind::Any => {}
+        }
+    }
+}
+
+/// The component name of a `#/components/schemas/...` reference
+pub(crate) fn schema_name(reference: &str) -> Option<&str> {
+    reference
+        .strip_prefix(SCHEMA_PREFIX)
+        .filter(|name| !name.contains('/'))
+}
+
+/// Convert a schema or a reference to one
+pub(crate) fn type_def(schema: &ReferenceOr<Schema>, spec: &OpenAPI) -> TypeDef {
+    match schema {
+        ReferenceOr::Reference { reference } => reference_def(reference, spec),
+        ReferenceOr::Item(schema) => schema_def(schema, spec),
+    }
+}
+
+pub(crate) fn boxed_type_def(schema: &ReferenceOr<Box<Schema>>, spec: &OpenAPI) -> TypeDef {
+    match schema {
+        ReferenceOr::Reference { reference } => reference_def(reference, spec),
+        ReferenceOr::Item(schema) => schema_def(schema, spec),
+    }
+}
+
+fn reference_def(reference: &str, spec: &OpenAPI) -> TypeDef {
+    if let Some(name) = schema_name(reference)
+        && spec
+            .components
+            .as_ref()
+            .is_some_and(|components| components.schemas.contains_key(name))
+    {
+        return TypeDef::new(TypeKind::Reference(name.to_string()));
+    }
+
+    SchemaResolver::new()
+        .resolve_reference(reference, spec)
+        .map_or_else(TypeDef::any, |schema| schema_def(&schema, spec))
+}
+
+pub(crate) fn schema_def(schema: &Schema, spec: &OpenAPI) -> TypeDef {
+    let data = &schema.schema_data;
+    let mut constraints = Constraints::default();
+    let mut nullable = data.nullable;
+
+    let kind = match &schema.schema_kind {
+        SchemaKind::Type(Type::String(string)) => {
+            constraints.min_length = string.min_length;
+            constraints.max_length = string.max_length;
+            constraints.pattern = string.pattern.clone();
+            nullable |= string.enumeration.contains(&None);
+            let values: Vec<Value> = string
+                .enumeration
+                .iter()
+                .flatten()
+                .map(|value| Value::from(value.as_str()))
+                .collect();
+            enum_or_primitive(Primitive::String, values, format_name(&string.format))
+        }
+        SchemaKind::Type(Type::Number(number)) => {
+            constraints.minimum = number.minimum;
+            constraints.maximum = number.maximum;
+            constraints.exclusive_minimum = number.exclusive_minimum;
+            constraints.exclusive_maximum = number.exclusive_maximum;
+            constraints.multiple_of = number.multiple_of;
+            nullable |= number.enumeration.contains(&None);
+            let values: Vec<Value> = number
+                .enumeration
+                .iter()
+                .flatten()
+                .map(|value| Value::from(*value))
+                .collect();
+            enum_or_primitive(Primitive::Number, values, format_name(&number.format))
+        }
+        SchemaKind::Type(Type::Integer(integer)) => {
+            constraints.minimum = integer.minimum.map(|value| value as f64);
+            constraints.maximum = integer.maximum.map(|value| value as f64);
+            constraints.exclusive_minimum = integer.exclusive_minimum;
+            constraints.exclusive_maximum = integer.exclusive_maximum;
+            constraints.multiple_of = integer.multiple_of.map(|value| value as f64);
+            nullable |= integer.enumeration.contains(&None);
+            let values: Vec<Value> = integer
+                .enumeration
+                .iter()
+                .flatten()
+                .map(|value| Value::from(*value))
+                .collect();
+            enum_or_primitive(Primitive::Integer, values, format_name(&integer.format))
+        }
+        SchemaKind::Type(Type::Boolean(boolean)) => {
+            nullable |= boolean.enumeration.contains(&None);
+            let values: Vec<Value> = boolean
+                .enumeration
+                .iter()
+                .flatten()
+                .map(|value| Value::from(*value))
+                .collect();
+            enum_or_primitive(Primitive::Boolean, values, None)
+        }
+        SchemaKind::Type(Type::Object(object)) => {
+            constraints.min_properties = object.min_properties;
+            constraints.max_properties = object.max_properties;
+            TypeKind::Object(object_def(
+                &object.properties,
+                &object.required,
+                object.additional_properties.as_ref(),
+                spec,
+            ))
+        }
+        SchemaKind::Type(Type::Array(array)) => {
+            constraints.min_items = array.min_items;
+            constraints.max_items = array.max_items;
+            constraints.unique_items = array.unique_items;
+            TypeKind::Array(Box::new(
+                array
+                    .items
+                    .as_ref()
+                    .map_or_else(TypeDef::any, |items| boxed_type_def(items, spec)),
+            ))
+        }
+        SchemaKind::OneOf { one_of } => union_kind(one_of, true, schema, spec),
+        SchemaKind::AnyOf { any_of } => union_kind(any_of, false, schema, spec),
+        SchemaKind::AllOf { all_of } => {
+            let members: Vec<TypeDef> = all_of.iter().map(|item| type_def(item, spec)).collect();
+            return with_schema_data(intersection(members), schema, constraints);
+        }
+        SchemaKind::Not { .. } => TypeKind::Any,
+        SchemaKind::Any(any) => {
+            nullable |= any.enumeration.contains(&Value::Null);
+            return with_schema_data(any_schema_def(any, schema, spec), schema, constraints)
+                .nullable_if(nullable);
+        }
+    };
+
+    with_schema_data(TypeDef::new(kind), schema, constraints).nullable_if(nullable)
+}
+
+impl TypeDef {
+    fn nullable_if(mut self, nullable: bool) -> Self {
+        self.nullable |= nullable;
+        self
+    }
+}
+
+/// Copy documentation and flags from the schema onto the converted type
+fn with_schema_data(mut ty: TypeDef, schema: &Schema, constraints: Constraints) -> TypeDef {
+    let data = &schema.schema_data;
+    ty.nullable |= data.nullable;
+    ty.deprecated |= data.deprecated;
+    ty.read_only |= data.read_only;
+    ty.write_only |= data.write_only;
+    if data.title.is_some() {
+        ty.title = data.title.clone();
+    }
+    if data.description.is_some() {
+        ty.description = data.description.clone();
+    }
+    if data.default.is_some() {
+        ty.default = data.default.clone();
+    }
+    if data.example.is_some() {
+        ty.example = data.example.clone();
+    }
+    if !constraints.is_empty() {
+        ty.constraints = constraints;
+    }
+    ty.extensions.extend(data.extensions.clone());
+    ty
+}
+
+fn enum_or_primitive(primitive: Primitive, values: Vec<Value>, format: Option<String>) -> TypeKind {
+    if values.is_empty() {
+        TypeKind::Primitive { primitive, format }
+    } else {
+        TypeKind::Enum { primitive, values }
+    }
+}
+
+fn format_name<T: serde::Serialize>(format: &VariantOrUnknownOrEmpty<T>) -> Option<String> {
+    match format {
+        VariantOrUnknownOrEmpty::Item(format) => serde_json::to_value(format)
+            .ok()
+            .and_then(|value| value.as_str().map(String::from)),
+        VariantOrUnknownOrEmpty::Unknown(format) => Some(format.clone()),
+        VariantOrUnknownOrEmpty::Empty => None,
+    }
+}
+
+fn object_def(
+    properties: &IndexMap<String, ReferenceOr<Box<Schema>>>,
+    required: &[String],
+    additional_properties: Option<&AdditionalProperties>,
+    spec: &OpenAPI,
+) -> ObjectDef {
+    ObjectDef {
+        properties: properties
+            .iter()
+            .map(|(name, schema)| Property {
+                name: name.clone(),
+                required: required.contains(name),
+                ty: boxed_type_def(schema, spec),
+            })
+            .collect(),
+        additional_properties: match additional_properties {
+            Some(AdditionalProperties::Any(true)) => Some(Box::new(TypeDef::any())),
+            Some(AdditionalProperties::Schema(schema)) => Some(Box::new(type_def(schema, spec))),
+            Some(AdditionalProperties::Any(false)) | None => None,
+        },
+    }
+}
+
+fn union_kind(
+    variants: &[ReferenceOr<Schema>],
+    exclusive: bool,
+    schema: &Schema,
+    spec: &OpenAPI,
+) -> TypeKind {
+    let variants: Vec<TypeDef> = variants.iter().map(|item| type_def(item, spec)).collect();
+    let discriminator = schema
+        .schema_data
+        .discriminator
+        .as_ref()
+        .map(|discriminator| {
+            let mut mapping: IndexMap<String, String> = discriminator
+                .mapping
+                .iter()
+                .map(|(value, target)| {
+                    let name = schema_name(target).unwrap_or(target);
+                    (value.clone(), name.to_string())
+                })
+                .collect();
+            for variant in &variants {
+                if let TypeKind::Reference(name) = &variant.kind
+                    && !mapping.values().any(|target| target == name)
+                {
+                    mapping.insert(name.clone(), name.clone());
+                }
+            }
+            Discriminator {
+                property_name: discriminator.property_name.clone(),
+                mapping,
+            }
+        });
+
+    TypeKind::Union {
+        variants,
+        exclusive,
+        discriminator,
+    }
+}
+
+/// `allOf` with a single member is the member itself
+fn intersection(mut members: Vec<TypeDef>) -> TypeDef {
+    if members.len() == 1 {
+        members.remove(0)
+    } else {
+        TypeDef::new(TypeKind::Intersection(members))
+    }
+}
+
+/// Convert a schema without a recognized `type`, which may mix keywords
+fn any_schema_def(any: &AnySchema, schema: &Schema, spec: &OpenAPI) -> TypeDef {
+    let constraints = Constraints {
+        min_length: any.min_length,
+        max_length: any.max_length,
+        pattern: any.pattern.clone(),
+        minimum: any.minimum,
+        maximum: any.maximum,
+        exclusive_minimum: any.exclusive_minimum.unwrap_or(false),
+        exclusive_maximum: any.exclusive_maximum.unwrap_or(false),
+        multiple_of: any.multiple_of,
+        min_items: any.min_items,
+        max_items: any.max_items,
+        unique_items: any.unique_items.unwrap_or(false),
+        min_properties: any.min_properties,
+        max_properties: any.max_properties,
+    };
+
+    let has_object_keywords = !any.properties.is_empty() || any.additional_properties.is_some();
+    let object = || {
+        TypeDef::new(TypeKind::Object(object_def(
+            &any.properties,
+            &any.required,
+            any.additional_properties.as_ref(),
+            spec,
+        )))
+    };
+
+    let mut ty = if !any.all_of.is_empty() {
+        let mut members: Vec<TypeDef> =
+            any.all_of.iter().map(|item| type_def(item, spec)).collect();
+        if has_object_keywords {
+            members.push(object());
+        }
+        intersection(members)
+    } else if !any.one_of.is_empty() {
+        TypeDef::new(union_kind(&any.one_of, true, schema, spec))
+    } else if !any.any_of.is_empty() {
+        TypeDef::new(union_kind(&any.any_of, false, schema, spec))
+    } else {
+        let primitive = match any.typ.as_deref() {
+            Some("string") => Some(Primitive::String),
+            Some("number") => Some(Primitive::Number),
+            Some("integer") => Some(Primitive::Integer),
+            Some("boolean") => Some(Primitive::Boolean),
+            _ => None,
+        };
+        let values: Vec<Value> = any
+            .enumeration
+            .iter()
+            .filter(|value| !value.is_null())
+            .cloned()
+            .collect();
+
+        match (any.typ.as_deref(), primitive) {
+            (_, Some(primitive)) => {
+                TypeDef::new(enum_or_primitive(primitive, values, any.format.clone()))
+            }
+            (Some("array"), _) | (None, _) if any.items.is_some() => {
+                TypeDef::new(TypeKind::Array(Box::new(
+                    any.items
+                        .as_ref()
+                        .map_or_else(TypeDef::any, |items| boxed_type_def(items, spec)),
+                )))
+            }
+            (Some("array"), _) => TypeDef::new(TypeKind::Array(Box::new(TypeDef::any()))),
+            (Some("object"), _) => object(),
+            (None, _) if has_object_keywords => object(),
+            _ => TypeDef::any(),
+        }
+    };
+
+    if !matches!(ty.kind, TypeKind::Reference(_)) && ty.constraints.is_empty() {
+        ty.constraints = constraints;
+    }
+    ty
+}
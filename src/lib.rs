@@ -1,5 +1,14 @@
 // Re-export the public API
+pub mod conformance;
+pub mod generators;
+pub mod ir;
+pub mod lint;
+pub mod mock;
+pub mod naming;
 pub mod resolve_ref;
+pub mod transform;
+pub mod validate;
+pub mod walk;
 
 #[cfg(test)]
 mod tests {
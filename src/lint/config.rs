@@ -0,0 +1,148 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+/// Severity of a lint diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The rule is disabled
+    Off,
+    Info,
+    #[serde(alias = "warning")]
+    Warn,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Severity::Off => "off",
+            Severity::Info => "info",
+            Severity::Warn => "warn",
+            Severity::Error => "error",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Configuration of a single rule
+///
+/// A rule can either be configured with a bare severity (`operation-summary: error`)
+/// or with a map holding an optional severity plus rule specific options.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RuleConfig {
+    Severity(Severity),
+    Detailed {
+        #[serde(default)]
+        severity: Option<Severity>,
+        #[serde(flatten)]
+        options: IndexMap<String, serde_json::Value>,
+    },
+}
+
+impl RuleConfig {
+    /// The configured severity, if any
+    pub fn severity(&self) -> Option<Severity> {
+        match self {
+            RuleConfig::Severity(severity) => Some(*severity),
+            RuleConfig::Detailed { severity, .. } => *severity,
+        }
+    }
+
+    /// Rule specific options
+    pub fn options(&self) -> Option<&IndexMap<String, serde_json::Value>> {
+        match self {
+            RuleConfig::Severity(_) => None,
+            RuleConfig::Detailed { options, .. } => Some(options),
+        }
+    }
+}
+
+/// Lint configuration, usually loaded from a YAML or JSON file
+///
+/// ```yaml
+/// rules:
+///   operation-summary: error
+///   property-camel-case: off
+///   pagination-parameters:
+///     severity: warn
+///     names: [page, pageSize]
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LintConfig {
+    #[serde(default)]
+    pub rules: IndexMap<String, RuleConfig>,
+}
+
+impl LintConfig {
+    /// Load a configuration file from disk
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, LintConfigError> {
+        fs::read_to_string(path)?.parse()
+    }
+
+    /// Set the severity of a rule, keeping any configured options
+    pub fn set_severity(&mut self, rule: &str, severity: Severity) {
+        match self.rules.get_mut(rule) {
+            Some(RuleConfig::Detailed {
+                severity: current, ..
+            }) => *current = Some(severity),
+            Some(config) => *config = RuleConfig::Severity(severity),
+            None => {
+                self.rules
+                    .insert(rule.to_string(), RuleConfig::Severity(severity));
+            }
+        }
+    }
+}
+
+impl FromStr for LintConfig {
+    type Err = LintConfigError;
+
+    /// Parse a configuration from a YAML or JSON string
+    fn from_str(data: &str) -> Result<Self, Self::Err> {
+        Ok(serde_yaml::from_str(data)?)
+    }
+}
+
+/// Errors that can occur while loading a lint configuration
+#[derive(Debug)]
+pub enum LintConfigError {
+    Io(std::io::Error),
+    Parse(serde_yaml::Error),
+}
+
+impl fmt::Display for LintConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LintConfigError::Io(err) => write!(f, "could not read lint config: {}", err),
+            LintConfigError::Parse(err) => write!(f, "invalid lint config: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for LintConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LintConfigError::Io(err) => Some(err),
+            LintConfigError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<std::io::Error> for LintConfigError {
+    fn from(err: std::io::Error) -> Self {
+        LintConfigError::Io(err)
+    }
+}
+
+impl From<serde_yaml::Error> for LintConfigError {
+    fn from(err: serde_yaml::Error) -> Self {
+        LintConfigError::Parse(err)
+    }
+}
@@ -0,0 +1,325 @@
+//! OpenAPI lint engine
+//!
+//! This module checks an OpenAPI specification against a configurable set of
+//! style rules. Rules implement the [`LintRule`] trait; the built-in rule set can
+//! be extended with custom rules, and each rule's severity can be configured
+//! through a [`LintConfig`]. Any object in the specification can opt out of rules
+//! with an `x-rustval-ignore` extension holding a rule name, a list of rule names
+//! or `true` to ignore every rule below that object.
+
+mod config;
+mod rules;
+
+use indexmap::IndexMap;
+use openapiv3::OpenAPI;
+use serde::Serialize;
+
+use crate::walk::unescape_pointer;
+
+// Re-export the public API
+pub use config::{LintConfig, LintConfigError, RuleConfig, Severity};
+pub use rules::{
+    NoInlineResponseSchema, OperationSummary, OperationTags, PaginationParameters, PathKebabCase,
+    PropertyCamelCase, builtin_rules,
+};
+
+/// Extension used to silence rules on an object and everything below it
+pub const IGNORE_EXTENSION: &str = "x-rustval-ignore";
+
+/// A single rule violation reported by a [`LintRule`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// JSON pointer of the offending node, e.g. `#/paths/~1pets/get`
+    pub pointer: String,
+    pub message: String,
+}
+
+impl Violation {
+    pub fn new(pointer: impl Into<String>, message: impl Into<String>) -> Self {
+        Violation {
+            pointer: pointer.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// A violation together with the rule that produced it and its effective severity
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    pub rule: String,
+    pub severity: Severity,
+    pub pointer: String,
+    pub message: String,
+}
+
+/// Everything a rule gets access to while checking a specification
+pub struct LintContext<'a> {
+    pub spec: &'a OpenAPI,
+    /// Rule specific options from the configuration file
+    pub options: &'a IndexMap<String, serde_json::Value>,
+}
+
+/// A lint rule that can be run against an OpenAPI specification
+pub trait LintRule {
+    /// The unique rule name used in configuration files and ignore annotations
+    fn name(&self) -> &str;
+
+    /// The severity used when the configuration does not mention the rule
+    fn default_severity(&self) -> Severity {
+        Severity::Warn
+    }
+
+    /// Check the specification and return every violation found
+    fn check(&self, ctx: &LintContext<'_>) -> Vec<Violation>;
+}
+
+/// Runs a set of lint rules with a given configuration
+pub struct Linter {
+    rules: Vec<Box<dyn LintRule>>,
+    config: LintConfig,
+}
+
+impl Linter {
+    /// Create a linter with the built-in rule set
+    pub fn new(config: LintConfig) -> Self {
+        Linter {
+            rules: builtin_rules(),
+            config,
+        }
+    }
+
+    /// Create a linter without any rules
+    pub fn empty(config: LintConfig) -> Self {
+        Linter {
+            rules: Vec::new(),
+            config,
+        }
+    }
+
+    /// Add a custom rule
+    pub fn with_rule(mut self, rule: impl LintRule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// The rules this linter runs
+    pub fn rules(&self) -> impl Iterator<Item = &dyn LintRule> {
+        self.rules.iter().map(|rule| rule.as_ref())
+    }
+
+    /// Lint a specification and return all diagnostics that are not turned off
+    /// or ignored through `x-rustval-ignore`
+    pub fn lint(&self, spec: &OpenAPI) -> Vec<Diagnostic> {
+        let document = serde_json::to_value(spec).unwrap_or_default();
+        let no_options = IndexMap::new();
+        let mut diagnostics = Vec::new();
+
+        for rule in &self.rules {
+            let rule_config = self.config.rules.get(rule.name());
+            let severity = rule_config
+                .and_then(RuleConfig::severity)
+                .unwrap_or_else(|| rule.default_severity());
+            if severity == Severity::Off {
+                continue;
+            }
+
+            let ctx = LintContext {
+                spec,
+                options: rule_config
+                    .and_then(RuleConfig::options)
+                    .unwrap_or(&no_options),
+            };
+
+            for violation in rule.check(&ctx) {
+                if is_ignored(&document, &violation.pointer, rule.name()) {
+                    continue;
+                }
+                diagnostics.push(Diagnostic {
+                    rule: rule.name().to_string(),
+                    severity,
+                    pointer: violation.pointer,
+                    message: violation.message,
+                });
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// Lint a specification with the built-in rules and the given configuration
+pub fn lint(spec: &OpenAPI, config: &LintConfig) -> Vec<Diagnostic> {
+    Linter::new(config.clone()).lint(spec)
+}
+
+/// Check whether a rule is ignored on the node at `pointer` or any of its ancestors
+fn is_ignored(document: &serde_json::Value, pointer: &str, rule: &str) -> bool {
+    let mut node = document;
+    if ignores(node, rule) {
+        return true;
+    }
+
+    for segment in pointer.trim_start_matches("#/").split('/') {
+        let segment = unescape_pointer(segment);
+        let next = match node {
+            serde_json::Value::Object(map) => map.get(&segment),
+            serde_json::Value::Array(list) => {
+                segment.parse::<usize>().ok().and_then(|i| list.get(i))
+            }
+            _ => None,
+        };
+        match next {
+            Some(next) => node = next,
+            None => return false,
+        }
+        if ignores(node, rule) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn ignores(node: &serde_json::Value, rule: &str) -> bool {
+    match node.get(IGNORE_EXTENSION) {
+        Some(serde_json::Value::Bool(all)) => *all,
+        Some(serde_json::Value::String(name)) => name == rule || name == "*",
+        Some(serde_json::Value::Array(names)) => names.iter().any(|name| {
+            name.as_str()
+                .is_some_and(|name| name == rule || name == "*")
+        }),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = r#"
+openapi: 3.0.3
+info: { title: Pets, version: "1.0" }
+paths:
+  /pet_store/{petId}:
+    get:
+      operationId: getPet
+      responses:
+        "200":
+          description: ok
+          content:
+            application/json:
+              schema:
+                type: object
+                properties:
+                  pet_name: { type: string }
+  /pets:
+    get:
+      summary: List pets
+      tags: [pets]
+      x-rustval-ignore: [pagination-parameters]
+      parameters:
+        - { name: per_page, in: query, schema: { type: integer } }
+      responses:
+        "200": { description: ok }
+"#;
+
+    fn rules_hit(diagnostics: &[Diagnostic]) -> Vec<&str> {
+        diagnostics.iter().map(|d| d.rule.as_str()).collect()
+    }
+
+    #[test]
+    fn test_builtin_rules() {
+        let spec: OpenAPI = serde_yaml::from_str(SPEC).unwrap();
+        let diagnostics = lint(&spec, &LintConfig::default());
+        let hit = rules_hit(&diagnostics);
+
+        assert!(hit.contains(&"operation-summary"));
+        assert!(hit.contains(&"operation-tags"));
+        assert!(hit.contains(&"path-kebab-case"));
+        assert!(hit.contains(&"property-camel-case"));
+        assert!(hit.contains(&"no-inline-response-schema"));
+        // Silenced through x-rustval-ignore on the operation
+        assert!(!hit.contains(&"pagination-parameters"));
+    }
+
+    #[test]
+    fn test_inline_component_response() {
+        let spec: OpenAPI = serde_yaml::from_str(
+            r#"
+openapi: 3.0.3
+info: { title: Pets, version: "1.0" }
+paths: {}
+components:
+  responses:
+    Problem:
+      description: error
+      content:
+        application/json:
+          schema:
+            type: object
+            properties:
+              message: { type: string }
+"#,
+        )
+        .unwrap();
+        let diagnostics = lint(&spec, &LintConfig::default());
+        let inline = diagnostics
+            .iter()
+            .find(|d| d.rule == "no-inline-response-schema")
+            .unwrap();
+        assert_eq!(
+            inline.pointer,
+            "#/components/responses/Problem/content/application~1json/schema"
+        );
+        assert_eq!(
+            inline.message,
+            "response `Problem` declares an inline schema"
+        );
+    }
+
+    #[test]
+    fn test_config_severity() {
+        let spec: OpenAPI = serde_yaml::from_str(SPEC).unwrap();
+        let config: LintConfig = r#"
+rules:
+  operation-summary: error
+  property-camel-case: off
+"#
+        .parse()
+        .unwrap();
+        let diagnostics = lint(&spec, &config);
+
+        assert!(!rules_hit(&diagnostics).contains(&"property-camel-case"));
+        let summary = diagnostics
+            .iter()
+            .find(|d| d.rule == "operation-summary")
+            .unwrap();
+        assert_eq!(summary.severity, Severity::Error);
+        assert_eq!(summary.pointer, "#/paths/~1pet_store~1{petId}/get");
+    }
+
+    #[test]
+    fn test_custom_rule() {
+        struct NoTrace;
+        impl LintRule for NoTrace {
+            fn name(&self) -> &str {
+                "no-trace"
+            }
+            fn check(&self, ctx: &LintContext<'_>) -> Vec<Violation> {
+                ctx.spec
+                    .operations()
+                    .filter(|(_, method, _)| *method == "get")
+                    .map(|(path, method, _)| {
+                        Violation::new(crate::walk::operation_pointer(path, method), "found")
+                    })
+                    .collect()
+            }
+        }
+
+        let spec: OpenAPI = serde_yaml::from_str(SPEC).unwrap();
+        let diagnostics = Linter::empty(LintConfig::default())
+            .with_rule(NoTrace)
+            .lint(&spec);
+        assert_eq!(diagnostics.len(), 2);
+    }
+}
@@ -0,0 +1,281 @@
+use openapiv3::{ReferenceOr, Schema, SchemaKind, Type};
+
+use crate::lint::{LintContext, LintRule, Violation};
+use crate::resolve_ref::resolve_parameter_ref;
+use crate::walk::{escape_pointer, operation_pointer, visit_schemas};
+
+/// All built-in rules, in the order they are run
+pub fn builtin_rules() -> Vec<Box<dyn LintRule>> {
+    vec![
+        Box::new(OperationSummary),
+        Box::new(OperationTags),
+        Box::new(PathKebabCase),
+        Box::new(PropertyCamelCase),
+        Box::new(NoInlineResponseSchema),
+        Box::new(PaginationParameters),
+    ]
+}
+
+/// Every operation must have a non-empty `summary`
+pub struct OperationSummary;
+impl LintRule for OperationSummary {
+    fn name(&self) -> &str {
+        "operation-summary"
+    }
+
+    fn check(&self, ctx: &LintContext<'_>) -> Vec<Violation> {
+        ctx.spec
+            .operations()
+            .filter(|(_, _, op)| op.summary.as_deref().is_none_or(|s| s.trim().is_empty()))
+            .map(|(path, method, _)| {
+                Violation::new(
+                    operation_pointer(path, method),
+                    format!("{} {} has no summary", method.to_uppercase(), path),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Every operation must have at least one tag
+pub struct OperationTags;
+impl LintRule for OperationTags {
+    fn name(&self) -> &str {
+        "operation-tags"
+    }
+
+    fn check(&self, ctx: &LintContext<'_>) -> Vec<Violation> {
+        ctx.spec
+            .operations()
+            .filter(|(_, _, op)| op.tags.is_empty())
+            .map(|(path, method, _)| {
+                Violation::new(
+                    operation_pointer(path, method),
+                    format!("{} {} has no tags", method.to_uppercase(), path),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Literal path segments must be kebab-case
+pub struct PathKebabCase;
+impl LintRule for PathKebabCase {
+    fn name(&self) -> &str {
+        "path-kebab-case"
+    }
+
+    fn check(&self, ctx: &LintContext<'_>) -> Vec<Violation> {
+        ctx.spec
+            .paths
+            .paths
+            .keys()
+            .filter_map(|path| {
+                let segment = path
+                    .split('/')
+                    .filter(|segment| !segment.is_empty() && !segment.starts_with('{'))
+                    .find(|segment| !is_kebab_case(segment))?;
+                Some(Violation::new(
+                    format!("#/paths/{}", escape_pointer(path)),
+                    format!("path segment `{}` of {} is not kebab-case", segment, path),
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Schema property names must be camelCase
+pub struct PropertyCamelCase;
+impl LintRule for PropertyCamelCase {
+    fn name(&self) -> &str {
+        "property-camel-case"
+    }
+
+    fn check(&self, ctx: &LintContext<'_>) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        visit_schemas(ctx.spec, &mut |pointer, schema| {
+            if let SchemaKind::Type(Type::Object(obj)) = &schema.schema_kind {
+                for name in obj.properties.keys().filter(|name| !is_camel_case(name)) {
+                    violations.push(Violation::new(
+                        format!("{}/properties/{}", pointer, escape_pointer(name)),
+                        format!("property `{}` is not camelCase", name),
+                    ));
+                }
+            }
+        });
+        violations
+    }
+}
+
+/// Response bodies must reference named schemas instead of declaring complex ones inline
+pub struct NoInlineResponseSchema;
+impl LintRule for NoInlineResponseSchema {
+    fn name(&self) -> &str {
+        "no-inline-response-schema"
+    }
+
+    fn check(&self, ctx: &LintContext<'_>) -> Vec<Violation> {
+        // Pointers of the responses with a description of each
+        let mut responses = Vec::new();
+        if let Some(components) = &ctx.spec.components {
+            for (name, response) in &components.responses {
+                responses.push((
+                    format!("#/components/responses/{}", escape_pointer(name)),
+                    format!("response `{}`", name),
+                    response,
+                ));
+            }
+        }
+        for (path, method, operation) in ctx.spec.operations() {
+            let statuses = operation
+                .responses
+                .default
+                .iter()
+                .map(|response| ("default".to_string(), response))
+                .chain(
+                    operation
+                        .responses
+                        .responses
+                        .iter()
+                        .map(|(status, response)| (status.to_string(), response)),
+                );
+            for (status, response) in statuses {
+                responses.push((
+                    format!("{}/responses/{}", operation_pointer(path, method), status),
+                    format!("{} response of {} {}", status, method.to_uppercase(), path),
+                    response,
+                ));
+            }
+        }
+
+        let mut violations = Vec::new();
+        for (pointer, label, response) in responses {
+            let ReferenceOr::Item(response) = response else {
+                continue;
+            };
+            for (media_type, media) in &response.content {
+                if let Some(ReferenceOr::Item(schema)) = &media.schema
+                    && is_complex_inline(schema)
+                {
+                    violations.push(Violation::new(
+                        format!("{}/content/{}/schema", pointer, escape_pointer(media_type)),
+                        format!("{} declares an inline schema", label),
+                    ));
+                }
+            }
+        }
+        violations
+    }
+}
+
+/// Pagination query parameters must use the configured names
+///
+/// Options: `names` lists the allowed parameter names (defaults to `page`,
+/// `pageSize` and `cursor`).
+pub struct PaginationParameters;
+
+impl PaginationParameters {
+    const DEFAULT_NAMES: [&'static str; 3] = ["page", "pageSize", "cursor"];
+    const PAGINATION_LIKE: [&'static str; 12] = [
+        "page",
+        "pagesize",
+        "pagenumber",
+        "perpage",
+        "limit",
+        "offset",
+        "size",
+        "cursor",
+        "skip",
+        "take",
+        "after",
+        "before",
+    ];
+
+    fn is_pagination_like(name: &str) -> bool {
+        let normalized: String = name
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_ascii_lowercase();
+        Self::PAGINATION_LIKE.contains(&normalized.as_str())
+    }
+}
+
+impl LintRule for PaginationParameters {
+    fn name(&self) -> &str {
+        "pagination-parameters"
+    }
+
+    fn check(&self, ctx: &LintContext<'_>) -> Vec<Violation> {
+        let allowed: Vec<String> = match ctx.options.get("names").and_then(|v| v.as_array()) {
+            Some(names) => names
+                .iter()
+                .filter_map(|name| name.as_str().map(String::from))
+                .collect(),
+            None => Self::DEFAULT_NAMES
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+        };
+
+        let mut violations = Vec::new();
+        for (path, method, operation) in ctx.spec.operations() {
+            for (index, parameter) in operation.parameters.iter().enumerate() {
+                let parameter = match parameter {
+                    ReferenceOr::Reference { reference } => {
+                        match resolve_parameter_ref(reference, ctx.spec) {
+                            Some(parameter) => parameter,
+                            None => continue,
+                        }
+                    }
+                    ReferenceOr::Item(parameter) => parameter.clone(),
+                };
+                let openapiv3::Parameter::Query { parameter_data, .. } = parameter else {
+                    continue;
+                };
+
+                let name = &parameter_data.name;
+                if Self::is_pagination_like(name) && !allowed.contains(name) {
+                    violations.push(Violation::new(
+                        format!("{}/parameters/{}", operation_pointer(path, method), index),
+                        format!(
+                            "pagination parameter `{}` should be one of: {}",
+                            name,
+                            allowed.join(", ")
+                        ),
+                    ));
+                }
+            }
+        }
+        violations
+    }
+}
+
+fn is_camel_case(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_lowercase()) && chars.all(|c| c.is_ascii_alphanumeric())
+}
+
+fn is_kebab_case(segment: &str) -> bool {
+    segment.split('-').all(|part| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    })
+}
+
+/// Objects, compositions and arrays of those are considered complex
+fn is_complex_inline(schema: &Schema) -> bool {
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(_))
+        | SchemaKind::OneOf { .. }
+        | SchemaKind::AllOf { .. }
+        | SchemaKind::AnyOf { .. } => true,
+        SchemaKind::Type(Type::Array(array)) => match &array.items {
+            Some(ReferenceOr::Item(items)) => is_complex_inline(items),
+            _ => false,
+        },
+        _ => false,
+    }
+}
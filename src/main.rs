@@ -1,4 +1,85 @@
+use std::net::TcpListener;
+use std::time::Duration;
+use std::{env, fs, process};
+
+use openapiv3::{OpenAPI, ReferenceOr};
+use rustval::mock::{MockServer, MockServerOptions};
+
+const USAGE: &str = "usage: rustval mock <spec> [--host <host>] [--port <port>] \
+                     [--latency <ms>] [--seed <seed>] [--no-validate]";
+
 fn main() {
-    println!("Running the reference resolution example...");
-    println!("To see the example in action, run: cargo run --example resolve_references");
+    let args: Vec<String> = env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("mock") => {
+            if let Err(message) = mock(&args[1..]) {
+                eprintln!("{}", message);
+                process::exit(1);
+            }
+        }
+        _ => {
+            println!("Running the reference resolution example...");
+            println!("To see the example in action, run: cargo run --example resolve_references");
+            println!("To serve a spec with mock responses: {}", USAGE);
+        }
+    }
+}
+
+/// Serve the operations of a spec on localhost until interrupted
+fn mock(args: &[String]) -> Result<(), String> {
+    let mut spec_path = None;
+    let mut host = "127.0.0.1".to_string();
+    let mut port = 4010;
+    let mut options = MockServerOptions::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .cloned()
+                .ok_or_else(|| format!("{} needs a value\n{}", name, USAGE))
+        };
+        match arg.as_str() {
+            "--host" => host = value("--host")?,
+            "--port" => port = parse(&value("--port")?, "--port")?,
+            "--latency" => {
+                options.latency = Duration::from_millis(parse(&value("--latency")?, "--latency")?)
+            }
+            "--seed" => options.mock.seed = parse(&value("--seed")?, "--seed")?,
+            "--no-validate" => options.validate_requests = false,
+            path if spec_path.is_none() && !path.starts_with("--") => spec_path = Some(path),
+            other => return Err(format!("unexpected argument {}\n{}", other, USAGE)),
+        }
+    }
+    let spec_path = spec_path.ok_or_else(|| USAGE.to_string())?;
+
+    let data = fs::read_to_string(spec_path)
+        .map_err(|error| format!("could not read {}: {}", spec_path, error))?;
+    // YAML is a superset of JSON, so this reads both
+    let spec: OpenAPI = serde_yaml::from_str(&data)
+        .map_err(|error| format!("could not parse {}: {}", spec_path, error))?;
+
+    let listener = TcpListener::bind((host.as_str(), port))
+        .map_err(|error| format!("could not listen on {}:{}: {}", host, port, error))?;
+    println!(
+        "Mock server for {} listening on http://{}:{}",
+        spec_path, host, port
+    );
+    for (path, item) in &spec.paths.paths {
+        if let ReferenceOr::Item(item) = item {
+            for (method, _) in item.iter() {
+                println!("  {:7} {}", method.to_ascii_uppercase(), path);
+            }
+        }
+    }
+
+    MockServer::new(spec, options)
+        .serve(&listener)
+        .map_err(|error| error.to_string())
+}
+
+fn parse<T: std::str::FromStr>(value: &str, name: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value {} for {}", value, name))
 }
@@ -0,0 +1,503 @@
+use std::borrow::{Borrow, Cow};
+
+use indexmap::IndexMap;
+use openapiv3::{
+    AdditionalProperties, AnySchema, Discriminator, OpenAPI, ReferenceOr, Schema, SchemaKind,
+    StringFormat, Type, VariantOrUnknownOrEmpty,
+};
+use regex::Regex;
+use serde_json::{Map, Value};
+
+use crate::mock::rng::Rng;
+use crate::mock::strings::{format_string, pattern_string, words};
+use crate::resolve_ref::resolve_schema_ref;
+use crate::validate::Direction;
+
+/// Levels beyond [`MockOptions::max_depth`] at which generation stops with
+/// `null`, for schemas whose recursion goes through required properties
+const HARD_DEPTH_LIMIT: usize = 16;
+
+/// How many times a string with a `pattern` is regenerated to fit its length
+/// constraints, and an array item to be unique
+const ATTEMPTS: usize = 10;
+
+/// Options for [`generate_example`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockOptions {
+    /// Seed of the random generator; the same seed yields the same value
+    pub seed: u64,
+    /// Nesting depth from which optional properties are left out and arrays
+    /// get their minimum length, which ends recursive schemas
+    pub max_depth: usize,
+    /// Return a schema's `example`, or else its `default`, when declared
+    pub use_examples: bool,
+    /// Length of arrays without `minItems`/`maxItems`
+    pub array_length: (usize, usize),
+    /// Leave out `readOnly` properties of requests and `writeOnly` properties
+    /// of responses
+    pub direction: Option<Direction>,
+}
+
+impl Default for MockOptions {
+    fn default() -> Self {
+        MockOptions {
+            seed: 0,
+            max_depth: 4,
+            use_examples: true,
+            array_length: (1, 3),
+            direction: None,
+        }
+    }
+}
+
+/// Generate a JSON value that is valid against a schema
+///
+/// Strings follow their `format` and `pattern`, numbers and collections their
+/// bounds, and `oneOf`/`anyOf` pick one variant, setting the discriminator
+/// property when there is one. References are resolved against `spec` as they
+/// are reached, so recursive schemas end once [`MockOptions::max_depth`] is
+/// exceeded.
+///
+/// ### Examples
+///
+/// ```
+/// # use openapiv3::{OpenAPI, ReferenceOr};
+/// # use rustval::mock::{MockOptions, generate_example};
+/// # use rustval::validate::validate_value;
+/// let spec: OpenAPI = serde_yaml::from_str(r##"
+/// openapi: 3.0.3
+/// info: { title: Pets, version: "1.0" }
+/// paths: {}
+/// components:
+///   schemas:
+///     Pet:
+///       type: object
+///       required: [id, name]
+///       properties:
+///         id: { type: string, format: uuid }
+///         name: { type: string, maxLength: 10 }
+/// "##).unwrap();
+///
+/// let schema = ReferenceOr::ref_("#/components/schemas/Pet");
+/// let pet = generate_example(&schema, &spec, &MockOptions::default());
+/// assert!(validate_value(&pet, &schema, &spec).is_empty());
+/// assert_eq!(pet, generate_example(&schema, &spec, &MockOptions::default()));
+/// ```
+pub fn generate_example(
+    schema: &ReferenceOr<Schema>,
+    spec: &OpenAPI,
+    options: &MockOptions,
+) -> Value {
+    let mut generator = Generator {
+        spec,
+        options,
+        rng: Rng::new(options.seed),
+    };
+    generator.generate_ref(schema, 0)
+}
+
+struct Generator<'a> {
+    spec: &'a OpenAPI,
+    options: &'a MockOptions,
+    rng: Rng,
+}
+
+impl Generator<'_> {
+    fn resolve<'s, S: Borrow<Schema>>(
+        &self,
+        schema: &'s ReferenceOr<S>,
+    ) -> Option<Cow<'s, Schema>> {
+        match schema {
+            ReferenceOr::Reference { reference } => {
+                resolve_schema_ref(reference, self.spec).map(Cow::Owned)
+            }
+            ReferenceOr::Item(schema) => Some(Cow::Borrowed(schema.borrow())),
+        }
+    }
+
+    fn generate_ref<S: Borrow<Schema>>(&mut self, schema: &ReferenceOr<S>, depth: usize) -> Value {
+        match self.resolve(schema) {
+            Some(resolved) => self.generate(&resolved, depth),
+            None => Value::Null,
+        }
+    }
+
+    fn generate(&mut self, schema: &Schema, depth: usize) -> Value {
+        let data = &schema.schema_data;
+        if depth > self.options.max_depth + HARD_DEPTH_LIMIT
+            || (data.nullable && depth > self.options.max_depth)
+        {
+            return Value::Null;
+        }
+        if self.options.use_examples
+            && let Some(example) = data.example.as_ref().or(data.default.as_ref())
+        {
+            return example.clone();
+        }
+
+        match &schema.schema_kind {
+            SchemaKind::Type(Type::String(string)) => {
+                let values: Vec<&String> = string.enumeration.iter().flatten().collect();
+                if !values.is_empty() {
+                    return Value::String(self.rng.pick(&values).to_string());
+                }
+                let format = match &string.format {
+                    VariantOrUnknownOrEmpty::Item(StringFormat::Date) => Some("date"),
+                    VariantOrUnknownOrEmpty::Item(StringFormat::DateTime) => Some("date-time"),
+                    VariantOrUnknownOrEmpty::Item(StringFormat::Byte) => Some("byte"),
+                    VariantOrUnknownOrEmpty::Unknown(format) => Some(format.as_str()),
+                    _ => None,
+                };
+                Value::String(self.string(
+                    format,
+                    string.min_length,
+                    string.max_length,
+                    string.pattern.as_deref(),
+                ))
+            }
+            SchemaKind::Type(Type::Number(number)) => {
+                let values: Vec<f64> = number.enumeration.iter().flatten().copied().collect();
+                if !values.is_empty() {
+                    return Value::from(*self.rng.pick(&values));
+                }
+                self.number(
+                    number.minimum,
+                    number.exclusive_minimum,
+                    number.maximum,
+                    number.exclusive_maximum,
+                    number.multiple_of,
+                    false,
+                )
+            }
+            SchemaKind::Type(Type::Integer(integer)) => {
+                let values: Vec<i64> = integer.enumeration.iter().flatten().copied().collect();
+                if !values.is_empty() {
+                    return Value::from(*self.rng.pick(&values));
+                }
+                self.number(
+                    integer.minimum.map(|m| m as f64),
+                    integer.exclusive_minimum,
+                    integer.maximum.map(|m| m as f64),
+                    integer.exclusive_maximum,
+                    integer.multiple_of.map(|m| m as f64),
+                    true,
+                )
+            }
+            SchemaKind::Type(Type::Boolean(boolean)) => {
+                let values: Vec<bool> = boolean.enumeration.iter().flatten().copied().collect();
+                if !values.is_empty() {
+                    return Value::Bool(*self.rng.pick(&values));
+                }
+                Value::Bool(self.rng.bool())
+            }
+            SchemaKind::Type(Type::Object(object)) => self.object(
+                &object.properties,
+                &object.required,
+                object.additional_properties.as_ref(),
+                object.min_properties,
+                depth,
+            ),
+            SchemaKind::Type(Type::Array(array)) => self.array(
+                array.items.as_ref(),
+                array.min_items,
+                array.max_items,
+                array.unique_items,
+                depth,
+            ),
+            SchemaKind::OneOf { one_of } => {
+                self.variant(one_of, data.discriminator.as_ref(), depth)
+            }
+            SchemaKind::AnyOf { any_of } => {
+                self.variant(any_of, data.discriminator.as_ref(), depth)
+            }
+            SchemaKind::AllOf { all_of } => self.all_of(all_of, depth),
+            SchemaKind::Not { .. } => Value::Null,
+            SchemaKind::Any(any) => self.generate_any(any, data.discriminator.as_ref(), depth),
+        }
+    }
+
+    /// Generate for a schema that mixes keywords of several types
+    fn generate_any(
+        &mut self,
+        any: &AnySchema,
+        discriminator: Option<&Discriminator>,
+        depth: usize,
+    ) -> Value {
+        if !any.enumeration.is_empty() {
+            return self.rng.pick(&any.enumeration).clone();
+        }
+        if !any.one_of.is_empty() {
+            return self.variant(&any.one_of, discriminator, depth);
+        }
+        if !any.any_of.is_empty() {
+            return self.variant(&any.any_of, discriminator, depth);
+        }
+
+        let value = match any.typ.as_deref() {
+            Some("string") => Value::String(self.string(
+                any.format.as_deref(),
+                any.min_length,
+                any.max_length,
+                any.pattern.as_deref(),
+            )),
+            Some(typ @ ("number" | "integer")) => self.number(
+                any.minimum,
+                any.exclusive_minimum.unwrap_or_default(),
+                any.maximum,
+                any.exclusive_maximum.unwrap_or_default(),
+                any.multiple_of,
+                typ == "integer",
+            ),
+            Some("boolean") => Value::Bool(self.rng.bool()),
+            Some("array") => self.array(
+                any.items.as_ref(),
+                any.min_items,
+                any.max_items,
+                any.unique_items.unwrap_or_default(),
+                depth,
+            ),
+            None if any.items.is_some() => self.array(
+                any.items.as_ref(),
+                any.min_items,
+                any.max_items,
+                any.unique_items.unwrap_or_default(),
+                depth,
+            ),
+            Some("object") | None => self.object(
+                &any.properties,
+                &any.required,
+                any.additional_properties.as_ref(),
+                any.min_properties,
+                depth,
+            ),
+            _ => Value::Object(Map::new()),
+        };
+        if any.all_of.is_empty() {
+            return value;
+        }
+        let mut merged = self.all_of(&any.all_of, depth);
+        merge(&mut merged, value);
+        merged
+    }
+
+    fn string(
+        &mut self,
+        format: Option<&str>,
+        min_length: Option<usize>,
+        max_length: Option<usize>,
+        pattern: Option<&str>,
+    ) -> String {
+        if let Some(value) = format.and_then(|format| format_string(format, &mut self.rng)) {
+            return value;
+        }
+        let min = min_length.unwrap_or(0);
+        let max = max_length.unwrap_or(min.max(12)).max(min);
+        if let Some(pattern) = pattern {
+            let regex = Regex::new(pattern).ok();
+            let mut candidate = None;
+            for _ in 0..ATTEMPTS {
+                let Some(text) = pattern_string(pattern, &mut self.rng) else {
+                    break;
+                };
+                let length = text.chars().count();
+                let fits = length >= min && length <= max;
+                let matches = regex.as_ref().is_none_or(|regex| regex.is_match(&text));
+                if fits && matches {
+                    return text;
+                }
+                candidate.get_or_insert(text);
+            }
+            if let Some(text) = candidate {
+                return text;
+            }
+        }
+        words(&mut self.rng, min.max(1).min(max), max)
+    }
+
+    fn number(
+        &mut self,
+        minimum: Option<f64>,
+        exclusive_minimum: bool,
+        maximum: Option<f64>,
+        exclusive_maximum: bool,
+        multiple_of: Option<f64>,
+        integer: bool,
+    ) -> Value {
+        let low = minimum.unwrap_or_else(|| maximum.map_or(0.0, |max| max - 100.0).min(0.0));
+        let high = maximum.unwrap_or(low.max(0.0) + 1000.0);
+
+        if let Some(step) = multiple_of.filter(|step| *step > 0.0) {
+            let mut first = (low / step).ceil() as i64;
+            let mut last = (high / step).floor() as i64;
+            if exclusive_minimum && first as f64 * step <= low {
+                first += 1;
+            }
+            if exclusive_maximum && last as f64 * step >= high {
+                last -= 1;
+            }
+            let value = self.rng.int(first, last.max(first)) as f64 * step;
+            return if integer {
+                Value::from(value as i64)
+            } else {
+                Value::from(value)
+            };
+        }
+
+        if integer {
+            let mut first = low.ceil() as i64;
+            let mut last = high.floor() as i64;
+            if exclusive_minimum && first as f64 <= low {
+                first += 1;
+            }
+            if exclusive_maximum && last as f64 >= high {
+                last -= 1;
+            }
+            return Value::from(self.rng.int(first, last.max(first)));
+        }
+
+        let value = (self.rng.float(low, high) * 100.0).round() / 100.0;
+        let too_low = value < low || (exclusive_minimum && value <= low);
+        let too_high = value > high || (exclusive_maximum && value >= high);
+        if too_low || too_high {
+            Value::from((low + high) / 2.0)
+        } else {
+            Value::from(value)
+        }
+    }
+
+    /// Whether a property is left out for the configured direction
+    fn is_excluded<S: Borrow<Schema>>(&self, schema: &ReferenceOr<S>) -> bool {
+        let Some(schema) = self.resolve(schema) else {
+            return false;
+        };
+        match self.options.direction {
+            Some(Direction::Request) => schema.schema_data.read_only,
+            Some(Direction::Response) => schema.schema_data.write_only,
+            None => false,
+        }
+    }
+
+    fn object(
+        &mut self,
+        properties: &IndexMap<String, ReferenceOr<Box<Schema>>>,
+        required: &[String],
+        additional: Option<&AdditionalProperties>,
+        min_properties: Option<usize>,
+        depth: usize,
+    ) -> Value {
+        let mut map = Map::new();
+        for (name, schema) in properties {
+            let required = required.contains(name);
+            if (!required && depth >= self.options.max_depth) || self.is_excluded(schema) {
+                continue;
+            }
+            map.insert(name.clone(), self.generate_ref(schema, depth + 1));
+        }
+
+        let mut extra = min_properties.unwrap_or(0).saturating_sub(map.len());
+        if properties.is_empty() && depth < self.options.max_depth {
+            extra = extra.max(1);
+        }
+        for i in 0..extra {
+            let value = match additional {
+                Some(AdditionalProperties::Schema(schema)) => self.generate_ref(schema, depth + 1),
+                Some(AdditionalProperties::Any(true)) => Value::String(words(&mut self.rng, 3, 12)),
+                _ => break,
+            };
+            map.insert(format!("key{}", i + 1), value);
+        }
+        Value::Object(map)
+    }
+
+    fn array(
+        &mut self,
+        items: Option<&ReferenceOr<Box<Schema>>>,
+        min_items: Option<usize>,
+        max_items: Option<usize>,
+        unique_items: bool,
+        depth: usize,
+    ) -> Value {
+        let min = min_items.unwrap_or(0);
+        let max = max_items.unwrap_or(usize::MAX).max(min);
+        let length = if depth >= self.options.max_depth {
+            min
+        } else {
+            let (low, high) = self.options.array_length;
+            let low = low.clamp(min, max);
+            self.rng.int(low as i64, high.clamp(low, max) as i64) as usize
+        };
+
+        let mut values: Vec<Value> = Vec::with_capacity(length);
+        for _ in 0..length {
+            let mut value = self.item(items, depth);
+            for _ in 1..ATTEMPTS {
+                if !unique_items || !values.contains(&value) {
+                    break;
+                }
+                value = self.item(items, depth);
+            }
+            values.push(value);
+        }
+        Value::Array(values)
+    }
+
+    fn item(&mut self, items: Option<&ReferenceOr<Box<Schema>>>, depth: usize) -> Value {
+        match items {
+            Some(items) => self.generate_ref(items, depth + 1),
+            None => Value::String(words(&mut self.rng, 3, 12)),
+        }
+    }
+
+    /// A value for one variant of a `oneOf` or `anyOf`
+    fn variant(
+        &mut self,
+        variants: &[ReferenceOr<Schema>],
+        discriminator: Option<&Discriminator>,
+        depth: usize,
+    ) -> Value {
+        if variants.is_empty() {
+            return Value::Null;
+        }
+        let variant = self.rng.pick(variants);
+        let mut value = self.generate_ref(variant, depth);
+        if let (Some(discriminator), ReferenceOr::Reference { reference }, Value::Object(map)) =
+            (discriminator, variant, &mut value)
+        {
+            let name = reference.rsplit('/').next().unwrap_or(reference);
+            let tag = discriminator
+                .mapping
+                .iter()
+                .find(|(_, target)| *target == reference || *target == name)
+                .map_or(name, |(tag, _)| tag.as_str());
+            map.insert(
+                discriminator.property_name.clone(),
+                Value::String(tag.to_string()),
+            );
+        }
+        value
+    }
+
+    /// The union of the values generated for every `allOf` member
+    fn all_of(&mut self, members: &[ReferenceOr<Schema>], depth: usize) -> Value {
+        let mut merged = Value::Null;
+        for member in members {
+            let value = self.generate_ref(member, depth);
+            merge(&mut merged, value);
+        }
+        merged
+    }
+}
+
+/// Merge `value` into `target`, combining object properties and otherwise
+/// keeping the first non-null value
+fn merge(target: &mut Value, value: Value) {
+    match (target, value) {
+        (Value::Object(target), Value::Object(map)) => {
+            for (key, value) in map {
+                target.entry(key).or_insert(value);
+            }
+        }
+        (target @ Value::Null, value) => *target = value,
+        _ => {}
+    }
+}
@@ -0,0 +1,348 @@
+//! Mock data generation
+//!
+//! [`generate_example`] produces schema-valid sample values for test fixtures
+//! and for documentation that lacks examples. Generation is deterministic for a
+//! given [`MockOptions::seed`]. [`MockServer`] serves the operations of a spec
+//! with such values, or with the examples the spec declares, so that clients
+//! can be developed before the real service exists.
+
+mod example;
+mod rng;
+mod server;
+mod strings;
+
+// Re-export the public API
+pub use example::{MockOptions, generate_example};
+pub use server::{MockServer, MockServerOptions};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validate::{Direction, validate_value};
+    use openapiv3::{OpenAPI, ReferenceOr};
+    use serde_json::json;
+
+    const SPEC: &str = r##"
+openapi: 3.0.3
+info: { title: Zoo, version: "1.0" }
+paths: {}
+components:
+  schemas:
+    Animal:
+      oneOf:
+        - $ref: "#/components/schemas/Cat"
+        - $ref: "#/components/schemas/Dog"
+      discriminator:
+        propertyName: kind
+        mapping:
+          kitty: "#/components/schemas/Cat"
+    Cat:
+      type: object
+      required: [kind, name, born, tags]
+      properties:
+        kind: { type: string }
+        id: { type: string, format: uuid, readOnly: true }
+        name: { type: string, minLength: 2, maxLength: 8 }
+        code: { type: string, pattern: "^[A-Z]{3}-\\d{2,4}$" }
+        born: { type: string, format: date-time }
+        email: { type: string, format: email }
+        lives: { type: integer, minimum: 1, maximum: 9, exclusiveMaximum: true }
+        weight: { type: number, minimum: 0.5, maximum: 12, multipleOf: 0.5 }
+        tags:
+          type: array
+          minItems: 2
+          maxItems: 4
+          uniqueItems: true
+          items: { type: string, enum: [fluffy, grumpy, lazy, playful] }
+    Dog:
+      allOf:
+        - type: object
+          required: [kind]
+          properties:
+            kind: { type: string }
+        - type: object
+          properties:
+            good: { type: boolean }
+            size: { type: string, enum: [small, large], example: large }
+    Node:
+      type: object
+      required: [children]
+      properties:
+        value: { type: integer, multipleOf: 5 }
+        parent: { $ref: "#/components/schemas/Node" }
+        children:
+          type: array
+          items: { $ref: "#/components/schemas/Node" }
+"##;
+
+    fn spec() -> OpenAPI {
+        serde_yaml::from_str(SPEC).unwrap()
+    }
+
+    fn depth(value: &serde_json::Value) -> usize {
+        match value {
+            serde_json::Value::Object(map) => 1 + map.values().map(depth).max().unwrap_or(0),
+            serde_json::Value::Array(items) => 1 + items.iter().map(depth).max().unwrap_or(0),
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn test_generate_example() {
+        let spec = spec();
+        for name in ["Animal", "Cat", "Dog", "Node"] {
+            let schema = ReferenceOr::ref_(&format!("#/components/schemas/{}", name));
+            for seed in 0..20 {
+                let options = MockOptions {
+                    seed,
+                    ..Default::default()
+                };
+                let value = generate_example(&schema, &spec, &options);
+                let errors = validate_value(&value, &schema, &spec);
+                assert!(errors.is_empty(), "{} {}: {:?}", name, value, errors);
+                assert_eq!(value, generate_example(&schema, &spec, &options));
+            }
+        }
+
+        let cat = ReferenceOr::ref_("#/components/schemas/Cat");
+        let first = generate_example(&cat, &spec, &MockOptions::default());
+        let other = MockOptions {
+            seed: 7,
+            ..Default::default()
+        };
+        assert_ne!(first, generate_example(&cat, &spec, &other));
+        assert!(first["code"].as_str().unwrap().contains('-'));
+
+        // The discriminator follows the chosen variant and its mapping
+        let animal = ReferenceOr::ref_("#/components/schemas/Animal");
+        let kinds: Vec<serde_json::Value> = (0..20)
+            .map(|seed| {
+                let options = MockOptions {
+                    seed,
+                    ..Default::default()
+                };
+                generate_example(&animal, &spec, &options)["kind"].clone()
+            })
+            .collect();
+        assert!(kinds.contains(&json!("kitty")) && kinds.contains(&json!("Dog")));
+
+        // Examples are preferred unless disabled
+        let dog = ReferenceOr::ref_("#/components/schemas/Dog");
+        assert_eq!(
+            generate_example(&dog, &spec, &MockOptions::default())["size"],
+            "large"
+        );
+        let sizes: Vec<serde_json::Value> = (0..20)
+            .map(|seed| {
+                let options = MockOptions {
+                    seed,
+                    use_examples: false,
+                    ..Default::default()
+                };
+                generate_example(&dog, &spec, &options)["size"].clone()
+            })
+            .collect();
+        assert!(sizes.contains(&json!("small")));
+
+        // readOnly properties are left out of requests
+        let options = MockOptions {
+            direction: Some(Direction::Request),
+            ..Default::default()
+        };
+        assert!(generate_example(&cat, &spec, &options).get("id").is_none());
+        assert!(first.get("id").is_some());
+
+        // Recursion ends at the depth limit
+        let node = ReferenceOr::ref_("#/components/schemas/Node");
+        let options = MockOptions {
+            max_depth: 2,
+            ..Default::default()
+        };
+        assert!(depth(&generate_example(&node, &spec, &options)) <= 6);
+    }
+
+    const SERVER_SPEC: &str = r##"
+openapi: 3.0.3
+info: { title: Pets, version: "1.0" }
+paths:
+  /pets:
+    get:
+      parameters:
+        - { name: limit, in: query, schema: { type: integer, maximum: 100 } }
+      responses:
+        "200":
+          description: ok
+          headers:
+            X-Total-Count: { schema: { type: integer, minimum: 0 } }
+          content:
+            application/json:
+              schema: { type: array, items: { $ref: "#/components/schemas/Pet" } }
+            text/plain:
+              schema: { type: string }
+              example: Rex, Tom
+    post:
+      requestBody:
+        required: true
+        content:
+          application/json:
+            schema: { $ref: "#/components/schemas/Pet" }
+      responses:
+        "201":
+          description: created
+          content:
+            application/json:
+              schema: { $ref: "#/components/schemas/Pet" }
+              examples:
+                rex: { value: { id: 1, name: Rex } }
+                tom: { value: { id: 2, name: Tom } }
+        4XX:
+          description: error
+          content:
+            application/json:
+              schema: { type: object, required: [message], properties: { message: { type: string } } }
+  /pets/mine:
+    delete:
+      responses:
+        "204": { description: deleted }
+  /pets/{petId}:
+    get:
+      parameters:
+        - { name: petId, in: path, required: true, schema: { type: integer } }
+      responses:
+        "200":
+          description: ok
+          content:
+            application/json:
+              schema: { $ref: "#/components/schemas/Pet" }
+components:
+  schemas:
+    Pet:
+      type: object
+      required: [id, name]
+      properties:
+        id: { type: integer, readOnly: true }
+        name: { type: string, maxLength: 10 }
+"##;
+
+    #[test]
+    fn test_mock_server() {
+        use crate::conformance::{HttpRequest, check_response};
+        use std::io::{Read, Write};
+        use std::net::{TcpListener, TcpStream};
+        use std::time::{Duration, Instant};
+
+        let spec: OpenAPI = serde_yaml::from_str(SERVER_SPEC).unwrap();
+        let server = MockServer::new(spec.clone(), MockServerOptions::default());
+        let respond = |request: HttpRequest| server.respond(&request);
+        let header = |response: &crate::conformance::HttpResponse, name: &str| {
+            response
+                .headers
+                .iter()
+                .find(|(candidate, _)| candidate == name)
+                .map(|(_, value)| value.clone())
+        };
+
+        // Generated data conforms to the declared response
+        for (method, path) in [
+            ("GET", "/pets"),
+            ("GET", "/pets/7"),
+            ("DELETE", "/pets/mine"),
+        ] {
+            let response = respond(HttpRequest::new(method, path));
+            let report = check_response(&spec, method, path, &response);
+            assert!(report.is_conformant(), "{} {}: {:?}", method, path, report);
+        }
+        let response = respond(HttpRequest::new("GET", "/pets"));
+        assert_eq!(response.status, 200);
+        assert!(header(&response, "X-Total-Count").is_some());
+        assert_eq!(
+            response.body,
+            respond(HttpRequest::new("GET", "/pets")).body
+        );
+        assert_eq!(
+            respond(HttpRequest::new("DELETE", "/pets/mine")).status,
+            204
+        );
+
+        // Content negotiation
+        let response = respond(HttpRequest::new("GET", "/pets").with_header("Accept", "text/*"));
+        assert_eq!(response.body.as_deref(), Some("Rex, Tom"));
+        assert_eq!(
+            header(&response, "Content-Type").as_deref(),
+            Some("text/plain")
+        );
+        let response = respond(HttpRequest::new("GET", "/pets").with_header("Accept", "image/png"));
+        assert_eq!(response.status, 406);
+
+        // Examples and the Prefer header
+        let create = || {
+            HttpRequest::new("POST", "/pets")
+                .with_header("Content-Type", "application/json")
+                .with_body(r#"{"name": "Rex"}"#)
+        };
+        let response = respond(create());
+        assert_eq!(response.status, 201);
+        assert_eq!(response.body.as_deref(), Some(r#"{"id":1,"name":"Rex"}"#));
+        let response = respond(create().with_header("Prefer", "example=tom"));
+        assert_eq!(response.body.as_deref(), Some(r#"{"id":2,"name":"Tom"}"#));
+        let response = respond(create().with_header("Prefer", "code=409"));
+        assert_eq!(response.status, 409);
+        assert!(check_response(&spec, "POST", "/pets", &response).is_conformant());
+        assert_eq!(
+            respond(create().with_header("Prefer", "code=500")).status,
+            500
+        );
+
+        // Invalid and unknown requests
+        let response = respond(HttpRequest::new("GET", "/pets?limit=500"));
+        assert_eq!(response.status, 400);
+        assert!(response.body.unwrap().contains("limit"));
+        assert_eq!(respond(HttpRequest::new("POST", "/pets")).status, 400);
+        assert_eq!(respond(HttpRequest::new("GET", "/owners")).status, 404);
+        let response = respond(HttpRequest::new("PUT", "/pets"));
+        assert_eq!(response.status, 405);
+        assert_eq!(header(&response, "Allow").as_deref(), Some("GET, POST"));
+        let options = MockServerOptions {
+            validate_requests: false,
+            ..Default::default()
+        };
+        let lenient = MockServer::new(spec.clone(), options);
+        assert_eq!(
+            lenient
+                .respond(&HttpRequest::new("GET", "/pets?limit=500"))
+                .status,
+            200
+        );
+
+        // Over the network, with latency
+        let options = MockServerOptions {
+            latency: Duration::from_millis(50),
+            ..Default::default()
+        };
+        let server = MockServer::new(spec, options);
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        std::thread::spawn(move || server.serve(&listener));
+
+        let started = Instant::now();
+        let mut stream = TcpStream::connect(address).unwrap();
+        let body = r#"{"name":"Tom"}"#;
+        write!(
+            stream,
+            "POST /pets HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\n\
+             Prefer: example=tom\r\nContent-Length: {}\r\n\r\n{}",
+            body.len(),
+            body
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(50));
+        assert!(
+            response.starts_with("HTTP/1.1 201 Created\r\n"),
+            "{}",
+            response
+        );
+        assert!(response.ends_with("\r\n\r\n{\"id\":2,\"name\":\"Tom\"}"));
+    }
+}
@@ -0,0 +1,455 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use openapiv3::{
+    MediaType, OpenAPI, Operation, ParameterSchemaOrContent, ReferenceOr, Response, Responses,
+    StatusCode,
+};
+use serde_json::{Value, json};
+
+use crate::conformance::{
+    HttpRequest, HttpResponse, check_request, essence, find_header, is_json, match_path,
+    select_response,
+};
+use crate::mock::{MockOptions, generate_example};
+use crate::resolve_ref::{ExampleResolver, HeaderResolver, OpenApiResolver, ResponseResolver};
+use crate::validate::Direction;
+
+/// Options for [`MockServer`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockServerOptions {
+    /// Options for the data generated when a response declares no example
+    pub mock: MockOptions,
+    /// Delay before every response is sent
+    pub latency: Duration,
+    /// Answer requests that do not conform to the spec with `400 Bad Request`
+    pub validate_requests: bool,
+}
+
+impl Default for MockServerOptions {
+    fn default() -> Self {
+        MockServerOptions {
+            mock: MockOptions::default(),
+            latency: Duration::ZERO,
+            validate_requests: true,
+        }
+    }
+}
+
+/// An HTTP server answering every operation of a spec with sample responses
+///
+/// Requests are matched against the path templates of `openapi.paths`. The
+/// response is the lowest declared `2XX` status, whose body is the first
+/// declared example of the negotiated media type, or data generated from its
+/// schema. Clients select another status with `Prefer: code=404` and a named
+/// example with `Prefer: example=name`.
+#[derive(Debug, Clone)]
+pub struct MockServer {
+    spec: OpenAPI,
+    options: MockServerOptions,
+}
+
+impl MockServer {
+    pub fn new(spec: OpenAPI, options: MockServerOptions) -> Self {
+        MockServer { spec, options }
+    }
+
+    /// Serve connections from `listener`, each on its own thread, until the
+    /// listener fails
+    pub fn serve(&self, listener: &TcpListener) -> io::Result<()> {
+        thread::scope(|scope| {
+            for stream in listener.incoming() {
+                let stream = stream?;
+                scope.spawn(move || self.handle(stream));
+            }
+            Ok(())
+        })
+    }
+
+    fn handle(&self, stream: TcpStream) -> io::Result<()> {
+        let mut reader = BufReader::new(&stream);
+        let response = match read_request(&mut reader)? {
+            Some(request) => {
+                thread::sleep(self.options.latency);
+                self.respond(&request)
+            }
+            None => error(400, "malformed HTTP request"),
+        };
+        write_response(&stream, &response)
+    }
+
+    /// The response the server sends for `request`
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # use rustval::conformance::HttpRequest;
+    /// # use rustval::mock::{MockServer, MockServerOptions};
+    /// let spec = serde_yaml::from_str(r#"
+    /// openapi: 3.0.3
+    /// info: { title: Pets, version: "1.0" }
+    /// paths:
+    ///   /pets/{petId}:
+    ///     get:
+    ///       parameters:
+    ///         - { name: petId, in: path, required: true, schema: { type: integer } }
+    ///       responses:
+    ///         "200":
+    ///           description: ok
+    ///           content:
+    ///             application/json:
+    ///               schema: { type: object }
+    ///               example: { name: Rex }
+    ///         "404": { description: not found }
+    /// "#).unwrap();
+    /// let server = MockServer::new(spec, MockServerOptions::default());
+    ///
+    /// let response = server.respond(&HttpRequest::new("GET", "/pets/1"));
+    /// assert_eq!(response.status, 200);
+    /// assert_eq!(response.body.as_deref(), Some(r#"{"name":"Rex"}"#));
+    ///
+    /// let request = HttpRequest::new("GET", "/pets/1").with_header("Prefer", "code=404");
+    /// assert_eq!(server.respond(&request).status, 404);
+    /// assert_eq!(server.respond(&HttpRequest::new("GET", "/pets/rex")).status, 400);
+    /// ```
+    pub fn respond(&self, request: &HttpRequest) -> HttpResponse {
+        // CORS preflight requests of browsers on another origin
+        if request.method.eq_ignore_ascii_case("options")
+            && let Some(method) = find_header(&request.headers, "access-control-request-method")
+        {
+            let headers =
+                find_header(&request.headers, "access-control-request-headers").unwrap_or("*");
+            return HttpResponse::new(204)
+                .with_header("Access-Control-Allow-Methods", method)
+                .with_header("Access-Control-Allow-Headers", headers);
+        }
+
+        let Some(found) = match_path(&self.spec, &request.path) else {
+            return error(404, format!("no path matches {}", request.path));
+        };
+        let method = request.method.to_ascii_lowercase();
+        let Some((_, operation)) = found
+            .path_item
+            .iter()
+            .find(|(candidate, _)| *candidate == method)
+        else {
+            let allowed: Vec<String> = found
+                .path_item
+                .iter()
+                .map(|(method, _)| method.to_ascii_uppercase())
+                .collect();
+            return error(
+                405,
+                format!("{} is not allowed on {}", request.method, found.template),
+            )
+            .with_header("Allow", allowed.join(", "));
+        };
+
+        if self.options.validate_requests {
+            let report = check_request(&self.spec, request);
+            if !report.is_conformant() {
+                let issues: Vec<String> = report.issues.iter().map(ToString::to_string).collect();
+                return json_response(
+                    400,
+                    &json!({ "message": "request does not conform to the spec", "issues": issues }),
+                );
+            }
+        }
+
+        let preferences = Preferences::parse(find_header(&request.headers, "prefer"));
+        self.operation_response(operation, request, &preferences)
+    }
+
+    fn operation_response(
+        &self,
+        operation: &Operation,
+        request: &HttpRequest,
+        preferences: &Preferences,
+    ) -> HttpResponse {
+        let Some((status, declared)) = select_status(&operation.responses, preferences.code) else {
+            let message = match preferences.code {
+                Some(code) => format!("status {} is not documented", code),
+                None => "the operation documents no response".to_string(),
+            };
+            return error(500, message);
+        };
+        let Some(declared) = ResponseResolver::new().resolve_reference_or(declared, &self.spec)
+        else {
+            return error(500, "the response reference does not resolve");
+        };
+
+        let mut response = HttpResponse::new(status);
+        for (name, value) in self.response_headers(&declared) {
+            response = response.with_header(name, value);
+        }
+        if declared.content.is_empty() || status == 204 || status == 304 {
+            return response;
+        }
+
+        let Some((media_type, content)) =
+            negotiate(&declared.content, find_header(&request.headers, "accept"))
+        else {
+            let declared: Vec<&str> = declared.content.keys().map(String::as_str).collect();
+            return error(
+                406,
+                format!("acceptable media types are {}", declared.join(", ")),
+            );
+        };
+        match self.body(content, preferences.example.as_deref()) {
+            Some(body) => response
+                .with_header("Content-Type", media_type)
+                .with_body(render(media_type, &body)),
+            None => response,
+        }
+    }
+
+    /// The named example, else the first declared example, else generated data
+    fn body(&self, content: &MediaType, name: Option<&str>) -> Option<Value> {
+        let resolver = ExampleResolver::new();
+        let named = name
+            .and_then(|name| content.examples.get(name))
+            .and_then(|example| resolver.resolve_reference_or(example, &self.spec))
+            .and_then(|example| example.value);
+        let first = || {
+            content.examples.values().find_map(|example| {
+                resolver
+                    .resolve_reference_or(example, &self.spec)
+                    .and_then(|example| example.value)
+            })
+        };
+        named
+            .or_else(|| content.example.clone())
+            .or_else(first)
+            .or_else(|| {
+                let schema = content.schema.as_ref()?;
+                Some(generate_example(schema, &self.spec, &self.mock_options()))
+            })
+    }
+
+    /// Values for the headers declared by a response
+    fn response_headers(&self, response: &Response) -> Vec<(String, String)> {
+        let resolver = HeaderResolver::new();
+        response
+            .headers
+            .iter()
+            .filter_map(|(name, header)| {
+                let header = resolver.resolve_reference_or(header, &self.spec)?;
+                let value = match (header.example, &header.format) {
+                    (Some(example), _) => example,
+                    (None, ParameterSchemaOrContent::Schema(schema)) => {
+                        generate_example(schema, &self.spec, &self.mock_options())
+                    }
+                    (None, ParameterSchemaOrContent::Content(_)) => return None,
+                };
+                let value = match value {
+                    Value::String(value) => value,
+                    Value::Null => return None,
+                    value => value.to_string(),
+                };
+                Some((name.clone(), value))
+            })
+            .collect()
+    }
+
+    fn mock_options(&self) -> MockOptions {
+        MockOptions {
+            direction: Some(Direction::Response),
+            ..self.options.mock.clone()
+        }
+    }
+}
+
+/// The preferences of a `Prefer` header that select a response
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Preferences {
+    code: Option<u16>,
+    example: Option<String>,
+}
+
+impl Preferences {
+    fn parse(header: Option<&str>) -> Self {
+        let mut preferences = Preferences::default();
+        for preference in header.unwrap_or_default().split([',', ';']) {
+            let Some((name, value)) = preference.split_once('=') else {
+                continue;
+            };
+            let value = value.trim().trim_matches('"');
+            match name.trim().to_ascii_lowercase().as_str() {
+                "code" => preferences.code = value.parse().ok(),
+                "example" => preferences.example = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        preferences
+    }
+}
+
+/// The status to answer with and its declared response
+///
+/// A preferred status is looked up like a real response would be, so that
+/// `code=404` also selects `4XX` or `default`. Otherwise the lowest declared
+/// `2XX` status wins, then the first declared status, then `default` as `200`.
+fn select_status(
+    responses: &Responses,
+    preferred: Option<u16>,
+) -> Option<(u16, &ReferenceOr<Response>)> {
+    if let Some(code) = preferred {
+        return select_response(responses, code).map(|response| (code, response));
+    }
+    let status = |code: &StatusCode| match code {
+        StatusCode::Code(code) => *code,
+        StatusCode::Range(range) => range * 100,
+    };
+    responses
+        .responses
+        .iter()
+        .filter(|(code, _)| (200..300).contains(&status(code)))
+        .min_by_key(|(code, _)| status(code))
+        .or_else(|| responses.responses.first())
+        .map(|(code, response)| (status(code), response))
+        .or_else(|| responses.default.as_ref().map(|response| (200, response)))
+}
+
+/// The declared media type that best fits an `Accept` header
+///
+/// Without an `Accept` header JSON is preferred. Quality values are honored
+/// only to exclude media types with `q=0`; otherwise the order of the header
+/// decides.
+fn negotiate<'c>(
+    content: &'c indexmap::IndexMap<String, MediaType>,
+    accept: Option<&str>,
+) -> Option<(&'c str, &'c MediaType)> {
+    let Some(accept) = accept.filter(|accept| !accept.trim().is_empty()) else {
+        return content
+            .iter()
+            .find(|(name, _)| is_json(&essence(name)))
+            .or_else(|| content.first())
+            .map(|(name, media_type)| (name.as_str(), media_type));
+    };
+    accept
+        .split(',')
+        .filter(|range| {
+            !range
+                .split(';')
+                .skip(1)
+                .any(|param| matches!(param.replace(' ', "").as_str(), "q=0" | "q=0.0"))
+        })
+        .map(essence)
+        .find_map(|range| {
+            content.iter().find(|(name, _)| {
+                let name = essence(name);
+                match range.strip_suffix("/*") {
+                    Some("*") => true,
+                    Some(main) => name.split('/').next() == Some(main),
+                    None => name == range,
+                }
+            })
+        })
+        .map(|(name, media_type)| (name.as_str(), media_type))
+}
+
+/// Serialize a body: strings as is for non-JSON media types, JSON otherwise
+fn render(media_type: &str, body: &Value) -> String {
+    match body {
+        Value::String(text) if !is_json(&essence(media_type)) => text.clone(),
+        body => body.to_string(),
+    }
+}
+
+fn json_response(status: u16, body: &Value) -> HttpResponse {
+    HttpResponse::new(status)
+        .with_header("Content-Type", "application/json")
+        .with_body(body.to_string())
+}
+
+fn error(status: u16, message: impl Into<String>) -> HttpResponse {
+    json_response(status, &json!({ "message": message.into() }))
+}
+
+/// Read one request, or `None` when it is not valid HTTP/1.x
+fn read_request(reader: &mut impl BufRead) -> io::Result<Option<HttpRequest>> {
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Ok(None);
+    };
+    // Requests through a proxy use the absolute form of the target
+    let target = match target.split_once("://") {
+        Some((_, rest)) => rest.find('/').map_or("/", |i| &rest[i..]),
+        None => target,
+    };
+    let mut request = HttpRequest::new(method, target);
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            return Ok(None);
+        };
+        request = request.with_header(name.trim(), value.trim());
+    }
+
+    let length = find_header(&request.headers, "content-length")
+        .map(|length| length.parse::<usize>())
+        .transpose();
+    let Ok(length) = length else {
+        return Ok(None);
+    };
+    if let Some(length) = length.filter(|length| *length > 0) {
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body)?;
+        request.body = Some(String::from_utf8_lossy(&body).into_owned());
+    }
+    Ok(Some(request))
+}
+
+fn write_response(mut stream: impl Write, response: &HttpResponse) -> io::Result<()> {
+    let body = response.body.as_deref().unwrap_or_default();
+    let mut head = format!(
+        "HTTP/1.1 {} {}\r\n",
+        response.status,
+        reason(response.status)
+    );
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    head.push_str("Access-Control-Allow-Origin: *\r\n");
+    head.push_str("Connection: close\r\n\r\n");
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(body.as_bytes())?;
+    stream.flush()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        202 => "Accepted",
+        204 => "No Content",
+        301 => "Moved Permanently",
+        302 => "Found",
+        304 => "Not Modified",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        406 => "Not Acceptable",
+        409 => "Conflict",
+        422 => "Unprocessable Entity",
+        429 => "Too Many Requests",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
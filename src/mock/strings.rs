@@ -0,0 +1,176 @@
+use regex_syntax::hir::{Class, Hir, HirKind};
+
+use crate::mock::rng::Rng;
+
+const WORDS: &[&str] = &[
+    "lorem",
+    "ipsum",
+    "dolor",
+    "sit",
+    "amet",
+    "consectetur",
+    "adipiscing",
+    "elit",
+    "sed",
+    "do",
+    "eiusmod",
+    "tempor",
+    "incididunt",
+    "labore",
+    "magna",
+    "aliqua",
+];
+
+/// Repetitions without an upper bound, such as `+` or `*`, repeat at most this
+/// many times beyond their minimum
+const OPEN_REPETITION: u32 = 5;
+
+/// Text of `min..=max` characters made of lorem ipsum words
+pub(crate) fn words(rng: &mut Rng, min: usize, max: usize) -> String {
+    let length = rng.int(min as i64, max as i64) as usize;
+    let mut text = String::new();
+    while text.len() < length {
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(word(rng));
+    }
+    text.truncate(length);
+    if text.ends_with(' ') {
+        text.pop();
+        text.push('x');
+    }
+    text
+}
+
+fn word(rng: &mut Rng) -> &'static str {
+    WORDS[rng.index(WORDS.len())]
+}
+
+/// A value for a well-known string `format`, or `None` for other formats
+pub(crate) fn format_string(format: &str, rng: &mut Rng) -> Option<String> {
+    let value = match format {
+        "date" => date(rng),
+        "date-time" => format!(
+            "{}T{:02}:{:02}:{:02}Z",
+            date(rng),
+            rng.int(0, 23),
+            rng.int(0, 59),
+            rng.int(0, 59)
+        ),
+        "uuid" => {
+            let hex: String = (0..32)
+                .map(|i| match i {
+                    12 => '4',
+                    16 => ['8', '9', 'a', 'b'][rng.index(4)],
+                    _ => char::from_digit(rng.int(0, 15) as u32, 16).unwrap_or('0'),
+                })
+                .collect();
+            format!(
+                "{}-{}-{}-{}-{}",
+                &hex[..8],
+                &hex[8..12],
+                &hex[12..16],
+                &hex[16..20],
+                &hex[20..]
+            )
+        }
+        "email" => format!("{}.{}@example.com", word(rng), word(rng)),
+        "uri" | "url" => format!("https://example.com/{}", word(rng)),
+        "hostname" => format!("{}.example.com", word(rng)),
+        "ipv4" => format!(
+            "{}.{}.{}.{}",
+            rng.int(1, 254),
+            rng.int(0, 255),
+            rng.int(0, 255),
+            rng.int(1, 254)
+        ),
+        "ipv6" => (0..8)
+            .map(|_| format!("{:x}", rng.int(0, 0xffff)))
+            .collect::<Vec<_>>()
+            .join(":"),
+        "byte" => base64(&(0..6).map(|_| rng.int(0, 255) as u8).collect::<Vec<_>>()),
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn date(rng: &mut Rng) -> String {
+    format!(
+        "{}-{:02}-{:02}",
+        rng.int(2000, 2030),
+        rng.int(1, 12),
+        rng.int(1, 28)
+    )
+}
+
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::new();
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, byte)| n | (*byte as u32) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+        for _ in chunk.len()..3 {
+            encoded.push('=');
+        }
+    }
+    encoded
+}
+
+/// A string matching a regular expression, or `None` when it does not parse
+///
+/// Anchors and other assertions are ignored, which is what unanchored
+/// patterns need as well since any match suffices.
+pub(crate) fn pattern_string(pattern: &str, rng: &mut Rng) -> Option<String> {
+    let hir = regex_syntax::parse(pattern).ok()?;
+    let mut text = String::new();
+    write_hir(&hir, rng, &mut text);
+    Some(text)
+}
+
+fn write_hir(hir: &Hir, rng: &mut Rng, text: &mut String) {
+    match hir.kind() {
+        HirKind::Empty | HirKind::Look(_) => {}
+        HirKind::Literal(literal) => text.push_str(&String::from_utf8_lossy(&literal.0)),
+        HirKind::Class(Class::Unicode(class)) => {
+            let printable: Vec<char> = class
+                .ranges()
+                .iter()
+                .flat_map(|range| (range.start()..=range.end()).take(128))
+                .filter(|c| c.is_ascii_graphic() || *c == ' ')
+                .collect();
+            if !printable.is_empty() {
+                text.push(*rng.pick(&printable));
+            } else if let Some(range) = class.ranges().first() {
+                text.push(range.start());
+            }
+        }
+        HirKind::Class(Class::Bytes(class)) => {
+            if let Some(range) = class.ranges().first() {
+                let byte = rng.int(range.start() as i64, range.end() as i64) as u8;
+                text.push(if byte.is_ascii() { byte as char } else { 'x' });
+            }
+        }
+        HirKind::Repetition(repetition) => {
+            let max = repetition
+                .max
+                .unwrap_or(repetition.min + OPEN_REPETITION)
+                .min(repetition.min + OPEN_REPETITION);
+            for _ in 0..rng.int(repetition.min as i64, max as i64) {
+                write_hir(&repetition.sub, rng, text);
+            }
+        }
+        HirKind::Capture(capture) => write_hir(&capture.sub, rng, text),
+        HirKind::Concat(parts) => {
+            for part in parts {
+                write_hir(part, rng, text);
+            }
+        }
+        HirKind::Alternation(alternatives) => write_hir(rng.pick(alternatives), rng, text),
+    }
+}
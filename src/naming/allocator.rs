@@ -0,0 +1,98 @@
+use std::collections::{BTreeMap, HashSet};
+
+use indexmap::IndexMap;
+
+/// Assigns unique names to keyed requests
+///
+/// Names are requested for stable keys, typically JSON pointers into the
+/// specification. When several keys want the same name, the smallest key keeps
+/// it and the others get numeric suffixes in key order (`Pet`, `Pet2`, ...).
+/// The result depends only on the set of requests, never on the order they were
+/// made in, and a suffix never takes a name that another key asked for.
+/// Names requested with [`NameAllocator::prefer`], such as explicit
+/// `operationId`s, win over derived ones regardless of their key.
+#[derive(Debug, Clone, Default)]
+pub struct NameAllocator {
+    requests: IndexMap<String, (String, bool)>,
+    taken: HashSet<String>,
+}
+
+impl NameAllocator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mark a name as unavailable, e.g. because it is already declared elsewhere
+    pub fn reserve(&mut self, name: impl Into<String>) -> &mut Self {
+        self.taken.insert(name.into());
+        self
+    }
+
+    /// Request `name` for `key`; a later request for the same key replaces it
+    pub fn request(&mut self, key: impl Into<String>, name: impl Into<String>) -> &mut Self {
+        self.requests.insert(key.into(), (name.into(), false));
+        self
+    }
+
+    /// Request `name` for `key`, taking precedence over regular requests
+    pub fn prefer(&mut self, key: impl Into<String>, name: impl Into<String>) -> &mut Self {
+        self.requests.insert(key.into(), (name.into(), true));
+        self
+    }
+
+    /// Resolve every request, returning the assigned names in request order
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// # use rustval::naming::NameAllocator;
+    /// let mut names = NameAllocator::new();
+    /// names
+    ///     .request("#/b", "Pet")
+    ///     .request("#/a", "Pet")
+    ///     .request("#/c", "Pet2");
+    /// let names = names.resolve();
+    /// assert_eq!(names["#/a"], "Pet");
+    /// assert_eq!(names["#/b"], "Pet3");
+    /// assert_eq!(names["#/c"], "Pet2");
+    /// ```
+    pub fn resolve(&self) -> IndexMap<String, String> {
+        let mut taken = self.taken.clone();
+        taken.extend(self.requests.values().map(|(name, _)| name.clone()));
+
+        let mut groups: BTreeMap<&str, Vec<(bool, &str)>> = BTreeMap::new();
+        for (key, (name, preferred)) in &self.requests {
+            groups.entry(name).or_default().push((!preferred, key));
+        }
+
+        let mut assigned: IndexMap<&str, String> = IndexMap::new();
+        for (name, mut keys) in groups {
+            keys.sort_unstable();
+            let mut keys = keys.into_iter().map(|(_, key)| key);
+
+            if !self.taken.contains(name)
+                && let Some(first) = keys.next()
+            {
+                assigned.insert(first, name.to_string());
+            }
+
+            let mut suffix = 2;
+            for key in keys {
+                let candidate = loop {
+                    let candidate = format!("{}{}", name, suffix);
+                    suffix += 1;
+                    if !taken.contains(&candidate) {
+                        break candidate;
+                    }
+                };
+                taken.insert(candidate.clone());
+                assigned.insert(key, candidate);
+            }
+        }
+
+        self.requests
+            .keys()
+            .map(|key| (key.clone(), assigned[key.as_str()].clone()))
+            .collect()
+    }
+}
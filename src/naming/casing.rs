@@ -0,0 +1,117 @@
+/// Identifier casing strategies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Casing {
+    /// `petStore`
+    Camel,
+    /// `PetStore`
+    Pascal,
+    /// `pet_store`
+    Snake,
+    /// `PET_STORE`
+    ScreamingSnake,
+    /// `pet-store`
+    Kebab,
+}
+
+impl Casing {
+    /// Convert `input` to this casing
+    ///
+    /// ### Examples
+    ///
+    /// ```rust
+    /// # use rustval::naming::Casing;
+    /// assert_eq!(Casing::Pascal.apply("user-profile.v2"), "UserProfileV2");
+    /// assert_eq!(Casing::Camel.apply("HTTPServer_status"), "httpServerStatus");
+    /// assert_eq!(Casing::Snake.apply("petId"), "pet_id");
+    /// ```
+    pub fn apply(&self, input: &str) -> String {
+        let words = split_words(input);
+        match self {
+            Casing::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_lowercase()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            Casing::Pascal => words.iter().map(|word| capitalize(word)).collect(),
+            Casing::Snake => join_lowercase(&words, "_"),
+            Casing::ScreamingSnake => join_lowercase(&words, "_").to_uppercase(),
+            Casing::Kebab => join_lowercase(&words, "-"),
+        }
+    }
+}
+
+pub fn to_camel_case(input: &str) -> String {
+    Casing::Camel.apply(input)
+}
+
+pub fn to_pascal_case(input: &str) -> String {
+    Casing::Pascal.apply(input)
+}
+
+pub fn to_snake_case(input: &str) -> String {
+    Casing::Snake.apply(input)
+}
+
+pub fn to_kebab_case(input: &str) -> String {
+    Casing::Kebab.apply(input)
+}
+
+/// Split an identifier into words
+///
+/// Any character that is not alphanumeric separates words, as do lowercase to
+/// uppercase transitions (`petId`) and the end of an acronym (`HTTPServer`).
+/// Digits stay attached to the word they follow, so `v2` is a single word.
+pub fn split_words(input: &str) -> Vec<String> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+
+        if let Some(&previous) = current.chars().last().as_ref() {
+            let next_is_lower = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            let boundary = c.is_uppercase()
+                && (previous.is_lowercase()
+                    || previous.is_ascii_digit()
+                    || (previous.is_uppercase() && next_is_lower));
+            if boundary {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.push(c);
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// Uppercase the first character and keep the rest, so acronyms survive
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn join_lowercase(words: &[String], separator: &str) -> String {
+    words
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
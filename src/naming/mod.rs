@@ -0,0 +1,214 @@
+//! Deterministic naming of generated types and operations
+//!
+//! Component names such as `user-profile.v2`, anonymous inline schemas and
+//! operations without an `operationId` all need stable identifiers in generated
+//! code. This module converts between casings, avoids reserved words of the
+//! target language and resolves collisions so that regenerating from the same
+//! specification always yields the same names.
+
+mod allocator;
+mod casing;
+mod reserved;
+
+use indexmap::IndexMap;
+use openapiv3::OpenAPI;
+
+use crate::walk::{escape_pointer, operation_pointer};
+
+// Re-export the public API
+pub use allocator::NameAllocator;
+pub use casing::{
+    Casing, split_words, to_camel_case, to_kebab_case, to_pascal_case, to_snake_case,
+};
+pub use reserved::Language;
+
+/// Convert `input` to a valid identifier in `casing` for `language`
+///
+/// Identifiers that would start with a digit are prefixed with `_`, and reserved
+/// words get a trailing `_`.
+pub fn to_identifier(input: &str, casing: Casing, language: Language) -> String {
+    let cased = casing.apply(input);
+    let cased = if cased.is_empty() || cased.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", cased)
+    } else {
+        cased
+    };
+    language.escape(&cased)
+}
+
+/// The type name for a component schema, e.g. `Foo[Bar]` -> `FooBar`
+pub fn component_type_name(name: &str, language: Language) -> String {
+    to_identifier(name, Casing::Pascal, language)
+}
+
+/// A name for an anonymous schema built from the path that leads to it
+///
+/// ### Examples
+///
+/// ```rust
+/// # use rustval::naming::inline_type_name;
+/// assert_eq!(
+///     inline_type_name(&["getUsers", "200", "response", "items", "item"]),
+///     "GetUsers200ResponseItemsItem"
+/// );
+/// ```
+pub fn inline_type_name(parts: &[&str]) -> String {
+    parts.iter().map(|part| to_pascal_case(part)).collect()
+}
+
+/// The camelCase name of an operation
+///
+/// The `operationId` is used when present; otherwise the name is derived from
+/// the method and path, so `GET /pets/{petId}` becomes `getPetsPetId`.
+pub fn operation_name(operation_id: Option<&str>, method: &str, path: &str) -> String {
+    match operation_id.filter(|id| !id.trim().is_empty()) {
+        Some(id) => to_camel_case(id),
+        None => to_camel_case(&format!("{} {}", method.to_ascii_lowercase(), path)),
+    }
+}
+
+/// Unique type names for all component schemas, keyed by component name
+pub fn type_names(spec: &OpenAPI, language: Language) -> IndexMap<String, String> {
+    component_type_names(
+        spec.components
+            .iter()
+            .flat_map(|components| components.schemas.keys())
+            .map(String::as_str),
+        language,
+    )
+}
+
+/// Unique type names for the given component names, keyed by component name
+pub fn component_type_names<'a>(
+    names: impl IntoIterator<Item = &'a str>,
+    language: Language,
+) -> IndexMap<String, String> {
+    let mut allocator = NameAllocator::new();
+    let names: Vec<&str> = names.into_iter().collect();
+    for name in &names {
+        allocator.request(schema_pointer(name), component_type_name(name, language));
+    }
+    let resolved = allocator.resolve();
+    names
+        .into_iter()
+        .map(|name| (name.to_string(), resolved[&schema_pointer(name)].clone()))
+        .collect()
+}
+
+/// Unique operation names, keyed by the JSON pointer of each operation
+///
+/// Names taken from an `operationId` win collisions with derived names.
+pub fn operation_names(spec: &OpenAPI) -> IndexMap<String, String> {
+    let mut allocator = NameAllocator::new();
+    for (path, method, operation) in spec.operations() {
+        let pointer = operation_pointer(path, method);
+        let name = operation_name(operation.operation_id.as_deref(), method, path);
+        if operation.operation_id.is_some() {
+            allocator.prefer(pointer, name);
+        } else {
+            allocator.request(pointer, name);
+        }
+    }
+    allocator.resolve()
+}
+
+fn schema_pointer(name: &str) -> String {
+    format!("#/components/schemas/{}", escape_pointer(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_casing() {
+        let cases = [
+            (
+                "user-profile.v2",
+                "userProfileV2",
+                "UserProfileV2",
+                "user_profile_v2",
+            ),
+            ("Foo[Bar]", "fooBar", "FooBar", "foo_bar"),
+            ("HTTPServer", "httpServer", "HTTPServer", "http_server"),
+            ("pet_ID", "petID", "PetID", "pet_id"),
+            (
+                "getPets200Response",
+                "getPets200Response",
+                "GetPets200Response",
+                "get_pets200_response",
+            ),
+        ];
+        for (input, camel, pascal, snake) in cases {
+            assert_eq!(to_camel_case(input), camel, "camel case of {}", input);
+            assert_eq!(to_pascal_case(input), pascal, "pascal case of {}", input);
+            assert_eq!(to_snake_case(input), snake, "snake case of {}", input);
+        }
+        assert_eq!(to_kebab_case("PetStore v2"), "pet-store-v2");
+        assert_eq!(Casing::ScreamingSnake.apply("maxItems"), "MAX_ITEMS");
+    }
+
+    #[test]
+    fn test_identifiers() {
+        assert_eq!(
+            component_type_name("2fa-token", Language::TypeScript),
+            "_2faToken"
+        );
+        assert_eq!(component_type_name("date", Language::TypeScript), "Date_");
+        assert_eq!(
+            to_identifier("type", Casing::Snake, Language::Rust),
+            "type_"
+        );
+        assert_eq!(
+            to_identifier("from", Casing::Camel, Language::Python),
+            "from_"
+        );
+        assert_eq!(to_identifier("from", Casing::Camel, Language::Rust), "from");
+
+        assert_eq!(operation_name(None, "GET", "/pets/{petId}"), "getPetsPetId");
+        assert_eq!(
+            operation_name(Some("List-Pets"), "get", "/pets"),
+            "listPets"
+        );
+        assert_eq!(operation_name(Some(" "), "post", "/"), "post");
+    }
+
+    #[test]
+    fn test_collisions() {
+        let spec: OpenAPI = serde_yaml::from_str(
+            r##"
+openapi: 3.0.3
+info: { title: Pets, version: "1.0" }
+paths:
+  /pets:
+    get:
+      operationId: getPets
+      responses: { "200": { description: ok } }
+  /Pets:
+    get:
+      responses: { "200": { description: ok } }
+components:
+  schemas:
+    pet: { type: object }
+    Pet: { type: object }
+    pet-2: { type: object }
+"##,
+        )
+        .unwrap();
+
+        let types = type_names(&spec, Language::TypeScript);
+        assert_eq!(
+            types.values().map(String::as_str).collect::<Vec<_>>(),
+            vec!["Pet3", "Pet", "Pet2"]
+        );
+
+        let operations = operation_names(&spec);
+        assert_eq!(operations["#/paths/~1pets/get"], "getPets");
+        assert_eq!(operations["#/paths/~1Pets/get"], "getPets2");
+
+        // Reordering the requests does not change the outcome
+        let reversed = component_type_names(["pet-2", "Pet", "pet"], Language::TypeScript);
+        assert_eq!(reversed["pet"], "Pet3");
+        assert_eq!(reversed["Pet"], "Pet");
+    }
+}
@@ -0,0 +1,185 @@
+/// A target language of code generation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    TypeScript,
+    Rust,
+    Python,
+}
+
+/// Keywords plus global type names that generated declarations would shadow
+const TYPESCRIPT: &[&str] = &[
+    "any",
+    "as",
+    "async",
+    "await",
+    "bigint",
+    "boolean",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "declare",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "from",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "keyof",
+    "let",
+    "never",
+    "new",
+    "null",
+    "number",
+    "object",
+    "of",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "return",
+    "static",
+    "string",
+    "super",
+    "switch",
+    "symbol",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "type",
+    "typeof",
+    "undefined",
+    "unknown",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+    "Array",
+    "Blob",
+    "Boolean",
+    "Date",
+    "Error",
+    "File",
+    "FormData",
+    "Map",
+    "Number",
+    "Object",
+    "Omit",
+    "Partial",
+    "Pick",
+    "Promise",
+    "Record",
+    "Response",
+    "Set",
+    "String",
+];
+
+/// Keywords plus prelude types that generated declarations would shadow
+const RUST: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "gen", "macro",
+    "override", "priv", "try", "typeof", "unsized", "virtual", "yield", "Box", "Option", "Result",
+    "String", "Vec",
+];
+
+/// Keywords plus builtins and the `typing` and `pydantic` names generated
+/// modules import
+const PYTHON: &[&str] = &[
+    "False",
+    "None",
+    "True",
+    "and",
+    "as",
+    "assert",
+    "async",
+    "await",
+    "break",
+    "class",
+    "continue",
+    "def",
+    "del",
+    "elif",
+    "else",
+    "except",
+    "finally",
+    "for",
+    "from",
+    "global",
+    "if",
+    "import",
+    "in",
+    "is",
+    "lambda",
+    "nonlocal",
+    "not",
+    "or",
+    "pass",
+    "raise",
+    "return",
+    "try",
+    "while",
+    "with",
+    "yield",
+    "bool",
+    "bytes",
+    "dict",
+    "float",
+    "int",
+    "list",
+    "object",
+    "str",
+    "type",
+    "Annotated",
+    "Any",
+    "BaseModel",
+    "ConfigDict",
+    "Dict",
+    "Field",
+    "List",
+    "Literal",
+    "Optional",
+    "Union",
+];
+
+impl Language {
+    pub fn reserved_words(&self) -> &'static [&'static str] {
+        match self {
+            Language::TypeScript => TYPESCRIPT,
+            Language::Rust => RUST,
+            Language::Python => PYTHON,
+        }
+    }
+
+    pub fn is_reserved(&self, word: &str) -> bool {
+        self.reserved_words().contains(&word)
+    }
+
+    /// Append `_` to reserved words, e.g. `type` -> `type_`
+    pub fn escape(&self, word: &str) -> String {
+        if self.is_reserved(word) {
+            format!("{}_", word)
+        } else {
+            word.to_string()
+        }
+    }
+}
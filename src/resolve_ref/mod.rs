@@ -9,8 +9,8 @@ mod utils;
 
 // Re-export the public API
 pub use resolvers::{
-    ExampleResolver, GenericResolver, ParameterResolver, RequestBodyResolver, ResponseResolver,
-    SchemaResolver,
+    ExampleResolver, GenericResolver, HeaderResolver, ParameterResolver, RequestBodyResolver,
+    ResponseResolver, SchemaResolver,
 };
 pub use schema::{resolve_schema_fully, resolve_schema_list};
 pub use traits::{ComponentGetter, OpenApiResolver};
@@ -18,6 +18,6 @@ pub use utils::parse_ref;
 
 // Convenience functions
 pub use resolvers::{
-    resolve_example_ref, resolve_parameter_ref, resolve_reference_or, resolve_request_body_ref,
-    resolve_response_ref, resolve_schema_ref,
+    resolve_example_ref, resolve_header_ref, resolve_parameter_ref, resolve_reference_or,
+    resolve_request_body_ref, resolve_response_ref, resolve_schema_ref,
 };
@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use openapiv3::{Example, OpenAPI, Parameter, ReferenceOr, RequestBody, Response, Schema};
+use openapiv3::{Example, Header, OpenAPI, Parameter, ReferenceOr, RequestBody, Response, Schema};
 
 use crate::resolve_ref::traits::{ComponentGetter, OpenApiResolver};
 use crate::resolve_ref::utils::parse_ref;
@@ -127,12 +127,28 @@ impl ComponentGetter<Example> for ExampleGetter {
     }
 }
 
+/// Header component getter
+pub struct HeaderGetter;
+impl ComponentGetter<Header> for HeaderGetter {
+    fn component_type() -> &'static str {
+        "headers"
+    }
+
+    fn get_component<'a>(
+        components: &'a openapiv3::Components,
+        name: &str,
+    ) -> Option<&'a ReferenceOr<Header>> {
+        components.headers.get(name)
+    }
+}
+
 // Resolver type aliases
 pub type SchemaResolver = GenericResolver<Schema, SchemaGetter>;
 pub type ResponseResolver = GenericResolver<Response, ResponseGetter>;
 pub type ParameterResolver = GenericResolver<Parameter, ParameterGetter>;
 pub type RequestBodyResolver = GenericResolver<RequestBody, RequestBodyGetter>;
 pub type ExampleResolver = GenericResolver<Example, ExampleGetter>;
+pub type HeaderResolver = GenericResolver<Header, HeaderGetter>;
 
 // Convenience functions
 /// Resolve a schema reference
@@ -160,6 +176,11 @@ pub fn resolve_example_ref(reference: &str, spec: &OpenAPI) -> Option<Example> {
     ExampleResolver::new().resolve_reference(reference, spec)
 }
 
+/// Resolve a header reference
+pub fn resolve_header_ref(reference: &str, spec: &OpenAPI) -> Option<Header> {
+    HeaderResolver::new().resolve_reference(reference, spec)
+}
+
 /// General purpose function to resolve a ReferenceOr of any OpenAPI component type
 pub fn resolve_reference_or<T, R>(
     reference_or: &ReferenceOr<T>,
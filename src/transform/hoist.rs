@@ -0,0 +1,312 @@
+use std::collections::HashSet;
+
+use indexmap::IndexMap;
+use openapiv3::{
+    AdditionalProperties, Components, MediaType, OpenAPI, Operation, Parameter, ParameterData,
+    ParameterSchemaOrContent, PathItem, ReferenceOr, Response, Schema, SchemaKind, StatusCode,
+    Type,
+};
+
+use crate::naming::{inline_type_name, operation_names, to_pascal_case};
+use crate::walk::operation_pointer;
+
+/// Move inline object and enum schemas into `components.schemas`
+///
+/// Every inline schema with properties or an `enum` found in component schemas,
+/// parameters, request bodies, responses and operations is replaced by a
+/// `$ref` to a new component. Names are derived from where the schema was found,
+/// e.g. `GetUsers200ResponseItemsItem` for the items of the `items` property of
+/// the 200 response of `getUsers`. Identical schemas hoisted under the same name,
+/// such as the JSON and XML representation of one response, share a component.
+///
+/// Returns the names of the added components in the order they were added.
+///
+/// ### Examples
+///
+/// ```rust
+/// # use rustval::resolve_ref::resolve_schema_ref;
+/// # use rustval::transform::hoist_inline_schemas;
+/// let mut spec: openapiv3::OpenAPI = serde_yaml::from_str(r#"
+/// openapi: 3.0.3
+/// info: { title: Users, version: "1.0" }
+/// paths:
+///   /users:
+///     get:
+///       responses:
+///         "200":
+///           description: ok
+///           content:
+///             application/json:
+///               schema:
+///                 type: object
+///                 properties:
+///                   name: { type: string }
+/// "#).unwrap();
+///
+/// assert_eq!(hoist_inline_schemas(&mut spec), vec!["GetUsers200Response"]);
+/// assert!(resolve_schema_ref("#/components/schemas/GetUsers200Response", &spec).is_some());
+/// ```
+pub fn hoist_inline_schemas(spec: &mut OpenAPI) -> Vec<String> {
+    let operation_names = operation_names(spec);
+    let mut components = spec.components.take().unwrap_or_default();
+    let mut hoister = Hoister {
+        taken: components.schemas.keys().cloned().collect(),
+        hoisted: IndexMap::new(),
+        seen: Vec::new(),
+    };
+
+    hoister.components(&mut components);
+
+    for (path, item) in spec.paths.paths.iter_mut() {
+        let ReferenceOr::Item(item) = item else {
+            continue;
+        };
+        let path_name = to_pascal_case(path);
+        for parameter in &mut item.parameters {
+            hoister.parameter(parameter, &path_name);
+        }
+        for (method, operation) in operations_mut(item) {
+            let name = &operation_names[&operation_pointer(path, method)];
+            hoister.operation(operation, name);
+        }
+    }
+
+    let names: Vec<String> = hoister.hoisted.keys().cloned().collect();
+    components.schemas.extend(
+        hoister
+            .hoisted
+            .into_iter()
+            .map(|(name, schema)| (name, ReferenceOr::Item(schema))),
+    );
+    spec.components = Some(components);
+    names
+}
+
+struct Hoister {
+    /// Component names in use, including the hoisted ones
+    taken: HashSet<String>,
+    hoisted: IndexMap<String, Schema>,
+    /// Requested name and original schema of everything hoisted, for reuse
+    seen: Vec<(String, Schema, String)>,
+}
+
+impl Hoister {
+    fn components(&mut self, components: &mut Components) {
+        for (name, schema) in components.schemas.iter_mut() {
+            // Named schemas stay where they are, only their children move
+            if let ReferenceOr::Item(schema) = schema {
+                let name = to_pascal_case(name);
+                self.children(schema, &name);
+            }
+        }
+        for (name, parameter) in components.parameters.iter_mut() {
+            self.parameter(parameter, &to_pascal_case(name));
+        }
+        for (name, body) in components.request_bodies.iter_mut() {
+            if let ReferenceOr::Item(body) = body {
+                let name = inline_type_name(&[name, "Body"]);
+                self.content(&mut body.content, &name);
+            }
+        }
+        for (name, response) in components.responses.iter_mut() {
+            self.response(response, &inline_type_name(&[name, "Response"]));
+        }
+    }
+
+    fn operation(&mut self, operation: &mut Operation, name: &str) {
+        for parameter in &mut operation.parameters {
+            self.parameter(parameter, name);
+        }
+        if let Some(ReferenceOr::Item(body)) = &mut operation.request_body {
+            self.content(&mut body.content, &inline_type_name(&[name, "Body"]));
+        }
+
+        let responses = &mut operation.responses;
+        for (status, response) in responses.responses.iter_mut() {
+            let status = match status {
+                StatusCode::Code(code) => code.to_string(),
+                StatusCode::Range(range) => format!("{}XX", range),
+            };
+            self.response(response, &inline_type_name(&[name, &status, "Response"]));
+        }
+        if let Some(response) = &mut responses.default {
+            self.response(response, &inline_type_name(&[name, "Default", "Response"]));
+        }
+    }
+
+    fn parameter(&mut self, parameter: &mut ReferenceOr<Parameter>, parent: &str) {
+        let ReferenceOr::Item(parameter) = parameter else {
+            return;
+        };
+        let data = parameter_data_mut(parameter);
+        let name = inline_type_name(&[parent, &data.name]);
+        match &mut data.format {
+            ParameterSchemaOrContent::Schema(schema) => self.schema(schema, &name),
+            ParameterSchemaOrContent::Content(content) => self.content(content, &name),
+        }
+    }
+
+    fn response(&mut self, response: &mut ReferenceOr<Response>, name: &str) {
+        let ReferenceOr::Item(response) = response else {
+            return;
+        };
+        for (header_name, header) in response.headers.iter_mut() {
+            if let ReferenceOr::Item(header) = header
+                && let ParameterSchemaOrContent::Schema(schema) = &mut header.format
+            {
+                self.schema(schema, &inline_type_name(&[name, header_name, "Header"]));
+            }
+        }
+        self.content(&mut response.content, name);
+    }
+
+    fn content(&mut self, content: &mut IndexMap<String, MediaType>, name: &str) {
+        for media_type in content.values_mut() {
+            if let Some(schema) = &mut media_type.schema {
+                self.schema(schema, name);
+            }
+        }
+    }
+
+    fn schema(&mut self, schema: &mut ReferenceOr<Schema>, name: &str) {
+        if let ReferenceOr::Item(item) = schema
+            && let Some(reference) = self.hoist(item, name)
+        {
+            *schema = ReferenceOr::Reference { reference };
+        }
+    }
+
+    fn boxed(&mut self, schema: &mut ReferenceOr<Box<Schema>>, name: &str) {
+        if let ReferenceOr::Item(item) = schema
+            && let Some(reference) = self.hoist(item, name)
+        {
+            *schema = ReferenceOr::Reference { reference };
+        }
+    }
+
+    /// Hoist the schema if it deserves a name, returning the new reference
+    fn hoist(&mut self, schema: &mut Schema, name: &str) -> Option<String> {
+        if !is_hoistable(schema) {
+            self.children(schema, name);
+            return None;
+        }
+
+        if let Some((_, _, existing)) = self
+            .seen
+            .iter()
+            .find(|(requested, original, _)| requested == name && original == schema)
+        {
+            return Some(reference_to(existing));
+        }
+
+        let original = schema.clone();
+        let unique = self.unique_name(name);
+        // Keep the parent ahead of the children it causes to be hoisted
+        let index = self.hoisted.len();
+        self.children(schema, &unique);
+        self.hoisted
+            .shift_insert(index, unique.clone(), schema.clone());
+        self.seen.push((name.to_string(), original, unique.clone()));
+        Some(reference_to(&unique))
+    }
+
+    fn children(&mut self, schema: &mut Schema, name: &str) {
+        match &mut schema.schema_kind {
+            SchemaKind::Type(Type::Object(object)) => {
+                for (property, schema) in object.properties.iter_mut() {
+                    self.boxed(schema, &inline_type_name(&[name, property]));
+                }
+                if let Some(AdditionalProperties::Schema(schema)) =
+                    &mut object.additional_properties
+                {
+                    self.schema(schema, &inline_type_name(&[name, "Value"]));
+                }
+            }
+            SchemaKind::Type(Type::Array(array)) => {
+                if let Some(items) = &mut array.items {
+                    self.boxed(items, &inline_type_name(&[name, "Item"]));
+                }
+            }
+            SchemaKind::OneOf { one_of: members } => self.members(members, name, "OneOf"),
+            SchemaKind::AnyOf { any_of: members } => self.members(members, name, "AnyOf"),
+            SchemaKind::AllOf { all_of: members } => self.members(members, name, "AllOf"),
+            SchemaKind::Any(any) => {
+                for (property, schema) in any.properties.iter_mut() {
+                    self.boxed(schema, &inline_type_name(&[name, property]));
+                }
+                if let Some(AdditionalProperties::Schema(schema)) = &mut any.additional_properties {
+                    self.schema(schema, &inline_type_name(&[name, "Value"]));
+                }
+                if let Some(items) = &mut any.items {
+                    self.boxed(items, &inline_type_name(&[name, "Item"]));
+                }
+                self.members(&mut any.one_of, name, "OneOf");
+                self.members(&mut any.any_of, name, "AnyOf");
+                self.members(&mut any.all_of, name, "AllOf");
+            }
+            SchemaKind::Type(_) | SchemaKind::Not { .. } => {}
+        }
+    }
+
+    fn members(&mut self, members: &mut [ReferenceOr<Schema>], name: &str, keyword: &str) {
+        let name = inline_type_name(&[name, keyword]);
+        for member in members {
+            self.schema(member, &name);
+        }
+    }
+
+    fn unique_name(&mut self, name: &str) -> String {
+        let mut unique = name.to_string();
+        let mut suffix = 2;
+        while self.taken.contains(&unique) {
+            unique = format!("{}{}", name, suffix);
+            suffix += 1;
+        }
+        self.taken.insert(unique.clone());
+        unique
+    }
+}
+
+/// Objects with properties and enumerations are worth a name of their own
+fn is_hoistable(schema: &Schema) -> bool {
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(object)) => !object.properties.is_empty(),
+        SchemaKind::Type(Type::String(string)) => !string.enumeration.is_empty(),
+        SchemaKind::Type(Type::Integer(integer)) => !integer.enumeration.is_empty(),
+        SchemaKind::Type(Type::Number(number)) => !number.enumeration.is_empty(),
+        SchemaKind::Any(any) => {
+            let composed =
+                !any.one_of.is_empty() || !any.any_of.is_empty() || !any.all_of.is_empty();
+            !composed && (!any.properties.is_empty() || !any.enumeration.is_empty())
+        }
+        _ => false,
+    }
+}
+
+fn reference_to(name: &str) -> String {
+    format!("#/components/schemas/{}", name)
+}
+
+fn parameter_data_mut(parameter: &mut Parameter) -> &mut ParameterData {
+    match parameter {
+        Parameter::Query { parameter_data, .. }
+        | Parameter::Header { parameter_data, .. }
+        | Parameter::Path { parameter_data, .. }
+        | Parameter::Cookie { parameter_data, .. } => parameter_data,
+    }
+}
+
+fn operations_mut(item: &mut PathItem) -> impl Iterator<Item = (&'static str, &mut Operation)> {
+    [
+        ("get", &mut item.get),
+        ("put", &mut item.put),
+        ("post", &mut item.post),
+        ("delete", &mut item.delete),
+        ("options", &mut item.options),
+        ("head", &mut item.head),
+        ("patch", &mut item.patch),
+        ("trace", &mut item.trace),
+    ]
+    .into_iter()
+    .filter_map(|(method, operation)| operation.as_mut().map(|operation| (method, operation)))
+}
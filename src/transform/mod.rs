@@ -0,0 +1,138 @@
+//! Transformations of OpenAPI specifications
+//!
+//! Transforms rewrite a specification in place into an equivalent one that is
+//! easier to generate code from.
+
+mod hoist;
+
+// Re-export the public API
+pub use hoist::hoist_inline_schemas;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resolve_ref::{OpenApiResolver, SchemaResolver, resolve_response_ref};
+    use openapiv3::{OpenAPI, ReferenceOr};
+
+    const SPEC: &str = r##"
+openapi: 3.0.3
+info: { title: Users, version: "1.0" }
+paths:
+  /users:
+    get:
+      operationId: getUsers
+      parameters:
+        - name: sort
+          in: query
+          schema: { type: string, enum: [asc, desc] }
+        - { name: limit, in: query, schema: { type: integer } }
+      responses:
+        "200":
+          description: ok
+          content:
+            application/json:
+              schema: &page
+                type: object
+                properties:
+                  items:
+                    type: array
+                    items:
+                      type: object
+                      properties:
+                        id: { type: integer }
+                        role: { $ref: "#/components/schemas/Role" }
+            application/xml:
+              schema: *page
+        default: { $ref: "#/components/responses/Error" }
+    post:
+      requestBody:
+        content:
+          application/json:
+            schema:
+              type: object
+              properties:
+                name: { type: string }
+      responses:
+        "201": { description: created }
+components:
+  responses:
+    Error:
+      description: error
+      content:
+        application/json:
+          schema:
+            type: object
+            properties:
+              message: { type: string }
+  schemas:
+    Role:
+      type: object
+      properties:
+        level: { type: string, enum: [admin, user] }
+        scope:
+          oneOf:
+            - type: object
+              properties:
+                org: { type: string }
+            - type: string
+    PostUsersBody: { type: string }
+"##;
+
+    #[test]
+    fn test_hoist_inline_schemas() {
+        let mut spec: OpenAPI = serde_yaml::from_str(SPEC).unwrap();
+        let hoisted = hoist_inline_schemas(&mut spec);
+
+        assert_eq!(
+            hoisted,
+            vec![
+                "RoleLevel",
+                "RoleScopeOneOf",
+                "ErrorResponse",
+                "GetUsersSort",
+                "GetUsers200Response",
+                "GetUsers200ResponseItemsItem",
+                "PostUsersBody2",
+            ]
+        );
+
+        let schemas = &spec.components.as_ref().unwrap().schemas;
+        let error = resolve_response_ref("#/components/responses/Error", &spec).unwrap();
+        assert_eq!(
+            error.content["application/json"].schema,
+            Some(ReferenceOr::ref_("#/components/schemas/ErrorResponse"))
+        );
+
+        // Both media types share one component
+        let operation = spec.paths.paths["/users"]
+            .as_item()
+            .unwrap()
+            .get
+            .as_ref()
+            .unwrap();
+        let ok = operation.responses.responses[&openapiv3::StatusCode::Code(200)]
+            .as_item()
+            .unwrap();
+        for media_type in ok.content.values() {
+            assert_eq!(
+                media_type.schema,
+                Some(ReferenceOr::ref_(
+                    "#/components/schemas/GetUsers200Response"
+                ))
+            );
+        }
+
+        // Every hoisted schema is resolvable and no inline object remains
+        let resolver = SchemaResolver::new();
+        for name in &hoisted {
+            let reference = format!("#/components/schemas/{}", name);
+            assert!(resolver.resolve_reference(&reference, &spec).is_some());
+        }
+        assert!(schemas["PostUsersBody"].as_item().is_some());
+        assert_eq!(
+            hoist_inline_schemas(&mut spec),
+            Vec::<String>::new(),
+            "hoisting is idempotent"
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use std::fmt;
+
+use indexmap::IndexMap;
+use openapiv3::{
+    Example, Header, MediaType, OpenAPI, Parameter, ParameterSchemaOrContent, ReferenceOr, Schema,
+};
+use serde_json::Value;
+
+use crate::resolve_ref::{ExampleResolver, OpenApiResolver};
+use crate::validate::instance::{ValidationError, validate_value};
+use crate::walk::{Visitor, escape_pointer, walk_spec};
+
+/// An example that does not match the schema it documents
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleError {
+    /// JSON pointer of the example in the specification
+    pub pointer: String,
+    pub error: ValidationError,
+}
+
+impl fmt::Display for ExampleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.pointer, self.error)
+    }
+}
+
+/// Validate every example in the specification against its schema
+///
+/// This covers `example` and `examples` on media types, parameters and headers,
+/// including those of callbacks, as well as the `example` keyword on schemas.
+/// Referenced examples are resolved through [`ExampleResolver`]; examples that
+/// only have an `externalValue` are skipped.
+pub fn validate_examples(spec: &OpenAPI) -> Vec<ExampleError> {
+    let mut visitor = ExampleVisitor {
+        spec,
+        errors: Vec::new(),
+    };
+    walk_spec(spec, &mut visitor);
+    visitor.errors
+}
+
+struct ExampleVisitor<'a> {
+    spec: &'a OpenAPI,
+    errors: Vec<ExampleError>,
+}
+
+impl ExampleVisitor<'_> {
+    fn check(&mut self, value: &Value, schema: &ReferenceOr<Schema>, pointer: String) {
+        for error in validate_value(value, schema, self.spec) {
+            self.errors.push(ExampleError {
+                pointer: pointer.clone(),
+                error,
+            });
+        }
+    }
+
+    fn check_all(
+        &mut self,
+        example: Option<&Value>,
+        examples: &IndexMap<String, ReferenceOr<Example>>,
+        schema: &ReferenceOr<Schema>,
+        pointer: &str,
+    ) {
+        if let Some(example) = example {
+            self.check(example, schema, format!("{}/example", pointer));
+        }
+
+        let resolver = ExampleResolver::new();
+        for (name, example) in examples {
+            let example_pointer = format!("{}/examples/{}", pointer, escape_pointer(name));
+            match resolver.resolve_reference_or(example, self.spec) {
+                Some(Example {
+                    value: Some(value), ..
+                }) => self.check(&value, schema, format!("{}/value", example_pointer)),
+                Some(_) => {}
+                None => self.errors.push(ExampleError {
+                    pointer: example_pointer,
+                    error: ValidationError {
+                        instance_path: String::new(),
+                        schema_path: String::new(),
+                        message: "example reference could not be resolved".to_string(),
+                    },
+                }),
+            }
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for ExampleVisitor<'a> {
+    fn visit_schema(&mut self, pointer: &str, schema: &'a Schema) {
+        if let Some(example) = &schema.schema_data.example {
+            self.check(
+                example,
+                &ReferenceOr::Item(schema.clone()),
+                format!("{}/example", pointer),
+            );
+        }
+    }
+
+    fn visit_parameter(&mut self, pointer: &str, parameter: &'a Parameter) {
+        // Parameters with `content` are handled by `visit_media_type`
+        let data = parameter.parameter_data_ref();
+        if let ParameterSchemaOrContent::Schema(schema) = &data.format {
+            self.check_all(data.example.as_ref(), &data.examples, schema, pointer);
+        }
+    }
+
+    fn visit_header(&mut self, pointer: &str, header: &'a Header) {
+        if let ParameterSchemaOrContent::Schema(schema) = &header.format {
+            self.check_all(header.example.as_ref(), &header.examples, schema, pointer);
+        }
+    }
+
+    fn visit_media_type(&mut self, pointer: &str, media_type: &'a MediaType) {
+        if let Some(schema) = &media_type.schema {
+            self.check_all(
+                media_type.example.as_ref(),
+                &media_type.examples,
+                schema,
+                pointer,
+            );
+        }
+    }
+}
@@ -0,0 +1,910 @@
+use std::borrow::{Borrow, Cow};
+use std::fmt;
+
+use indexmap::IndexMap;
+use openapiv3::{
+    AdditionalProperties, AnySchema, Discriminator, IntegerFormat, OpenAPI, ReferenceOr, Schema,
+    SchemaKind, StringFormat, Type, VariantOrUnknownOrEmpty,
+};
+use regex::Regex;
+use serde_json::{Map, Value};
+
+use crate::resolve_ref::resolve_schema_ref;
+use crate::validate::format::check_string_format;
+use crate::walk::escape_pointer;
+
+/// A mismatch between a JSON value and the schema it was validated against
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// JSON pointer into the validated value, empty for the root
+    pub instance_path: String,
+    /// Location of the failing keyword, starting at the validated schema
+    /// (`#` for inline schemas, the reference for referenced ones)
+    pub schema_path: String,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.instance_path.is_empty() {
+            "/"
+        } else {
+            &self.instance_path
+        };
+        write!(f, "{}: {} ({})", path, self.message, self.schema_path)
+    }
+}
+
+/// The direction a value travels in, used for `readOnly` and `writeOnly`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Sent by the client: `readOnly` properties must not be present
+    Request,
+    /// Sent by the server: `writeOnly` properties must not be present
+    Response,
+}
+
+/// Options for [`validate_value_with`]
+#[derive(Debug, Clone, Default)]
+pub struct ValidationOptions {
+    /// Enables `readOnly`/`writeOnly` checks when set
+    pub direction: Option<Direction>,
+}
+
+impl ValidationOptions {
+    pub fn request() -> Self {
+        ValidationOptions {
+            direction: Some(Direction::Request),
+        }
+    }
+
+    pub fn response() -> Self {
+        ValidationOptions {
+            direction: Some(Direction::Response),
+        }
+    }
+}
+
+/// Validate a JSON value against a schema using OpenAPI 3.0 semantics
+///
+/// References are resolved as the value is walked, so recursive schemas are
+/// followed only as deep as the value goes.
+///
+/// ### Examples
+///
+/// ```
+/// # use openapiv3::{OpenAPI, ReferenceOr};
+/// # use rustval::validate::validate_value;
+/// let spec: OpenAPI = serde_yaml::from_str(r#"
+/// openapi: 3.0.3
+/// info: { title: Pets, version: "1.0" }
+/// paths: {}
+/// components:
+///   schemas:
+///     Pet:
+///       type: object
+///       required: [name]
+///       properties:
+///         name: { type: string }
+/// "#).unwrap();
+///
+/// let schema = ReferenceOr::ref_("#/components/schemas/Pet");
+/// let errors = validate_value(&serde_json::json!({ "name": 5 }), &schema, &spec);
+/// assert_eq!(errors[0].instance_path, "/name");
+/// assert_eq!(errors[0].schema_path, "#/components/schemas/Pet/properties/name/type");
+/// ```
+pub fn validate_value(
+    value: &Value,
+    schema: &ReferenceOr<Schema>,
+    spec: &OpenAPI,
+) -> Vec<ValidationError> {
+    validate_value_with(value, schema, spec, &ValidationOptions::default())
+}
+
+/// Validate a JSON value against a schema with the given options
+pub fn validate_value_with(
+    value: &Value,
+    schema: &ReferenceOr<Schema>,
+    spec: &OpenAPI,
+    options: &ValidationOptions,
+) -> Vec<ValidationError> {
+    let mut validator = Validator {
+        spec,
+        direction: options.direction,
+        errors: Vec::new(),
+        active: Vec::new(),
+    };
+
+    let schema_path = match schema {
+        ReferenceOr::Reference { reference } => reference.as_str(),
+        ReferenceOr::Item(_) => "#",
+    };
+    validator.validate_ref(value, schema, "", schema_path);
+    validator.errors
+}
+
+struct Validator<'a> {
+    spec: &'a OpenAPI,
+    direction: Option<Direction>,
+    errors: Vec<ValidationError>,
+    /// The references being validated with the instance path they apply to
+    active: Vec<(String, String)>,
+}
+
+impl Validator<'_> {
+    fn error(&mut self, path: &str, schema_path: &str, message: impl Into<String>) {
+        self.errors.push(ValidationError {
+            instance_path: path.to_string(),
+            schema_path: schema_path.to_string(),
+            message: message.into(),
+        });
+    }
+
+    fn resolve<'s, S: Borrow<Schema>>(
+        &self,
+        schema: &'s ReferenceOr<S>,
+    ) -> Option<Cow<'s, Schema>> {
+        match schema {
+            ReferenceOr::Reference { reference } => {
+                resolve_schema_ref(reference, self.spec).map(Cow::Owned)
+            }
+            ReferenceOr::Item(schema) => Some(Cow::Borrowed(schema.borrow())),
+        }
+    }
+
+    /// Validate against a schema that may still be a reference
+    ///
+    /// A reference that leads back to itself without descending into the value
+    /// adds no constraint and is skipped.
+    fn validate_ref<S: Borrow<Schema>>(
+        &mut self,
+        value: &Value,
+        schema: &ReferenceOr<S>,
+        path: &str,
+        schema_path: &str,
+    ) {
+        if let ReferenceOr::Reference { reference } = schema {
+            let key = (reference.clone(), path.to_string());
+            if self.active.contains(&key) {
+                return;
+            }
+            self.active.push(key);
+            self.validate_resolved(value, schema, path, schema_path);
+            self.active.pop();
+        } else {
+            self.validate_resolved(value, schema, path, schema_path);
+        }
+    }
+
+    fn validate_resolved<S: Borrow<Schema>>(
+        &mut self,
+        value: &Value,
+        schema: &ReferenceOr<S>,
+        path: &str,
+        schema_path: &str,
+    ) {
+        match (self.resolve(schema), schema) {
+            (Some(resolved), _) => self.validate_schema(value, &resolved, path, schema_path),
+            (None, ReferenceOr::Reference { reference }) => self.error(
+                path,
+                schema_path,
+                format!("could not resolve {}", reference),
+            ),
+            (None, ReferenceOr::Item(_)) => {}
+        }
+    }
+
+    /// Run a nested validation and return its errors without recording them
+    fn probe<S: Borrow<Schema>>(
+        &self,
+        value: &Value,
+        schema: &ReferenceOr<S>,
+        path: &str,
+        schema_path: &str,
+    ) -> Vec<ValidationError> {
+        let mut validator = Validator {
+            spec: self.spec,
+            direction: self.direction,
+            errors: Vec::new(),
+            active: self.active.clone(),
+        };
+        validator.validate_ref(value, schema, path, schema_path);
+        validator.errors
+    }
+
+    fn validate_schema(&mut self, value: &Value, schema: &Schema, path: &str, schema_path: &str) {
+        if value.is_null() && schema.schema_data.nullable {
+            return;
+        }
+
+        let at = |keyword: &str| format!("{}/{}", schema_path, keyword);
+
+        match &schema.schema_kind {
+            SchemaKind::Type(Type::String(string)) => {
+                let Some(text) = value.as_str() else {
+                    return self.type_error(value, "string", path, &at("type"));
+                };
+                if !string.enumeration.is_empty()
+                    && !string
+                        .enumeration
+                        .iter()
+                        .any(|e| e.as_deref() == Some(text))
+                {
+                    self.error(
+                        path,
+                        &at("enum"),
+                        format!("{:?} is not one of the allowed values", text),
+                    );
+                }
+                let format = match &string.format {
+                    VariantOrUnknownOrEmpty::Item(StringFormat::Date) => Some("date"),
+                    VariantOrUnknownOrEmpty::Item(StringFormat::DateTime) => Some("date-time"),
+                    VariantOrUnknownOrEmpty::Item(StringFormat::Byte) => Some("byte"),
+                    VariantOrUnknownOrEmpty::Unknown(format) => Some(format.as_str()),
+                    _ => None,
+                };
+                self.check_string(
+                    text,
+                    string.min_length,
+                    string.max_length,
+                    string.pattern.as_deref(),
+                    format,
+                    path,
+                    schema_path,
+                );
+            }
+            SchemaKind::Type(Type::Number(number)) => {
+                let Some(n) = value.as_f64() else {
+                    return self.type_error(value, "number", path, &at("type"));
+                };
+                if !number.enumeration.is_empty() && !number.enumeration.contains(&Some(n)) {
+                    self.error(
+                        path,
+                        &at("enum"),
+                        format!("{} is not one of the allowed values", n),
+                    );
+                }
+                self.check_number(
+                    n,
+                    number.minimum,
+                    number.exclusive_minimum,
+                    number.maximum,
+                    number.exclusive_maximum,
+                    number.multiple_of,
+                    path,
+                    schema_path,
+                );
+            }
+            SchemaKind::Type(Type::Integer(integer)) => {
+                let Some(n) = as_integer(value) else {
+                    return self.type_error(value, "integer", path, &at("type"));
+                };
+                let range = match integer.format {
+                    VariantOrUnknownOrEmpty::Item(IntegerFormat::Int32) => {
+                        Some(("int32", i32::MIN as i128, i32::MAX as i128))
+                    }
+                    VariantOrUnknownOrEmpty::Item(IntegerFormat::Int64) => {
+                        Some(("int64", i64::MIN as i128, i64::MAX as i128))
+                    }
+                    _ => None,
+                };
+                if let Some((format, min, max)) = range
+                    && (n < min || n > max)
+                {
+                    self.error(
+                        path,
+                        &at("format"),
+                        format!("{} is out of range for {}", n, format),
+                    );
+                }
+                if !integer.enumeration.is_empty()
+                    && !integer
+                        .enumeration
+                        .iter()
+                        .any(|e| e.map(i128::from) == Some(n))
+                {
+                    self.error(
+                        path,
+                        &at("enum"),
+                        format!("{} is not one of the allowed values", n),
+                    );
+                }
+                self.check_number(
+                    n as f64,
+                    integer.minimum.map(|m| m as f64),
+                    integer.exclusive_minimum,
+                    integer.maximum.map(|m| m as f64),
+                    integer.exclusive_maximum,
+                    integer.multiple_of.map(|m| m as f64),
+                    path,
+                    schema_path,
+                );
+            }
+            SchemaKind::Type(Type::Boolean(boolean)) => {
+                let Some(b) = value.as_bool() else {
+                    return self.type_error(value, "boolean", path, &at("type"));
+                };
+                if !boolean.enumeration.is_empty() && !boolean.enumeration.contains(&Some(b)) {
+                    self.error(
+                        path,
+                        &at("enum"),
+                        format!("{} is not one of the allowed values", b),
+                    );
+                }
+            }
+            SchemaKind::Type(Type::Object(object)) => {
+                let Some(map) = value.as_object() else {
+                    return self.type_error(value, "object", path, &at("type"));
+                };
+                self.check_object(
+                    map,
+                    &object.properties,
+                    &object.required,
+                    object.additional_properties.as_ref(),
+                    object.min_properties,
+                    object.max_properties,
+                    path,
+                    schema_path,
+                );
+            }
+            SchemaKind::Type(Type::Array(array)) => {
+                let Some(items) = value.as_array() else {
+                    return self.type_error(value, "array", path, &at("type"));
+                };
+                self.check_array(
+                    items,
+                    array.items.as_ref(),
+                    array.min_items,
+                    array.max_items,
+                    array.unique_items,
+                    path,
+                    schema_path,
+                );
+            }
+            SchemaKind::OneOf { one_of } => self.check_one_of(
+                value,
+                one_of,
+                schema.schema_data.discriminator.as_ref(),
+                path,
+                &at("oneOf"),
+            ),
+            SchemaKind::AnyOf { any_of } => self.check_any_of(
+                value,
+                any_of,
+                schema.schema_data.discriminator.as_ref(),
+                path,
+                &at("anyOf"),
+            ),
+            SchemaKind::AllOf { all_of } => self.check_all_of(value, all_of, path, &at("allOf")),
+            SchemaKind::Not { not } => self.check_not(value, not, path, &at("not")),
+            SchemaKind::Any(any) => self.validate_any(
+                value,
+                any,
+                schema.schema_data.discriminator.as_ref(),
+                path,
+                schema_path,
+            ),
+        }
+    }
+
+    /// Validate against a schema that mixes keywords of several types
+    fn validate_any(
+        &mut self,
+        value: &Value,
+        any: &AnySchema,
+        discriminator: Option<&Discriminator>,
+        path: &str,
+        schema_path: &str,
+    ) {
+        let at = |keyword: &str| format!("{}/{}", schema_path, keyword);
+
+        if let Some(typ) = &any.typ {
+            let matches = match typ.as_str() {
+                "string" => value.is_string(),
+                "number" => value.is_number(),
+                "integer" => as_integer(value).is_some(),
+                "boolean" => value.is_boolean(),
+                "object" => value.is_object(),
+                "array" => value.is_array(),
+                _ => true,
+            };
+            if !matches {
+                return self.type_error(value, typ, path, &at("type"));
+            }
+        }
+
+        if !any.enumeration.is_empty() && !any.enumeration.contains(value) {
+            self.error(path, &at("enum"), "value is not one of the allowed values");
+        }
+
+        match value {
+            Value::String(text) => self.check_string(
+                text,
+                any.min_length,
+                any.max_length,
+                any.pattern.as_deref(),
+                any.format.as_deref(),
+                path,
+                schema_path,
+            ),
+            Value::Number(n) => self.check_number(
+                n.as_f64().unwrap_or_default(),
+                any.minimum,
+                any.exclusive_minimum.unwrap_or_default(),
+                any.maximum,
+                any.exclusive_maximum.unwrap_or_default(),
+                any.multiple_of,
+                path,
+                schema_path,
+            ),
+            Value::Object(map) => self.check_object(
+                map,
+                &any.properties,
+                &any.required,
+                any.additional_properties.as_ref(),
+                any.min_properties,
+                any.max_properties,
+                path,
+                schema_path,
+            ),
+            Value::Array(items) => self.check_array(
+                items,
+                any.items.as_ref(),
+                any.min_items,
+                any.max_items,
+                any.unique_items.unwrap_or_default(),
+                path,
+                schema_path,
+            ),
+            _ => {}
+        }
+
+        if !any.one_of.is_empty() {
+            self.check_one_of(value, &any.one_of, discriminator, path, &at("oneOf"));
+        }
+        if !any.any_of.is_empty() {
+            self.check_any_of(value, &any.any_of, discriminator, path, &at("anyOf"));
+        }
+        if !any.all_of.is_empty() {
+            self.check_all_of(value, &any.all_of, path, &at("allOf"));
+        }
+        if let Some(not) = &any.not {
+            self.check_not(value, not, path, &at("not"));
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn check_string(
+        &mut self,
+        text: &str,
+        min_length: Option<usize>,
+        max_length: Option<usize>,
+        pattern: Option<&str>,
+        format: Option<&str>,
+        path: &str,
+        schema_path: &str,
+    ) {
+        let at = |keyword: &str| format!("{}/{}", schema_path, keyword);
+        let length = text.chars().count();
+        if let Some(min) = min_length
+            && length < min
+        {
+            self.error(
+                path,
+                &at("minLength"),
+                format!("string is shorter than {} characters", min),
+            );
+        }
+        if let Some(max) = max_length
+            && length > max
+        {
+            self.error(
+                path,
+                &at("maxLength"),
+                format!("string is longer than {} characters", max),
+            );
+        }
+        if let Some(pattern) = pattern {
+            match Regex::new(pattern) {
+                Ok(regex) if !regex.is_match(text) => self.error(
+                    path,
+                    &at("pattern"),
+                    format!("string does not match pattern {}", pattern),
+                ),
+                Ok(_) => {}
+                Err(_) => self.error(path, &at("pattern"), format!("invalid pattern {}", pattern)),
+            }
+        }
+        if let Some(format) = format
+            && !check_string_format(format, text)
+        {
+            self.error(
+                path,
+                &at("format"),
+                format!("{:?} is not a valid {}", text, format),
+            );
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn check_number(
+        &mut self,
+        n: f64,
+        minimum: Option<f64>,
+        exclusive_minimum: bool,
+        maximum: Option<f64>,
+        exclusive_maximum: bool,
+        multiple_of: Option<f64>,
+        path: &str,
+        schema_path: &str,
+    ) {
+        let at = |keyword: &str| format!("{}/{}", schema_path, keyword);
+        if let Some(min) = minimum {
+            if exclusive_minimum && n <= min {
+                self.error(
+                    path,
+                    &at("minimum"),
+                    format!("{} must be greater than {}", n, min),
+                );
+            } else if n < min {
+                self.error(
+                    path,
+                    &at("minimum"),
+                    format!("{} must be at least {}", n, min),
+                );
+            }
+        }
+        if let Some(max) = maximum {
+            if exclusive_maximum && n >= max {
+                self.error(
+                    path,
+                    &at("maximum"),
+                    format!("{} must be less than {}", n, max),
+                );
+            } else if n > max {
+                self.error(
+                    path,
+                    &at("maximum"),
+                    format!("{} must be at most {}", n, max),
+                );
+            }
+        }
+        if let Some(multiple) = multiple_of
+            && multiple != 0.0
+            && (n / multiple).fract() != 0.0
+        {
+            self.error(
+                path,
+                &at("multipleOf"),
+                format!("{} is not a multiple of {}", n, multiple),
+            );
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn check_object(
+        &mut self,
+        map: &Map<String, Value>,
+        properties: &IndexMap<String, ReferenceOr<Box<Schema>>>,
+        required: &[String],
+        additional_properties: Option<&AdditionalProperties>,
+        min_properties: Option<usize>,
+        max_properties: Option<usize>,
+        path: &str,
+        schema_path: &str,
+    ) {
+        let at = |keyword: &str| format!("{}/{}", schema_path, keyword);
+
+        for name in required {
+            if map.contains_key(name) {
+                continue;
+            }
+            // Read-only properties are not sent in requests and write-only
+            // properties are not returned in responses, even when required
+            let exempt = properties
+                .get(name)
+                .and_then(|property| self.resolve(property))
+                .is_some_and(|property| self.is_excluded(&property));
+            if !exempt {
+                self.error(
+                    path,
+                    &at("required"),
+                    format!("missing required property {:?}", name),
+                );
+            }
+        }
+
+        // Declared properties are checked in schema order, the rest afterwards
+        for (name, property) in properties {
+            let Some(property_value) = map.get(name) else {
+                continue;
+            };
+            let property_path = format!("{}/{}", path, escape_pointer(name));
+            let property_schema_path =
+                format!("{}/properties/{}", schema_path, escape_pointer(name));
+            if self
+                .resolve(property)
+                .is_some_and(|resolved| self.is_excluded(&resolved))
+            {
+                let message = match self.direction {
+                    Some(Direction::Request) => "read-only property must not be sent in a request",
+                    _ => "write-only property must not be returned in a response",
+                };
+                self.error(&property_path, &property_schema_path, message);
+            } else {
+                self.validate_ref(
+                    property_value,
+                    property,
+                    &property_path,
+                    &property_schema_path,
+                );
+            }
+        }
+
+        for (name, property_value) in map {
+            if properties.contains_key(name) {
+                continue;
+            }
+            let property_path = format!("{}/{}", path, escape_pointer(name));
+            match additional_properties {
+                Some(AdditionalProperties::Any(false)) => self.error(
+                    &property_path,
+                    &at("additionalProperties"),
+                    format!("unexpected property {:?}", name),
+                ),
+                Some(AdditionalProperties::Schema(schema)) => self.validate_ref(
+                    property_value,
+                    schema.as_ref(),
+                    &property_path,
+                    &at("additionalProperties"),
+                ),
+                _ => {}
+            }
+        }
+
+        if let Some(min) = min_properties
+            && map.len() < min
+        {
+            self.error(
+                path,
+                &at("minProperties"),
+                format!("object has fewer than {} properties", min),
+            );
+        }
+        if let Some(max) = max_properties
+            && map.len() > max
+        {
+            self.error(
+                path,
+                &at("maxProperties"),
+                format!("object has more than {} properties", max),
+            );
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn check_array(
+        &mut self,
+        items: &[Value],
+        item_schema: Option<&ReferenceOr<Box<Schema>>>,
+        min_items: Option<usize>,
+        max_items: Option<usize>,
+        unique_items: bool,
+        path: &str,
+        schema_path: &str,
+    ) {
+        let at = |keyword: &str| format!("{}/{}", schema_path, keyword);
+        if let Some(min) = min_items
+            && items.len() < min
+        {
+            self.error(
+                path,
+                &at("minItems"),
+                format!("array has fewer than {} items", min),
+            );
+        }
+        if let Some(max) = max_items
+            && items.len() > max
+        {
+            self.error(
+                path,
+                &at("maxItems"),
+                format!("array has more than {} items", max),
+            );
+        }
+        if unique_items
+            && items
+                .iter()
+                .enumerate()
+                .any(|(i, item)| items[..i].contains(item))
+        {
+            self.error(path, &at("uniqueItems"), "array items are not unique");
+        }
+        if let Some(item_schema) = item_schema {
+            for (index, item) in items.iter().enumerate() {
+                self.validate_ref(
+                    item,
+                    item_schema,
+                    &format!("{}/{}", path, index),
+                    &at("items"),
+                );
+            }
+        }
+    }
+
+    fn check_one_of(
+        &mut self,
+        value: &Value,
+        one_of: &[ReferenceOr<Schema>],
+        discriminator: Option<&Discriminator>,
+        path: &str,
+        schema_path: &str,
+    ) {
+        if let Some(discriminator) = discriminator {
+            return self.check_discriminator(value, one_of, discriminator, path, schema_path);
+        }
+
+        let matching = one_of
+            .iter()
+            .enumerate()
+            .filter(|(i, s)| {
+                self.probe(value, s, path, &format!("{}/{}", schema_path, i))
+                    .is_empty()
+            })
+            .count();
+        if matching != 1 {
+            self.error(
+                path,
+                schema_path,
+                format!(
+                    "value matches {} oneOf schemas, expected exactly 1",
+                    matching
+                ),
+            );
+        }
+    }
+
+    fn check_any_of(
+        &mut self,
+        value: &Value,
+        any_of: &[ReferenceOr<Schema>],
+        discriminator: Option<&Discriminator>,
+        path: &str,
+        schema_path: &str,
+    ) {
+        if let Some(discriminator) = discriminator {
+            return self.check_discriminator(value, any_of, discriminator, path, schema_path);
+        }
+
+        let matches_any = any_of.iter().enumerate().any(|(i, s)| {
+            self.probe(value, s, path, &format!("{}/{}", schema_path, i))
+                .is_empty()
+        });
+        if !matches_any {
+            self.error(path, schema_path, "value does not match any anyOf schema");
+        }
+    }
+
+    fn check_all_of(
+        &mut self,
+        value: &Value,
+        all_of: &[ReferenceOr<Schema>],
+        path: &str,
+        schema_path: &str,
+    ) {
+        for (index, schema) in all_of.iter().enumerate() {
+            self.validate_ref(value, schema, path, &format!("{}/{}", schema_path, index));
+        }
+    }
+
+    fn check_not(
+        &mut self,
+        value: &Value,
+        not: &ReferenceOr<Schema>,
+        path: &str,
+        schema_path: &str,
+    ) {
+        if self.probe(value, not, path, schema_path).is_empty() {
+            self.error(path, schema_path, "value must not match the `not` schema");
+        }
+    }
+
+    /// Select the variant named by the discriminator property and validate against it
+    fn check_discriminator(
+        &mut self,
+        value: &Value,
+        variants: &[ReferenceOr<Schema>],
+        discriminator: &Discriminator,
+        path: &str,
+        schema_path: &str,
+    ) {
+        let property = &discriminator.property_name;
+        let discriminator_path = match schema_path.rsplit_once('/') {
+            Some((parent, _)) => format!("{}/discriminator", parent),
+            None => format!("{}/discriminator", schema_path),
+        };
+
+        let Some(tag) = value.get(property).and_then(Value::as_str) else {
+            return self.error(
+                path,
+                &discriminator_path,
+                format!("missing discriminator property {:?}", property),
+            );
+        };
+
+        // Mapping values are either references or bare component names
+        let reference = match discriminator.mapping.get(tag) {
+            Some(target) if target.starts_with('#') => target.clone(),
+            Some(target) => format!("#/components/schemas/{}", target),
+            None => format!("#/components/schemas/{}", tag),
+        };
+
+        // Inline variants are matched on the schema the reference resolves to
+        let Some(target) = resolve_schema_ref(&reference, self.spec) else {
+            return self.error(
+                &format!("{}/{}", path, escape_pointer(property)),
+                &discriminator_path,
+                format!("unknown discriminator value {:?}", tag),
+            );
+        };
+        let index = variants.iter().position(|variant| match variant {
+            ReferenceOr::Reference { reference: r } => *r == reference,
+            ReferenceOr::Item(schema) => *schema == target,
+        });
+
+        match index {
+            Some(index) => {
+                self.validate_schema(value, &target, path, &format!("{}/{}", schema_path, index))
+            }
+            None => self.error(
+                &format!("{}/{}", path, escape_pointer(property)),
+                &discriminator_path,
+                format!("discriminator value {:?} does not select a variant", tag),
+            ),
+        }
+    }
+
+    /// Whether the property must not appear in the current direction
+    fn is_excluded(&self, property: &Schema) -> bool {
+        match self.direction {
+            Some(Direction::Request) => property.schema_data.read_only,
+            Some(Direction::Response) => property.schema_data.write_only,
+            None => false,
+        }
+    }
+
+    fn type_error(&mut self, value: &Value, expected: &str, path: &str, schema_path: &str) {
+        self.error(
+            path,
+            schema_path,
+            format!("expected {}, found {}", expected, json_type_name(value)),
+        );
+    }
+}
+
+/// Integers may be encoded as floats without a fractional part (`1.0`)
+fn as_integer(value: &Value) -> Option<i128> {
+    let number = value.as_number()?;
+    number
+        .as_i64()
+        .map(i128::from)
+        .or_else(|| number.as_u64().map(i128::from))
+        .or_else(|| {
+            number
+                .as_f64()
+                .filter(|f| f.fract() == 0.0 && f.abs() < 1e38)
+                .map(|f| f as i128)
+        })
+}
+
+pub(crate) fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
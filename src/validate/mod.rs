@@ -0,0 +1,297 @@
+//! Validation of JSON values against OpenAPI schemas
+//!
+//! This module checks JSON instances, such as request and response bodies or the
+//! examples embedded in a specification, against the schemas they are declared for.
+
+mod examples;
+mod format;
+mod instance;
+
+// Re-export the public API
+pub use examples::{ExampleError, validate_examples};
+pub use instance::{
+    Direction, ValidationError, ValidationOptions, validate_value, validate_value_with,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openapiv3::OpenAPI;
+
+    const SPEC: &str = r##"
+openapi: 3.0.3
+info: { title: Pets, version: "1.0" }
+paths:
+  /pets:
+    get:
+      parameters:
+        - name: limit
+          in: query
+          schema: { type: integer, maximum: 100 }
+          example: 500
+      responses:
+        "200":
+          description: ok
+          content:
+            application/json:
+              schema: { $ref: "#/components/schemas/Pet" }
+              examples:
+                good: { $ref: "#/components/examples/Rex" }
+                bad:
+                  value: { name: 12, kind: fish }
+components:
+  examples:
+    Rex:
+      value: { name: Rex, kind: dog, born: "2020-01-31" }
+  schemas:
+    Pet:
+      type: object
+      required: [name]
+      properties:
+        name: { type: string }
+        kind: { type: string, enum: [cat, dog] }
+        born: { type: string, format: date, example: "31/01/2020" }
+"##;
+
+    #[test]
+    fn test_validate_examples() {
+        let spec: OpenAPI = serde_yaml::from_str(SPEC).unwrap();
+        let errors = validate_examples(&spec);
+        let found: Vec<(&str, &str)> = errors
+            .iter()
+            .map(|e| (e.pointer.as_str(), e.error.instance_path.as_str()))
+            .collect();
+
+        assert_eq!(
+            found,
+            vec![
+                ("#/components/schemas/Pet/properties/born/example", ""),
+                ("#/paths/~1pets/get/parameters/0/example", ""),
+                (
+                    "#/paths/~1pets/get/responses/200/content/application~1json/examples/bad/value",
+                    "/name"
+                ),
+                (
+                    "#/paths/~1pets/get/responses/200/content/application~1json/examples/bad/value",
+                    "/kind"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_examples_headers_and_callbacks() {
+        let spec: OpenAPI = serde_yaml::from_str(
+            r##"
+openapi: 3.0.3
+info: { title: Hooks, version: "1.0" }
+paths:
+  /subscriptions:
+    post:
+      responses:
+        "201":
+          description: created
+          headers:
+            X-Rate-Limit: { schema: { type: integer }, example: many }
+      callbacks:
+        onEvent:
+          "{$request.body#/url}":
+            post:
+              requestBody:
+                content:
+                  application/json:
+                    schema: { type: object, properties: { id: { type: integer } } }
+                    example: { id: abc }
+              responses:
+                "200": { description: ok }
+components:
+  headers:
+    X-Trace: { schema: { type: string, format: uuid }, example: not-a-uuid }
+  callbacks:
+    onPing:
+      "{$request.body#/url}":
+        get:
+          responses:
+            "200":
+              description: ok
+              content:
+                text/plain: { schema: { type: string, maxLength: 2 }, example: pong }
+"##,
+        )
+        .unwrap();
+        let errors = validate_examples(&spec);
+        let pointers: Vec<&str> = errors.iter().map(|e| e.pointer.as_str()).collect();
+
+        assert_eq!(
+            pointers,
+            vec![
+                "#/components/headers/X-Trace/example",
+                "#/components/callbacks/onPing/{$request.body#~1url}/get/responses/200/content/text~1plain/example",
+                "#/paths/~1subscriptions/post/responses/201/headers/X-Rate-Limit/example",
+                "#/paths/~1subscriptions/post/callbacks/onEvent/{$request.body#~1url}/post/requestBody/content/application~1json/example",
+            ]
+        );
+    }
+
+    const MODELS: &str = r##"
+openapi: 3.0.3
+info: { title: Pets, version: "1.0" }
+paths: {}
+components:
+  schemas:
+    Pet:
+      oneOf:
+        - $ref: "#/components/schemas/Cat"
+        - $ref: "#/components/schemas/Dog"
+      discriminator:
+        propertyName: kind
+        mapping:
+          cat: Cat
+          dog: "#/components/schemas/Dog"
+    Cat:
+      type: object
+      required: [kind, id, lives]
+      additionalProperties: false
+      properties:
+        kind: { type: string }
+        id: { type: string, format: uuid, readOnly: true }
+        lives: { type: integer, format: int32 }
+        password: { type: string, writeOnly: true }
+        nickname: { type: string, nullable: true }
+    Dog:
+      type: object
+      required: [kind]
+      properties:
+        kind: { type: string }
+        email: { type: string, format: email }
+"##;
+
+    fn paths(errors: &[ValidationError]) -> Vec<(&str, &str)> {
+        errors
+            .iter()
+            .map(|e| (e.instance_path.as_str(), e.schema_path.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_validate_value_discriminator() {
+        let spec: OpenAPI = serde_yaml::from_str(MODELS).unwrap();
+        let pet = openapiv3::ReferenceOr::ref_("#/components/schemas/Pet");
+
+        let cat = serde_json::json!({
+            "kind": "cat",
+            "id": "not-a-uuid",
+            "lives": 4294967296i64,
+            "nickname": null,
+            "color": "black"
+        });
+        assert_eq!(
+            paths(&validate_value(&cat, &pet, &spec)),
+            vec![
+                (
+                    "/id",
+                    "#/components/schemas/Pet/oneOf/0/properties/id/format"
+                ),
+                (
+                    "/lives",
+                    "#/components/schemas/Pet/oneOf/0/properties/lives/format"
+                ),
+                (
+                    "/color",
+                    "#/components/schemas/Pet/oneOf/0/additionalProperties"
+                ),
+            ]
+        );
+
+        let dog = serde_json::json!({ "kind": "dog", "email": "nope" });
+        assert_eq!(
+            paths(&validate_value(&dog, &pet, &spec)),
+            vec![(
+                "/email",
+                "#/components/schemas/Pet/oneOf/1/properties/email/format"
+            )]
+        );
+
+        let fish = serde_json::json!({ "kind": "fish" });
+        assert_eq!(
+            paths(&validate_value(&fish, &pet, &spec)),
+            vec![("/kind", "#/components/schemas/Pet/discriminator")]
+        );
+    }
+
+    #[test]
+    fn test_validate_value_direction() {
+        let spec: OpenAPI = serde_yaml::from_str(MODELS).unwrap();
+        let cat = openapiv3::ReferenceOr::ref_("#/components/schemas/Cat");
+
+        let request = serde_json::json!({ "kind": "cat", "lives": 9, "password": "x" });
+        assert!(
+            validate_value_with(&request, &cat, &spec, &ValidationOptions::request()).is_empty()
+        );
+
+        let response = serde_json::json!({
+            "kind": "cat",
+            "id": "0b7c1a52-57a4-4a53-a2d4-3f1d7c1e0c8e",
+            "lives": 9,
+            "password": "x"
+        });
+        let errors = validate_value_with(&response, &cat, &spec, &ValidationOptions::response());
+        assert_eq!(
+            paths(&errors),
+            vec![("/password", "#/components/schemas/Cat/properties/password")]
+        );
+
+        let errors = validate_value_with(&response, &cat, &spec, &ValidationOptions::request());
+        assert_eq!(
+            paths(&errors),
+            vec![("/id", "#/components/schemas/Cat/properties/id")]
+        );
+    }
+
+    #[test]
+    fn test_validate_value_recursive() {
+        let spec: OpenAPI = serde_yaml::from_str(
+            r##"
+openapi: 3.0.3
+info: { title: Trees, version: "1.0" }
+paths: {}
+components:
+  schemas:
+    Tree:
+      oneOf:
+        - { type: string }
+        - $ref: "#/components/schemas/Branch"
+    Branch:
+      type: object
+      required: [children]
+      properties:
+        children:
+          type: array
+          items: { $ref: "#/components/schemas/Tree" }
+    Loop:
+      allOf:
+        - $ref: "#/components/schemas/Loop"
+        - { type: integer }
+"##,
+        )
+        .unwrap();
+        let tree = openapiv3::ReferenceOr::ref_("#/components/schemas/Tree");
+
+        let value = serde_json::json!({ "children": ["leaf", { "children": ["leaf"] }] });
+        assert!(validate_value(&value, &tree, &spec).is_empty());
+
+        let value = serde_json::json!({ "children": ["leaf", { "children": [1] }] });
+        assert_eq!(
+            paths(&validate_value(&value, &tree, &spec)),
+            vec![("", "#/components/schemas/Tree/oneOf")]
+        );
+
+        // A reference back to itself on the same value adds no constraint
+        let cycle = openapiv3::ReferenceOr::ref_("#/components/schemas/Loop");
+        assert!(validate_value(&serde_json::json!(3), &cycle, &spec).is_empty());
+        assert_eq!(
+            paths(&validate_value(&serde_json::json!("3"), &cycle, &spec)),
+            vec![("", "#/components/schemas/Loop/allOf/1/type")]
+        );
+    }
+}
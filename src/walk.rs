@@ -0,0 +1,282 @@
+//! OpenAPI document traversal helpers
+//!
+//! This module provides utilities for visiting the schemas and operations of an
+//! OpenAPI specification together with the JSON pointer of each visited node.
+
+use indexmap::IndexMap;
+use openapiv3::{
+    AdditionalProperties, Callback, Header, MediaType, OpenAPI, Operation, Parameter,
+    ParameterSchemaOrContent, PathItem, ReferenceOr, Response, Schema, SchemaKind, Type,
+};
+
+/// Escape a single JSON pointer segment (`~` becomes `~0`, `/` becomes `~1`)
+///
+/// ### Examples
+///
+/// ```
+/// # use rustval::walk::escape_pointer;
+/// assert_eq!(escape_pointer("/pets/{id}"), "~1pets~1{id}");
+/// ```
+pub fn escape_pointer(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// Unescape a single JSON pointer segment
+pub fn unescape_pointer(segment: &str) -> String {
+    segment.replace("~1", "/").replace("~0", "~")
+}
+
+/// Build the JSON pointer of an operation, e.g. `#/paths/~1pets/get`
+pub fn operation_pointer(path: &str, method: &str) -> String {
+    format!("#/paths/{}/{}", escape_pointer(path), method)
+}
+
+/// Callbacks invoked while walking a specification with [`walk_spec`]
+///
+/// Every method has an empty default implementation, so visitors only need to
+/// implement the callbacks they are interested in.
+pub trait Visitor<'a> {
+    /// Called for every inline schema, including nested ones
+    fn visit_schema(&mut self, _pointer: &str, _schema: &'a Schema) {}
+
+    /// Called for every inline parameter
+    fn visit_parameter(&mut self, _pointer: &str, _parameter: &'a Parameter) {}
+
+    /// Called for every inline header of the components and of responses
+    fn visit_header(&mut self, _pointer: &str, _header: &'a Header) {}
+
+    /// Called for every media type of request bodies, responses, parameters
+    /// and headers
+    fn visit_media_type(&mut self, _pointer: &str, _media_type: &'a MediaType) {}
+}
+
+/// Walk the whole specification in document order
+///
+/// Component schemas are visited first, then the reusable parameters, headers,
+/// request bodies, responses and callbacks, and finally everything declared
+/// inline on path items and operations, including their callbacks. References
+/// are not followed, so every node is visited exactly once under the pointer
+/// where it is declared.
+pub fn walk_spec<'a>(spec: &'a OpenAPI, visitor: &mut dyn Visitor<'a>) {
+    if let Some(components) = &spec.components {
+        for (name, schema) in &components.schemas {
+            if let ReferenceOr::Item(schema) = schema {
+                let pointer = format!("#/components/schemas/{}", escape_pointer(name));
+                walk_schema(schema, &pointer, visitor);
+            }
+        }
+        for (name, parameter) in &components.parameters {
+            if let ReferenceOr::Item(parameter) = parameter {
+                let pointer = format!("#/components/parameters/{}", escape_pointer(name));
+                walk_parameter(parameter, &pointer, visitor);
+            }
+        }
+        for (name, header) in &components.headers {
+            if let ReferenceOr::Item(header) = header {
+                let pointer = format!("#/components/headers/{}", escape_pointer(name));
+                walk_header(header, &pointer, visitor);
+            }
+        }
+        for (name, body) in &components.request_bodies {
+            if let ReferenceOr::Item(body) = body {
+                let pointer = format!(
+                    "#/components/requestBodies/{}/content",
+                    escape_pointer(name)
+                );
+                walk_content(&body.content, &pointer, visitor);
+            }
+        }
+        for (name, response) in &components.responses {
+            if let ReferenceOr::Item(response) = response {
+                let pointer = format!("#/components/responses/{}", escape_pointer(name));
+                walk_response(response, &pointer, visitor);
+            }
+        }
+        for (name, callback) in &components.callbacks {
+            if let ReferenceOr::Item(callback) = callback {
+                let pointer = format!("#/components/callbacks/{}", escape_pointer(name));
+                walk_callback(callback, &pointer, visitor);
+            }
+        }
+    }
+
+    for (path, path_item) in &spec.paths.paths {
+        if let ReferenceOr::Item(path_item) = path_item {
+            let pointer = format!("#/paths/{}", escape_pointer(path));
+            walk_path_item(path_item, &pointer, visitor);
+        }
+    }
+}
+
+/// Visit every inline schema in the specification
+///
+/// This is a shorthand for [`walk_spec`] with a visitor that only looks at schemas.
+pub fn visit_schemas<'a>(spec: &'a OpenAPI, visitor: &mut dyn FnMut(&str, &'a Schema)) {
+    struct SchemaVisitor<'v, 'a>(&'v mut dyn FnMut(&str, &'a Schema));
+    impl<'a> Visitor<'a> for SchemaVisitor<'_, 'a> {
+        fn visit_schema(&mut self, pointer: &str, schema: &'a Schema) {
+            (self.0)(pointer, schema)
+        }
+    }
+
+    walk_spec(spec, &mut SchemaVisitor(visitor));
+}
+
+/// Walk a schema and all inline schemas nested inside it
+pub fn walk_schema<'a>(schema: &'a Schema, pointer: &str, visitor: &mut dyn Visitor<'a>) {
+    visitor.visit_schema(pointer, schema);
+
+    match &schema.schema_kind {
+        SchemaKind::Type(Type::Object(obj)) => {
+            for (name, property) in &obj.properties {
+                if let ReferenceOr::Item(property) = property {
+                    let pointer = format!("{}/properties/{}", pointer, escape_pointer(name));
+                    walk_schema(property, &pointer, visitor);
+                }
+            }
+            if let Some(AdditionalProperties::Schema(additional)) = &obj.additional_properties
+                && let ReferenceOr::Item(additional) = additional.as_ref()
+            {
+                let pointer = format!("{}/additionalProperties", pointer);
+                walk_schema(additional, &pointer, visitor);
+            }
+        }
+        SchemaKind::Type(Type::Array(array)) => {
+            if let Some(ReferenceOr::Item(items)) = &array.items {
+                let pointer = format!("{}/items", pointer);
+                walk_schema(items, &pointer, visitor);
+            }
+        }
+        SchemaKind::OneOf { one_of: list }
+        | SchemaKind::AllOf { all_of: list }
+        | SchemaKind::AnyOf { any_of: list } => {
+            let keyword = match &schema.schema_kind {
+                SchemaKind::OneOf { .. } => "oneOf",
+                SchemaKind::AllOf { .. } => "allOf",
+                _ => "anyOf",
+            };
+            for (index, item) in list.iter().enumerate() {
+                if let ReferenceOr::Item(item) = item {
+                    let pointer = format!("{}/{}/{}", pointer, keyword, index);
+                    walk_schema(item, &pointer, visitor);
+                }
+            }
+        }
+        SchemaKind::Not { not } => {
+            if let ReferenceOr::Item(not) = not.as_ref() {
+                let pointer = format!("{}/not", pointer);
+                walk_schema(not, &pointer, visitor);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn walk_parameter<'a>(parameter: &'a Parameter, pointer: &str, visitor: &mut dyn Visitor<'a>) {
+    visitor.visit_parameter(pointer, parameter);
+    walk_format(&parameter.parameter_data_ref().format, pointer, visitor);
+}
+
+/// Walk the `schema` or `content` of a parameter or header
+fn walk_format<'a>(
+    format: &'a ParameterSchemaOrContent,
+    pointer: &str,
+    visitor: &mut dyn Visitor<'a>,
+) {
+    match format {
+        ParameterSchemaOrContent::Schema(ReferenceOr::Item(schema)) => {
+            walk_schema(schema, &format!("{}/schema", pointer), visitor);
+        }
+        ParameterSchemaOrContent::Schema(ReferenceOr::Reference { .. }) => {}
+        ParameterSchemaOrContent::Content(content) => {
+            walk_content(content, &format!("{}/content", pointer), visitor);
+        }
+    }
+}
+
+fn walk_path_item<'a>(path_item: &'a PathItem, pointer: &str, visitor: &mut dyn Visitor<'a>) {
+    for (index, parameter) in path_item.parameters.iter().enumerate() {
+        if let ReferenceOr::Item(parameter) = parameter {
+            let pointer = format!("{}/parameters/{}", pointer, index);
+            walk_parameter(parameter, &pointer, visitor);
+        }
+    }
+    for (method, operation) in path_item.iter() {
+        walk_operation(operation, &format!("{}/{}", pointer, method), visitor);
+    }
+}
+
+fn walk_operation<'a>(operation: &'a Operation, pointer: &str, visitor: &mut dyn Visitor<'a>) {
+    for (index, parameter) in operation.parameters.iter().enumerate() {
+        if let ReferenceOr::Item(parameter) = parameter {
+            let pointer = format!("{}/parameters/{}", pointer, index);
+            walk_parameter(parameter, &pointer, visitor);
+        }
+    }
+
+    if let Some(ReferenceOr::Item(body)) = &operation.request_body {
+        let pointer = format!("{}/requestBody/content", pointer);
+        walk_content(&body.content, &pointer, visitor);
+    }
+
+    let responses = operation
+        .responses
+        .default
+        .iter()
+        .map(|response| ("default".to_string(), response))
+        .chain(
+            operation
+                .responses
+                .responses
+                .iter()
+                .map(|(status, response)| (status.to_string(), response)),
+        );
+    for (status, response) in responses {
+        if let ReferenceOr::Item(response) = response {
+            let pointer = format!("{}/responses/{}", pointer, status);
+            walk_response(response, &pointer, visitor);
+        }
+    }
+
+    for (name, callback) in &operation.callbacks {
+        let pointer = format!("{}/callbacks/{}", pointer, escape_pointer(name));
+        walk_callback(callback, &pointer, visitor);
+    }
+}
+
+fn walk_response<'a>(response: &'a Response, pointer: &str, visitor: &mut dyn Visitor<'a>) {
+    for (name, header) in &response.headers {
+        if let ReferenceOr::Item(header) = header {
+            let pointer = format!("{}/headers/{}", pointer, escape_pointer(name));
+            walk_header(header, &pointer, visitor);
+        }
+    }
+    walk_content(&response.content, &format!("{}/content", pointer), visitor);
+}
+
+/// A callback maps runtime expressions to path items
+fn walk_callback<'a>(callback: &'a Callback, pointer: &str, visitor: &mut dyn Visitor<'a>) {
+    for (expression, path_item) in callback {
+        let pointer = format!("{}/{}", pointer, escape_pointer(expression));
+        walk_path_item(path_item, &pointer, visitor);
+    }
+}
+
+fn walk_header<'a>(header: &'a Header, pointer: &str, visitor: &mut dyn Visitor<'a>) {
+    visitor.visit_header(pointer, header);
+    walk_format(&header.format, pointer, visitor);
+}
+
+fn walk_content<'a>(
+    content: &'a IndexMap<String, MediaType>,
+    pointer: &str,
+    visitor: &mut dyn Visitor<'a>,
+) {
+    for (media_type, media) in content {
+        let pointer = format!("{}/{}", pointer, escape_pointer(media_type));
+        visitor.visit_media_type(&pointer, media);
+        if let Some(ReferenceOr::Item(schema)) = &media.schema {
+            walk_schema(schema, &format!("{}/schema", pointer), visitor);
+        }
+    }
+}
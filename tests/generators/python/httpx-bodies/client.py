@@ -0,0 +1,246 @@
+# Generated by rustval. Do not edit manually.
+# Forms
+# OpenAPI spec version: 1.0
+
+from __future__ import annotations
+
+import json
+from dataclasses import dataclass
+from typing import Any, Dict, Generic, List, Literal, Optional, Tuple, TypeVar, Union
+from urllib.parse import quote, urlencode
+
+import httpx
+from pydantic import BaseModel, TypeAdapter
+from pydantic_core import to_jsonable_python
+
+from .models import *  # noqa: F401,F403
+
+
+_Status = TypeVar("_Status")
+_Data = TypeVar("_Data")
+
+
+@dataclass
+class ApiResponse(Generic[_Status, _Data]):
+    """A response with a status the spec declares and its validated body"""
+
+    status_code: _Status
+    data: _Data
+    response: httpx.Response
+
+
+UploadResponse = ApiResponse[Literal[204], None]
+LoginResponse = ApiResponse[Literal[204], None]
+
+
+class Client:
+    """A synchronous client for Forms"""
+
+    def __init__(
+        self,
+        base_url: str = "",
+        *,
+        client: Optional[httpx.Client] = None,
+        **kwargs: Any,
+    ) -> None:
+        """Send requests to `base_url`, or through `client` when given
+
+        Other keyword arguments configure the `httpx.Client`.
+        """
+        self._client = client if client is not None else httpx.Client(base_url=base_url, **kwargs)
+
+    def close(self) -> None:
+        self._client.close()
+
+    def __enter__(self) -> Client:
+        return self
+
+    def __exit__(self, *args: Any) -> None:
+        self.close()
+
+    def upload(self, body: UploadBody) -> UploadResponse:
+        response = self._client.request(
+            "POST",
+            "/upload",
+            files=_multipart(body, {"meta": "application/json"}),
+        )
+        if response.status_code == 204:
+            return ApiResponse[Literal[204], None](204, None, response)
+        raise ApiError(response)
+
+    def login(self, body: LoginBody) -> LoginResponse:
+        response = self._client.request(
+            "POST",
+            "/login",
+            content=_form(body, {"scopes": ("spaceDelimited", False)}),
+            headers=_values([("Content-Type", "application/x-www-form-urlencoded")]),
+        )
+        if response.status_code == 204:
+            return ApiResponse[Literal[204], None](204, None, response)
+        raise ApiError(response)
+
+
+class AsyncClient:
+    """An asynchronous client for Forms"""
+
+    def __init__(
+        self,
+        base_url: str = "",
+        *,
+        client: Optional[httpx.AsyncClient] = None,
+        **kwargs: Any,
+    ) -> None:
+        """Send requests to `base_url`, or through `client` when given
+
+        Other keyword arguments configure the `httpx.AsyncClient`.
+        """
+        self._client = client if client is not None else httpx.AsyncClient(base_url=base_url, **kwargs)
+
+    async def aclose(self) -> None:
+        await self._client.aclose()
+
+    async def __aenter__(self) -> AsyncClient:
+        return self
+
+    async def __aexit__(self, *args: Any) -> None:
+        await self.aclose()
+
+    async def upload(self, body: UploadBody) -> UploadResponse:
+        response = await self._client.request(
+            "POST",
+            "/upload",
+            files=_multipart(body, {"meta": "application/json"}),
+        )
+        if response.status_code == 204:
+            return ApiResponse[Literal[204], None](204, None, response)
+        raise ApiError(response)
+
+    async def login(self, body: LoginBody) -> LoginResponse:
+        response = await self._client.request(
+            "POST",
+            "/login",
+            content=_form(body, {"scopes": ("spaceDelimited", False)}),
+            headers=_values([("Content-Type", "application/x-www-form-urlencoded")]),
+        )
+        if response.status_code == 204:
+            return ApiResponse[Literal[204], None](204, None, response)
+        raise ApiError(response)
+
+
+class ApiError(Exception):
+    """A response with a status the spec does not declare"""
+
+    def __init__(self, response: httpx.Response) -> None:
+        super().__init__(f"unexpected status {response.status_code}: {response.text}")
+        self.response = response
+        self.status_code = response.status_code
+
+
+def _dump(value: Any) -> Any:
+    """The JSON-compatible form of a value, leaving out model fields that are None"""
+    if isinstance(value, BaseModel):
+        return value.model_dump(mode="json", by_alias=True, exclude_none=True)
+    if isinstance(value, list):
+        return [_dump(item) for item in value]
+    if isinstance(value, dict):
+        return {key: _dump(item) for key, item in value.items()}
+    return to_jsonable_python(value, by_alias=True)
+
+
+def _form(body: Any, styles: Dict[str, Tuple[str, bool]]) -> Optional[str]:
+    """A form body encoded like query parameters, in the exploded `form` style
+    unless `styles` gives the style and explode of a property"""
+    if body is None:
+        return None
+    params = [(name, value, *styles.get(name, ("form", True))) for name, value in _dump(body).items()]
+    return urlencode(_query(params))
+
+
+def _multipart(
+    body: Any, content_types: Dict[str, str]
+) -> Optional[List[Tuple[str, Tuple[Optional[str], Any, Optional[str]]]]]:
+    """The parts of a multipart body: one per file and per item of other lists,
+    except for properties sent as JSON, with the content type of `content_types`"""
+    if body is None:
+        return None
+    values = body.model_dump(by_alias=True, exclude_none=True) if isinstance(body, BaseModel) else body
+    parts = []
+    for name, value in values.items():
+        content_type = content_types.get(name)
+        as_json = content_type is not None and "json" in content_type
+        for item in value if isinstance(value, list) and not as_json else [value]:
+            if isinstance(item, bytes):
+                parts.append((name, (name, item, content_type or "application/octet-stream")))
+            elif as_json or isinstance(item, (dict, list)):
+                parts.append((name, (None, json.dumps(_dump(item)), content_type or "application/json")))
+            else:
+                parts.append((name, (None, _scalar(_dump(item)), content_type)))
+    return parts
+
+
+def _scalar(value: Any) -> str:
+    if isinstance(value, bool):
+        return "true" if value else "false"
+    return "" if value is None else str(value)
+
+
+def _path(name: str, value: Any, style: str, explode: bool) -> str:
+    """A path parameter serialized according to its `style` and `explode`"""
+    value = _dump(value)
+    if isinstance(value, list):
+        parts = [quote(_scalar(item), safe="") for item in value]
+    elif isinstance(value, dict) and explode:
+        parts = [f"{quote(key, safe='')}={quote(_scalar(item), safe='')}" for key, item in value.items()]
+    elif isinstance(value, dict):
+        parts = [quote(_scalar(part), safe="") for pair in value.items() for part in pair]
+    else:
+        parts = [quote(_scalar(value), safe="")]
+    if style == "label":
+        return "." + ("." if explode else ",").join(parts)
+    if style == "matrix" and explode and isinstance(value, list):
+        return "".join(f";{name}={part}" for part in parts)
+    if style == "matrix" and explode and isinstance(value, dict):
+        return "".join(f";{part}" for part in parts)
+    if style == "matrix":
+        return f";{name}=" + ",".join(parts)
+    return ",".join(parts)
+
+
+def _query(params: List[Tuple[str, Any, str, bool]]) -> List[Tuple[str, str]]:
+    """Query parameters serialized according to their `style` and `explode`,
+    leaving out `None`"""
+    query = []
+    for name, value, style, explode in params:
+        value = _dump(value)
+        if value is None:
+            continue
+        if isinstance(value, list) and explode and style == "form":
+            query.extend((name, _scalar(item)) for item in value)
+        elif isinstance(value, list):
+            separator = {"spaceDelimited": " ", "pipeDelimited": "|"}.get(style, ",")
+            query.append((name, separator.join(_scalar(item) for item in value)))
+        elif isinstance(value, dict) and style == "deepObject":
+            query.extend((f"{name}[{key}]", _scalar(item)) for key, item in value.items())
+        elif isinstance(value, dict) and explode:
+            query.extend((key, _scalar(item)) for key, item in value.items())
+        elif isinstance(value, dict):
+            query.append((name, ",".join(_scalar(part) for pair in value.items() for part in pair)))
+        else:
+            query.append((name, _scalar(value)))
+    return query
+
+
+def _values(params: List[Tuple[str, Any]]) -> Dict[str, str]:
+    """Header or cookie values in the `simple` style, leaving out `None`"""
+    values = {}
+    for name, value in params:
+        value = _dump(value)
+        if value is None:
+            continue
+        if isinstance(value, list):
+            values[name] = ",".join(_scalar(item) for item in value)
+        elif isinstance(value, dict):
+            values[name] = ",".join(_scalar(part) for pair in value.items() for part in pair)
+        else:
+            values[name] = _scalar(value)
+    return values
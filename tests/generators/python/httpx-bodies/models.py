@@ -0,0 +1,27 @@
+# Generated by rustval. Do not edit manually.
+# Forms
+# OpenAPI spec version: 1.0
+
+from __future__ import annotations
+
+from typing import List, Optional
+
+from pydantic import BaseModel
+
+
+class UploadBodyMeta(BaseModel):
+    title: Optional[str] = None
+
+
+class UploadBody(BaseModel):
+    file: Optional[bytes] = None
+    meta: Optional[UploadBodyMeta] = None
+
+
+class LoginBody(BaseModel):
+    scopes: Optional[List[str]] = None
+
+
+UploadBodyMeta.model_rebuild()
+UploadBody.model_rebuild()
+LoginBody.model_rebuild()
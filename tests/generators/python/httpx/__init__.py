@@ -0,0 +1,6 @@
+# Generated by rustval. Do not edit manually.
+# Pets
+# OpenAPI spec version: 1.0
+
+from .client import ApiError, ApiResponse, AsyncClient, Client
+from .models import *  # noqa: F401,F403
@@ -0,0 +1,51 @@
+# Generated by rustval. Do not edit manually.
+# Pets
+# OpenAPI spec version: 1.0
+
+from __future__ import annotations
+
+import datetime as _datetime
+from typing import Annotated, List, Literal, Optional, Union
+
+from pydantic import BaseModel, ConfigDict, Field
+
+
+class Dog(BaseModel):
+    model_config = ConfigDict(populate_by_name=True)
+
+    pet_type: Literal["dog"] = Field("dog", alias="petType")
+    good_boy: Optional[bool] = Field(None, alias="good-boy")
+
+
+class Base(BaseModel):
+    model_config = ConfigDict(populate_by_name=True)
+
+    pet_type: str = Field(alias="petType")
+    name: str = Field(min_length=1, pattern="^\\w+$")
+    born: Optional[_datetime.datetime] = None
+    size: Optional[Literal["small", "large"]] = None
+    json_: Optional[str] = Field(None, alias="json")
+
+
+class Cat(Base):
+    """A cat"""
+
+    pet_type: Literal["cat"] = Field("cat", alias="petType")
+    lives: Optional[int] = Field(None, ge=0, le=9)
+
+
+Pet = Annotated[Union[Cat, Dog], Field(discriminator="pet_type")]
+
+
+class Node(BaseModel):
+    value: str
+    children: Optional[List[Node]] = None
+
+
+Tags = Annotated[List[str], Field(max_length=5)]
+
+
+Dog.model_rebuild()
+Base.model_rebuild()
+Cat.model_rebuild()
+Node.model_rebuild()
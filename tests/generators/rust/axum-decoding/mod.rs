@@ -0,0 +1,8 @@
+//! Generated by rustval. Do not edit manually.
+//! Forms
+//! OpenAPI spec version: 1.0
+
+pub mod models;
+pub mod server;
+
+pub use server::{Api, router};
@@ -0,0 +1,349 @@
+//! Generated by rustval. Do not edit manually.
+//! Forms
+//! OpenAPI spec version: 1.0
+
+#[allow(unused_imports)]
+use super::models::*;
+
+/// The operations of Forms
+///
+/// [`router`] serves an implementation.
+pub trait Api: Send + Sync + 'static {
+    fn get_tree(&self, id: i64, params: GetTreeParams) -> impl std::future::Future<Output = GetTreeResponse> + Send;
+
+    fn login(&self, body: Option<LoginBody>) -> impl std::future::Future<Output = LoginResponse> + Send;
+
+    fn upload(&self, body: UploadBody) -> impl std::future::Future<Output = UploadResponse> + Send;
+}
+
+/// The responses of [`Api::get_tree`]
+#[derive(Debug)]
+pub enum GetTreeResponse {
+    /// Done
+    NoContent,
+}
+
+impl axum::response::IntoResponse for GetTreeResponse {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::NoContent => axum::http::StatusCode::NO_CONTENT.into_response(),
+        }
+    }
+}
+
+/// The responses of [`Api::login`]
+#[derive(Debug)]
+pub enum LoginResponse {
+    /// Done
+    NoContent,
+}
+
+impl axum::response::IntoResponse for LoginResponse {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::NoContent => axum::http::StatusCode::NO_CONTENT.into_response(),
+        }
+    }
+}
+
+/// The responses of [`Api::upload`]
+#[derive(Debug)]
+pub enum UploadResponse {
+    /// Done
+    NoContent,
+}
+
+impl axum::response::IntoResponse for UploadResponse {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::NoContent => axum::http::StatusCode::NO_CONTENT.into_response(),
+        }
+    }
+}
+
+/// The rejection of a request whose parameters do not match the spec
+#[derive(Debug)]
+pub struct Rejection(pub String);
+
+impl axum::response::IntoResponse for Rejection {
+    fn into_response(self) -> axum::response::Response {
+        (axum::http::StatusCode::BAD_REQUEST, self.0).into_response()
+    }
+}
+
+impl From<Rejection> for axum::response::Response {
+    fn from(rejection: Rejection) -> Self {
+        axum::response::IntoResponse::into_response(rejection)
+    }
+}
+
+impl<S: Send + Sync> axum::extract::FromRequestParts<S> for GetTreeParams {
+    type Rejection = Rejection;
+
+    async fn from_request_parts(parts: &mut axum::http::request::Parts, _state: &S) -> Result<Self, Rejection> {
+        let query = query_pairs(parts);
+        from_params(vec![
+            ("filter", deep_object(&query, "filter", &[("depth", "integer", false)])),
+        ])
+    }
+}
+
+/// A router serving every operation of [`Api`] with `api`
+pub fn router<T: Api>(api: T) -> axum::Router {
+    axum::Router::new()
+        .route("/trees/{id_json}", axum::routing::get(get_tree::<T>))
+        .route("/login", axum::routing::post(login::<T>))
+        .route("/upload", axum::routing::post(upload::<T>))
+        .with_state(std::sync::Arc::new(api))
+}
+
+async fn get_tree<T: Api>(axum::extract::State(api): axum::extract::State<std::sync::Arc<T>>, axum::extract::Path(id_json): axum::extract::Path<String>, params: GetTreeParams) -> Result<GetTreeResponse, axum::response::Response> {
+    let id_json = segment_values(&id_json, &["", ".json"])?;
+    let id = path_param(id_json[0], "integer", false, "simple", false)?;
+    Ok(api.get_tree(id, params).await)
+}
+
+async fn login<T: Api>(axum::extract::State(api): axum::extract::State<std::sync::Arc<T>>, body: axum::extract::Request) -> Result<LoginResponse, axum::response::Response> {
+    let body = match form_pairs(body).await? {
+        Some(body) => Some(from_params(vec![
+            ("user", param_value(query_values(&body, "user"), "string", false, "form", true)),
+            ("scopes", param_value(query_values(&body, "scopes"), "string", true, "spaceDelimited", false)),
+        ])?),
+        None => None,
+    };
+    Ok(api.login(body).await)
+}
+
+async fn upload<T: Api>(axum::extract::State(api): axum::extract::State<std::sync::Arc<T>>, body: axum::extract::Multipart) -> Result<UploadResponse, axum::response::Response> {
+    let body = multipart_parts(body).await?;
+    let body = UploadBody {
+        file: part_file(&body, "file")?,
+        meta: part_value(&body, "meta", "string", false, true)?,
+        tags: part_value(&body, "tags", "string", true, false)?,
+    };
+    Ok(api.upload(body).await)
+}
+
+/// The decoded name-value pairs of the query string
+fn query_pairs(parts: &axum::http::request::Parts) -> Vec<(String, String)> {
+    axum::extract::Query::<Vec<(String, String)>>::try_from_uri(&parts.uri)
+        .map(|axum::extract::Query(pairs)| pairs)
+        .unwrap_or_default()
+}
+
+/// Every value of a query parameter, in order
+fn query_values<'a>(query: &'a [(String, String)], name: &str) -> Vec<&'a str> {
+    query
+        .iter()
+        .filter(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+        .collect()
+}
+
+/// Convert the raw values of a parameter to JSON according to the `kind` of
+/// its items, its `style` and `explode`; `None` when it is absent
+fn param_value(
+    values: Vec<&str>,
+    kind: &str,
+    array: bool,
+    style: &str,
+    explode: bool,
+) -> Option<serde_json::Value> {
+    let item = |value: &str| match kind {
+        "integer" | "number" => value
+            .parse::<serde_json::Number>()
+            .map_or_else(|_| serde_json::Value::from(value), serde_json::Value::Number),
+        "boolean" => match value {
+            "true" => serde_json::Value::Bool(true),
+            "false" => serde_json::Value::Bool(false),
+            _ => serde_json::Value::from(value),
+        },
+        _ => serde_json::Value::from(value),
+    };
+    if !array {
+        return values.first().map(|value| item(value));
+    }
+    if values.is_empty() {
+        return None;
+    }
+    let separator = match style {
+        "spaceDelimited" => " ",
+        "pipeDelimited" => "|",
+        _ => ",",
+    };
+    let items = values
+        .iter()
+        .flat_map(|value| {
+            if explode && style == "form" {
+                vec![*value]
+            } else {
+                value.split(separator).collect()
+            }
+        })
+        .map(item)
+        .collect();
+    Some(serde_json::Value::Array(items))
+}
+
+/// Deserialize parameters from their JSON values, rejecting the request when
+/// they do not match
+fn from_params<T: serde::de::DeserializeOwned>(
+    params: Vec<(&str, Option<serde_json::Value>)>,
+) -> Result<T, Rejection> {
+    let map = params
+        .into_iter()
+        .filter_map(|(name, value)| Some((name.to_string(), value?)))
+        .collect();
+    serde_json::from_value(serde_json::Value::Object(map)).map_err(|error| Rejection(error.to_string()))
+}
+
+/// The properties of a `deepObject` parameter, gathered from its
+/// `name[property]` pairs and converted according to the `kinds` of their
+/// items; `None` when it is absent
+fn deep_object(
+    query: &[(String, String)],
+    name: &str,
+    kinds: &[(&str, &str, bool)],
+) -> Option<serde_json::Value> {
+    let mut properties: Vec<(&str, Vec<&str>)> = Vec::new();
+    for (key, value) in query {
+        let Some(property) = key
+            .strip_prefix(name)
+            .and_then(|key| key.strip_prefix('['))
+            .and_then(|key| key.strip_suffix(']'))
+        else {
+            continue;
+        };
+        match properties.iter_mut().find(|(existing, _)| *existing == property) {
+            Some((_, values)) => values.push(value),
+            None => properties.push((property, vec![value])),
+        }
+    }
+    if properties.is_empty() {
+        return None;
+    }
+    let object = properties
+        .into_iter()
+        .filter_map(|(property, values)| {
+            let (kind, array) = kinds
+                .iter()
+                .find(|(name, ..)| *name == property)
+                .map_or(("string", false), |(_, kind, array)| (*kind, *array));
+            Some((property.to_string(), param_value(values, kind, array, "form", true)?))
+        })
+        .collect();
+    Some(serde_json::Value::Object(object))
+}
+
+/// The decoded name-value pairs of a form body; `None` when it is empty
+async fn form_pairs(
+    request: axum::extract::Request,
+) -> Result<Option<Vec<(String, String)>>, axum::response::Response> {
+    use axum::extract::FromRequest;
+    use axum::response::IntoResponse;
+
+    let (parts, body) = request.into_parts();
+    let body = axum::body::Bytes::from_request(axum::extract::Request::from_parts(parts.clone(), body), &())
+        .await
+        .map_err(IntoResponse::into_response)?;
+    if body.is_empty() {
+        return Ok(None);
+    }
+    let request = axum::extract::Request::from_parts(parts, body.into());
+    let axum::Form(pairs) = axum::Form::from_request(request, &())
+        .await
+        .map_err(IntoResponse::into_response)?;
+    Ok(Some(pairs))
+}
+
+/// The name and content of every part of a multipart body, in order
+async fn multipart_parts(
+    mut multipart: axum::extract::Multipart,
+) -> Result<Vec<(String, axum::body::Bytes)>, axum::response::Response> {
+    use axum::response::IntoResponse;
+
+    let mut parts = Vec::new();
+    while let Some(field) = multipart.next_field().await.map_err(IntoResponse::into_response)? {
+        let name = field.name().unwrap_or_default().to_string();
+        parts.push((name, field.bytes().await.map_err(IntoResponse::into_response)?));
+    }
+    Ok(parts)
+}
+
+/// The content of a required file part, rejecting the request when it is
+/// missing
+fn part_file(parts: &[(String, axum::body::Bytes)], name: &str) -> Result<Vec<u8>, Rejection> {
+    parts
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, content)| content.to_vec())
+        .ok_or_else(|| Rejection(format!("missing part `{}`", name)))
+}
+
+/// Deserialize a property of a multipart body from its parts: a JSON part
+/// carries the whole value, otherwise the text of each part is converted
+/// according to the `kind` of its items
+fn part_value<T: serde::de::DeserializeOwned>(
+    parts: &[(String, axum::body::Bytes)],
+    name: &str,
+    kind: &str,
+    array: bool,
+    json: bool,
+) -> Result<T, Rejection> {
+    let reject = |error: &dyn std::fmt::Display| Rejection(format!("part `{}`: {}", name, error));
+    let mut values = Vec::new();
+    for (_, content) in parts.iter().filter(|(key, _)| key == name) {
+        values.push(std::str::from_utf8(content).map_err(|error| reject(&error))?);
+    }
+    let value = if json {
+        match values.first() {
+            Some(value) => Some(serde_json::from_str(value).map_err(|error| reject(&error))?),
+            None => None,
+        }
+    } else {
+        param_value(values, kind, array, "form", true)
+    };
+    serde_json::from_value(value.unwrap_or_default()).map_err(|error| reject(&error))
+}
+
+/// The values of the parameters sharing a path segment with the `literals`
+/// around and between them, e.g. `["7"]` for `7.json` and `["", ".json"]`;
+/// `NOT_FOUND` when the segment does not match
+fn segment_values<'a>(
+    segment: &'a str,
+    literals: &[&str],
+) -> Result<Vec<&'a str>, axum::response::Response> {
+    use axum::response::IntoResponse;
+
+    let not_found = || axum::http::StatusCode::NOT_FOUND.into_response();
+    let (first, rest) = literals.split_first().ok_or_else(not_found)?;
+    let (last, middle) = rest.split_last().ok_or_else(not_found)?;
+    let mut rest = segment
+        .strip_prefix(first)
+        .and_then(|rest| rest.strip_suffix(last))
+        .ok_or_else(not_found)?;
+    let mut values = Vec::new();
+    for literal in middle {
+        let (value, tail) = rest.split_once(literal).ok_or_else(not_found)?;
+        values.push(value);
+        rest = tail;
+    }
+    values.push(rest);
+    if values.iter().any(|value| value.is_empty()) {
+        return Err(not_found());
+    }
+    Ok(values)
+}
+
+/// Deserialize a path parameter from its text according to the `kind` of
+/// its items, its `style` and `explode`
+fn path_param<T: serde::de::DeserializeOwned>(
+    value: &str,
+    kind: &str,
+    array: bool,
+    style: &str,
+    explode: bool,
+) -> Result<T, Rejection> {
+    let value = param_value(vec![value], kind, array, style, explode).unwrap_or_default();
+    serde_json::from_value(value).map_err(|error| Rejection(error.to_string()))
+}
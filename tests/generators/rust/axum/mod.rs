@@ -0,0 +1,9 @@
+//! Generated by rustval. Do not edit manually.
+//! Pets
+//! The pet store
+//! OpenAPI spec version: 1.0
+
+pub mod models;
+pub mod server;
+
+pub use server::{Api, router};
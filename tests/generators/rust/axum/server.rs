@@ -0,0 +1,208 @@
+//! Generated by rustval. Do not edit manually.
+//! Pets
+//! The pet store
+//! OpenAPI spec version: 1.0
+
+#[allow(unused_imports)]
+use super::models::*;
+
+/// The operations of Pets
+///
+/// [`router`] serves an implementation.
+pub trait Api: Send + Sync + 'static {
+    /// List all pets
+    fn list_pets(&self, params: ListPetsParams, headers: ListPetsHeaders) -> impl std::future::Future<Output = ListPetsResponse> + Send;
+
+    fn create_pet(&self, body: Pet) -> impl std::future::Future<Output = CreatePetResponse> + Send;
+
+    fn get_pet(&self, pet_id: String) -> impl std::future::Future<Output = GetPetResponse> + Send;
+}
+
+/// The responses of [`Api::list_pets`]
+#[derive(Debug)]
+pub enum ListPetsResponse {
+    /// The pets
+    Ok(Vec<Pet>),
+}
+
+impl axum::response::IntoResponse for ListPetsResponse {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::Ok(body) => (axum::http::StatusCode::OK, axum::Json(body)).into_response(),
+        }
+    }
+}
+
+/// The responses of [`Api::create_pet`]
+#[derive(Debug)]
+pub enum CreatePetResponse {
+    /// Created
+    Created,
+}
+
+impl axum::response::IntoResponse for CreatePetResponse {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::Created => axum::http::StatusCode::CREATED.into_response(),
+        }
+    }
+}
+
+/// The responses of [`Api::get_pet`]
+#[derive(Debug)]
+pub enum GetPetResponse {
+    /// The pet
+    Ok(Pet),
+    /// An error
+    Default(axum::http::StatusCode, Error),
+}
+
+impl axum::response::IntoResponse for GetPetResponse {
+    fn into_response(self) -> axum::response::Response {
+        match self {
+            Self::Ok(body) => (axum::http::StatusCode::OK, axum::Json(body)).into_response(),
+            Self::Default(status, body) => (status, axum::Json(body)).into_response(),
+        }
+    }
+}
+
+/// The rejection of a request whose parameters do not match the spec
+#[derive(Debug)]
+pub struct Rejection(pub String);
+
+impl axum::response::IntoResponse for Rejection {
+    fn into_response(self) -> axum::response::Response {
+        (axum::http::StatusCode::BAD_REQUEST, self.0).into_response()
+    }
+}
+
+impl From<Rejection> for axum::response::Response {
+    fn from(rejection: Rejection) -> Self {
+        axum::response::IntoResponse::into_response(rejection)
+    }
+}
+
+impl<S: Send + Sync> axum::extract::FromRequestParts<S> for ListPetsParams {
+    type Rejection = Rejection;
+
+    async fn from_request_parts(parts: &mut axum::http::request::Parts, _state: &S) -> Result<Self, Rejection> {
+        let query = query_pairs(parts);
+        from_params(vec![
+            ("limit", param_value(query_values(&query, "limit"), "integer", false, "form", true)),
+            ("tags", param_value(query_values(&query, "tags"), "string", true, "form", true)),
+        ])
+    }
+}
+
+impl<S: Send + Sync> axum::extract::FromRequestParts<S> for ListPetsHeaders {
+    type Rejection = Rejection;
+
+    async fn from_request_parts(parts: &mut axum::http::request::Parts, _state: &S) -> Result<Self, Rejection> {
+        from_params(vec![
+            ("X-Request-Id", param_value(header_values(parts, "X-Request-Id"), "string", false, "simple", false)),
+        ])
+    }
+}
+
+/// A router serving every operation of [`Api`] with `api`
+pub fn router<T: Api>(api: T) -> axum::Router {
+    axum::Router::new()
+        .route("/pets", axum::routing::get(list_pets::<T>).post(create_pet::<T>))
+        .route("/pets/{petId}", axum::routing::get(get_pet::<T>))
+        .with_state(std::sync::Arc::new(api))
+}
+
+async fn list_pets<T: Api>(axum::extract::State(api): axum::extract::State<std::sync::Arc<T>>, params: ListPetsParams, headers: ListPetsHeaders) -> ListPetsResponse {
+    api.list_pets(params, headers).await
+}
+
+async fn create_pet<T: Api>(axum::extract::State(api): axum::extract::State<std::sync::Arc<T>>, axum::Json(body): axum::Json<Pet>) -> CreatePetResponse {
+    api.create_pet(body).await
+}
+
+async fn get_pet<T: Api>(axum::extract::State(api): axum::extract::State<std::sync::Arc<T>>, axum::extract::Path(pet_id): axum::extract::Path<String>) -> GetPetResponse {
+    api.get_pet(pet_id).await
+}
+
+/// The decoded name-value pairs of the query string
+fn query_pairs(parts: &axum::http::request::Parts) -> Vec<(String, String)> {
+    axum::extract::Query::<Vec<(String, String)>>::try_from_uri(&parts.uri)
+        .map(|axum::extract::Query(pairs)| pairs)
+        .unwrap_or_default()
+}
+
+/// Every value of a query parameter, in order
+fn query_values<'a>(query: &'a [(String, String)], name: &str) -> Vec<&'a str> {
+    query
+        .iter()
+        .filter(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+        .collect()
+}
+
+/// Every value of a header, in order
+fn header_values<'a>(parts: &'a axum::http::request::Parts, name: &str) -> Vec<&'a str> {
+    parts
+        .headers
+        .get_all(name)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .collect()
+}
+
+/// Convert the raw values of a parameter to JSON according to the `kind` of
+/// its items, its `style` and `explode`; `None` when it is absent
+fn param_value(
+    values: Vec<&str>,
+    kind: &str,
+    array: bool,
+    style: &str,
+    explode: bool,
+) -> Option<serde_json::Value> {
+    let item = |value: &str| match kind {
+        "integer" | "number" => value
+            .parse::<serde_json::Number>()
+            .map_or_else(|_| serde_json::Value::from(value), serde_json::Value::Number),
+        "boolean" => match value {
+            "true" => serde_json::Value::Bool(true),
+            "false" => serde_json::Value::Bool(false),
+            _ => serde_json::Value::from(value),
+        },
+        _ => serde_json::Value::from(value),
+    };
+    if !array {
+        return values.first().map(|value| item(value));
+    }
+    if values.is_empty() {
+        return None;
+    }
+    let separator = match style {
+        "spaceDelimited" => " ",
+        "pipeDelimited" => "|",
+        _ => ",",
+    };
+    let items = values
+        .iter()
+        .flat_map(|value| {
+            if explode && style == "form" {
+                vec![*value]
+            } else {
+                value.split(separator).collect()
+            }
+        })
+        .map(item)
+        .collect();
+    Some(serde_json::Value::Array(items))
+}
+
+/// Deserialize parameters from their JSON values, rejecting the request when
+/// they do not match
+fn from_params<T: serde::de::DeserializeOwned>(
+    params: Vec<(&str, Option<serde_json::Value>)>,
+) -> Result<T, Rejection> {
+    let map = params
+        .into_iter()
+        .filter_map(|(name, value)| Some((name.to_string(), value?)))
+        .collect();
+    serde_json::from_value(serde_json::Value::Object(map)).map_err(|error| Rejection(error.to_string()))
+}
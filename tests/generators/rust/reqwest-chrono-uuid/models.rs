@@ -0,0 +1,88 @@
+//! Generated by rustval. Do not edit manually.
+//! Pets
+//! The pet store
+//! OpenAPI spec version: 1.0
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "petType")]
+pub enum Pet {
+    #[serde(rename = "cat")]
+    Cat(Cat),
+    #[serde(rename = "dog")]
+    Dog(Dog),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cat {
+    /// The name of the pet
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub born: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<CatSize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lives: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CatSize {
+    #[serde(rename = "small")]
+    Small,
+    #[serde(rename = "large")]
+    Large,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dog {
+    /// The name of the pet
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub born: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<CatSize>,
+    #[serde(rename = "good-boy", default, skip_serializing_if = "Option::is_none")]
+    pub good_boy: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Base {
+    #[serde(rename = "petType")]
+    pub pet_type: String,
+    /// The name of the pet
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub born: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<CatSize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Node {
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<Box<Node>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<Node>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Error {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ListPetsParams {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ListPetsHeaders {
+    #[serde(rename = "X-Request-Id")]
+    pub x_request_id: String,
+}
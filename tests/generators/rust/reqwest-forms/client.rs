@@ -0,0 +1,236 @@
+//! Generated by rustval. Do not edit manually.
+//! Forms
+//! OpenAPI spec version: 1.0
+
+use serde::Serialize;
+
+#[allow(unused_imports)]
+use super::models::*;
+
+/// A client for Forms
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    http: reqwest::Client,
+}
+
+impl Client {
+    /// A client sending requests to `base_url`, e.g. `https://api.example.com/v1`
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_client(base_url, reqwest::Client::new())
+    }
+
+    /// A client sending requests through a configured `reqwest::Client`
+    pub fn with_client(base_url: impl Into<String>, http: reqwest::Client) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        Client { base_url, http }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub async fn upload(&self, body: &UploadBody) -> Result<UploadResponse, Error> {
+        let url = format!("{}/upload", self.base_url);
+        let request = self.http.request(reqwest::Method::POST, url);
+        let mut form = reqwest::multipart::Form::new();
+        form = form.part("file", file_part("file", &body.file, Some("image/png"))?);
+        if let Some(value) = &body.avatar {
+            form = form.part("avatar", file_part("avatar", value, Some("image/jpeg"))?);
+        }
+        if let Some(value) = &body.attachments {
+            for item in value {
+                form = form.part("attachments", file_part("attachments", item, None)?);
+            }
+        }
+        if let Some(value) = &body.meta {
+            form = form.part("meta", text_part(value, None)?);
+        }
+        if let Some(value) = &body.tags {
+            for item in value {
+                form = form.part("tags", text_part(item, None)?);
+            }
+        }
+        let request = request.multipart(form);
+        let response = request.send().await?;
+        match response.status().as_u16() {
+            204 => Ok(UploadResponse::NoContent),
+            _ => Err(unexpected(response).await),
+        }
+    }
+
+    pub async fn login(&self, body: Option<&LoginBody>) -> Result<LoginResponse, Error> {
+        let url = format!("{}/login", self.base_url);
+        let request = self.http.request(reqwest::Method::POST, url);
+        let request = match body {
+            Some(body) => request.form(&form_pairs(body, &[("scopes", "spaceDelimited", false)])),
+            None => request,
+        };
+        let response = request.send().await?;
+        match response.status().as_u16() {
+            204 => Ok(LoginResponse::NoContent),
+            _ => Err(unexpected(response).await),
+        }
+    }
+}
+
+/// The responses of [`Client::upload`]
+#[derive(Debug)]
+pub enum UploadResponse {
+    /// Done
+    NoContent,
+}
+
+/// The responses of [`Client::login`]
+#[derive(Debug)]
+pub enum LoginResponse {
+    /// Done
+    NoContent,
+}
+
+/// The error of a client method
+#[derive(Debug)]
+pub enum Error {
+    /// The request could not be sent or the response could not be read
+    Request(reqwest::Error),
+    /// The server answered with a status the spec does not declare
+    Status {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Request(error) => write!(f, "request failed: {}", error),
+            Error::Status { status, body } => write!(f, "unexpected status {}: {}", status, body),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Request(error) => Some(error),
+            Error::Status { .. } => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Error::Request(error)
+    }
+}
+
+/// The error of a response whose status the spec does not declare
+async fn unexpected(response: reqwest::Response) -> Error {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    Error::Status { status, body }
+}
+
+/// Serialize a query parameter according to its `style` and `explode`;
+/// `None` adds nothing
+fn append_query<T: Serialize>(
+    query: &mut Vec<(String, String)>,
+    name: &str,
+    value: &T,
+    style: &str,
+    explode: bool,
+) {
+    match serde_json::to_value(value).unwrap_or_default() {
+        serde_json::Value::Null => {}
+        serde_json::Value::Array(items) if explode && style == "form" => {
+            for item in &items {
+                query.push((name.to_string(), scalar(item)));
+            }
+        }
+        serde_json::Value::Array(items) => {
+            let separator = match style {
+                "spaceDelimited" => " ",
+                "pipeDelimited" => "|",
+                _ => ",",
+            };
+            let items: Vec<String> = items.iter().map(scalar).collect();
+            query.push((name.to_string(), items.join(separator)));
+        }
+        serde_json::Value::Object(map) if style == "deepObject" => {
+            for (key, value) in &map {
+                query.push((format!("{}[{}]", name, key), scalar(value)));
+            }
+        }
+        serde_json::Value::Object(map) if explode => {
+            for (key, value) in &map {
+                query.push((key.clone(), scalar(value)));
+            }
+        }
+        serde_json::Value::Object(map) => {
+            let pairs: Vec<String> = map
+                .iter()
+                .flat_map(|(key, value)| [key.clone(), scalar(value)])
+                .collect();
+            query.push((name.to_string(), pairs.join(",")));
+        }
+        value => query.push((name.to_string(), scalar(&value))),
+    }
+}
+
+/// Serialize a form body's properties like `form` query parameters, or by
+/// the `style` and `explode` of their encoding
+fn form_pairs<T: Serialize>(body: &T, styles: &[(&str, &str, bool)]) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    if let serde_json::Value::Object(map) = serde_json::to_value(body).unwrap_or_default() {
+        for (name, value) in &map {
+            let (style, explode) = styles
+                .iter()
+                .find(|(property, _, _)| property == name)
+                .map_or(("form", true), |(_, style, explode)| (*style, *explode));
+            append_query(&mut pairs, name, value, style, explode);
+        }
+    }
+    pairs
+}
+
+/// A multipart file, `application/octet-stream` unless its encoding says otherwise
+fn file_part(
+    name: &str,
+    bytes: &[u8],
+    content_type: Option<&str>,
+) -> Result<reqwest::multipart::Part, reqwest::Error> {
+    reqwest::multipart::Part::bytes(bytes.to_vec())
+        .file_name(name.to_string())
+        .mime_str(content_type.unwrap_or("application/octet-stream"))
+}
+
+/// A multipart value: objects, arrays and JSON encodings as JSON, anything
+/// else as plain text
+fn text_part<T: Serialize>(
+    value: &T,
+    content_type: Option<&str>,
+) -> Result<reqwest::multipart::Part, reqwest::Error> {
+    let value = serde_json::to_value(value).unwrap_or_default();
+    let json = value.is_object()
+        || value.is_array()
+        || content_type.is_some_and(|content_type| content_type.contains("json"));
+    let part = if json {
+        reqwest::multipart::Part::text(value.to_string())
+            .mime_str(content_type.unwrap_or("application/json"))?
+    } else {
+        reqwest::multipart::Part::text(scalar(&value))
+    };
+    match content_type {
+        Some(content_type) if !json => part.mime_str(content_type),
+        _ => Ok(part),
+    }
+}
+
+/// The text of a JSON value, without quotes for strings
+fn scalar(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(text) => text.clone(),
+        serde_json::Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
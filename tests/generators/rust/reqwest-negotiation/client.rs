@@ -0,0 +1,191 @@
+//! Generated by rustval. Do not edit manually.
+//! Files
+//! OpenAPI spec version: 1.0
+
+use serde::Serialize;
+
+#[allow(unused_imports)]
+use super::models::*;
+
+/// A client for Files
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    http: reqwest::Client,
+}
+
+impl Client {
+    /// A client sending requests to `base_url`, e.g. `https://api.example.com/v1`
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_client(base_url, reqwest::Client::new())
+    }
+
+    /// A client sending requests through a configured `reqwest::Client`
+    pub fn with_client(base_url: impl Into<String>, http: reqwest::Client) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        Client { base_url, http }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub async fn get_file(&self, id: &str) -> Result<GetFileResponse, Error> {
+        let url = format!("{}/files/{}", self.base_url, path_param("id", &id, "simple", false));
+        let request = self.http.request(reqwest::Method::GET, url);
+        let request = request.header("Accept", "application/json, text/csv, application/pdf, text/plain");
+        let response = request.send().await?;
+        let status = response.status();
+        match status.as_u16() {
+            200 => {
+                let content = if is_media_type(&response, "application/json") {
+                    GetFileOkContent::ApplicationJson(response.json().await?)
+                } else if is_media_type(&response, "text/csv") {
+                    GetFileOkContent::TextCsv(response.text().await?)
+                } else {
+                    GetFileOkContent::ApplicationPdf(response.bytes().await?.to_vec())
+                };
+                Ok(GetFileResponse::Ok(content))
+            }
+            204 => Ok(GetFileResponse::NoContent),
+            500..=599 => Ok(GetFileResponse::Status5XX(status, response.text().await?)),
+            _ => Err(unexpected(response).await),
+        }
+    }
+}
+
+/// The responses of [`Client::get_file`]
+#[derive(Debug)]
+pub enum GetFileResponse {
+    /// The file
+    Ok(GetFileOkContent),
+    /// Empty
+    NoContent,
+    /// Failure
+    Status5XX(reqwest::StatusCode, String),
+}
+
+/// The content of [`GetFileResponse::Ok`] by media type
+#[derive(Debug)]
+pub enum GetFileOkContent {
+    /// `application/json`
+    ApplicationJson(GetFile200Response),
+    /// `text/csv`
+    TextCsv(String),
+    /// `application/pdf`
+    ApplicationPdf(Vec<u8>),
+}
+
+/// The error of a client method
+#[derive(Debug)]
+pub enum Error {
+    /// The request could not be sent or the response could not be read
+    Request(reqwest::Error),
+    /// The server answered with a status the spec does not declare
+    Status {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Request(error) => write!(f, "request failed: {}", error),
+            Error::Status { status, body } => write!(f, "unexpected status {}: {}", status, body),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Request(error) => Some(error),
+            Error::Status { .. } => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Error::Request(error)
+    }
+}
+
+/// The error of a response whose status the spec does not declare
+async fn unexpected(response: reqwest::Response) -> Error {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    Error::Status { status, body }
+}
+
+/// Whether the `Content-Type` of a response is a declared media type, which
+/// may be a range such as `text/*`
+fn is_media_type(response: &reqwest::Response, media_type: &str) -> bool {
+    let essence = |media_type: &str| {
+        let essence = media_type.split(';').next().unwrap_or_default();
+        essence.trim().to_ascii_lowercase()
+    };
+    let content_type = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(essence)
+        .unwrap_or_default();
+    let declared = essence(media_type);
+    match declared.strip_suffix("/*") {
+        Some("*") => true,
+        Some(kind) => content_type.split('/').next() == Some(kind),
+        None => content_type == declared,
+    }
+}
+
+/// Serialize a path parameter according to its `style` and `explode`
+fn path_param<T: Serialize>(name: &str, value: &T, style: &str, explode: bool) -> String {
+    let value = serde_json::to_value(value).unwrap_or_default();
+    let parts: Vec<String> = match &value {
+        serde_json::Value::Array(items) => items.iter().map(|item| encode(&scalar(item))).collect(),
+        serde_json::Value::Object(map) if explode => map
+            .iter()
+            .map(|(key, value)| format!("{}={}", encode(key), encode(&scalar(value))))
+            .collect(),
+        serde_json::Value::Object(map) => map
+            .iter()
+            .flat_map(|(key, value)| [encode(key), encode(&scalar(value))])
+            .collect(),
+        value => vec![encode(&scalar(value))],
+    };
+    match style {
+        "label" if explode => format!(".{}", parts.join(".")),
+        "label" => format!(".{}", parts.join(",")),
+        "matrix" if explode && value.is_array() => {
+            parts.iter().map(|part| format!(";{}={}", name, part)).collect()
+        }
+        "matrix" if explode && value.is_object() => {
+            parts.iter().map(|part| format!(";{}", part)).collect()
+        }
+        "matrix" => format!(";{}={}", name, parts.join(",")),
+        _ => parts.join(","),
+    }
+}
+
+/// The text of a JSON value, without quotes for strings
+fn scalar(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(text) => text.clone(),
+        serde_json::Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+/// Percent-encode everything but unreserved characters
+fn encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            byte => format!("%{:02X}", byte),
+        })
+        .collect()
+}
@@ -0,0 +1,8 @@
+//! Generated by rustval. Do not edit manually.
+//! Files
+//! OpenAPI spec version: 1.0
+
+pub mod client;
+pub mod models;
+
+pub use client::{Client, Error};
@@ -0,0 +1,11 @@
+//! Generated by rustval. Do not edit manually.
+//! Files
+//! OpenAPI spec version: 1.0
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GetFile200Response {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
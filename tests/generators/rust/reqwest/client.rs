@@ -0,0 +1,254 @@
+//! Generated by rustval. Do not edit manually.
+//! Pets
+//! The pet store
+//! OpenAPI spec version: 1.0
+
+use serde::Serialize;
+
+#[allow(unused_imports)]
+use super::models::*;
+
+/// A client for Pets
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    http: reqwest::Client,
+}
+
+impl Client {
+    /// A client sending requests to `base_url`, e.g. `https://api.example.com/v1`
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_client(base_url, reqwest::Client::new())
+    }
+
+    /// A client sending requests through a configured `reqwest::Client`
+    pub fn with_client(base_url: impl Into<String>, http: reqwest::Client) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        Client { base_url, http }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// List all pets
+    pub async fn list_pets(&self, params: &ListPetsParams, headers: &ListPetsHeaders) -> Result<ListPetsResponse, Error2> {
+        let url = format!("{}/pets", self.base_url);
+        let request = self.http.request(reqwest::Method::GET, url);
+        let mut query = Vec::new();
+        append_query(&mut query, "limit", &params.limit, "form", true);
+        append_query(&mut query, "tags", &params.tags, "form", true);
+        let request = request.query(&query);
+        let request = match header_value(&headers.x_request_id) {
+            Some(value) => request.header("X-Request-Id", value),
+            None => request,
+        };
+        let response = request.send().await?;
+        match response.status().as_u16() {
+            200 => Ok(ListPetsResponse::Ok(response.json().await?)),
+            _ => Err(unexpected(response).await),
+        }
+    }
+
+    pub async fn create_pet(&self, body: &Pet) -> Result<CreatePetResponse, Error2> {
+        let url = format!("{}/pets", self.base_url);
+        let request = self.http.request(reqwest::Method::POST, url);
+        let request = request.json(body);
+        let response = request.send().await?;
+        match response.status().as_u16() {
+            201 => Ok(CreatePetResponse::Created),
+            _ => Err(unexpected(response).await),
+        }
+    }
+
+    #[deprecated]
+    pub async fn get_pet(&self, pet_id: &str) -> Result<GetPetResponse, Error2> {
+        let url = format!("{}/pets/{}", self.base_url, path_param("petId", &pet_id, "simple", false));
+        let request = self.http.request(reqwest::Method::GET, url);
+        let response = request.send().await?;
+        let status = response.status();
+        match status.as_u16() {
+            200 => Ok(GetPetResponse::Ok(response.json().await?)),
+            _ => Ok(GetPetResponse::Default(status, response.json().await?)),
+        }
+    }
+}
+
+/// The responses of [`Client::list_pets`]
+#[derive(Debug)]
+pub enum ListPetsResponse {
+    /// The pets
+    Ok(Vec<Pet>),
+}
+
+/// The responses of [`Client::create_pet`]
+#[derive(Debug)]
+pub enum CreatePetResponse {
+    /// Created
+    Created,
+}
+
+/// The responses of [`Client::get_pet`]
+#[derive(Debug)]
+pub enum GetPetResponse {
+    /// The pet
+    Ok(Pet),
+    /// An error
+    Default(reqwest::StatusCode, Error),
+}
+
+/// The error of a client method
+#[derive(Debug)]
+pub enum Error2 {
+    /// The request could not be sent or the response could not be read
+    Request(reqwest::Error),
+    /// The server answered with a status the spec does not declare
+    Status {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+}
+
+impl std::fmt::Display for Error2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error2::Request(error) => write!(f, "request failed: {}", error),
+            Error2::Status { status, body } => write!(f, "unexpected status {}: {}", status, body),
+        }
+    }
+}
+
+impl std::error::Error for Error2 {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error2::Request(error) => Some(error),
+            Error2::Status { .. } => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error2 {
+    fn from(error: reqwest::Error) -> Self {
+        Error2::Request(error)
+    }
+}
+
+/// The error of a response whose status the spec does not declare
+async fn unexpected(response: reqwest::Response) -> Error2 {
+    let status = response.status();
+    let body = response.text().await.unwrap_or_default();
+    Error2::Status { status, body }
+}
+
+/// Serialize a path parameter according to its `style` and `explode`
+fn path_param<T: Serialize>(name: &str, value: &T, style: &str, explode: bool) -> String {
+    let value = serde_json::to_value(value).unwrap_or_default();
+    let parts: Vec<String> = match &value {
+        serde_json::Value::Array(items) => items.iter().map(|item| encode(&scalar(item))).collect(),
+        serde_json::Value::Object(map) if explode => map
+            .iter()
+            .map(|(key, value)| format!("{}={}", encode(key), encode(&scalar(value))))
+            .collect(),
+        serde_json::Value::Object(map) => map
+            .iter()
+            .flat_map(|(key, value)| [encode(key), encode(&scalar(value))])
+            .collect(),
+        value => vec![encode(&scalar(value))],
+    };
+    match style {
+        "label" if explode => format!(".{}", parts.join(".")),
+        "label" => format!(".{}", parts.join(",")),
+        "matrix" if explode && value.is_array() => {
+            parts.iter().map(|part| format!(";{}={}", name, part)).collect()
+        }
+        "matrix" if explode && value.is_object() => {
+            parts.iter().map(|part| format!(";{}", part)).collect()
+        }
+        "matrix" => format!(";{}={}", name, parts.join(",")),
+        _ => parts.join(","),
+    }
+}
+
+/// Serialize a query parameter according to its `style` and `explode`;
+/// `None` adds nothing
+fn append_query<T: Serialize>(
+    query: &mut Vec<(String, String)>,
+    name: &str,
+    value: &T,
+    style: &str,
+    explode: bool,
+) {
+    match serde_json::to_value(value).unwrap_or_default() {
+        serde_json::Value::Null => {}
+        serde_json::Value::Array(items) if explode && style == "form" => {
+            for item in &items {
+                query.push((name.to_string(), scalar(item)));
+            }
+        }
+        serde_json::Value::Array(items) => {
+            let separator = match style {
+                "spaceDelimited" => " ",
+                "pipeDelimited" => "|",
+                _ => ",",
+            };
+            let items: Vec<String> = items.iter().map(scalar).collect();
+            query.push((name.to_string(), items.join(separator)));
+        }
+        serde_json::Value::Object(map) if style == "deepObject" => {
+            for (key, value) in &map {
+                query.push((format!("{}[{}]", name, key), scalar(value)));
+            }
+        }
+        serde_json::Value::Object(map) if explode => {
+            for (key, value) in &map {
+                query.push((key.clone(), scalar(value)));
+            }
+        }
+        serde_json::Value::Object(map) => {
+            let pairs: Vec<String> = map
+                .iter()
+                .flat_map(|(key, value)| [key.clone(), scalar(value)])
+                .collect();
+            query.push((name.to_string(), pairs.join(",")));
+        }
+        value => query.push((name.to_string(), scalar(&value))),
+    }
+}
+
+/// Serialize a header parameter in the `simple` style; `None` sends no header
+fn header_value<T: Serialize>(value: &T) -> Option<String> {
+    match serde_json::to_value(value).ok()? {
+        serde_json::Value::Null => None,
+        serde_json::Value::Array(items) => {
+            Some(items.iter().map(scalar).collect::<Vec<_>>().join(","))
+        }
+        serde_json::Value::Object(map) => Some(
+            map.iter()
+                .flat_map(|(key, value)| [key.clone(), scalar(value)])
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+        value => Some(scalar(&value)),
+    }
+}
+
+/// The text of a JSON value, without quotes for strings
+fn scalar(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(text) => text.clone(),
+        serde_json::Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+/// Percent-encode everything but unreserved characters
+fn encode(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            byte => format!("%{:02X}", byte),
+        })
+        .collect()
+}
@@ -0,0 +1,9 @@
+//! Generated by rustval. Do not edit manually.
+//! Pets
+//! The pet store
+//! OpenAPI spec version: 1.0
+
+pub mod client;
+pub mod models;
+
+pub use client::{Client, Error2};
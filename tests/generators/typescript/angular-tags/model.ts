@@ -0,0 +1,5 @@
+/**
+ * Generated by rustval. Do not edit manually.
+ * Tags
+ * OpenAPI spec version: 1.0
+ */
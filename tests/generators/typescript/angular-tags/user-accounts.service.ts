@@ -0,0 +1,55 @@
+/**
+ * Generated by rustval. Do not edit manually.
+ * Tags
+ * OpenAPI spec version: 1.0
+ */
+
+import { Injectable, inject } from '@angular/core';
+import { HttpClient, HttpErrorResponse, HttpHeaders } from '@angular/common/http';
+import type { HttpResponse } from '@angular/common/http';
+import { catchError, of, switchMap, throwError } from 'rxjs';
+import type { Observable } from 'rxjs';
+
+const readHttpResponse = (
+  response: Observable<HttpResponse<Blob>>,
+): Observable<{ status: number; contentType?: string; data: unknown; headers: HttpHeaders }> =>
+  response.pipe(
+    catchError((error: unknown) =>
+      error instanceof HttpErrorResponse && error.status !== 0 ? of(error) : throwError(() => error),
+    ),
+    switchMap(async (res) => {
+      const body: unknown = res instanceof HttpErrorResponse ? res.error : res.body;
+      const contentType = res.headers.get('Content-Type')?.split(';')[0].trim().toLowerCase();
+      let data: unknown = undefined;
+      if (body instanceof Blob && body.size > 0 && ![204, 205, 304].includes(res.status)) {
+        if (/json/i.test(contentType ?? '')) {
+          data = JSON.parse(await body.text());
+        } else if (contentType?.startsWith('text/')) {
+          data = await body.text();
+        } else {
+          data = body;
+        }
+      }
+      return { status: res.status, contentType, data, headers: res.headers };
+    }),
+  );
+
+export type GetMeResponse = { status: 200; contentType: 'text/plain'; data: string } | { status: 200; contentType: 'application/json'; data: string } | { status: number; data: void } | { status: number; data: {
+  message?: string;
+} };
+
+@Injectable({ providedIn: 'root' })
+export class UserAccountsService {
+  private readonly http = inject(HttpClient);
+
+  getMe(): Observable<GetMeResponse & { headers: HttpHeaders }> {
+    const url = `/users/me`;
+    const requestOptions = {
+      headers: new HttpHeaders({ Accept: 'text/plain, application/json' }),
+      observe: 'response' as const,
+      responseType: 'blob' as const,
+    };
+    const response = this.http.request('GET', url, requestOptions);
+    return readHttpResponse(response) as Observable<GetMeResponse & { headers: HttpHeaders }>;
+  }
+}
@@ -0,0 +1,162 @@
+/**
+ * Generated by rustval. Do not edit manually.
+ * Petstore
+ * OpenAPI spec version: 1.0
+ */
+
+import { Injectable, inject } from '@angular/core';
+import { HttpClient, HttpErrorResponse, HttpHeaders, HttpParams } from '@angular/common/http';
+import type { HttpResponse } from '@angular/common/http';
+import { catchError, of, switchMap, throwError } from 'rxjs';
+import type { Observable } from 'rxjs';
+import type { Error_, Pet, Photo } from './model';
+
+const toFormData = (body: object, contentTypes: Record<string, string> = {}): FormData => {
+  const formData = new FormData();
+  Object.entries(body).forEach(([name, value]) => {
+    if (value === undefined) {
+      return;
+    }
+    const contentType = contentTypes[name];
+    // A JSON part carries the whole value, otherwise every item is a part
+    const items = Array.isArray(value) && !/json/i.test(contentType ?? '') ? value : [value];
+    items.forEach((item) => {
+      if (item instanceof Blob) {
+        formData.append(name, contentType && !item.type ? new Blob([item], { type: contentType }) : item);
+        return;
+      }
+      const text = item !== null && typeof item === 'object' ? JSON.stringify(item) : String(item);
+      formData.append(name, contentType ? new Blob([text], { type: contentType }) : text);
+    });
+  });
+  return formData;
+};
+
+const appendHttpParam = (
+  params: HttpParams,
+  name: string,
+  value: unknown,
+  style: 'form' | 'spaceDelimited' | 'pipeDelimited' | 'deepObject',
+  explode: boolean,
+): HttpParams => {
+  if (value === undefined) {
+    return params;
+  }
+  if (Array.isArray(value)) {
+    if (explode) {
+      return value.reduce((result: HttpParams, item) => result.append(name, String(item)), params);
+    }
+    const separator = style === 'spaceDelimited' ? ' ' : style === 'pipeDelimited' ? '|' : ',';
+    return params.append(name, value.map(String).join(separator));
+  }
+  if (value !== null && typeof value === 'object') {
+    const entries = Object.entries(value).filter(([, item]) => item !== undefined);
+    if (style === 'deepObject') {
+      return entries.reduce((result, [key, item]) => result.append(`${name}[${key}]`, String(item)), params);
+    }
+    if (explode) {
+      return entries.reduce((result, [key, item]) => result.append(key, String(item)), params);
+    }
+    return params.append(name, entries.flat().map(String).join(','));
+  }
+  return params.append(name, String(value));
+};
+
+const toHttpHeaders = (values: object | undefined): HttpHeaders => {
+  return Object.entries(values ?? {}).reduce(
+    (headers, [name, value]) =>
+      value === undefined
+        ? headers
+        : headers.set(name, Array.isArray(value) ? value.join(',') : String(value)),
+    new HttpHeaders(),
+  );
+};
+
+const readHttpResponse = (
+  response: Observable<HttpResponse<Blob>>,
+): Observable<{ status: number; contentType?: string; data: unknown; headers: HttpHeaders }> =>
+  response.pipe(
+    catchError((error: unknown) =>
+      error instanceof HttpErrorResponse && error.status !== 0 ? of(error) : throwError(() => error),
+    ),
+    switchMap(async (res) => {
+      const body: unknown = res instanceof HttpErrorResponse ? res.error : res.body;
+      const contentType = res.headers.get('Content-Type')?.split(';')[0].trim().toLowerCase();
+      let data: unknown = undefined;
+      if (body instanceof Blob && body.size > 0 && ![204, 205, 304].includes(res.status)) {
+        if (/json/i.test(contentType ?? '')) {
+          data = JSON.parse(await body.text());
+        } else if (contentType?.startsWith('text/')) {
+          data = await body.text();
+        } else {
+          data = body;
+        }
+      }
+      return { status: res.status, contentType, data, headers: res.headers };
+    }),
+  );
+
+export type ListPetsParams = {
+  limit?: number;
+  tags?: string[];
+};
+
+export type ListPetsHeaders = {
+  'X-Request-Id': string;
+};
+
+export type ListPetsResponse = { status: 200; data: Pet[] } | { status: number; data: Error_ };
+
+export interface CreatePetBody {
+  name: string;
+}
+
+export type CreatePetResponse = { status: 201; data: void };
+
+export type UploadPhotoResponse = { status: 204; data: void };
+
+@Injectable({ providedIn: 'root' })
+export class DefaultService {
+  private readonly http = inject(HttpClient);
+
+  /**
+   * List all pets
+   */
+  listPets(params: ListPetsParams | undefined, headers: ListPetsHeaders): Observable<ListPetsResponse & { headers: HttpHeaders }> {
+    const url = `/pets`;
+    let httpParams = new HttpParams();
+    httpParams = appendHttpParam(httpParams, 'limit', params?.limit, 'form', true);
+    httpParams = appendHttpParam(httpParams, 'tags', params?.tags, 'pipeDelimited', false);
+    const requestOptions = {
+      params: httpParams,
+      headers: toHttpHeaders(headers),
+      observe: 'response' as const,
+      responseType: 'blob' as const,
+    };
+    const response = this.http.request('GET', url, requestOptions);
+    return readHttpResponse(response) as Observable<ListPetsResponse & { headers: HttpHeaders }>;
+  }
+
+  createPet(body: CreatePetBody): Observable<CreatePetResponse & { headers: HttpHeaders }> {
+    const url = `/pets`;
+    const requestOptions = {
+      headers: new HttpHeaders({ 'Content-Type': 'application/json' }),
+      body,
+      observe: 'response' as const,
+      responseType: 'blob' as const,
+    };
+    const response = this.http.request('POST', url, requestOptions);
+    return readHttpResponse(response) as Observable<CreatePetResponse & { headers: HttpHeaders }>;
+  }
+
+  uploadPhoto(petId: string, body?: Photo): Observable<UploadPhotoResponse & { headers: HttpHeaders }> {
+    const url = `/pets/${encodeURIComponent(String(petId))}/photo`;
+    const requestOptions = {
+      body: body === undefined ? undefined : toFormData(body, { file: 'image/png' }),
+      observe: 'response' as const,
+      responseType: 'blob' as const,
+    };
+    const response = this.http.request('PUT', url, requestOptions);
+    return readHttpResponse(response) as Observable<UploadPhotoResponse & { headers: HttpHeaders }>;
+  }
+}
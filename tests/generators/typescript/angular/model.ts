@@ -0,0 +1,17 @@
+/**
+ * Generated by rustval. Do not edit manually.
+ * Petstore
+ * OpenAPI spec version: 1.0
+ */
+
+export interface Pet {
+  name?: string;
+}
+
+export interface Photo {
+  file?: Blob;
+}
+
+export interface Error_ {
+  message?: string;
+}
@@ -0,0 +1,128 @@
+/**
+ * Generated by rustval. Do not edit manually.
+ * Petstore
+ * OpenAPI spec version: 1.0
+ */
+
+import { customInstance } from './custom-instance';
+import type { Error_, Pet, Photo } from './model';
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+type SecondParameter<T extends (...args: any) => any> = Parameters<T>[1];
+
+const appendQueryParam = (
+  query: URLSearchParams,
+  name: string,
+  value: unknown,
+  style: 'form' | 'spaceDelimited' | 'pipeDelimited' | 'deepObject',
+  explode: boolean,
+): void => {
+  if (value === undefined) {
+    return;
+  }
+  if (Array.isArray(value)) {
+    if (explode) {
+      value.forEach((item) => query.append(name, String(item)));
+      return;
+    }
+    const separator = style === 'spaceDelimited' ? ' ' : style === 'pipeDelimited' ? '|' : ',';
+    query.append(name, value.map(String).join(separator));
+    return;
+  }
+  if (value !== null && typeof value === 'object') {
+    const entries = Object.entries(value).filter(([, item]) => item !== undefined);
+    if (style === 'deepObject') {
+      entries.forEach(([key, item]) => query.append(`${name}[${key}]`, String(item)));
+    } else if (explode) {
+      entries.forEach(([key, item]) => query.append(key, String(item)));
+    } else {
+      query.append(name, entries.flat().map(String).join(','));
+    }
+    return;
+  }
+  query.append(name, String(value));
+};
+
+const toFormData = (body: object, contentTypes: Record<string, string> = {}): FormData => {
+  const formData = new FormData();
+  Object.entries(body).forEach(([name, value]) => {
+    if (value === undefined) {
+      return;
+    }
+    const contentType = contentTypes[name];
+    // A JSON part carries the whole value, otherwise every item is a part
+    const items = Array.isArray(value) && !/json/i.test(contentType ?? '') ? value : [value];
+    items.forEach((item) => {
+      if (item instanceof Blob) {
+        formData.append(name, contentType && !item.type ? new Blob([item], { type: contentType }) : item);
+        return;
+      }
+      const text = item !== null && typeof item === 'object' ? JSON.stringify(item) : String(item);
+      formData.append(name, contentType ? new Blob([text], { type: contentType }) : text);
+    });
+  });
+  return formData;
+};
+
+export type ListPetsParams = {
+  limit?: number;
+  tags?: string[];
+};
+
+export type ListPetsHeaders = {
+  'X-Request-Id': string;
+};
+
+export type ListPetsResponse = { status: 200; data: Pet[] } | { status: number; data: Error_ };
+
+export const getListPetsUrl = (params?: ListPetsParams) => {
+  const query = new URLSearchParams();
+  appendQueryParam(query, 'limit', params?.limit, 'form', true);
+  appendQueryParam(query, 'tags', params?.tags, 'pipeDelimited', false);
+  const search = query.toString();
+  return `/pets${search ? `?${search}` : ''}`;
+};
+
+/**
+ * List all pets
+ */
+export const listPets = (params: ListPetsParams | undefined, headers: ListPetsHeaders, options?: SecondParameter<typeof customInstance>) => {
+  return customInstance<Pet[]>({
+    url: getListPetsUrl(params),
+    method: 'GET',
+    headers: { ...headers },
+  }, options);
+};
+
+export interface CreatePetBody {
+  name: string;
+}
+
+export type CreatePetResponse = { status: 201; data: void };
+
+export const getCreatePetUrl = () => {
+  return `/pets`;
+};
+
+export const createPet = (body: CreatePetBody, options?: SecondParameter<typeof customInstance>) => {
+  return customInstance<void>({
+    url: getCreatePetUrl(),
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json' },
+    data: body,
+  }, options);
+};
+
+export type UploadPhotoResponse = { status: 204; data: void };
+
+export const getUploadPhotoUrl = (petId: string) => {
+  return `/pets/${encodeURIComponent(String(petId))}/photo`;
+};
+
+export const uploadPhoto = (petId: string, body?: Photo, options?: SecondParameter<typeof customInstance>) => {
+  return customInstance<void>({
+    url: getUploadPhotoUrl(petId),
+    method: 'PUT',
+    data: body === undefined ? undefined : toFormData(body, { file: 'image/png' }),
+  }, options);
+};
@@ -0,0 +1,308 @@
+/**
+ * Generated by rustval. Do not edit manually.
+ * Petstore
+ * OpenAPI spec version: 1.0
+ */
+
+import express from 'express';
+import type { NextFunction, Request, RequestHandler, Response, Router } from 'express';
+import { z } from 'zod';
+import type { Error_, Pet, Photo } from './model';
+import { listPets } from './routes/pets/listPets';
+import { createPet } from './routes/pets/createPet';
+import { uploadPhoto } from './routes/pets/uploadPhoto';
+
+/** The parts of a request an operation validates */
+interface RequestSchemas {
+  params?: z.ZodTypeAny;
+  query?: z.AnyZodObject;
+  headers?: z.AnyZodObject;
+  body?: z.ZodTypeAny;
+  /** Query parameters in the `deepObject` style, sent as `name[key]=value` */
+  deepObjects?: string[];
+  form?: FormFields;
+}
+
+/** The properties of a form body that are lists, and those sent as JSON */
+interface FormFields {
+  arrays: string[];
+  json: string[];
+  /** Lists sent as one value joined by a delimiter */
+  delimited?: Record<string, string>;
+  /** Objects in the `deepObject` style, sent as `name[key]` fields */
+  deepObjects?: string[];
+}
+
+/** The validated parts of a request */
+interface RequestInput {
+  params?: unknown;
+  query?: unknown;
+  headers?: unknown;
+  body?: unknown;
+}
+
+/** Access to the raw parts of a request */
+interface RequestReader {
+  params: Record<string, string>;
+  query: (name: string) => string[] | undefined;
+  header: (name: string) => string | undefined;
+  /** Every query parameter with its values */
+  entries: () => [string, unknown][];
+  body: () => Promise<unknown>;
+}
+
+type ParseResult = { success: true; input: RequestInput } | { success: false; issues: z.ZodIssue[] };
+
+/** The `name[key]` query parameters of a `deepObject` parameter as an object */
+const deepObject = (name: string, entries: [string, unknown][]): Record<string, unknown> | undefined => {
+  const prefix = `${name}[`;
+  const pairs = entries
+    .filter(([key]) => key.startsWith(prefix) && key.endsWith(']'))
+    .map(([key, value]): [string, unknown] => [key.slice(prefix.length, -1), Array.isArray(value) ? value[0] : value]);
+  return pairs.length === 0 ? undefined : Object.fromEntries(pairs);
+};
+
+const parseJson = async (value: unknown): Promise<unknown> => {
+  const text = value instanceof Blob ? await value.text() : value;
+  if (typeof text !== 'string') {
+    return text;
+  }
+  try {
+    return JSON.parse(text);
+  } catch {
+    return text;
+  }
+};
+
+/** A form body with a list for every array property, its `deepObject` fields assembled and its JSON parts parsed */
+async function readForm(body: unknown, form: FormFields | undefined): Promise<unknown> {
+  if (form === undefined || body === null || typeof body !== 'object') {
+    return body;
+  }
+  const fields: Record<string, unknown> = { ...body };
+  for (const name of form.deepObjects ?? []) {
+    if (fields[name] === undefined) {
+      fields[name] = deepObject(name, Object.entries(fields));
+    }
+  }
+  for (const name of new Set([...form.arrays, ...form.json])) {
+    const value = fields[name];
+    if (value === undefined) {
+      continue;
+    }
+    let items = Array.isArray(value) ? value : [value];
+    const separator = form.delimited?.[name];
+    if (separator !== undefined) {
+      items = items.flatMap((item) => String(item).split(separator));
+    }
+    if (form.json.includes(name)) {
+      items = await Promise.all(items.map(parseJson));
+    }
+    fields[name] = form.arrays.includes(name) ? items : items[0];
+  }
+  return fields;
+}
+
+async function parseRequest(schemas: RequestSchemas, request: RequestReader): Promise<ParseResult> {
+  const issues: z.ZodIssue[] = [];
+  const parse = (part: string, schema: z.ZodTypeAny | undefined, value: unknown) => {
+    if (schema === undefined) {
+      return undefined;
+    }
+    const result = schema.safeParse(value);
+    if (result.success) {
+      return result.data;
+    }
+    issues.push(...result.error.issues.map((issue) => ({ ...issue, path: [part, ...issue.path] })));
+    return undefined;
+  };
+  const pick = (schema: z.AnyZodObject | undefined, read: (name: string) => unknown) =>
+    Object.fromEntries(Object.keys(schema?.shape ?? {}).map((name) => [name, read(name)]));
+  const query = (name: string) =>
+    schemas.deepObjects?.includes(name) ? deepObject(name, request.entries()) : request.query(name);
+  const body = schemas.body === undefined ? undefined : await readForm(await request.body(), schemas.form);
+  const input = {
+    params: parse('params', schemas.params, request.params),
+    query: parse('query', schemas.query, pick(schemas.query, query)),
+    headers: parse('headers', schemas.headers, pick(schemas.headers, request.header)),
+    body: parse('body', schemas.body, body),
+  };
+  return issues.length === 0 ? { success: true, input } : { success: false, issues };
+}
+
+const first = (value: unknown) => (Array.isArray(value) ? value[0] : value);
+
+const split = (separator: string) => (value: unknown) =>
+  Array.isArray(value)
+    ? value.flatMap((item) => String(item).split(separator))
+    : typeof value === 'string'
+      ? value.split(separator)
+      : value;
+
+const validate =
+  (schemas: RequestSchemas): RequestHandler =>
+  (req, res, next) => {
+    parseRequest(schemas, {
+      params: req.params,
+      query: (name) => {
+        const value = req.query[name];
+        return value === undefined ? undefined : ([] as unknown[]).concat(value).map(String);
+      },
+      header: (name) => req.get(name),
+      // The extended query parser nests `name[key]` parameters already
+      entries: () =>
+        Object.entries(req.query).flatMap(([key, value]) =>
+          value !== null && typeof value === 'object' && !Array.isArray(value)
+            ? Object.entries(value).map(([inner, item]): [string, unknown] => [`${key}[${inner}]`, item])
+            : [[key, value] as [string, unknown]],
+        ),
+      body: async () => (req.get('Content-Type') === undefined ? undefined : withFiles(req)),
+    }).then((result) => {
+      if (!result.success) {
+        res.status(400).json({ message: 'Invalid request', issues: result.issues });
+        return;
+      }
+      res.locals.input = result.input;
+      next();
+    }, next);
+  };
+
+/** The body of a request with the files `multer` parsed added as `Blob`s */
+function withFiles(req: Request): unknown {
+  const files = (req as Request & { files?: unknown }).files;
+  if (!Array.isArray(files)) {
+    return req.body;
+  }
+  const body: Record<string, unknown> = { ...req.body };
+  for (const file of files as { fieldname: string; buffer: Buffer; mimetype: string }[]) {
+    const blob = new Blob([new Uint8Array(file.buffer)], { type: file.mimetype });
+    const value = body[file.fieldname];
+    body[file.fieldname] = value === undefined ? blob : ([] as unknown[]).concat(value, blob);
+  }
+  return body;
+}
+
+async function send(
+  res: Response,
+  response: { status: number; contentType?: string; data: unknown },
+): Promise<void> {
+  if (response.data === undefined) {
+    res.status(response.status).end();
+  } else if (response.contentType !== undefined && !/json/i.test(response.contentType)) {
+    const data =
+      response.data instanceof Blob ? Buffer.from(await response.data.arrayBuffer()) : response.data;
+    res.status(response.status).type(response.contentType).send(data);
+  } else {
+    res.status(response.status).json(response.data);
+  }
+}
+
+export const PetSchema = z.object({
+  name: z.string().optional(),
+});
+
+export const PhotoSchema = z.object({
+  file: z.instanceof(Blob).optional(),
+});
+
+export const ErrorSchema = z.object({
+  message: z.string().optional(),
+});
+
+export type ListPetsParams = {
+  limit?: number;
+  tags?: string[];
+};
+
+export type ListPetsHeaders = {
+  'X-Request-Id': string;
+};
+
+export type ListPetsResponse = { status: 200; data: Pet[] } | { status: number; data: Error_ };
+
+export interface ListPetsContext {
+  query: ListPetsParams;
+  headers: ListPetsHeaders;
+  req: Request;
+  res: Response;
+}
+
+export type ListPetsHandler = (context: ListPetsContext) => ListPetsResponse | Promise<ListPetsResponse>;
+
+const listPetsSchemas: RequestSchemas = {
+  query: z.object({
+    limit: z.preprocess(first, z.coerce.number().pipe(z.number().int()).optional()),
+    tags: z.preprocess(split('|'), z.array(z.string()).optional()),
+  }),
+  headers: z.object({
+    'X-Request-Id': z.string(),
+  }),
+};
+
+export interface CreatePetBody {
+  name: string;
+}
+
+export type CreatePetResponse = { status: 201; data: void };
+
+export interface CreatePetContext {
+  body: CreatePetBody;
+  req: Request;
+  res: Response;
+}
+
+export type CreatePetHandler = (context: CreatePetContext) => CreatePetResponse | Promise<CreatePetResponse>;
+
+const createPetSchemas: RequestSchemas = {
+  body: z.object({
+    name: z.string(),
+  }),
+};
+
+export type UploadPhotoResponse = { status: 204; data: void };
+
+export interface UploadPhotoContext {
+  params: { petId: string };
+  body: Photo | undefined;
+  req: Request;
+  res: Response;
+}
+
+export type UploadPhotoHandler = (context: UploadPhotoContext) => UploadPhotoResponse | Promise<UploadPhotoResponse>;
+
+const uploadPhotoSchemas: RequestSchemas = {
+  params: z.object({
+    petId: z.string(),
+  }),
+  body: PhotoSchema.optional(),
+};
+
+export function createRouter(): Router {
+  const router = express.Router();
+  router.use(express.json(), express.urlencoded({ extended: true }), express.text());
+  router.get('/pets', validate(listPetsSchemas), async (req: Request, res: Response, next: NextFunction) => {
+    try {
+      const input = res.locals.input as Omit<ListPetsContext, 'req' | 'res'>;
+      await send(res, await listPets({ ...input, req, res }));
+    } catch (error) {
+      next(error);
+    }
+  });
+  router.post('/pets', validate(createPetSchemas), async (req: Request, res: Response, next: NextFunction) => {
+    try {
+      const input = res.locals.input as Omit<CreatePetContext, 'req' | 'res'>;
+      await send(res, await createPet({ ...input, req, res }));
+    } catch (error) {
+      next(error);
+    }
+  });
+  router.put('/pets/:petId/photo', validate(uploadPhotoSchemas), async (req: Request, res: Response, next: NextFunction) => {
+    try {
+      const input = res.locals.input as Omit<UploadPhotoContext, 'req' | 'res'>;
+      await send(res, await uploadPhoto({ ...input, req, res }));
+    } catch (error) {
+      next(error);
+    }
+  });
+  return router;
+}
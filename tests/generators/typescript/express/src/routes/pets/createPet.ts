@@ -0,0 +1,7 @@
+// Scaffolded by rustval. This file is yours to edit; regeneration leaves it alone.
+
+import type { CreatePetHandler } from '../../app';
+
+export const createPet: CreatePetHandler = async (_context) => {
+  throw new Error('createPet is not implemented');
+};
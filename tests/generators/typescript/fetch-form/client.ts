@@ -0,0 +1,86 @@
+/**
+ * Generated by rustval. Do not edit manually.
+ * Forms
+ * OpenAPI spec version: 1.0
+ */
+
+const appendQueryParam = (
+  query: URLSearchParams,
+  name: string,
+  value: unknown,
+  style: 'form' | 'spaceDelimited' | 'pipeDelimited' | 'deepObject',
+  explode: boolean,
+): void => {
+  if (value === undefined) {
+    return;
+  }
+  if (Array.isArray(value)) {
+    if (explode) {
+      value.forEach((item) => query.append(name, String(item)));
+      return;
+    }
+    const separator = style === 'spaceDelimited' ? ' ' : style === 'pipeDelimited' ? '|' : ',';
+    query.append(name, value.map(String).join(separator));
+    return;
+  }
+  if (value !== null && typeof value === 'object') {
+    const entries = Object.entries(value).filter(([, item]) => item !== undefined);
+    if (style === 'deepObject') {
+      entries.forEach(([key, item]) => query.append(`${name}[${key}]`, String(item)));
+    } else if (explode) {
+      entries.forEach(([key, item]) => query.append(key, String(item)));
+    } else {
+      query.append(name, entries.flat().map(String).join(','));
+    }
+    return;
+  }
+  query.append(name, String(value));
+};
+
+const toUrlSearchParams = (
+  body: object,
+  encoding: Record<string, { style: 'form' | 'spaceDelimited' | 'pipeDelimited' | 'deepObject'; explode: boolean }> = {},
+): URLSearchParams => {
+  const params = new URLSearchParams();
+  Object.entries(body).forEach(([name, value]) =>
+    appendQueryParam(params, name, value, encoding[name]?.style ?? 'form', encoding[name]?.explode ?? true),
+  );
+  return params;
+};
+
+const parseBody = async (res: Response): Promise<unknown> => {
+  if ([204, 205, 304].includes(res.status)) {
+    return undefined;
+  }
+  const contentType = res.headers.get('Content-Type') ?? '';
+  if (/json/i.test(contentType)) {
+    const text = await res.text();
+    return text ? JSON.parse(text) : undefined;
+  }
+  if (contentType.startsWith('text/')) {
+    return res.text();
+  }
+  return res.blob();
+};
+
+export interface LoginBody {
+  scopes?: string[];
+}
+
+export type LoginResponse = { status: 204; data: void };
+
+export const getLoginUrl = () => {
+  return `/login`;
+};
+
+export const login = async (body: LoginBody, options?: RequestInit): Promise<LoginResponse & { headers: Headers }> => {
+  const requestHeaders = new Headers(options?.headers);
+  const res = await fetch(getLoginUrl(), {
+    ...options,
+    method: 'POST',
+    headers: requestHeaders,
+    body: toUrlSearchParams(body, { scopes: { style: 'spaceDelimited', explode: false } }),
+  });
+  const data = await parseBody(res);
+  return { status: res.status, data, headers: res.headers } as LoginResponse & { headers: Headers };
+};
@@ -0,0 +1,39 @@
+/**
+ * Generated by rustval. Do not edit manually.
+ * Files
+ * OpenAPI spec version: 1.0
+ */
+
+const parseBody = async (res: Response): Promise<unknown> => {
+  if ([204, 205, 304].includes(res.status)) {
+    return undefined;
+  }
+  const contentType = res.headers.get('Content-Type') ?? '';
+  if (/json/i.test(contentType)) {
+    const text = await res.text();
+    return text ? JSON.parse(text) : undefined;
+  }
+  if (contentType.startsWith('text/')) {
+    return res.text();
+  }
+  return res.blob();
+};
+
+export type GetFileResponse = { status: 200; contentType: 'application/json'; data: string[] } | { status: 200; contentType: 'text/csv'; data: string } | { status: 200; contentType: 'application/pdf'; data: Blob } | { status: number; data: string } | { status: number; data: void };
+
+export const getGetFileUrl = (id: string) => {
+  return `/files/${encodeURIComponent(String(id))}`;
+};
+
+export const getFile = async (id: string, options?: RequestInit): Promise<GetFileResponse & { headers: Headers }> => {
+  const requestHeaders = new Headers(options?.headers);
+  requestHeaders.set('Accept', 'application/json, text/csv, application/pdf, text/plain');
+  const res = await fetch(getGetFileUrl(id), {
+    ...options,
+    method: 'GET',
+    headers: requestHeaders,
+  });
+  const data = await parseBody(res);
+  const contentType = res.headers.get('Content-Type')?.split(';')[0].trim().toLowerCase();
+  return { status: res.status, contentType, data, headers: res.headers } as GetFileResponse & { headers: Headers };
+};
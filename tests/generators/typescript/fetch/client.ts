@@ -0,0 +1,159 @@
+/**
+ * Generated by rustval. Do not edit manually.
+ * Petstore
+ * OpenAPI spec version: 1.0
+ */
+
+import type { Error_, Pet, Photo } from './model';
+
+const appendQueryParam = (
+  query: URLSearchParams,
+  name: string,
+  value: unknown,
+  style: 'form' | 'spaceDelimited' | 'pipeDelimited' | 'deepObject',
+  explode: boolean,
+): void => {
+  if (value === undefined) {
+    return;
+  }
+  if (Array.isArray(value)) {
+    if (explode) {
+      value.forEach((item) => query.append(name, String(item)));
+      return;
+    }
+    const separator = style === 'spaceDelimited' ? ' ' : style === 'pipeDelimited' ? '|' : ',';
+    query.append(name, value.map(String).join(separator));
+    return;
+  }
+  if (value !== null && typeof value === 'object') {
+    const entries = Object.entries(value).filter(([, item]) => item !== undefined);
+    if (style === 'deepObject') {
+      entries.forEach(([key, item]) => query.append(`${name}[${key}]`, String(item)));
+    } else if (explode) {
+      entries.forEach(([key, item]) => query.append(key, String(item)));
+    } else {
+      query.append(name, entries.flat().map(String).join(','));
+    }
+    return;
+  }
+  query.append(name, String(value));
+};
+
+const setHeaders = (target: Headers, values: object | undefined): void => {
+  Object.entries(values ?? {}).forEach(([name, value]) => {
+    if (value !== undefined) {
+      target.set(name, Array.isArray(value) ? value.join(',') : String(value));
+    }
+  });
+};
+
+const toFormData = (body: object, contentTypes: Record<string, string> = {}): FormData => {
+  const formData = new FormData();
+  Object.entries(body).forEach(([name, value]) => {
+    if (value === undefined) {
+      return;
+    }
+    const contentType = contentTypes[name];
+    // A JSON part carries the whole value, otherwise every item is a part
+    const items = Array.isArray(value) && !/json/i.test(contentType ?? '') ? value : [value];
+    items.forEach((item) => {
+      if (item instanceof Blob) {
+        formData.append(name, contentType && !item.type ? new Blob([item], { type: contentType }) : item);
+        return;
+      }
+      const text = item !== null && typeof item === 'object' ? JSON.stringify(item) : String(item);
+      formData.append(name, contentType ? new Blob([text], { type: contentType }) : text);
+    });
+  });
+  return formData;
+};
+
+const parseBody = async (res: Response): Promise<unknown> => {
+  if ([204, 205, 304].includes(res.status)) {
+    return undefined;
+  }
+  const contentType = res.headers.get('Content-Type') ?? '';
+  if (/json/i.test(contentType)) {
+    const text = await res.text();
+    return text ? JSON.parse(text) : undefined;
+  }
+  if (contentType.startsWith('text/')) {
+    return res.text();
+  }
+  return res.blob();
+};
+
+export type ListPetsParams = {
+  limit?: number;
+  tags?: string[];
+};
+
+export type ListPetsHeaders = {
+  'X-Request-Id': string;
+};
+
+export type ListPetsResponse = { status: 200; data: Pet[] } | { status: number; data: Error_ };
+
+export const getListPetsUrl = (params?: ListPetsParams) => {
+  const query = new URLSearchParams();
+  appendQueryParam(query, 'limit', params?.limit, 'form', true);
+  appendQueryParam(query, 'tags', params?.tags, 'pipeDelimited', false);
+  const search = query.toString();
+  return `/pets${search ? `?${search}` : ''}`;
+};
+
+/**
+ * List all pets
+ */
+export const listPets = async (params: ListPetsParams | undefined, headers: ListPetsHeaders, options?: RequestInit): Promise<ListPetsResponse & { headers: Headers }> => {
+  const requestHeaders = new Headers(options?.headers);
+  setHeaders(requestHeaders, headers);
+  const res = await fetch(getListPetsUrl(params), {
+    ...options,
+    method: 'GET',
+    headers: requestHeaders,
+  });
+  const data = await parseBody(res);
+  return { status: res.status, data, headers: res.headers } as ListPetsResponse & { headers: Headers };
+};
+
+export interface CreatePetBody {
+  name: string;
+}
+
+export type CreatePetResponse = { status: 201; data: void };
+
+export const getCreatePetUrl = () => {
+  return `/pets`;
+};
+
+export const createPet = async (body: CreatePetBody, options?: RequestInit): Promise<CreatePetResponse & { headers: Headers }> => {
+  const requestHeaders = new Headers(options?.headers);
+  requestHeaders.set('Content-Type', 'application/json');
+  const res = await fetch(getCreatePetUrl(), {
+    ...options,
+    method: 'POST',
+    headers: requestHeaders,
+    body: JSON.stringify(body),
+  });
+  const data = await parseBody(res);
+  return { status: res.status, data, headers: res.headers } as CreatePetResponse & { headers: Headers };
+};
+
+export type UploadPhotoResponse = { status: 204; data: void };
+
+export const getUploadPhotoUrl = (petId: string) => {
+  return `/pets/${encodeURIComponent(String(petId))}/photo`;
+};
+
+export const uploadPhoto = async (petId: string, body?: Photo, options?: RequestInit): Promise<UploadPhotoResponse & { headers: Headers }> => {
+  const requestHeaders = new Headers(options?.headers);
+  const res = await fetch(getUploadPhotoUrl(petId), {
+    ...options,
+    method: 'PUT',
+    headers: requestHeaders,
+    body: body === undefined ? undefined : toFormData(body, { file: 'image/png' }),
+  });
+  const data = await parseBody(res);
+  return { status: res.status, data, headers: res.headers } as UploadPhotoResponse & { headers: Headers };
+};
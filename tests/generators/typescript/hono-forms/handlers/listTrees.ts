@@ -0,0 +1,7 @@
+// Scaffolded by rustval. This file is yours to edit; regeneration leaves it alone.
+
+import type { ListTreesHandler } from '../server';
+
+export const listTrees: ListTreesHandler = async (_context) => {
+  throw new Error('listTrees is not implemented');
+};
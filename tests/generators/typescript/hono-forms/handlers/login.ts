@@ -0,0 +1,7 @@
+// Scaffolded by rustval. This file is yours to edit; regeneration leaves it alone.
+
+import type { LoginHandler } from '../server';
+
+export const login: LoginHandler = async (_context) => {
+  throw new Error('login is not implemented');
+};
@@ -0,0 +1,7 @@
+// Scaffolded by rustval. This file is yours to edit; regeneration leaves it alone.
+
+import type { UploadHandler } from '../server';
+
+export const upload: UploadHandler = async (_context) => {
+  throw new Error('upload is not implemented');
+};
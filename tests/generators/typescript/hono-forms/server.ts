@@ -0,0 +1,287 @@
+/**
+ * Generated by rustval. Do not edit manually.
+ * Forms
+ * OpenAPI spec version: 1.0
+ */
+
+import { Hono } from 'hono';
+import type { Context, MiddlewareHandler } from 'hono';
+import type { ContentfulStatusCode, StatusCode } from 'hono/utils/http-status';
+import { z } from 'zod';
+import type { Meta } from './model';
+import { listTrees } from './handlers/listTrees';
+import { upload } from './handlers/upload';
+import { login } from './handlers/login';
+
+/** The parts of a request an operation validates */
+interface RequestSchemas {
+  params?: z.ZodTypeAny;
+  query?: z.AnyZodObject;
+  headers?: z.AnyZodObject;
+  body?: z.ZodTypeAny;
+  /** Query parameters in the `deepObject` style, sent as `name[key]=value` */
+  deepObjects?: string[];
+  form?: FormFields;
+}
+
+/** The properties of a form body that are lists, and those sent as JSON */
+interface FormFields {
+  arrays: string[];
+  json: string[];
+  /** Lists sent as one value joined by a delimiter */
+  delimited?: Record<string, string>;
+  /** Objects in the `deepObject` style, sent as `name[key]` fields */
+  deepObjects?: string[];
+}
+
+/** The validated parts of a request */
+interface RequestInput {
+  params?: unknown;
+  query?: unknown;
+  headers?: unknown;
+  body?: unknown;
+}
+
+/** Access to the raw parts of a request */
+interface RequestReader {
+  params: Record<string, string>;
+  query: (name: string) => string[] | undefined;
+  header: (name: string) => string | undefined;
+  /** Every query parameter with its values */
+  entries: () => [string, unknown][];
+  body: () => Promise<unknown>;
+}
+
+type ParseResult = { success: true; input: RequestInput } | { success: false; issues: z.ZodIssue[] };
+
+/** The `name[key]` query parameters of a `deepObject` parameter as an object */
+const deepObject = (name: string, entries: [string, unknown][]): Record<string, unknown> | undefined => {
+  const prefix = `${name}[`;
+  const pairs = entries
+    .filter(([key]) => key.startsWith(prefix) && key.endsWith(']'))
+    .map(([key, value]): [string, unknown] => [key.slice(prefix.length, -1), Array.isArray(value) ? value[0] : value]);
+  return pairs.length === 0 ? undefined : Object.fromEntries(pairs);
+};
+
+const parseJson = async (value: unknown): Promise<unknown> => {
+  const text = value instanceof Blob ? await value.text() : value;
+  if (typeof text !== 'string') {
+    return text;
+  }
+  try {
+    return JSON.parse(text);
+  } catch {
+    return text;
+  }
+};
+
+/** A form body with a list for every array property, its `deepObject` fields assembled and its JSON parts parsed */
+async function readForm(body: unknown, form: FormFields | undefined): Promise<unknown> {
+  if (form === undefined || body === null || typeof body !== 'object') {
+    return body;
+  }
+  const fields: Record<string, unknown> = { ...body };
+  for (const name of form.deepObjects ?? []) {
+    if (fields[name] === undefined) {
+      fields[name] = deepObject(name, Object.entries(fields));
+    }
+  }
+  for (const name of new Set([...form.arrays, ...form.json])) {
+    const value = fields[name];
+    if (value === undefined) {
+      continue;
+    }
+    let items = Array.isArray(value) ? value : [value];
+    const separator = form.delimited?.[name];
+    if (separator !== undefined) {
+      items = items.flatMap((item) => String(item).split(separator));
+    }
+    if (form.json.includes(name)) {
+      items = await Promise.all(items.map(parseJson));
+    }
+    fields[name] = form.arrays.includes(name) ? items : items[0];
+  }
+  return fields;
+}
+
+async function parseRequest(schemas: RequestSchemas, request: RequestReader): Promise<ParseResult> {
+  const issues: z.ZodIssue[] = [];
+  const parse = (part: string, schema: z.ZodTypeAny | undefined, value: unknown) => {
+    if (schema === undefined) {
+      return undefined;
+    }
+    const result = schema.safeParse(value);
+    if (result.success) {
+      return result.data;
+    }
+    issues.push(...result.error.issues.map((issue) => ({ ...issue, path: [part, ...issue.path] })));
+    return undefined;
+  };
+  const pick = (schema: z.AnyZodObject | undefined, read: (name: string) => unknown) =>
+    Object.fromEntries(Object.keys(schema?.shape ?? {}).map((name) => [name, read(name)]));
+  const query = (name: string) =>
+    schemas.deepObjects?.includes(name) ? deepObject(name, request.entries()) : request.query(name);
+  const body = schemas.body === undefined ? undefined : await readForm(await request.body(), schemas.form);
+  const input = {
+    params: parse('params', schemas.params, request.params),
+    query: parse('query', schemas.query, pick(schemas.query, query)),
+    headers: parse('headers', schemas.headers, pick(schemas.headers, request.header)),
+    body: parse('body', schemas.body, body),
+  };
+  return issues.length === 0 ? { success: true, input } : { success: false, issues };
+}
+
+const first = (value: unknown) => (Array.isArray(value) ? value[0] : value);
+
+export type ServerEnv = { Variables: { input: RequestInput } };
+
+const validate =
+  (schemas: RequestSchemas): MiddlewareHandler<ServerEnv> =>
+  async (c, next) => {
+    const result = await parseRequest(schemas, {
+      params: c.req.param() as Record<string, string>,
+      query: (name) => c.req.queries(name),
+      header: (name) => c.req.header(name),
+      entries: () => Object.entries(c.req.queries()),
+      body: () => readBody(c),
+    });
+    if (!result.success) {
+      return c.json({ message: 'Invalid request', issues: result.issues }, 400);
+    }
+    c.set('input', result.input);
+    await next();
+  };
+
+async function readBody(c: Context<ServerEnv>): Promise<unknown> {
+  const type = c.req.header('Content-Type') ?? '';
+  if (type.startsWith('multipart/form-data') || type.startsWith('application/x-www-form-urlencoded')) {
+    return c.req.parseBody({ all: true });
+  }
+  if (type.startsWith('text/')) {
+    return c.req.text();
+  }
+  if (type !== '' && !/[/+]json\b/.test(type)) {
+    return c.req.blob();
+  }
+  const text = await c.req.text();
+  try {
+    return text === '' ? undefined : JSON.parse(text);
+  } catch {
+    return text;
+  }
+}
+
+function send(
+  c: Context<ServerEnv>,
+  response: { status: number; contentType?: string; data: unknown },
+): Response {
+  if (response.data === undefined) {
+    return c.body(null, response.status as StatusCode);
+  }
+  if (response.contentType !== undefined && !/json/i.test(response.contentType)) {
+    return new Response(response.data as BodyInit, {
+      status: response.status,
+      headers: { 'Content-Type': response.contentType },
+    });
+  }
+  return c.json(response.data, response.status as ContentfulStatusCode);
+}
+
+export const MetaSchema = z.object({
+  title: z.string().optional(),
+});
+
+export type ListTreesParams = {
+  filter?: {
+    depth?: number;
+  };
+};
+
+export type ListTreesResponse = { status: 204; data: void };
+
+export interface ListTreesContext {
+  query: ListTreesParams;
+  c: Context<ServerEnv>;
+}
+
+export type ListTreesHandler = (context: ListTreesContext) => ListTreesResponse | Promise<ListTreesResponse>;
+
+const listTreesSchemas: RequestSchemas = {
+  query: z.object({
+    filter: z.preprocess(first, z.object({
+      depth: z.coerce.number().pipe(z.number().int()).optional(),
+    }).optional()),
+  }),
+  deepObjects: ['filter'],
+};
+
+export interface UploadBody {
+  files?: Blob[];
+  meta?: Meta;
+  labels?: string[];
+  count?: number;
+}
+
+export type UploadResponse = { status: 204; data: void };
+
+export interface UploadContext {
+  body: UploadBody;
+  c: Context<ServerEnv>;
+}
+
+export type UploadHandler = (context: UploadContext) => UploadResponse | Promise<UploadResponse>;
+
+const uploadSchemas: RequestSchemas = {
+  body: z.object({
+    files: z.array(z.instanceof(Blob)).optional(),
+    meta: MetaSchema.optional(),
+    labels: z.array(z.string()).optional(),
+    count: z.coerce.number().pipe(z.number().int()).optional(),
+  }),
+  form: { arrays: ['files'], json: ['meta', 'labels'] },
+};
+
+export interface LoginBody {
+  scopes?: string[];
+  ids?: number[];
+  filter?: {
+    depth?: number;
+  };
+}
+
+export type LoginResponse = { status: 204; data: void };
+
+export interface LoginContext {
+  body: LoginBody | undefined;
+  c: Context<ServerEnv>;
+}
+
+export type LoginHandler = (context: LoginContext) => LoginResponse | Promise<LoginResponse>;
+
+const loginSchemas: RequestSchemas = {
+  body: z.object({
+    scopes: z.array(z.string()).optional(),
+    ids: z.array(z.coerce.number().pipe(z.number().int())).pipe(z.array(z.number().int())).optional(),
+    filter: z.object({
+      depth: z.coerce.number().pipe(z.number().int()).optional(),
+    }).optional(),
+  }).optional(),
+  form: { arrays: ['scopes', 'ids'], json: [], delimited: { scopes: ' ', ids: '|' }, deepObjects: ['filter'] },
+};
+
+export function createApp(): Hono<ServerEnv> {
+  const app = new Hono<ServerEnv>();
+  app.get('/trees', validate(listTreesSchemas), async (c) => {
+    const input = c.get('input') as Omit<ListTreesContext, 'c'>;
+    return send(c, await listTrees({ ...input, c }));
+  });
+  app.post('/upload', validate(uploadSchemas), async (c) => {
+    const input = c.get('input') as Omit<UploadContext, 'c'>;
+    return send(c, await upload({ ...input, c }));
+  });
+  app.post('/login', validate(loginSchemas), async (c) => {
+    const input = c.get('input') as Omit<LoginContext, 'c'>;
+    return send(c, await login({ ...input, c }));
+  });
+  return app;
+}
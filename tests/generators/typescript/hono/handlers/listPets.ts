@@ -0,0 +1,10 @@
+// Scaffolded by rustval. This file is yours to edit; regeneration leaves it alone.
+
+import type { ListPetsHandler } from '../server';
+
+/**
+ * List all pets
+ */
+export const listPets: ListPetsHandler = async (_context) => {
+  throw new Error('listPets is not implemented');
+};
@@ -0,0 +1,7 @@
+// Scaffolded by rustval. This file is yours to edit; regeneration leaves it alone.
+
+import type { UploadPhotoHandler } from '../server';
+
+export const uploadPhoto: UploadPhotoHandler = async (_context) => {
+  throw new Error('uploadPhoto is not implemented');
+};
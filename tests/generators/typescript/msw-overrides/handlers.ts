@@ -0,0 +1,23 @@
+/**
+ * Generated by rustval. Do not edit manually.
+ * Tree
+ * OpenAPI spec version: 1.0
+ */
+
+import { faker } from '@faker-js/faker';
+import { HttpResponse, http } from 'msw';
+import type { Node } from './model';
+
+export const getNodeMock = (overrides: Partial<Node> = {}, depth = 0): Node => ({
+  id: faker.string.uuid(),
+  kind: faker.helpers.arrayElement(['leaf', 'branch'] as const),
+  created: faker.date.past().toISOString(),
+  weight: faker.helpers.arrayElement([faker.number.int({ min: 0, max: 99 }), undefined]),
+  label: faker.helpers.arrayElement([faker.helpers.arrayElement([faker.string.alpha({ length: { min: 5, max: 5 } }), null]), undefined]),
+  owner: 'admin',
+  children: depth < 3 ? faker.helpers.arrayElement([Array.from({ length: faker.number.int({ min: 1, max: 3 }) }, () => getNodeMock({}, depth + 1)), undefined]) : undefined,
+  ...overrides,
+});
+
+export const handlers = [
+];
@@ -0,0 +1,48 @@
+/**
+ * Generated by rustval. Do not edit manually.
+ * Zoo
+ * OpenAPI spec version: 1.0
+ */
+
+import { faker } from '@faker-js/faker';
+import { HttpResponse, http } from 'msw';
+import type { Animal, Cat, Dog } from './model';
+
+export const getAnimalMock = (depth = 0): Animal => faker.helpers.arrayElement([{ ...getCatMock(), kind: 'cat' as const }, { ...getDogMock(), kind: 'Dog' as const }]);
+
+export const getCatMock = (overrides: Partial<Cat> = {}): Cat => ({
+  kind: faker.helpers.arrayElement([faker.string.alpha({ length: { min: 10, max: 20 } }), undefined]),
+  ...overrides,
+});
+
+export const getDogMock = (overrides: Partial<Dog> = {}): Dog => ({
+  kind: faker.helpers.arrayElement([faker.string.alpha({ length: { min: 10, max: 20 } }), undefined]),
+  ...overrides,
+});
+
+export const getLoginResponseMock = (): {
+  token?: string;
+} => ({
+  token: faker.helpers.arrayElement([faker.string.uuid(), undefined]),
+});
+
+export const getLoginMockHandler = (overrideResponse?: {
+  token?: string;
+}) => {
+  return http.post('*/session', () => {
+    return HttpResponse.json(overrideResponse ?? getLoginResponseMock(), { status: 200 });
+  });
+};
+
+export const getGetAnimalResponseMock = (): Animal => getAnimalMock(1);
+
+export const getGetAnimalMockHandler = (overrideResponse?: Animal) => {
+  return http.get('*/animals/:id.json', () => {
+    return HttpResponse.json(overrideResponse ?? getGetAnimalResponseMock(), { status: 200 });
+  });
+};
+
+export const handlers = [
+  getLoginMockHandler(),
+  getGetAnimalMockHandler(),
+];
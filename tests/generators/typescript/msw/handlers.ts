@@ -0,0 +1,50 @@
+/**
+ * Generated by rustval. Do not edit manually.
+ * Petstore
+ * OpenAPI spec version: 1.0
+ */
+
+import { faker } from '@faker-js/faker';
+import { HttpResponse, http } from 'msw';
+import type { Error_, Pet, Photo } from './model';
+
+export const getPetMock = (overrides: Partial<Pet> = {}): Pet => ({
+  name: faker.helpers.arrayElement([faker.string.alpha({ length: { min: 10, max: 20 } }), undefined]),
+  ...overrides,
+});
+
+export const getPhotoMock = (overrides: Partial<Photo> = {}): Photo => ({
+  file: faker.helpers.arrayElement([new Blob([faker.string.alpha(10)]), undefined]),
+  ...overrides,
+});
+
+export const getErrorMock = (overrides: Partial<Error_> = {}): Error_ => ({
+  message: faker.helpers.arrayElement([faker.string.alpha({ length: { min: 10, max: 20 } }), undefined]),
+  ...overrides,
+});
+
+export const getListPetsResponseMock = (): Pet[] => Array.from({ length: faker.number.int({ min: 1, max: 10 }) }, () => getPetMock());
+
+export const getListPetsMockHandler = (overrideResponse?: Pet[]) => {
+  return http.get('*/pets', () => {
+    return HttpResponse.json(overrideResponse ?? getListPetsResponseMock(), { status: 200 });
+  });
+};
+
+export const getCreatePetMockHandler = () => {
+  return http.post('*/pets', () => {
+    return new HttpResponse(null, { status: 201 });
+  });
+};
+
+export const getUploadPhotoMockHandler = () => {
+  return http.put('*/pets/:petId/photo', () => {
+    return new HttpResponse(null, { status: 204 });
+  });
+};
+
+export const handlers = [
+  getListPetsMockHandler(),
+  getCreatePetMockHandler(),
+  getUploadPhotoMockHandler(),
+];
@@ -0,0 +1,30 @@
+/**
+ * Generated by rustval. Do not edit manually.
+ * Shapes
+ * OpenAPI spec version: 1.0
+ */
+
+import { z } from 'zod';
+import type { Branch, Tree } from './model';
+
+export const CircleSchema = z.object({
+  type: z.string(),
+});
+
+export const LeafSchema = z.object({
+  type: z.string().optional(),
+});
+
+export const BranchSchema: z.ZodType<Branch> = z.object({
+  type: z.string().optional(),
+  children: z.array(z.lazy(() => TreeSchema)).optional(),
+});
+
+export const TreeSchema: z.ZodType<Tree> = z.union([LeafSchema.and(z.object({ type: z.literal('Leaf') })), z.lazy(() => BranchSchema).and(z.object({ type: z.literal('Branch') }))]);
+
+export const SquareSchema = z.object({
+  type: z.string(),
+  inner: z.lazy(() => TreeSchema).optional(),
+});
+
+export const ShapeSchema = z.discriminatedUnion('type', [CircleSchema.extend({ type: z.enum(['round', 'disc']) }), SquareSchema.extend({ type: z.literal('Square') })]);
@@ -0,0 +1,40 @@
+/**
+ * Generated by rustval. Do not edit manually.
+ * Petstore
+ * OpenAPI spec version: 1.0
+ */
+
+import { z } from 'zod';
+
+export const PetSchema = z.object({
+  name: z.string().optional(),
+});
+
+export const PhotoSchema = z.object({
+  file: z.instanceof(Blob).optional(),
+});
+
+export const ErrorSchema = z.object({
+  message: z.string().optional(),
+});
+
+export const listPetsQueryParams = z.object({
+  limit: z.number().int().optional(),
+  tags: z.array(z.string()).optional(),
+});
+
+export const listPetsHeader = z.object({
+  'X-Request-Id': z.string(),
+});
+
+export const listPetsResponse = z.array(PetSchema);
+
+export const createPetBody = z.object({
+  name: z.string(),
+});
+
+export const uploadPhotoParams = z.object({
+  petId: z.string(),
+});
+
+export const uploadPhotoBody = PhotoSchema;
@@ -0,0 +1,35 @@
+/**
+ * Generated by rustval. Do not edit manually.
+ * Zoo
+ * OpenAPI spec version: 1.0
+ */
+
+import { z } from 'zod';
+import type { Node } from './model';
+
+export const CatSchema = z.object({
+  kind: z.literal('cat'),
+  name: z.string().min(1).max(50).regex(new RegExp('^[A-Z]')),
+  email: z.string().email().nullable().optional(),
+});
+
+export const DogSchema = z.object({
+  kind: z.literal('dog'),
+  tags: z.array(z.enum(['good', 'loud'])).max(3).optional(),
+});
+
+export const AnimalSchema = z.discriminatedUnion('kind', [CatSchema.extend({ kind: z.literal('cat') }), DogSchema.extend({ kind: z.literal('dog') })]);
+
+export const NodeSchema: z.ZodType<Node> = z.object({
+  children: z.array(z.lazy(() => NodeSchema)).optional(),
+});
+
+export const getAnimalParams = z.object({
+  id: z.string().uuid(),
+});
+
+export const getAnimalQueryParams = z.object({
+  depth: z.number().int().min(0).max(10).optional(),
+});
+
+export const getAnimalResponse = AnimalSchema;